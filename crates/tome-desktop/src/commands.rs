@@ -463,6 +463,7 @@ pub async fn start_sync(
         let opts = tome::SyncOptions {
             dry_run: false,
             force: false,
+            adopt: false,
             // no_triage: the GUI's triage panel lands in 27-02; until then
             // we run with triage disabled to match the watcher's silent-
             // refetch posture (no interactive prompts in the GUI flow).
@@ -477,6 +478,18 @@ pub async fn start_sync(
             machine_path: &machine_path,
             machine_prefs: &machine_prefs,
             start_stage: None,
+            dump_plan_on_error: false,
+            no_cache: false,
+            stats: false,
+            only: &[],
+            exclude_sources: &[],
+            tags: &[],
+            tag_mode: Default::default(),
+            prune_disabled: false,
+            targets_only: false,
+            parallel: 1,
+            json: false,
+            library_git_commit: None,
         };
         // Plan 27-05: sync_with_outcome wraps sync() with a stage tracker
         // so the returned SyncOutcome carries the failed_stage + (future)
@@ -553,6 +566,7 @@ pub async fn retry_sync_from(
         let opts = tome::SyncOptions {
             dry_run: false,
             force: false,
+            adopt: false,
             no_triage: true,
             no_input: true,
             no_install: false,
@@ -561,6 +575,18 @@ pub async fn retry_sync_from(
             machine_path: &machine_path,
             machine_prefs: &machine_prefs,
             start_stage: Some(stage),
+            dump_plan_on_error: false,
+            no_cache: false,
+            stats: false,
+            only: &[],
+            exclude_sources: &[],
+            tags: &[],
+            tag_mode: Default::default(),
+            prune_disabled: false,
+            targets_only: false,
+            parallel: 1,
+            json: false,
+            library_git_commit: None,
         };
         tome::sync_with_outcome(&config, &paths, opts, &sink, &cancel)
     });
@@ -648,6 +674,7 @@ pub async fn retry_failed_items(
         let opts = tome::SyncOptions {
             dry_run: false,
             force: false,
+            adopt: false,
             no_triage: true,
             no_input: true,
             no_install: false,
@@ -656,6 +683,18 @@ pub async fn retry_failed_items(
             machine_path: &machine_path,
             machine_prefs: &machine_prefs,
             start_stage: None,
+            dump_plan_on_error: false,
+            no_cache: false,
+            stats: false,
+            only: &[],
+            exclude_sources: &[],
+            tags: &[],
+            tag_mode: Default::default(),
+            prune_disabled: false,
+            targets_only: false,
+            parallel: 1,
+            json: false,
+            library_git_commit: None,
         };
         tome::retry_partial_failures(&config, &paths, opts, &domain_failures, &sink, &cancel)
     });
@@ -593,9 +593,10 @@ export type ErrorCode =
  *  content-aware enum (variants carry the identifying data inline) avoids the
  *  hash-collision class hash-style IDs would invite (T-26-05-03).
  * 
- *  Variants align 1:1 with the 4 [`RepairKind`] auto-fix arms plus 2
- *  informational categories ([`Self::UnparsableFrontmatter`],
- *  [`Self::DivergingTarget`]) the UI surfaces with manual remediation hints.
+ *  Variants align 1:1 with the 5 [`RepairKind`] auto-fix arms plus the
+ *  informational [`Self::UnparsableFrontmatter`] category the UI surfaces
+ *  with a manual remediation hint. [`Self::DivergingTarget`] is only
+ *  informational when its `.tome-bak` backup slot is already taken.
  * 
  *  JSON wire-shape: `{ "kind": "library_stale_manifest", "skill": "name" }` etc.
  * 
@@ -627,9 +628,9 @@ export type FindingId =
  */
 { kind: "unparsable_frontmatter"; skill: SkillName } | 
 /**
- *  Real directory in a distribution directory whose content diverges from
- *  the matching library skill (informational; user must reconcile
- *  manually).
+ *  Real file or directory in a distribution directory that blocks the link
+ *  to a same-named library skill (a file, or a directory whose content
+ *  diverges). Auto-fixable by moving it aside to `<name>.tome-bak`.
  */
 { kind: "diverging_target"; directory: DirectoryName; path: string };
 
@@ -639,13 +640,19 @@ export type FindingId =
  *  promoted regardless of source field per D-CAT-1.
  * 
  *  JSON serialisation is snake_case (`"library"`, `"directory"`,
- *  `"config"`, `"foreign_symlink"`), matching the project convention.
+ *  `"config"`, `"foreign_symlink"`, `"conflict"`), matching the project
+ *  convention.
  * 
  *  Per POLISH-04: `ALL` array + compile-time exhaustiveness sentinel
  *  keep every variant pinned. Adding a variant without updating `ALL`
  *  is a `cargo check` failure.
  */
-export type IssueCategory = "library" | "directory" | "config" | "foreign_symlink";
+export type IssueCategory = "library" | "directory" | "config" | "foreign_symlink" | 
+/**
+ *  The same skill name is discovered in more than one directory; one
+ *  copy shadows the other. Never auto-repairable.
+ */
+"conflict";
 
 /**  Severity of a diagnostic issue. */
 export type IssueSeverity = 
@@ -922,7 +929,16 @@ export type RepairKind =
  *  content (should be a symlink)". Diverging content stays a
  *  no-repair Warning (the user must reconcile).
  */
-"consolidate_target_real_dir_to_symlink";
+"consolidate_target_real_dir_to_symlink" | 
+/**
+ *  A real file or diverging directory in a distribution dir that
+ *  blocks the link to a same-named library skill (the entry sync
+ *  reports as "skipped (path conflict)"). Repair moves it aside to
+ *  `<name>.tome-bak` so the next sync can create the link; nothing
+ *  is deleted, and an existing backup is never overwritten. Emit
+ *  site: `check_distribution_dir` "blocks the library link".
+ */
+"back_up_target_collision";
 
 /**
  *  GUI-facing aggregate of everything a single skill exposes (Phase 26 plan
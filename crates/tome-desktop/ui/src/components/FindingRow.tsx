@@ -41,7 +41,7 @@ function getRemediationHint(finding: DoctorFinding): string {
     case "unparsable_frontmatter":
       return "Edit the file's YAML frontmatter so it parses (delimiters ---, valid keys). Then re-open Health.";
     case "diverging_target":
-      return "Re-run tome sync to consolidate, or restore the affected target from backup. Then re-open Health.";
+      return "An earlier .tome-bak backup is in the way. Move or remove it, then re-open Health.";
     default:
       // Auto-fixable variants reach this only if `repair_kind` is null
       // (shouldn't happen — the Rust side guarantees the pairing) — fall
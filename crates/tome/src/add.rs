@@ -22,7 +22,9 @@ use anyhow::{Result, bail};
 use console::style;
 use tracing::warn;
 
-use crate::config::{Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, GitRef};
+use crate::config::{
+    Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, GitRef, NameTransform,
+};
 
 /// Result of parsing a GitHub `/tree/<ref>/<subdir>` suffix off the input URL.
 ///
@@ -340,7 +342,14 @@ pub(crate) fn add(config: &mut Config, opts: AddOptions<'_>) -> Result<()> {
         role: opts.role,
         git_ref,
         subdir: final_subdir,
+        max_depth: None,
+        follow_links: false,
+        name_transform: NameTransform::None,
+        prefix: None,
+        include: None,
+        exclude: None,
         override_applied: false,
+        scan_origin: Default::default(),
     };
 
     // Echo the resolved role in the success message (Phase 20). Falls back
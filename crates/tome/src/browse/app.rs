@@ -315,6 +315,9 @@ pub struct SkillRow {
     /// `None` = Unowned (Phase 14 D-C1) — falls through to global toggle
     /// scope per D-BROWSE-1.
     pub source_directory: Option<DirectoryName>,
+    /// File the preview reads: `SKILL.md` unless `skill_file_names` in
+    /// `tome.toml` matched another name first.
+    pub skill_file: String,
 }
 
 pub struct App {
@@ -1032,20 +1035,29 @@ impl App {
     }
 
     fn refresh_preview(&mut self) {
-        let Some((name, source, skill_path)) = self.selected_row_meta() else {
+        let Some(row) = self
+            .filtered_indices
+            .get(self.selected)
+            .and_then(|&i| self.rows.get(i))
+        else {
             self.preview_title = "Preview".into();
             self.preview_content = "No matching skill.".into();
             return;
         };
 
-        self.preview_title = format!("Preview: {name}");
+        self.preview_title = format!("Preview: {}", row.name);
 
-        let skill_file = Path::new(&skill_path).join("SKILL.md");
-        let header = format!("source: {source}\npath: {skill_path}\n\n");
+        // A `cursor-rules` skill's path is the rule file itself.
+        let skill_file = if Path::new(&row.path).is_file() {
+            PathBuf::from(&row.path)
+        } else {
+            Path::new(&row.path).join(&row.skill_file)
+        };
+        let header = format!("source: {}\npath: {}\n\n", row.source, row.path);
 
         self.preview_content = match fs::read_to_string(&skill_file) {
             Ok(content) if content.trim().is_empty() => {
-                format!("{header}[SKILL.md is empty]")
+                format!("{header}[{} is empty]", row.skill_file)
             }
             Ok(content) => format!("{header}{content}"),
             Err(err) => format!("{header}[failed to read {}: {err}]", skill_file.display()),
@@ -1082,6 +1094,7 @@ mod tests {
                     managed: false,
                     synced_at: String::new(),
                     source_directory: None,
+                    skill_file: "SKILL.md".into(),
                 }
             })
             .collect();
@@ -1288,6 +1301,7 @@ mod tests {
             managed: false,
             synced_at: String::new(),
             source_directory: None,
+            skill_file: "SKILL.md".into(),
         }];
         let app = App::new(rows);
         assert!(app.preview_content.contains("[SKILL.md is empty]"));
@@ -1307,6 +1321,7 @@ mod tests {
             managed: false,
             synced_at: String::new(),
             source_directory: None,
+            skill_file: "SKILL.md".into(),
         }];
         let app = App::new(rows);
         assert!(app.preview_content.contains("[failed to read"));
@@ -1350,6 +1365,7 @@ mod tests {
             managed: false,
             synced_at: synced.to_string(),
             source_directory: None,
+            skill_file: "SKILL.md".into(),
         }
     }
 
@@ -1884,6 +1900,7 @@ mod tests {
             managed: false,
             synced_at: String::new(),
             source_directory: dir.map(|d| DirectoryName::new(d).unwrap()),
+            skill_file: "SKILL.md".into(),
         }
     }
 
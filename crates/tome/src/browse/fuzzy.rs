@@ -119,6 +119,7 @@ mod tests {
                 managed: false,
                 synced_at: String::new(),
                 source_directory: None,
+                skill_file: "SKILL.md".into(),
             },
             SkillRow {
                 name: "git-commit".into(),
@@ -127,6 +128,7 @@ mod tests {
                 managed: false,
                 synced_at: String::new(),
                 source_directory: None,
+                skill_file: "SKILL.md".into(),
             },
             SkillRow {
                 name: "rust-clippy".into(),
@@ -135,6 +137,7 @@ mod tests {
                 managed: false,
                 synced_at: String::new(),
                 source_directory: None,
+                skill_file: "SKILL.md".into(),
             },
         ]
     }
@@ -178,6 +181,7 @@ mod tests {
             managed: false,
             synced_at: String::new(),
             source_directory: None,
+            skill_file: "SKILL.md".into(),
         }];
         let results = filter_rows_with_indices("abc", &rows);
         assert_eq!(results.len(), 1);
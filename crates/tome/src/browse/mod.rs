@@ -59,6 +59,7 @@ pub fn browse(
                 managed,
                 synced_at,
                 source_directory: Some(s.source_name),
+                skill_file: s.skill_file,
             }
         })
         .collect();
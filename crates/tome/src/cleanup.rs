@@ -17,7 +17,8 @@
 //! - **Bucket C (now-in-exclude-list):** Library skills whose distribution
 //!   symlinks were just removed because the skill was added to
 //!   `machine.toml::disabled` (global) or `directories.<name>.disabled`
-//!   (per-directory). Library content is preserved; only distribution
+//!   (per-directory), or filtered out by the directory's `include`/`exclude`
+//!   lists in `tome.toml`. Library content is preserved; only distribution
 //!   symlinks change.
 //!
 //! Buckets A and B are detected here in `cleanup_library` and surfaced via
@@ -33,7 +34,7 @@
 use anyhow::{Context, Result};
 use std::collections::HashSet;
 use std::io::{IsTerminal, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tracing::warn;
 
 use crate::config::DirectoryName;
@@ -50,8 +51,12 @@ pub struct ExcludedSkill {
     pub name: SkillName,
     /// `None` = excluded globally via `machine.toml::disabled`.
     /// `Some(dir)` = excluded for a specific directory via
-    /// `directories.<dir>.disabled`.
+    /// `directories.<dir>.disabled`, or via `include`/`exclude` when
+    /// `in_config` is set.
     pub directory: Option<DirectoryName>,
+    /// True when the directory's `include`/`exclude` lists in `tome.toml`
+    /// (rather than `machine.toml`) filtered the skill out.
+    pub in_config: bool,
 }
 
 /// Which filesystem operation failed during distribution-symlink cleanup.
@@ -253,6 +258,15 @@ pub(crate) fn render_cleanup_buckets(
                         excluded.name,
                     )?;
                 }
+                Some(dir) if excluded.in_config => {
+                    writeln!(
+                        writer,
+                        "  {} {} — adjust `include`/`exclude` for `directories.{}` in tome.toml to re-distribute",
+                        excluded.name,
+                        console::style(format!("(excluded for: {})", dir)).dim(),
+                        dir,
+                    )?;
+                }
                 Some(dir) => {
                     writeln!(
                         writer,
@@ -446,17 +460,20 @@ pub fn cleanup_library(
         // reaches that point. A `warn!` here ensures the action is visible
         // in `--verbose` / `TOME_LOG=warn` traces even if the renderer
         // later errors out, so CI logs always show *something* before silent
-        // deletions in flaky-mount or transient-FS scenarios.
-        tracing::warn!(
-            "auto-removing {} library entry(s) whose source file vanished \
-             from disk (non-interactive mode): {}",
-            case2_delete.len(),
-            case2_delete
-                .iter()
-                .map(|e| e.name.as_str())
-                .collect::<Vec<_>>()
-                .join(", ")
-        );
+        // deletions in flaky-mount or transient-FS scenarios. Dry runs
+        // (including `tome diff`) delete nothing, so they stay quiet.
+        if !dry_run {
+            tracing::warn!(
+                "auto-removing {} library entry(s) whose source file vanished \
+                 from disk (non-interactive mode): {}",
+                case2_delete.len(),
+                case2_delete
+                    .iter()
+                    .map(|e| e.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
         case2_delete.iter().map(|e| e.name.clone()).collect()
     } else {
         Vec::new()
@@ -518,24 +535,42 @@ pub fn cleanup_library(
 }
 
 /// Remove stale symlinks from a target directory.
-pub fn cleanup_target(target_dir: &Path, library_dir: &Path, dry_run: bool) -> Result<usize> {
-    if !target_dir.is_dir() {
-        return Ok(0);
+///
+/// With `live_skills`, a link into the library is also stale when its
+/// library entry is not in the set, even if the entry is still on disk —
+/// e.g. a skill added to `exclude` whose library copy has not been removed.
+pub fn cleanup_target(
+    target_dir: &Path,
+    library_dir: &Path,
+    live_skills: Option<&HashSet<String>>,
+    dry_run: bool,
+) -> Result<usize> {
+    let stale = stale_target_links(target_dir, library_dir, live_skills)?;
+    if !dry_run {
+        for path in &stale {
+            std::fs::remove_file(path)
+                .with_context(|| format!("failed to remove stale symlink {}", path.display()))?;
+        }
     }
+    Ok(stale.len())
+}
 
-    let mut removed = 0;
+/// Symlinks in `target_dir` that point into `library_dir` at an entry that
+/// no longer exists, or (with `live_skills`) at an entry not in that set.
+/// When the directory has a link ledger (`link_marker`), links not listed
+/// in it are left alone. Read-only; [`cleanup_target`] removes them.
+pub(crate) fn stale_target_links(
+    target_dir: &Path,
+    library_dir: &Path,
+    live_skills: Option<&HashSet<String>>,
+) -> Result<Vec<PathBuf>> {
+    let mut stale = Vec::new();
+    if !target_dir.is_dir() {
+        return Ok(stale);
+    }
 
-    // Canonicalize library_dir so that starts_with works when library_dir itself
-    // contains a symlink component (e.g., /var -> /private/var on macOS).
-    // We keep both forms so we can match symlinks created with either path variant.
-    let canonical_library = std::fs::canonicalize(library_dir).unwrap_or_else(|e| {
-        warn!(
-            "could not canonicalize library path {}: {} — symlinks using canonical paths may not be cleaned up",
-            library_dir.display(),
-            e
-        );
-        library_dir.to_path_buf()
-    });
+    let canonical_library = canonical_library(library_dir);
+    let ledger = crate::link_marker::load(target_dir)?;
 
     let entries = std::fs::read_dir(target_dir)
         .with_context(|| format!("failed to read target dir {}", target_dir.display()))?;
@@ -545,29 +580,122 @@ pub fn cleanup_target(target_dir: &Path, library_dir: &Path, dry_run: bool) -> R
             entry.with_context(|| format!("failed to read entry in {}", target_dir.display()))?;
         let path = entry.path();
 
-        if path.is_symlink() {
+        if path.is_symlink()
+            && crate::link_marker::is_marked(ledger.as_ref(), &entry.file_name().to_string_lossy())
+        {
             let raw_target = std::fs::read_link(&path)
                 .with_context(|| format!("failed to read symlink {}", path.display()))?;
             let target = resolve_symlink_target(&path, &raw_target);
 
-            // Match against both the original and canonical library path so we correctly
-            // handle macOS /var -> /private/var symlinks and similar platform quirks.
-            let points_into_library =
-                target.starts_with(library_dir) || target.starts_with(&canonical_library);
-
-            // Remove if it points into the library dir but the library entry is gone
-            if points_into_library && !target.exists() {
-                if !dry_run {
-                    std::fs::remove_file(&path).with_context(|| {
-                        format!("failed to remove stale symlink {}", path.display())
-                    })?;
-                }
-                removed += 1;
+            let Some(in_library) = in_library(&target, library_dir, &canonical_library) else {
+                continue;
+            };
+
+            // Stale if the library entry is gone, or no longer a live skill
+            let not_live = live_skills.is_some_and(|live| {
+                in_library
+                    .components()
+                    .next()
+                    .is_some_and(|name| !live.contains(&*name.as_os_str().to_string_lossy()))
+            });
+            if !target.exists() || not_live {
+                stale.push(path);
             }
         }
     }
 
-    Ok(removed)
+    stale.sort();
+    Ok(stale)
+}
+
+/// What one entry in a distribution directory is, for
+/// `tome status --target`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum TargetEntryKind {
+    /// Symlink into the library at an entry that exists.
+    ManagedValid,
+    /// Symlink into the library at an entry that is gone.
+    ManagedStale,
+    /// Symlink pointing outside the library, or one the directory's link
+    /// ledger (`link_marker`) doesn't list.
+    External,
+    /// Real file or directory — not a symlink, so tome doesn't manage it.
+    RealFile,
+}
+
+/// Every non-hidden entry in `target_dir` with its [`TargetEntryKind`],
+/// sorted by name. Uses the same library-path matching and link ledger as
+/// [`stale_target_links`], so a link cleanup would leave alone is never
+/// counted as tome's. A missing directory yields no entries.
+pub(crate) fn classify_target_entries(
+    target_dir: &Path,
+    library_dir: &Path,
+) -> Result<Vec<(String, TargetEntryKind)>> {
+    let mut classified = Vec::new();
+    if !target_dir.is_dir() {
+        return Ok(classified);
+    }
+    let canonical_library = canonical_library(library_dir);
+    let ledger = crate::link_marker::load(target_dir)?;
+
+    for entry in std::fs::read_dir(target_dir)
+        .with_context(|| format!("failed to read target dir {}", target_dir.display()))?
+    {
+        let entry =
+            entry.with_context(|| format!("failed to read entry in {}", target_dir.display()))?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        let kind = if path.is_symlink() {
+            let raw_target = std::fs::read_link(&path)
+                .with_context(|| format!("failed to read symlink {}", path.display()))?;
+            let target = resolve_symlink_target(&path, &raw_target);
+            if in_library(&target, library_dir, &canonical_library).is_none()
+                || !crate::link_marker::is_marked(ledger.as_ref(), &name)
+            {
+                TargetEntryKind::External
+            } else if target.exists() {
+                TargetEntryKind::ManagedValid
+            } else {
+                TargetEntryKind::ManagedStale
+            }
+        } else {
+            TargetEntryKind::RealFile
+        };
+        classified.push((name, kind));
+    }
+
+    classified.sort();
+    Ok(classified)
+}
+
+/// Canonicalize `library_dir` so that prefix checks work when it contains a
+/// symlink component (e.g., /var -> /private/var on macOS). Callers match
+/// against both forms so links created with either path variant are found.
+fn canonical_library(library_dir: &Path) -> PathBuf {
+    std::fs::canonicalize(library_dir).unwrap_or_else(|e| {
+        warn!(
+            "could not canonicalize library path {}: {} — symlinks using canonical paths may not be cleaned up",
+            library_dir.display(),
+            e
+        );
+        library_dir.to_path_buf()
+    })
+}
+
+/// `target` relative to the library, if it points into it under either the
+/// configured or the canonical library path.
+fn in_library<'a>(
+    target: &'a Path,
+    library_dir: &Path,
+    canonical_library: &Path,
+) -> Option<&'a Path> {
+    target
+        .strip_prefix(library_dir)
+        .or_else(|_| target.strip_prefix(canonical_library))
+        .ok()
 }
 
 #[cfg(test)]
@@ -582,7 +710,9 @@ mod tests {
     }
 
     fn config_with_dir(name: &str) -> crate::config::Config {
-        use crate::config::{Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType};
+        use crate::config::{
+            Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, NameTransform,
+        };
         use std::collections::BTreeMap;
         let mut directories = BTreeMap::new();
         directories.insert(
@@ -593,7 +723,14 @@ mod tests {
                 role: Some(DirectoryRole::Source),
                 git_ref: None,
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
         Config {
@@ -782,7 +919,7 @@ mod tests {
         let phantom = library.path().join("deleted-skill");
         unix_fs::symlink(&phantom, target.path().join("deleted-skill")).unwrap();
 
-        let removed = cleanup_target(target.path(), library.path(), false).unwrap();
+        let removed = cleanup_target(target.path(), library.path(), None, false).unwrap();
         assert_eq!(removed, 1);
     }
 
@@ -794,7 +931,7 @@ mod tests {
         let phantom = library.path().join("deleted-skill");
         unix_fs::symlink(&phantom, target.path().join("deleted-skill")).unwrap();
 
-        let removed = cleanup_target(target.path(), library.path(), true).unwrap();
+        let removed = cleanup_target(target.path(), library.path(), None, true).unwrap();
         assert_eq!(removed, 1, "dry-run should count the stale link");
         assert!(
             target.path().join("deleted-skill").is_symlink(),
@@ -802,6 +939,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cleanup_target_removes_links_to_entries_not_live() {
+        let library = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        for name in ["kept", "excluded"] {
+            std::fs::create_dir(library.path().join(name)).unwrap();
+            unix_fs::symlink(library.path().join(name), target.path().join(name)).unwrap();
+        }
+
+        // Without a live set, links to existing entries are left alone.
+        assert_eq!(
+            cleanup_target(target.path(), library.path(), None, false).unwrap(),
+            0
+        );
+
+        let live: HashSet<String> = ["kept".to_string()].into();
+        let removed = cleanup_target(target.path(), library.path(), Some(&live), false).unwrap();
+        assert_eq!(removed, 1);
+        assert!(target.path().join("kept").is_symlink());
+        assert!(!target.path().join("excluded").is_symlink());
+        assert!(library.path().join("excluded").is_dir());
+    }
+
+    #[test]
+    fn cleanup_target_only_removes_links_in_ledger() {
+        let library = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        for name in ["marked", "unmarked"] {
+            unix_fs::symlink(library.path().join(name), target.path().join(name)).unwrap();
+        }
+
+        // Without a ledger, both stale links count as tome's.
+        assert_eq!(
+            stale_target_links(target.path(), library.path(), None)
+                .unwrap()
+                .len(),
+            2
+        );
+
+        std::fs::write(
+            target.path().join(crate::link_marker::LINK_MARKER_FILENAME),
+            "marked\n",
+        )
+        .unwrap();
+        let removed = cleanup_target(target.path(), library.path(), None, false).unwrap();
+        assert_eq!(removed, 1);
+        assert!(!target.path().join("marked").is_symlink());
+        assert!(
+            target.path().join("unmarked").is_symlink(),
+            "a link missing from the ledger is not tome's"
+        );
+    }
+
     #[test]
     fn cleanup_target_preserves_external_symlinks() {
         let library = TempDir::new().unwrap();
@@ -814,12 +1004,59 @@ mod tests {
         // Broken symlink pointing OUTSIDE library dir (should be preserved)
         unix_fs::symlink("/some/external/path", target.path().join("external-link")).unwrap();
 
-        let removed = cleanup_target(target.path(), library.path(), false).unwrap();
+        let removed = cleanup_target(target.path(), library.path(), None, false).unwrap();
         assert_eq!(removed, 1);
         assert!(!target.path().join("library-link").exists());
         assert!(target.path().join("external-link").is_symlink());
     }
 
+    #[test]
+    fn classify_target_entries_sorts_each_kind() {
+        let library = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        std::fs::create_dir(library.path().join("live")).unwrap();
+        unix_fs::symlink(library.path().join("live"), target.path().join("live")).unwrap();
+        unix_fs::symlink(library.path().join("gone"), target.path().join("gone")).unwrap();
+        unix_fs::symlink("/some/external/path", target.path().join("external")).unwrap();
+        std::fs::create_dir(target.path().join("hand-made")).unwrap();
+        std::fs::write(target.path().join(".DS_Store"), "").unwrap();
+
+        let classified = classify_target_entries(target.path(), library.path()).unwrap();
+        assert_eq!(
+            classified,
+            vec![
+                ("external".to_string(), TargetEntryKind::External),
+                ("gone".to_string(), TargetEntryKind::ManagedStale),
+                ("hand-made".to_string(), TargetEntryKind::RealFile),
+                ("live".to_string(), TargetEntryKind::ManagedValid),
+            ]
+        );
+    }
+
+    #[test]
+    fn classify_target_entries_treats_unrecorded_links_as_external() {
+        let library = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        for name in ["ours", "theirs"] {
+            std::fs::create_dir(library.path().join(name)).unwrap();
+            unix_fs::symlink(library.path().join(name), target.path().join(name)).unwrap();
+        }
+        std::fs::write(
+            target.path().join(crate::link_marker::LINK_MARKER_FILENAME),
+            "ours\n",
+        )
+        .unwrap();
+
+        let classified = classify_target_entries(target.path(), library.path()).unwrap();
+        assert_eq!(
+            classified,
+            vec![
+                ("ours".to_string(), TargetEntryKind::ManagedValid),
+                ("theirs".to_string(), TargetEntryKind::External),
+            ]
+        );
+    }
+
     #[test]
     fn cleanup_dry_run_preserves_managed_symlink() {
         let library = TempDir::new().unwrap();
@@ -1250,10 +1487,17 @@ mod tests {
             ExcludedSkill {
                 name: crate::discover::SkillName::new("quux").unwrap(),
                 directory: None,
+                in_config: false,
             },
             ExcludedSkill {
                 name: crate::discover::SkillName::new("corge").unwrap(),
                 directory: Some(crate::config::DirectoryName::new("my-dir").unwrap()),
+                in_config: false,
+            },
+            ExcludedSkill {
+                name: crate::discover::SkillName::new("grault").unwrap(),
+                directory: Some(crate::config::DirectoryName::new("codex").unwrap()),
+                in_config: true,
             },
         ];
 
@@ -1281,6 +1525,10 @@ mod tests {
             rendered.contains("machine.toml::directories.my-dir.disabled"),
             "Bucket C per-directory hint must name the per-dir path:\n{rendered}"
         );
+        assert!(
+            rendered.contains("`include`/`exclude` for `directories.codex` in tome.toml"),
+            "Bucket C config-filter hint must point at tome.toml:\n{rendered}"
+        );
     }
 
     #[test]
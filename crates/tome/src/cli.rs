@@ -3,6 +3,8 @@
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
+use crate::list::TagMode;
+
 /// Verbosity level resolved from `--verbose` / `--quiet` flags.
 ///
 /// Per HARD-07: collapses what was previously `pub verbose: bool` +
@@ -43,7 +45,7 @@ impl LogLevel {
     /// is unset.
     pub fn directive(self) -> &'static str {
         match self {
-            Self::Quiet => "warn",
+            Self::Quiet => "error",
             Self::Normal => "info",
             Self::Verbose => "debug",
         }
@@ -85,6 +87,14 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub tome_home: Option<PathBuf>,
 
+    /// Use this library directory instead of `library_dir` for this run.
+    ///
+    /// The config file is not changed. The manifest and lockfile still live
+    /// in tome home, so switching libraries back and forth can leave links
+    /// in targets pointing at the other library.
+    #[arg(long, global = true, value_name = "DIR")]
+    pub library: Option<PathBuf>,
+
     /// Preview changes without modifying filesystem
     #[arg(long, global = true)]
     pub dry_run: bool,
@@ -112,6 +122,13 @@ pub struct Cli {
     /// this implies `--no-triage`.
     #[arg(long, global = true)]
     pub no_input: bool,
+
+    /// Exit non-zero if the command emitted any warning.
+    ///
+    /// The command still runs to completion; the exit status is decided at
+    /// the end. Warnings are counted even under `--quiet`, which hides them.
+    #[arg(long, global = true)]
+    pub warnings_as_errors: bool,
 }
 
 impl Cli {
@@ -131,12 +148,29 @@ impl Cli {
     }
 }
 
-#[derive(Debug, Clone, Copy, clap::ValueEnum)]
-pub enum LintFormat {
+/// Output format for commands with a machine-readable mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
     Text,
     Json,
 }
 
+/// Issue category `tome doctor --fix` repairs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DoctorFix {
+    /// Manifest entries whose library directory is gone, and broken
+    /// symlinks in the library
+    BrokenLibraryLinks,
+    /// Symlinks in distribution directories pointing at missing library skills
+    StaleTargetLinks,
+    /// Real directories in distribution directories that match a library
+    /// skill, replaced with symlinks
+    TargetCopies,
+    /// Real files or diverging directories blocking a library link in a
+    /// distribution directory, moved aside to `<name>.tome-bak`
+    TargetCollisions,
+}
+
 #[derive(Subcommand)]
 pub enum Command {
     /// Add a git skill repository
@@ -195,9 +229,31 @@ pub enum Command {
 
     /// Interactive wizard to configure directories
     #[command(
-        after_help = "Examples:\n  tome init\n  tome init --dry-run\n  tome init --no-input\n  tome init --dry-run --no-input"
+        long_about = "Interactive wizard to configure directories.\n\n\
+                      With --yes the wizard is skipped: the config is built from \
+                      --source / --target / --library alone, validated, saved, and \
+                      synced, with no prompts and no auto-discovery. An existing \
+                      tome.toml is backed up first. Meant for provisioning scripts \
+                      and containers without a TTY.",
+        after_help = "Examples:\n  tome init\n  tome init --dry-run\n  tome init --no-input\n  tome init --dry-run --no-input\n  \
+                      tome init --yes --source local=~/skills --target claude=~/.claude/skills\n  \
+                      tome init --yes --source plugins=~/.claude/plugins:claude-plugins --library ~/lib"
     )]
-    Init,
+    Init {
+        /// Skip the wizard and build the config from flags
+        #[arg(long)]
+        yes: bool,
+        /// Discovery directory, as NAME=PATH or NAME=PATH:TYPE (repeatable).
+        /// TYPE is `directory` (default) or `claude-plugins`
+        #[arg(long = "source", value_name = "NAME=PATH[:TYPE]", requires = "yes")]
+        sources: Vec<String>,
+        /// Distribution directory, as NAME=PATH (repeatable)
+        #[arg(long = "target", value_name = "NAME=PATH", requires = "yes")]
+        targets: Vec<String>,
+        /// Library location (default: <tome_home>/skills)
+        #[arg(long, value_name = "PATH", requires = "yes")]
+        library: Option<PathBuf>,
+    },
 
     /// Discover, consolidate, and distribute skills
     #[command(
@@ -207,12 +263,19 @@ pub enum Command {
                       auto_install_plugins consent flow — see docs/src/cross-machine-sync.md \
                       (or the rendered mdbook page at the same path if you have the docs \
                       built locally).",
-        after_help = "Examples:\n  tome sync\n  tome sync --dry-run\n  tome sync --force\n  tome sync --no-triage\n  tome sync --no-input\n  tome sync --no-install"
+        after_help = "Examples:\n  tome sync\n  tome sync --dry-run\n  tome sync --force\n  tome sync --force --adopt\n  tome sync --no-triage\n  tome sync --no-input\n  tome sync --no-install\n  tome sync --no-cache\n  tome sync --stats\n  tome sync --only my-skill\n  tome sync --exclude-source team-share\n  tome sync --tag rust\n  tome sync --prune-disabled\n  tome sync --check\n  tome sync --targets-only\n  tome sync --format json\n  tome sync --library-git-commit"
     )]
     Sync {
         /// Recreate all symlinks even if they appear up-to-date
         #[arg(short, long)]
         force: bool,
+        /// With --force, back up real files/directories in the way to `<name>.tome-bak` and replace them.
+        ///
+        /// Applies to unmanaged entries at a library path and to non-symlink
+        /// entries in distribution directories. An existing `.tome-bak` is
+        /// never overwritten; that collision is skipped.
+        #[arg(long, requires = "force")]
+        adopt: bool,
         /// Skip interactive triage of new/changed skills
         #[arg(long)]
         no_triage: bool,
@@ -222,35 +285,260 @@ pub enum Command {
         /// `machine.toml`. Mirrors Cargo's `--frozen` / `--locked`.
         #[arg(long)]
         no_install: bool,
+        /// On failure, write the plan-so-far to `<library>/.tome-cache/sync-plan.json`.
+        ///
+        /// Records what reconcile, discover, consolidate, and distribute had
+        /// decided before the error, for debugging partial syncs.
+        #[arg(long)]
+        dump_plan_on_error: bool,
+        /// Rescan every directory instead of reusing unchanged results.
+        ///
+        /// Sync caches each directory's discovery result and skips the scan
+        /// when nothing in it has changed; `--force` also bypasses the cache.
+        #[arg(long)]
+        no_cache: bool,
+        /// Print a per-phase timing table at the end of the run.
+        ///
+        /// Lists the wall-clock time and skill count for reconcile,
+        /// discover, consolidate, each distribution directory, cleanup, and
+        /// save — for diagnosing slow syncs.
+        #[arg(long)]
+        stats: bool,
+        /// Consolidate and distribute only the named skill (repeatable).
+        ///
+        /// Discovery still scans every directory, but only the named skills
+        /// are copied into the library and linked into targets. Library and
+        /// target cleanup are skipped so nothing else is touched, and so is
+        /// triage. Fails if a name isn't discovered.
+        #[arg(long, value_name = "SKILL")]
+        only: Vec<String>,
+        /// Leave the named source directory out of this run (repeatable).
+        ///
+        /// The directory is not resolved, scanned, or distributed to, and
+        /// its skills already in the library keep their entries and
+        /// existing links instead of being pruned. Unknown names warn.
+        #[arg(long, value_name = "NAME")]
+        exclude_source: Vec<String>,
+        /// Consolidate and distribute only skills with this tag (repeatable).
+        ///
+        /// Tags come from `metadata.tags` (or `tags`) in each SKILL.md.
+        /// Combines with `--only`. As with `--only`, library and target
+        /// cleanup and triage are skipped, so untagged skills are never
+        /// pruned by a tagged sync.
+        #[arg(long, value_name = "TAG")]
+        tag: Vec<String>,
+        /// Whether a skill needs every `--tag` (`all`) or any one (`any`)
+        #[arg(long, value_enum, default_value_t = TagMode::All, requires = "tag")]
+        tag_mode: TagMode,
+        /// Remove tome's links from directories disabled in machine.toml.
+        ///
+        /// Only symlinks pointing into the library are removed; external
+        /// links and real files are left alone. Skipped with `--only` or
+        /// `--tag`, like the rest of target cleanup.
+        #[arg(long)]
+        prune_disabled: bool,
+        /// Change nothing; exit non-zero if a sync would change anything.
+        ///
+        /// Runs the same dry-run pipeline as `tome diff`, prints what is out
+        /// of date, and fails when anything would be created, updated, or
+        /// removed. Writes nothing, not even the library directory. For CI,
+        /// like `cargo fmt --check`.
+        #[arg(long, conflicts_with_all = ["force", "only", "tag", "prune_disabled", "format"])]
+        check: bool,
+        /// Relink distribution directories from the library without rescanning sources.
+        ///
+        /// Skips reconcile, discovery, consolidation, triage, and library
+        /// cleanup; existing library entries are linked into each target and
+        /// stale links are removed. Fails if the library is empty or missing
+        /// — run a full `tome sync` first.
+        #[arg(long, conflicts_with_all = ["only", "tag", "check", "stats"])]
+        targets_only: bool,
+        /// Distribute to up to N directories at once.
+        ///
+        /// Directories are independent, so with many targets this shortens
+        /// the distribute phase; links within one directory are still made
+        /// in order. The summary lists directories in the usual order.
+        /// Default 1 (serial).
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = 1,
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+        )]
+        parallel: usize,
+        /// Summary format: `text`, or `json` for one JSON object on stdout.
+        ///
+        /// `json` turns off the progress spinner and the human-readable
+        /// summary, and prints the library, per-target, and cleanup counts
+        /// at the end even under `--quiet`.
+        #[arg(long, value_enum, default_value = "text", conflicts_with = "stats")]
+        format: OutputFormat,
+        /// Commit the library's git repository after the sync, without prompting.
+        ///
+        /// Applies when `library_dir` is a git repository, or lives inside
+        /// a git-tracked tome home. The message defaults to a summary such
+        /// as `tome sync: 2 created, 1 updated`; nothing is committed when
+        /// nothing changed. Git failures are warnings, not errors.
+        #[arg(long, value_name = "MESSAGE", num_args = 0..=1, conflicts_with_all = ["check", "targets_only"])]
+        library_git_commit: Option<Option<String>>,
     },
 
+    /// Preview, skill by skill, what `tome sync` would change
+    #[command(
+        long_about = "Preview, skill by skill, what `tome sync` would change.\n\n\
+                      Runs discover, consolidate, distribute, and cleanup in dry-run mode \
+                      and lists each affected skill under the library and every \
+                      distribution directory: `+` new, `~` changed, `-` removed. \
+                      Nothing is written. Plugin reconcile and git fetches are skipped; \
+                      git directories are read from their existing cache.",
+        after_help = "Examples:\n  tome diff\n  tome diff && tome sync"
+    )]
+    Diff,
+
+    /// Watch source directories and re-run sync whenever they change
+    #[command(
+        long_about = "Watch source directories and re-run sync whenever they change.\n\n\
+                      Runs one sync on startup, then watches every local discovery \
+                      directory (`directory` entries recursively, `claude-plugins` \
+                      entries via installed_plugins.json). Changes are debounced for \
+                      500ms so a burst of writes triggers a single sync. Triage is \
+                      skipped, as with `tome sync --no-triage`. `git` directories are \
+                      not watched. Stop with Ctrl-C.",
+        after_help = "Examples:\n  tome watch\n  tome watch --dry-run\n  tome watch --quiet"
+    )]
+    Watch,
+
     /// Show library, directories, last-sync, and health summary
-    #[command(after_help = "Examples:\n  tome status\n  tome status --json")]
+    #[command(
+        after_help = "Examples:\n  tome status\n  tome status --json\n  tome status --target claude\n  tome status --check"
+    )]
     Status {
         /// Output as JSON
         #[arg(long)]
         json: bool,
+        /// Print nothing and exit 0 when healthy; otherwise print a one-line
+        /// summary and exit 1.
+        ///
+        /// Healthy means no broken symlinks in the library or any
+        /// distribution directory, and every discovery directory exists.
+        /// Meant for cron jobs and monitoring.
+        #[arg(long, conflicts_with_all = ["json", "target"])]
+        check: bool,
+        /// Show link health for this distribution directory instead.
+        ///
+        /// Counts skills linked from the library, and lists stale links,
+        /// links pointing outside the library, and real files tome does
+        /// not manage.
+        #[arg(long, value_name = "NAME")]
+        target: Option<String>,
+        /// How many levels below each distribution directory to count links.
+        ///
+        /// The default `1` looks only at its immediate children; `2` also
+        /// counts `<dir>/<group>/<skill>` links in nested target layouts.
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = crate::doctor::DEFAULT_SCAN_DEPTH,
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..),
+            conflicts_with = "target"
+        )]
+        depth: usize,
     },
 
     /// Diagnose and repair broken symlinks or config issues
     #[command(
-        after_help = "Examples:\n  tome doctor\n  tome doctor --dry-run\n  tome doctor --json"
+        after_help = "Examples:\n  tome doctor\n  tome doctor --dry-run\n  tome doctor --json\n  tome doctor --fix stale-target-links\n  tome doctor --yes"
     )]
     Doctor {
         /// Output as JSON (skips repair)
         #[arg(long)]
         json: bool,
+        /// Repair only this category of issue, without prompting (repeatable).
+        ///
+        /// Other issues are reported but left alone, and orphan library
+        /// directories are never touched. Exits non-zero while any issue
+        /// remains.
+        #[arg(long, value_name = "KIND", conflicts_with = "json")]
+        fix: Vec<DoctorFix>,
+        /// Apply every auto-fixable repair without the confirmation prompt.
+        ///
+        /// For cron and CI, where there is no terminal to answer it. Orphan
+        /// library directories still need an interactive run. `--dry-run`
+        /// is respected.
+        #[arg(long, short, conflicts_with = "json")]
+        yes: bool,
+        /// How many levels below each distribution directory to check links.
+        ///
+        /// The default `1` looks only at its immediate children; `2` also
+        /// checks `<dir>/<group>/<skill>` links in nested target layouts.
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = crate::doctor::DEFAULT_SCAN_DEPTH,
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+        )]
+        depth: usize,
     },
 
     /// List all discovered skills with their directory
     #[command(
         alias = "ls",
-        after_help = "Examples:\n  tome list\n  tome list --json"
+        after_help = "Examples:\n  tome list\n  tome list -l\n  tome list --json\n  tome list --tag rust --tag cli --tag-mode any\n  tome list --since 2d"
     )]
     List {
         /// Output as JSON
         #[arg(long)]
         json: bool,
+        /// Add a DESCRIPTION column from each skill's frontmatter
+        #[arg(short, long, conflicts_with = "json")]
+        long: bool,
+        /// Leave the named source directory out of the listing (repeatable)
+        #[arg(long, value_name = "NAME")]
+        exclude_source: Vec<String>,
+        /// Show only skills from the named source directory (repeatable)
+        #[arg(long = "source", value_name = "NAME")]
+        sources: Vec<String>,
+        /// Show only skills with this tag (repeatable)
+        #[arg(long, value_name = "TAG")]
+        tag: Vec<String>,
+        /// Whether a skill needs every `--tag` (`all`) or any one (`any`)
+        #[arg(long, value_enum, default_value_t = TagMode::All, requires = "tag")]
+        tag_mode: TagMode,
+        /// Show only skills whose SKILL.md changed within this window (e.g. 1h, 2d)
+        #[arg(long, value_name = "DURATION", value_parser = crate::list::parse_since)]
+        since: Option<std::time::Duration>,
+    },
+
+    /// Show full details for one skill
+    #[command(
+        long_about = "Show full details for one skill.\n\n\
+                      Prints the skill's source directory and path, origin and \
+                      provenance, whether the library holds a copy, which \
+                      distribution directories link to it, and its description \
+                      and tags from SKILL.md frontmatter. An unknown name \
+                      suggests the closest discovered skill.",
+        after_help = "Examples:\n  tome info code-review"
+    )]
+    Info {
+        /// Skill name (as shown in `tome list`)
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+
+    /// Open a skill's SKILL.md in $EDITOR
+    #[command(
+        long_about = "Open a skill's SKILL.md in $EDITOR.\n\n\
+                      Resolves the skill through discovery and opens the source \
+                      file (symlinks followed), not the library copy. Uses $EDITOR, \
+                      then $VISUAL; with neither set, prints the path and hands it \
+                      to the platform opener (open / xdg-open) when run from a \
+                      terminal. The editor is launched directly, never through a shell.",
+        after_help = "Examples:\n  tome edit code-review\n  EDITOR='code --wait' tome edit code-review"
+    )]
+    Edit {
+        /// Skill name (as shown in `tome list`)
+        #[arg(value_name = "NAME")]
+        name: String,
     },
 
     /// Validate skill frontmatter and report issues
@@ -263,7 +551,7 @@ pub enum Command {
         path: Option<PathBuf>,
         /// Output format
         #[arg(long, value_enum, default_value = "text")]
-        format: LintFormat,
+        format: OutputFormat,
     },
 
     /// One-shot migration: convert a v0.9-shape library (managed skills as
@@ -287,6 +575,18 @@ pub enum Command {
         yes: bool,
     },
 
+    /// Rewrite an old-layout tome.toml in the current shape
+    #[command(
+        long_about = "Rewrite an old-layout tome.toml in the current shape.\n\n\
+                      Converts the pre-v0.6 [[sources]] and [targets.*] sections into \
+                      [directories.*] entries and stamps the current schema_version. \
+                      The original file is kept next to it as <file>.bak. Comments are \
+                      not carried over. Prints each change; with --dry-run, only prints \
+                      them. A config that is already current is left alone.",
+        after_help = "Examples:\n  tome migrate-config --dry-run\n  tome migrate-config\n  tome migrate-config --config ~/dotfiles/tome.toml"
+    )]
+    MigrateConfig,
+
     /// Interactively browse discovered skills
     #[command(after_help = "Examples:\n  tome browse")]
     Browse,
@@ -295,6 +595,35 @@ pub enum Command {
     #[command(after_help = "Examples:\n  tome eject\n  tome eject --dry-run")]
     Eject,
 
+    /// Remove every symlink tome created, and with --purge the config file
+    #[command(
+        long_about = "Remove every symlink tome created, and with --purge the config file.\n\n\
+                      Removes tome's symlinks from every distribution directory and any \
+                      symlinks left at the top of the library. Source directories, the \
+                      skill copies in the library, external symlinks, and real files are \
+                      never touched.",
+        after_help = "Examples:\n  tome uninstall\n  tome uninstall --dry-run\n  tome uninstall --purge --yes"
+    )]
+    Uninstall {
+        /// Also delete the config file (tome.toml)
+        #[arg(long)]
+        purge: bool,
+        /// Skip confirmation prompt
+        #[arg(long, short)]
+        yes: bool,
+    },
+
+    /// Remove tome's symlinks from directories disabled on this machine
+    #[command(
+        long_about = "Remove tome's symlinks from directories disabled on this machine.\n\n\
+                      Sync skips directories listed in disabled_directories in \
+                      machine.toml, so links created before a directory was disabled \
+                      stay behind. Prune removes every symlink into the library from \
+                      those directories, leaving external symlinks and real files alone.",
+        after_help = "Examples:\n  tome prune\n  tome prune --dry-run"
+    )]
+    Prune,
+
     /// Manage skills and directories — remove a configured directory entry
     /// or delete an Unowned skill from the library.
     #[command(
@@ -350,8 +679,10 @@ pub enum Command {
         new_path: PathBuf,
     },
 
-    /// Install shell completions for bash, zsh, fish, or powershell
-    #[command(after_help = "Examples:\n  tome completions fish\n  tome completions zsh --print")]
+    /// Install shell completions for bash, zsh, or fish (or print them for any shell)
+    #[command(
+        after_help = "Examples:\n  tome completions fish\n  tome completions zsh --print\n  tome completions elvish --print"
+    )]
     Completions {
         /// Shell to install completions for
         #[arg(value_enum)]
@@ -365,11 +696,59 @@ pub enum Command {
     Version,
 
     /// Show configuration
-    #[command(after_help = "Examples:\n  tome config\n  tome config --path")]
+    #[command(
+        after_help = "Examples:\n  tome config\n  tome config --path\n  tome config --validate\n  tome config edit",
+        args_conflicts_with_subcommands = true
+    )]
     Config {
+        #[command(subcommand)]
+        sub: Option<ConfigCommand>,
         /// Print config file path only
         #[arg(long)]
         path: bool,
+        /// Check the config and that every directory path exists, without syncing
+        #[arg(long, conflicts_with = "path")]
+        validate: bool,
+    },
+
+    /// Bundle the library into a .tar.gz archive
+    #[command(
+        long_about = "Bundle the library into a .tar.gz archive.\n\n\
+                      Writes every library skill under skills/<name>/ with symlinks \
+                      dereferenced, so the archive is self-contained, plus a \
+                      tome-export.json at the root listing each skill and the \
+                      directory it came from.",
+        after_help = "Examples:\n  tome export skills.tar.gz\n  tome export ~/backups/skills.tar.gz --force"
+    )]
+    Export {
+        /// Archive to write
+        #[arg(value_name = "OUTPUT")]
+        output: PathBuf,
+        /// Overwrite OUTPUT if it already exists
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Unpack a `tome export` archive into a directory
+    #[command(
+        long_about = "Unpack a `tome export` archive into a directory.\n\n\
+                      Extracts each skills/<name>/ entry into DEST, skipping skills \
+                      without a SKILL.md and names that already exist in DEST. \
+                      Symlinks and members whose path would escape DEST are \
+                      rejected. With --register, DEST is added to the config as a \
+                      directory source.",
+        after_help = "Examples:\n  tome import skills.tar.gz ~/shared-skills\n  tome import skills.tar.gz ~/shared-skills --register shared"
+    )]
+    Import {
+        /// Archive written by `tome export`
+        #[arg(value_name = "ARCHIVE")]
+        archive: PathBuf,
+        /// Directory to extract skills into (created if missing)
+        #[arg(value_name = "DEST")]
+        dest: PathBuf,
+        /// Also add DEST to the config as a source directory with this name
+        #[arg(long, value_name = "NAME")]
+        register: Option<String>,
     },
 
     /// Git-backed backup and restore for the skill library
@@ -382,6 +761,18 @@ pub enum Command {
     },
 }
 
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Open the config in $EDITOR, then validate it
+    #[command(long_about = "Open the config in $EDITOR, then validate it.\n\n\
+                      Uses $EDITOR, then $VISUAL, and creates the config with defaults if \
+                      it doesn't exist. When the editor exits the config is checked like \
+                      `tome config --validate`. Errors never discard your edits: when \
+                      interactive you're offered to re-open the editor, otherwise tome \
+                      exits non-zero and leaves the file as written.")]
+    Edit,
+}
+
 /// Variant of `tome remove` — directory removal vs unowned-skill deletion.
 /// Per D-API-2 (Phase 14): the merge replaces today's `tome remove <name>`
 /// shape (BREAKING). `tome remove dir` keeps today's directory-removal
@@ -582,7 +973,7 @@ mod tests {
 
     #[test]
     fn log_level_directive_maps_three_levels() {
-        assert_eq!(LogLevel::Quiet.directive(), "warn");
+        assert_eq!(LogLevel::Quiet.directive(), "error");
         assert_eq!(LogLevel::Normal.directive(), "info");
         assert_eq!(LogLevel::Verbose.directive(), "debug");
     }
@@ -0,0 +1,343 @@
+//! `tome migrate-config` — rewrite an old-layout `tome.toml` in the current
+//! shape.
+//!
+//! The only upgrade so far is from the pre-v0.6 layout: each `[[sources]]`
+//! entry and `[targets.<name>]` table becomes a `[directories.<name>]`
+//! entry, following the mapping in the v0.6.0 CHANGELOG entry. Every other
+//! key is carried over as-is.
+
+use anyhow::{Context, Result};
+use toml::{Table, Value};
+
+use super::types::{CONFIG_SCHEMA_VERSION, Config};
+
+/// An old-layout config rewritten in the current shape.
+#[derive(Debug)]
+pub struct ConfigMigration {
+    /// The migrated config, tildes unexpanded, ready for [`Config::save`].
+    pub config: Config,
+    /// One human-readable line per change, in file order.
+    pub changes: Vec<String>,
+}
+
+/// Migrate `content` — the text of a `tome.toml` — to the current layout.
+///
+/// Returns `None` when there is nothing to migrate. Errors when the file
+/// declares a newer `schema_version`, when an old entry can't be mapped
+/// (no name or path, unknown type, a name clash), or when the result
+/// doesn't load as a valid config; nothing is written in any of those cases.
+pub fn migrate_config(content: &str) -> Result<Option<ConfigMigration>> {
+    let mut table: Table = toml::from_str(content).context("config is not valid TOML")?;
+
+    let declared = match table.get("schema_version") {
+        Some(v) => Some(
+            v.as_integer()
+                .and_then(|v| u32::try_from(v).ok())
+                .context("schema_version must be a non-negative integer")?,
+        ),
+        None => None,
+    };
+    if let Some(version) = declared
+        && version > CONFIG_SCHEMA_VERSION
+    {
+        anyhow::bail!(
+            "schema_version {version} is newer than this tome supports ({CONFIG_SCHEMA_VERSION}); upgrade tome instead"
+        );
+    }
+
+    let sources = table.remove("sources");
+    let targets = table.remove("targets");
+    let outdated = declared.is_some_and(|v| v < CONFIG_SCHEMA_VERSION);
+    if sources.is_none() && targets.is_none() && !outdated {
+        return Ok(None);
+    }
+
+    let mut changes = Vec::new();
+    let mut directories = match table.remove("directories") {
+        Some(Value::Table(directories)) => directories,
+        Some(_) => anyhow::bail!("`directories` must be a table"),
+        None => Table::new(),
+    };
+
+    if let Some(sources) = sources {
+        let Value::Array(sources) = sources else {
+            anyhow::bail!("`sources` must be an array of tables ([[sources]])");
+        };
+        for (i, source) in sources.into_iter().enumerate() {
+            let Value::Table(source) = source else {
+                anyhow::bail!("[[sources]] entry #{} is not a table", i + 1);
+            };
+            migrate_source(source, i, &mut directories, &mut changes)?;
+        }
+    }
+
+    if let Some(targets) = targets {
+        let Value::Table(targets) = targets else {
+            anyhow::bail!("`targets` must be a table of [targets.<name>] entries");
+        };
+        for (name, target) in targets {
+            let Value::Table(target) = target else {
+                anyhow::bail!("[targets.{name}] is not a table");
+            };
+            migrate_target(&name, target, &mut directories, &mut changes)?;
+        }
+    }
+
+    table.insert("directories".into(), Value::Table(directories));
+    table.insert(
+        "schema_version".into(),
+        Value::Integer(CONFIG_SCHEMA_VERSION.into()),
+    );
+    changes.push(format!(
+        "set schema_version = {CONFIG_SCHEMA_VERSION}{}",
+        declared.map(|v| format!(" (was {v})")).unwrap_or_default()
+    ));
+
+    let emitted = toml::to_string(&table).context("failed to serialize migrated config")?;
+    let config = Config::from_toml(&emitted)
+        .context("migrated config does not parse as the current layout")?;
+    let mut check = config.clone();
+    check.expand_tildes()?;
+    check
+        .validate()
+        .context("migrated config does not pass validation")?;
+
+    Ok(Some(ConfigMigration { config, changes }))
+}
+
+/// Map one `[[sources]]` entry (`name`, `path`, `type`) to a directory:
+/// `claude-plugins` sources become `managed`, `directory` sources `source`.
+fn migrate_source(
+    mut source: Table,
+    index: usize,
+    directories: &mut Table,
+    changes: &mut Vec<String>,
+) -> Result<()> {
+    let name = take_str(&mut source, "name")
+        .with_context(|| format!("[[sources]] entry #{} has no `name`", index + 1))?;
+    let path = take_str(&mut source, "path")
+        .with_context(|| format!("[[sources]] '{name}' has no `path`"))?;
+    let kind = take_str(&mut source, "type").unwrap_or_else(|| "directory".into());
+    let role = match kind.as_str() {
+        "claude-plugins" => "managed",
+        "directory" => "source",
+        other => anyhow::bail!("[[sources]] '{name}': unknown type '{other}'"),
+    };
+    for key in source.keys() {
+        changes.push(format!(
+            "dropped `{key}` from [[sources]] '{name}' (no longer supported)"
+        ));
+    }
+    anyhow::ensure!(
+        !directories.contains_key(&name),
+        "[[sources]] '{name}' clashes with an existing [directories.{name}]\n\
+         hint: rename one of them, then re-run `tome migrate-config`."
+    );
+
+    directories.insert(name.clone(), directory_entry(path, &kind, role));
+    changes.push(format!(
+        "[[sources]] '{name}' -> [directories.{name}] (type = \"{kind}\", role = \"{role}\")"
+    ));
+    Ok(())
+}
+
+/// Map one `[targets.<name>]` table (`enabled`, `method`, `skills_dir`) to a
+/// `target` directory. Targets using a method other than `symlink` are
+/// dropped; a target sharing its name and path with a source merges into it
+/// as `synced`.
+fn migrate_target(
+    name: &str,
+    mut target: Table,
+    directories: &mut Table,
+    changes: &mut Vec<String>,
+) -> Result<()> {
+    let method = take_str(&mut target, "method").unwrap_or_else(|| "symlink".into());
+    if method != "symlink" {
+        changes.push(format!(
+            "dropped [targets.{name}]: the '{method}' method is no longer supported"
+        ));
+        return Ok(());
+    }
+    let path = take_str(&mut target, "skills_dir")
+        .or_else(|| take_str(&mut target, "path"))
+        .with_context(|| format!("[targets.{name}] has no `skills_dir`"))?;
+    let enabled = target
+        .remove("enabled")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(true);
+    for key in target.keys() {
+        changes.push(format!(
+            "dropped `{key}` from [targets.{name}] (no longer supported)"
+        ));
+    }
+
+    match directories.get_mut(name) {
+        Some(Value::Table(existing))
+            if existing.get("path").and_then(Value::as_str) == Some(path.as_str())
+                && existing.get("role").and_then(Value::as_str) == Some("source") =>
+        {
+            existing.insert("role".into(), Value::String("synced".into()));
+            changes.push(format!(
+                "[targets.{name}] merged into [directories.{name}] (same path; role = \"synced\")"
+            ));
+        }
+        Some(_) => anyhow::bail!(
+            "[targets.{name}] clashes with another directory named '{name}'\n\
+             hint: rename one of them in the old config, then re-run `tome migrate-config`."
+        ),
+        None => {
+            directories.insert(name.into(), directory_entry(path, "directory", "target"));
+            changes.push(format!(
+                "[targets.{name}] -> [directories.{name}] (type = \"directory\", role = \"target\")"
+            ));
+        }
+    }
+    if !enabled {
+        changes.push(format!(
+            "[targets.{name}] was disabled; add \"{name}\" to `disabled_directories` in machine.toml to keep it off"
+        ));
+    }
+    Ok(())
+}
+
+fn directory_entry(path: String, kind: &str, role: &str) -> Value {
+    Value::Table(Table::from_iter([
+        ("path".to_string(), Value::String(path)),
+        ("type".to_string(), Value::String(kind.into())),
+        ("role".to_string(), Value::String(role.into())),
+    ]))
+}
+
+/// Remove `key` from `table`, returning it when it was a string.
+fn take_str(table: &mut Table, key: &str) -> Option<String> {
+    match table.remove(key)? {
+        Value::String(s) => Some(s),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{DirectoryRole, DirectoryType};
+
+    const V05_CONFIG: &str = r#"
+library_dir = "~/.tome/skills"
+exclude = ["old-skill"]
+
+[[sources]]
+name = "my-skills"
+path = "~/skills"
+type = "directory"
+
+[[sources]]
+name = "plugins"
+path = "~/.claude/plugins/cache"
+type = "claude-plugins"
+
+[targets.claude]
+enabled = true
+method = "symlink"
+skills_dir = "~/.claude/skills"
+
+[targets.codex]
+enabled = false
+method = "symlink"
+skills_dir = "~/.codex/skills"
+
+[targets.legacy-mcp]
+enabled = true
+method = "mcp"
+"#;
+
+    #[test]
+    fn migrate_v05_layout_to_directories() {
+        let migration = migrate_config(V05_CONFIG)
+            .unwrap()
+            .expect("old layout migrates");
+        let config = &migration.config;
+        assert_eq!(config.schema_version, CONFIG_SCHEMA_VERSION);
+        assert_eq!(config.library_dir, std::path::Path::new("~/.tome/skills"));
+        assert!(config.exclude.contains("old-skill"));
+
+        let names: Vec<&str> = config.directories.keys().map(|n| n.as_str()).collect();
+        assert_eq!(names, vec!["claude", "codex", "my-skills", "plugins"]);
+        let my_skills = &config.directories["my-skills"];
+        assert_eq!(my_skills.directory_type, DirectoryType::Directory);
+        assert_eq!(my_skills.role(), DirectoryRole::Source);
+        let plugins = &config.directories["plugins"];
+        assert_eq!(plugins.directory_type, DirectoryType::ClaudePlugins);
+        assert_eq!(plugins.role(), DirectoryRole::Managed);
+        let claude = &config.directories["claude"];
+        assert_eq!(claude.path, std::path::Path::new("~/.claude/skills"));
+        assert_eq!(claude.role(), DirectoryRole::Target);
+
+        let changes = migration.changes.join("\n");
+        assert!(
+            changes.contains("[[sources]] 'plugins' -> [directories.plugins]"),
+            "{changes}"
+        );
+        assert!(
+            changes.contains("[targets.codex] was disabled"),
+            "{changes}"
+        );
+        assert!(
+            changes.contains("dropped [targets.legacy-mcp]: the 'mcp' method"),
+            "{changes}"
+        );
+
+        // The result is stable: migrating the saved form again is a no-op.
+        let saved = toml::to_string_pretty(config).unwrap();
+        assert!(saved.contains("schema_version = 1"), "{saved}");
+        assert!(migrate_config(&saved).unwrap().is_none());
+    }
+
+    #[test]
+    fn migrate_merges_source_and_target_with_same_path_into_synced() {
+        let content = r#"
+[[sources]]
+name = "claude"
+path = "/tmp/claude-skills"
+
+[targets.claude]
+skills_dir = "/tmp/claude-skills"
+"#;
+        let migration = migrate_config(content).unwrap().unwrap();
+        assert_eq!(
+            migration.config.directories["claude"].role(),
+            DirectoryRole::Synced
+        );
+    }
+
+    #[test]
+    fn migrate_rejects_name_clash_with_different_paths() {
+        let content = r#"
+[[sources]]
+name = "claude"
+path = "/tmp/a"
+
+[targets.claude]
+skills_dir = "/tmp/b"
+"#;
+        let err = migrate_config(content).unwrap_err().to_string();
+        assert!(err.contains("clashes with another directory"), "{err}");
+    }
+
+    #[test]
+    fn migrate_current_layout_is_none() {
+        let content = r#"
+[directories.local]
+path = "/tmp/skills"
+role = "source"
+"#;
+        assert!(migrate_config(content).unwrap().is_none());
+    }
+
+    #[test]
+    fn migrate_rejects_newer_schema_version() {
+        let err = migrate_config("schema_version = 99\n")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("newer than this tome supports"), "{err}");
+    }
+}
@@ -11,7 +11,8 @@
 //! |----------------|------------------------------------------------------------------|
 //! | `mod.rs`       | Public re-exports + `Config::load`/`load_or_default`/`save`/`save_checked`/`load_with_overrides` + tome-home/XDG-config helpers (`default_tome_home`, `default_config_path`, `resolve_config_dir`, `TomeHomeSource`, `resolve_tome_home_with_source`, `read_config_tome_home`, `write_xdg_tome_home`) + `defaults` |
 //! | `types.rs`     | `Config`, `DirectoryName`, `DirectoryConfig`, `DirectoryType`, `DirectoryRole`, `GitRef`, `BackupConfig` (data shapes + derive impls only) |
-//! | `validate.rs`  | `Config::validate` — role/type combos + Cases A/B/C overlap detection |
+//! | `validate.rs`  | `Config::validate` — role/type combos + Cases A–E overlap detection; `Config::path_problems` + `ConfigInvalid` for `tome config --validate` |
+//! | `migrate.rs`   | `migrate_config` — rewrites an old-layout `tome.toml` (pre-v0.6 `[[sources]]` / `[targets.*]`) in the current shape for `tome migrate-config` |
 //! | `overrides.rs` | `Config::apply_machine_overrides`, `warn_unknown_overrides`, `format_override_validation_error` (PORT-01..05 path overrides) |
 //!
 //! Tilde helpers (`expand_tilde`, `unexpand_tilde`) live in [`crate::paths`] —
@@ -25,16 +26,22 @@ use std::path::{Path, PathBuf};
 
 use crate::errors::{DomainErrorKind, WithDomainKind};
 
+mod migrate;
 mod overrides;
 mod types;
 mod validate;
 
 // Re-export the public API surface so external callers continue to use
 // `crate::config::Foo` paths byte-identically with the pre-split config.rs.
+use crate::paths::expand_env_vars;
 pub use crate::paths::expand_tilde;
+pub use migrate::{ConfigMigration, migrate_config};
 pub use types::{
-    BackupConfig, Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, GitRef,
+    BackupConfig, CONFIG_SCHEMA_VERSION, Config, ConflictPolicy, ConflictResolution,
+    DirectoryConfig, DirectoryDefaults, DirectoryName, DirectoryRole, DirectoryType, GitRef,
+    NameTransform,
 };
+pub use validate::ConfigInvalid;
 
 use crate::machine::MachinePrefs;
 use overrides::format_override_validation_error;
@@ -47,10 +54,10 @@ impl Config {
         if path.exists() {
             let content = std::fs::read_to_string(path)
                 .with_context(|| format!("failed to read {}", path.display()))?;
-            let mut config: Config = toml::from_str(&content).map_err(|e| {
+            let mut config = Config::from_toml(&content).map_err(|e| {
                 let mut msg = format!("failed to parse {}: {e}", path.display());
                 if content.contains("[[sources]]") || content.contains("[targets.") {
-                    msg.push_str("\nhint: tome v0.6 replaced [[sources]] and [targets.*] with [directories.*]. See CHANGELOG.md for migration instructions.");
+                    msg.push_str("\nhint: tome v0.6 replaced [[sources]] and [targets.*] with [directories.*]. Run `tome migrate-config` to convert this file.");
                 }
                 anyhow::anyhow!("{msg}")
             })?;
@@ -64,6 +71,45 @@ impl Config {
         }
     }
 
+    /// Parse the text of a `tome.toml`, then apply `[defaults]` to every
+    /// `directory` / `git` discovery directory that doesn't set the field
+    /// itself, noting each fill-in in the directory's `scan_origin` so a
+    /// save leaves it unset. Tildes are left unexpanded and nothing is
+    /// validated.
+    pub(crate) fn from_toml(content: &str) -> Result<Self, toml::de::Error> {
+        let mut config: Config = toml::from_str(content)?;
+        let defaults = &config.defaults;
+        for dir in config.directories.values_mut() {
+            let scanned = matches!(
+                dir.directory_type,
+                DirectoryType::Directory | DirectoryType::Git
+            );
+            if !scanned || !dir.role().is_discovery() {
+                continue;
+            }
+            let origin = &mut dir.scan_origin;
+            if let Some(max_depth) = defaults.max_depth
+                && dir.max_depth.is_none()
+            {
+                dir.max_depth = Some(max_depth);
+                origin.default_max_depth = true;
+            }
+            if let Some(follow_links) = defaults.follow_links
+                && !origin.follow_links_set
+            {
+                dir.follow_links = follow_links;
+                origin.default_follow_links = true;
+            }
+            if let Some(name_transform) = defaults.name_transform
+                && !origin.name_transform_set
+            {
+                dir.name_transform = name_transform;
+                origin.default_name_transform = true;
+            }
+        }
+        Ok(config)
+    }
+
     /// Load from CLI-provided path or default location.
     ///
     /// When an explicit path is provided and its parent directory does not
@@ -97,8 +143,14 @@ impl Config {
     /// HARD-08: atomic write via temp+rename. Mirrors `Manifest::save`,
     /// `Lockfile::save`, and `MachinePrefs::save`. A failure at the
     /// rename step leaves the previous on-disk content intact.
+    ///
+    /// The TOML is round-trip checked before anything is written, so a
+    /// serde regression errors here instead of leaving a config that no
+    /// longer parses.
     pub fn save(&self, path: &Path) -> Result<()> {
-        let content = toml::to_string_pretty(self).context("failed to serialize config")?;
+        let mut for_save = self.clone();
+        for_save.restore_env_paths();
+        let content = for_save.to_round_tripped_toml()?;
         atomic_write_toml(path, &content)
     }
 
@@ -119,6 +171,10 @@ impl Config {
         &self.exclude
     }
 
+    pub fn skill_file_names(&self) -> &[String] {
+        &self.skill_file_names
+    }
+
     /// Directories that participate in discovery (Managed, Synced, Source roles).
     pub fn discovery_dirs(&self) -> impl Iterator<Item = (&DirectoryName, &DirectoryConfig)> {
         self.directories
@@ -126,6 +182,26 @@ impl Config {
             .filter(|(_, dir)| dir.role().is_discovery())
     }
 
+    /// A copy of the config without the named discovery directories, for
+    /// `--exclude-source`. Names that match no discovery directory are
+    /// returned alongside so the caller can warn about them.
+    pub(crate) fn without_sources(&self, names: &[String]) -> (Config, Vec<String>) {
+        let mut config = self.clone();
+        let mut unknown = Vec::new();
+        for name in names {
+            let is_source = self
+                .directories
+                .get(name.as_str())
+                .is_some_and(|dir| dir.role().is_discovery());
+            if is_source {
+                config.directories.remove(name.as_str());
+            } else if !unknown.contains(name) {
+                unknown.push(name.clone());
+            }
+        }
+        (config, unknown)
+    }
+
     /// Directories that participate in distribution (Synced, Target roles).
     pub fn distribution_dirs(&self) -> impl Iterator<Item = (&DirectoryName, &DirectoryConfig)> {
         self.directories
@@ -140,15 +216,45 @@ impl Config {
             .filter(|(_, dir)| dir.role() == DirectoryRole::Managed)
     }
 
-    /// Expand `~` in all path fields.
+    /// Expand `$VAR` / `${VAR}` and then `~` in all path fields. Paths that
+    /// used a variable are remembered in `env_paths` so saving can write
+    /// them back unexpanded.
+    ///
+    /// `git` directories hold a URL, not a path, and skip variable expansion.
     pub(crate) fn expand_tildes(&mut self) -> Result<()> {
-        self.library_dir = expand_tilde(&self.library_dir)?;
+        let env_paths = &mut self.env_paths;
+        let mut expand = |raw: &Path| -> Result<PathBuf> {
+            let with_vars = expand_env_vars(raw)?;
+            let expanded = expand_tilde(&with_vars)?;
+            if with_vars != raw {
+                env_paths.insert(expanded.clone(), raw.to_path_buf());
+            }
+            Ok(expanded)
+        };
+        self.library_dir = expand(&self.library_dir)?;
         for dir in self.directories.values_mut() {
-            dir.path = expand_tilde(&dir.path)?;
+            dir.path = if dir.directory_type == DirectoryType::Git {
+                expand_tilde(&dir.path)?
+            } else {
+                expand(&dir.path)?
+            };
         }
         Ok(())
     }
 
+    /// Put back the `$VAR` form of every path still holding the value it
+    /// expanded to at load (see [`expand_tildes`](Self::expand_tildes)).
+    fn restore_env_paths(&mut self) {
+        if let Some(raw) = self.env_paths.get(&self.library_dir) {
+            self.library_dir = raw.clone();
+        }
+        for dir in self.directories.values_mut() {
+            if let Some(raw) = self.env_paths.get(&dir.path) {
+                dir.path = raw.clone();
+            }
+        }
+    }
+
     /// Load config and apply per-machine path overrides in one shot.
     ///
     /// **Order (I2 invariant — must not change):**
@@ -178,10 +284,10 @@ impl Config {
         let mut config = if path.exists() {
             let content = std::fs::read_to_string(path)
                 .with_context(|| format!("failed to read {}", path.display()))?;
-            toml::from_str(&content).map_err(|e| {
+            Self::from_toml(&content).map_err(|e| {
                 let mut msg = format!("failed to parse {}: {e}", path.display());
                 if content.contains("[[sources]]") || content.contains("[targets.") {
-                    msg.push_str("\nhint: tome v0.6 replaced [[sources]] and [targets.*] with [directories.*]. See CHANGELOG.md for migration instructions.");
+                    msg.push_str("\nhint: tome v0.6 replaced [[sources]] and [targets.*] with [directories.*]. Run `tome migrate-config` to convert this file.");
                 }
                 anyhow::anyhow!("{msg}")
             })?
@@ -284,6 +390,18 @@ impl Config {
     /// Call this instead of `save()` from the wizard or any other code that
     /// produces a Config in-memory rather than loading it from disk.
     pub fn save_checked(&self, path: &Path) -> Result<()> {
+        let emitted = self.to_checked_toml()?;
+
+        // Safe to save — write the same bytes we verified, atomically.
+        // HARD-08: temp+rename so a crash mid-rename preserves the prior
+        // on-disk tome.toml (the regression test pins this contract).
+        atomic_write_toml(path, &emitted)
+    }
+
+    /// The exact TOML `save_checked` would write: validated, `~/`-shaped,
+    /// and round-trip checked. The wizard's `--dry-run` prints this so a
+    /// piped preview is as portable as a saved config.
+    pub(crate) fn to_checked_toml(&self) -> Result<String> {
         // 1. Validation copy: validate() needs absolute paths to detect overlaps,
         //    so build an expanded clone for the check. The caller's Config is
         //    never mutated.
@@ -300,17 +418,24 @@ impl Config {
         //    overrides applied to `self.directories[*].path` would be the
         //    caller's responsibility to undo before passing to save_checked
         //    (lib.rs::sync save chain saves the pre-override Config).
+        //    Paths that used `$VAR` at load get that form back first.
         let mut for_save = self.clone();
+        for_save.restore_env_paths();
         for_save.library_dir = crate::paths::unexpand_tilde(&for_save.library_dir);
         for dir in for_save.directories.values_mut() {
             dir.path = crate::paths::unexpand_tilde(&dir.path);
         }
 
-        // 3. TOML round-trip: serialize, parse back, re-serialize, compare the
-        //    two TOML strings for byte equality. If they differ, a field has
-        //    been silently dropped or rewritten by serde.
+        // 3. TOML round-trip.
+        for_save.to_round_tripped_toml()
+    }
+
+    /// Serialize to TOML, parse it back, re-serialize, and compare the two
+    /// strings for byte equality. If they differ, a field has been silently
+    /// dropped or rewritten by serde and saving would lose data.
+    fn to_round_tripped_toml(&self) -> Result<String> {
         let emitted =
-            toml::to_string_pretty(&for_save).context("failed to serialize config (pre-check)")?;
+            toml::to_string_pretty(self).context("failed to serialize config (pre-check)")?;
         let reparsed: Config =
             toml::from_str(&emitted).context("round-trip: generated TOML did not reparse")?;
         let reemitted =
@@ -323,12 +448,52 @@ impl Config {
              hint: report this as a tome bug and share the generated output below.\n\
              --- first emit ---\n{emitted}\n--- second emit ---\n{reemitted}"
         );
+        Ok(emitted)
+    }
+}
 
-        // 4. Safe to save — write the same bytes we verified, atomically.
-        // HARD-08: temp+rename so a crash mid-rename preserves the prior
-        // on-disk tome.toml (the regression test pins this contract).
-        atomic_write_toml(path, &emitted)
+/// Add a `[directories.<name>]` table to the `tome.toml` at `path` without
+/// rewriting the rest of the file: the new table is appended to the text as
+/// written, so comments, `$VAR` paths, `[defaults]`, and key order are left
+/// alone. `dir.path` is stored in `~/`-shape. The result must load and
+/// validate like any config before it is written (atomically); a missing
+/// file is created holding just the new table.
+pub(crate) fn append_directory(
+    path: &Path,
+    name: &DirectoryName,
+    dir: &DirectoryConfig,
+) -> Result<()> {
+    let existing = if path.exists() {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?
+    } else {
+        String::new()
+    };
+
+    let mut dir = dir.clone();
+    dir.path = crate::paths::unexpand_tilde(&dir.path);
+    let table = BTreeMap::from([("directories", BTreeMap::from([(name, dir)]))]);
+    let entry = toml::to_string_pretty(&table).context("failed to serialize directory entry")?;
+
+    let mut content = existing;
+    if !content.is_empty() {
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push('\n');
     }
+    content.push_str(&entry);
+
+    let mut check = Config::from_toml(&content).with_context(|| {
+        format!(
+            "could not add [directories.{name}] to {}; add it by hand",
+            path.display()
+        )
+    })?;
+    check.expand_tildes()?;
+    check.validate()?;
+
+    atomic_write_toml(path, &content)
 }
 
 /// HARD-08: atomic-write helper used by both `Config::save` and
@@ -375,7 +540,8 @@ fn atomic_write_toml(path: &Path, content: &str) -> Result<()> {
 ///
 /// Resolution order:
 /// 1. `TOME_HOME` environment variable (if set and non-empty)
-/// 2. `~/.config/tome/config.toml` -> `tome_home` field
+/// 2. `~/.config/tome/config.toml` -> `tome_home` field (`$XDG_CONFIG_HOME`
+///    replaces `~/.config` when set)
 /// 3. `~/.tome/`
 pub fn default_tome_home() -> Result<PathBuf> {
     // 1. TOME_HOME env var
@@ -397,11 +563,29 @@ pub fn default_tome_home() -> Result<PathBuf> {
         .join(".tome"))
 }
 
-/// Read `tome_home` from the machine-level config at `~/.config/tome/config.toml`.
-pub(crate) fn read_config_tome_home() -> Result<Option<PathBuf>> {
-    let config_path = dirs::home_dir()
+/// tome's XDG config directory: `$XDG_CONFIG_HOME/tome`, or `~/.config/tome`
+/// when the variable is unset, empty, or relative (the XDG spec says to
+/// ignore relative values). Holds `config.toml` and `machine.toml`.
+///
+/// Deliberately not `dirs::config_dir()`, which is
+/// `~/Library/Application Support` on macOS — tome uses `~/.config` on every
+/// platform.
+pub fn xdg_config_dir() -> Result<PathBuf> {
+    if let Some(val) = std::env::var_os("XDG_CONFIG_HOME") {
+        let path = PathBuf::from(val);
+        if path.is_absolute() {
+            return Ok(path.join("tome"));
+        }
+    }
+    Ok(dirs::home_dir()
         .context("could not determine home directory")?
-        .join(".config/tome/config.toml");
+        .join(".config/tome"))
+}
+
+/// Read `tome_home` from the machine-level config at `~/.config/tome/config.toml`
+/// (see [`xdg_config_dir`]).
+pub(crate) fn read_config_tome_home() -> Result<Option<PathBuf>> {
+    let config_path = xdg_config_dir()?.join("config.toml");
     if !config_path.is_file() {
         return Ok(None);
     }
@@ -433,8 +617,7 @@ pub(crate) fn read_config_tome_home() -> Result<Option<PathBuf>> {
 /// Used by the wizard Step 0 (WUX-05) when the user chose a custom `tome_home` and
 /// accepted the persist-prompt.
 pub(crate) fn write_xdg_tome_home(tome_home: &Path) -> Result<()> {
-    let home = dirs::home_dir().context("could not determine home directory")?;
-    let path = home.join(".config/tome/config.toml");
+    let path = xdg_config_dir()?.join("config.toml");
 
     let mut table: toml::Table = if path.is_file() {
         std::fs::read_to_string(&path)
@@ -600,6 +783,26 @@ pub(super) mod defaults {
             })
             .join("skills")
     }
+
+    pub fn schema_version() -> u32 {
+        super::CONFIG_SCHEMA_VERSION
+    }
+
+    pub fn skill_file_names() -> Vec<String> {
+        vec![crate::skill::SKILL_FILE_NAME.to_string()]
+    }
+
+    pub fn is_default_skill_file_names(names: &[String]) -> bool {
+        names == [crate::skill::SKILL_FILE_NAME]
+    }
+
+    pub fn required_frontmatter() -> Vec<String> {
+        vec!["name".to_string()]
+    }
+
+    pub fn is_default_required_frontmatter(fields: &[String]) -> bool {
+        fields == ["name"]
+    }
 }
 
 #[cfg(test)]
@@ -623,7 +826,14 @@ mod tests {
                         git_ref: None,
 
                         subdir: None,
+                        max_depth: None,
+                        follow_links: false,
+                        name_transform: NameTransform::None,
+                        prefix: None,
+                        include: None,
+                        exclude: None,
                         override_applied: false,
+                        scan_origin: Default::default(),
                     },
                 ),
                 (
@@ -635,7 +845,14 @@ mod tests {
                         git_ref: None,
 
                         subdir: None,
+                        max_depth: None,
+                        follow_links: false,
+                        name_transform: NameTransform::None,
+                        prefix: None,
+                        include: None,
+                        exclude: None,
                         override_applied: false,
+                        scan_origin: Default::default(),
                     },
                 ),
                 (
@@ -647,7 +864,14 @@ mod tests {
                         git_ref: None,
 
                         subdir: None,
+                        max_depth: None,
+                        follow_links: false,
+                        name_transform: NameTransform::None,
+                        prefix: None,
+                        include: None,
+                        exclude: None,
                         override_applied: false,
+                        scan_origin: Default::default(),
                     },
                 ),
                 (
@@ -659,7 +883,14 @@ mod tests {
                         git_ref: None,
 
                         subdir: None,
+                        max_depth: None,
+                        follow_links: false,
+                        name_transform: NameTransform::None,
+                        prefix: None,
+                        include: None,
+                        exclude: None,
                         override_applied: false,
+                        scan_origin: Default::default(),
                     },
                 ),
             ]),
@@ -683,7 +914,14 @@ mod tests {
                         git_ref: None,
 
                         subdir: None,
+                        max_depth: None,
+                        follow_links: false,
+                        name_transform: NameTransform::None,
+                        prefix: None,
+                        include: None,
+                        exclude: None,
                         override_applied: false,
+                        scan_origin: Default::default(),
                     },
                 ),
                 (
@@ -695,7 +933,14 @@ mod tests {
                         git_ref: None,
 
                         subdir: None,
+                        max_depth: None,
+                        follow_links: false,
+                        name_transform: NameTransform::None,
+                        prefix: None,
+                        include: None,
+                        exclude: None,
                         override_applied: false,
+                        scan_origin: Default::default(),
                     },
                 ),
                 (
@@ -707,7 +952,14 @@ mod tests {
                         git_ref: None,
 
                         subdir: None,
+                        max_depth: None,
+                        follow_links: false,
+                        name_transform: NameTransform::None,
+                        prefix: None,
+                        include: None,
+                        exclude: None,
                         override_applied: false,
+                        scan_origin: Default::default(),
                     },
                 ),
                 (
@@ -719,7 +971,14 @@ mod tests {
                         git_ref: None,
 
                         subdir: None,
+                        max_depth: None,
+                        follow_links: false,
+                        name_transform: NameTransform::None,
+                        prefix: None,
+                        include: None,
+                        exclude: None,
                         override_applied: false,
+                        scan_origin: Default::default(),
                     },
                 ),
             ]),
@@ -782,7 +1041,7 @@ type = "directory"
         let err = Config::load(&path).unwrap_err();
         let msg = format!("{err}");
         assert!(
-            msg.contains("hint: tome v0.6 replaced [[sources]] and [targets.*] with [directories.*]. See CHANGELOG.md for migration instructions."),
+            msg.contains("hint: tome v0.6 replaced [[sources]] and [targets.*] with [directories.*]. Run `tome migrate-config` to convert this file."),
             "expected migration hint, got: {msg}"
         );
     }
@@ -804,7 +1063,7 @@ skills_dir = "/tmp"
         let err = Config::load(&path).unwrap_err();
         let msg = format!("{err}");
         assert!(
-            msg.contains("hint: tome v0.6 replaced [[sources]] and [targets.*] with [directories.*]. See CHANGELOG.md for migration instructions."),
+            msg.contains("hint: tome v0.6 replaced [[sources]] and [targets.*] with [directories.*]. Run `tome migrate-config` to convert this file."),
             "expected migration hint, got: {msg}"
         );
     }
@@ -822,7 +1081,14 @@ skills_dir = "/tmp"
                     role: None,
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Default::default()
@@ -856,6 +1122,107 @@ skills_dir = "/tmp"
         assert_eq!(expand_tilde(path).unwrap(), PathBuf::from("relative/path"));
     }
 
+    #[test]
+    fn expand_tildes_expands_env_vars_except_in_git_urls() {
+        let dir = |path: &str, directory_type| DirectoryConfig {
+            path: PathBuf::from(path),
+            directory_type,
+            role: None,
+            git_ref: None,
+            subdir: None,
+            max_depth: None,
+            follow_links: false,
+            name_transform: NameTransform::None,
+            prefix: None,
+            include: None,
+            exclude: None,
+            override_applied: false,
+            scan_origin: Default::default(),
+        };
+        let mut config = Config {
+            library_dir: PathBuf::from("$HOME/library"),
+            directories: BTreeMap::from([
+                (
+                    DirectoryName::new("local").unwrap(),
+                    dir("${HOME}/skills", DirectoryType::Directory),
+                ),
+                (
+                    DirectoryName::new("remote").unwrap(),
+                    dir("https://example.com/$repo.git", DirectoryType::Git),
+                ),
+            ]),
+            ..Default::default()
+        };
+        config.expand_tildes().unwrap();
+
+        let home = PathBuf::from(std::env::var("HOME").unwrap());
+        assert_eq!(config.library_dir, home.join("library"));
+        assert_eq!(config.directories["local"].path, home.join("skills"));
+        assert_eq!(
+            config.directories["remote"].path,
+            PathBuf::from("https://example.com/$repo.git")
+        );
+    }
+
+    #[test]
+    fn expand_tildes_rejects_undefined_env_var() {
+        let mut config = Config {
+            library_dir: PathBuf::from("$TOME_TEST_SURELY_UNDEFINED_VAR/library"),
+            ..Default::default()
+        };
+        let msg = config.expand_tildes().unwrap_err().to_string();
+        assert!(msg.contains("$TOME_TEST_SURELY_UNDEFINED_VAR"), "{msg}");
+    }
+
+    #[test]
+    fn save_keeps_env_var_paths_unexpanded() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("tome.toml");
+        std::fs::write(
+            &path,
+            r#"library_dir = "$HOME/.tome-env-test/library"
+
+[directories.local]
+path = "${HOME}/.tome-env-test/skills"
+role = "source"
+
+[directories.moved]
+path = "$HOME/.tome-env-test/old"
+role = "target"
+"#,
+        )
+        .unwrap();
+
+        let mut config = Config::load(&path).unwrap();
+        let home = PathBuf::from(std::env::var("HOME").unwrap());
+        assert_eq!(
+            config.library_dir,
+            home.join(".tome-env-test/library"),
+            "paths are expanded in memory"
+        );
+        // A path changed after load is saved as its new value.
+        config.directories.get_mut("moved").unwrap().path = tmp.path().join("new");
+
+        for save in [Config::save, Config::save_checked] {
+            save(&config, &path).unwrap();
+            let saved = std::fs::read_to_string(&path).unwrap();
+            assert!(
+                saved.contains(r#"library_dir = "$HOME/.tome-env-test/library""#),
+                "{saved}"
+            );
+            assert!(
+                saved.contains(r#"path = "${HOME}/.tome-env-test/skills""#),
+                "{saved}"
+            );
+            assert!(
+                saved.contains(&format!("path = \"{}\"", tmp.path().join("new").display())),
+                "{saved}"
+            );
+            let reloaded = Config::load(&path).unwrap();
+            assert_eq!(reloaded.library_dir, config.library_dir);
+        }
+    }
+
     // --- save_checked tests ---
 
     #[test]
@@ -872,7 +1239,14 @@ skills_dir = "/tmp"
                     role: Some(DirectoryRole::Target),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Default::default()
@@ -903,7 +1277,14 @@ skills_dir = "/tmp"
                     role: Some(DirectoryRole::Synced),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Default::default()
@@ -932,7 +1313,14 @@ skills_dir = "/tmp"
                     role: Some(DirectoryRole::Synced),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Default::default()
@@ -947,6 +1335,41 @@ skills_dir = "/tmp"
         assert_eq!(on_disk, reemitted, "saved file must round-trip exactly");
     }
 
+    #[test]
+    fn save_round_trips_without_validating() {
+        // Plain `save` is for configs loaded from disk: it skips validation
+        // (this role/type pair would fail it) but keeps the round-trip guard.
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("tome.toml");
+        let mut config = Config {
+            library_dir: PathBuf::from("/tmp/lib-save"),
+            ..Default::default()
+        };
+        config.directories.insert(
+            DirectoryName::new("odd").unwrap(),
+            DirectoryConfig {
+                path: PathBuf::from("/tmp/odd"),
+                directory_type: DirectoryType::Git,
+                role: Some(DirectoryRole::Target),
+                git_ref: None,
+                subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
+                override_applied: false,
+                scan_origin: Default::default(),
+            },
+        );
+        config.save(&path).expect("plain save does not validate");
+
+        let on_disk = std::fs::read_to_string(&path).unwrap();
+        let reparsed: Config = toml::from_str(&on_disk).unwrap();
+        assert_eq!(on_disk, toml::to_string_pretty(&reparsed).unwrap());
+    }
+
     #[test]
     fn save_checked_does_not_mutate_caller() {
         // Caller's library_dir uses tilde; save_checked must not rewrite it in the caller's Config.
@@ -1058,7 +1481,14 @@ skills_dir = "/tmp"
                     role: Some(DirectoryRole::Source),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Default::default()
@@ -1107,7 +1537,14 @@ skills_dir = "/tmp"
                     role: Some(DirectoryRole::Source),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Default::default()
@@ -1178,7 +1615,14 @@ skills_dir = "/tmp"
                     role: Some(DirectoryRole::Source),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: true,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Default::default()
@@ -1310,6 +1754,17 @@ skills_dir = "/tmp"
         );
     }
 
+    #[test]
+    fn tome_home_env_redirects_default_config_and_library() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let env_home = tmp.path().join("isolated");
+
+        with_env(&[("TOME_HOME", Some(env_home.as_os_str()))], || {
+            assert_eq!(default_config_path().unwrap(), env_home.join("tome.toml"));
+            assert_eq!(defaults::library_dir(), env_home.join("skills"));
+        });
+    }
+
     #[test]
     fn resolve_tome_home_with_source_uses_xdg_config() {
         let tmp = tempfile::TempDir::new().unwrap();
@@ -1325,7 +1780,11 @@ skills_dir = "/tmp"
         .unwrap();
 
         with_env(
-            &[("HOME", Some(home.as_os_str())), ("TOME_HOME", None)],
+            &[
+                ("HOME", Some(home.as_os_str())),
+                ("TOME_HOME", None),
+                ("XDG_CONFIG_HOME", None),
+            ],
             || {
                 let (path, src) = resolve_tome_home_with_source(None, None).unwrap();
                 assert_eq!(path, xdg_tome_home);
@@ -1335,13 +1794,77 @@ skills_dir = "/tmp"
         );
     }
 
+    #[test]
+    fn xdg_config_dir_honors_xdg_config_home() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let home = tmp.path().join("home");
+        let xdg = tmp.path().join("xdg");
+
+        with_env(
+            &[
+                ("HOME", Some(home.as_os_str())),
+                ("XDG_CONFIG_HOME", Some(xdg.as_os_str())),
+            ],
+            || assert_eq!(xdg_config_dir().unwrap(), xdg.join("tome")),
+        );
+        with_env(
+            &[("HOME", Some(home.as_os_str())), ("XDG_CONFIG_HOME", None)],
+            || assert_eq!(xdg_config_dir().unwrap(), home.join(".config/tome")),
+        );
+        // Empty and relative values are ignored per the XDG spec.
+        for ignored in ["", "relative/xdg"] {
+            with_env(
+                &[
+                    ("HOME", Some(home.as_os_str())),
+                    ("XDG_CONFIG_HOME", Some(std::ffi::OsStr::new(ignored))),
+                ],
+                || assert_eq!(xdg_config_dir().unwrap(), home.join(".config/tome")),
+            );
+        }
+    }
+
+    #[test]
+    fn resolve_tome_home_reads_config_under_xdg_config_home() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let home = tmp.path().join("home");
+        let xdg = tmp.path().join("xdg");
+        std::fs::create_dir_all(xdg.join("tome")).unwrap();
+        let xdg_tome_home = tmp.path().join("xdg-tome-home");
+        std::fs::write(
+            xdg.join("tome/config.toml"),
+            format!("tome_home = \"{}\"\n", xdg_tome_home.display()),
+        )
+        .unwrap();
+
+        with_env(
+            &[
+                ("HOME", Some(home.as_os_str())),
+                ("TOME_HOME", None),
+                ("XDG_CONFIG_HOME", Some(xdg.as_os_str())),
+            ],
+            || {
+                let (path, src) = resolve_tome_home_with_source(None, None).unwrap();
+                assert_eq!(path, xdg_tome_home);
+                assert_eq!(src, TomeHomeSource::XdgConfig);
+                assert_eq!(
+                    crate::machine::default_machine_path().unwrap(),
+                    xdg.join("tome/machine.toml")
+                );
+            },
+        );
+    }
+
     #[test]
     fn resolve_tome_home_with_source_falls_back_to_default() {
         let tmp = tempfile::TempDir::new().unwrap();
         let home = tmp.path().to_path_buf();
 
         with_env(
-            &[("HOME", Some(home.as_os_str())), ("TOME_HOME", None)],
+            &[
+                ("HOME", Some(home.as_os_str())),
+                ("TOME_HOME", None),
+                ("XDG_CONFIG_HOME", None),
+            ],
             || {
                 let (path, src) = resolve_tome_home_with_source(None, None).unwrap();
                 assert_eq!(path, home.join(".tome"));
@@ -1358,7 +1881,11 @@ skills_dir = "/tmp"
         let relative = Path::new("relative/custom");
 
         with_env(
-            &[("HOME", Some(home.as_os_str())), ("TOME_HOME", None)],
+            &[
+                ("HOME", Some(home.as_os_str())),
+                ("TOME_HOME", None),
+                ("XDG_CONFIG_HOME", None),
+            ],
             || {
                 let err = resolve_tome_home_with_source(Some(relative), None).unwrap_err();
                 let msg = err.to_string();
@@ -1381,59 +1908,78 @@ skills_dir = "/tmp"
     #[test]
     fn write_xdg_tome_home_creates_new_file() {
         let tmp = tempfile::TempDir::new().unwrap();
-        with_env(&[("HOME", Some(tmp.path().as_os_str()))], || {
-            let custom = tmp.path().join("dotfiles/tome");
-            write_xdg_tome_home(&custom).unwrap();
-
-            let xdg = tmp.path().join(".config/tome/config.toml");
-            assert!(xdg.is_file(), "XDG file should be created");
-            let content = std::fs::read_to_string(&xdg).unwrap();
-            let table: toml::Table = content.parse().unwrap();
-            let tome_home = table.get("tome_home").and_then(|v| v.as_str()).unwrap();
-            // Path is under HOME → collapsed form
-            assert_eq!(tome_home, "~/dotfiles/tome");
-        });
+        with_env(
+            &[
+                ("HOME", Some(tmp.path().as_os_str())),
+                ("XDG_CONFIG_HOME", None),
+            ],
+            || {
+                let custom = tmp.path().join("dotfiles/tome");
+                write_xdg_tome_home(&custom).unwrap();
+
+                let xdg = tmp.path().join(".config/tome/config.toml");
+                assert!(xdg.is_file(), "XDG file should be created");
+                let content = std::fs::read_to_string(&xdg).unwrap();
+                let table: toml::Table = content.parse().unwrap();
+                let tome_home = table.get("tome_home").and_then(|v| v.as_str()).unwrap();
+                // Path is under HOME → collapsed form
+                assert_eq!(tome_home, "~/dotfiles/tome");
+            },
+        );
     }
 
     #[test]
     fn write_xdg_tome_home_preserves_other_keys() {
         let tmp = tempfile::TempDir::new().unwrap();
-        with_env(&[("HOME", Some(tmp.path().as_os_str()))], || {
-            let xdg = tmp.path().join(".config/tome/config.toml");
-            std::fs::create_dir_all(xdg.parent().unwrap()).unwrap();
-            std::fs::write(&xdg, "other_key = \"preserve-me\"\ntome_home = \"~/old\"\n").unwrap();
-
-            let custom = tmp.path().join("dotfiles/tome");
-            write_xdg_tome_home(&custom).unwrap();
-
-            let content = std::fs::read_to_string(&xdg).unwrap();
-            let table: toml::Table = content.parse().unwrap();
-            // tome_home overwritten
-            assert_eq!(
-                table.get("tome_home").and_then(|v| v.as_str()),
-                Some("~/dotfiles/tome")
-            );
-            // other_key preserved
-            assert_eq!(
-                table.get("other_key").and_then(|v| v.as_str()),
-                Some("preserve-me")
-            );
-        });
+        with_env(
+            &[
+                ("HOME", Some(tmp.path().as_os_str())),
+                ("XDG_CONFIG_HOME", None),
+            ],
+            || {
+                let xdg = tmp.path().join(".config/tome/config.toml");
+                std::fs::create_dir_all(xdg.parent().unwrap()).unwrap();
+                std::fs::write(&xdg, "other_key = \"preserve-me\"\ntome_home = \"~/old\"\n")
+                    .unwrap();
+
+                let custom = tmp.path().join("dotfiles/tome");
+                write_xdg_tome_home(&custom).unwrap();
+
+                let content = std::fs::read_to_string(&xdg).unwrap();
+                let table: toml::Table = content.parse().unwrap();
+                // tome_home overwritten
+                assert_eq!(
+                    table.get("tome_home").and_then(|v| v.as_str()),
+                    Some("~/dotfiles/tome")
+                );
+                // other_key preserved
+                assert_eq!(
+                    table.get("other_key").and_then(|v| v.as_str()),
+                    Some("preserve-me")
+                );
+            },
+        );
     }
 
     #[test]
     fn write_xdg_tome_home_is_atomic() {
         let tmp = tempfile::TempDir::new().unwrap();
-        with_env(&[("HOME", Some(tmp.path().as_os_str()))], || {
-            let custom = tmp.path().join("dotfiles/tome");
-            write_xdg_tome_home(&custom).unwrap();
+        with_env(
+            &[
+                ("HOME", Some(tmp.path().as_os_str())),
+                ("XDG_CONFIG_HOME", None),
+            ],
+            || {
+                let custom = tmp.path().join("dotfiles/tome");
+                write_xdg_tome_home(&custom).unwrap();
 
-            let tmp_file = tmp.path().join(".config/tome/config.toml.tmp");
-            assert!(
-                !tmp_file.exists(),
-                "temp file should be removed after successful rename"
-            );
-        });
+                let tmp_file = tmp.path().join(".config/tome/config.toml.tmp");
+                assert!(
+                    !tmp_file.exists(),
+                    "temp file should be removed after successful rename"
+                );
+            },
+        );
     }
 
     /// HARD-08: rename failure during atomic save_checked must leave the
@@ -1453,10 +1999,20 @@ skills_dir = "/tmp"
 
         // Step 1: write Config A through the canonical save_checked path.
         let config_a = Config {
+            schema_version: CONFIG_SCHEMA_VERSION,
             library_dir: lib_dir.clone(),
             directories: BTreeMap::new(),
             exclude: Default::default(),
             backup: Default::default(),
+            relative_links: false,
+            on_conflict: ConflictPolicy::default(),
+            conflict_resolution: ConflictResolution::default(),
+            skill_file_names: defaults::skill_file_names(),
+            generate_index: false,
+            link_marker: false,
+            required_frontmatter: defaults::required_frontmatter(),
+            defaults: Default::default(),
+            env_paths: Default::default(),
         };
         config_a.save_checked(&path).unwrap();
         let bytes_a = std::fs::read(&path).unwrap();
@@ -1474,10 +2030,20 @@ skills_dir = "/tmp"
         // (Don't actually create lib_dir_b on disk — validate() doesn't
         // require existence, only that it's not a regular file.)
         let config_b = Config {
+            schema_version: CONFIG_SCHEMA_VERSION,
             library_dir: lib_dir_b,
             directories: BTreeMap::new(),
             exclude: Default::default(),
             backup: Default::default(),
+            relative_links: false,
+            on_conflict: ConflictPolicy::default(),
+            conflict_resolution: ConflictResolution::default(),
+            skill_file_names: defaults::skill_file_names(),
+            generate_index: false,
+            link_marker: false,
+            required_frontmatter: defaults::required_frontmatter(),
+            defaults: Default::default(),
+            env_paths: Default::default(),
         };
         let result = config_b.save_checked(&path);
 
@@ -1502,4 +2068,130 @@ skills_dir = "/tmp"
         let reloaded = Config::load(&path).unwrap();
         assert_eq!(reloaded.library_dir, lib_dir);
     }
+
+    #[test]
+    fn defaults_apply_to_sources_unless_overridden() {
+        let config = Config::from_toml(
+            r#"
+[defaults]
+max_depth = 3
+follow_links = true
+name_transform = "slugify"
+
+[directories.plain]
+path = "/tmp/plain"
+role = "source"
+
+[directories.custom]
+path = "/tmp/custom"
+role = "source"
+max_depth = 2
+follow_links = false
+
+[directories.out]
+path = "/tmp/out"
+role = "target"
+
+[directories.plugins]
+path = "/tmp/plugins"
+type = "claude-plugins"
+"#,
+        )
+        .unwrap();
+
+        let plain = &config.directories["plain"];
+        assert_eq!(plain.max_depth, Some(3));
+        assert!(plain.follow_links);
+        assert_eq!(plain.name_transform, NameTransform::Slugify);
+
+        // Per-source values win, including an explicit `false`.
+        let custom = &config.directories["custom"];
+        assert_eq!(custom.max_depth, Some(2));
+        assert!(!custom.follow_links);
+        assert_eq!(custom.name_transform, NameTransform::Slugify);
+
+        // Targets and claude-plugins directories aren't scanned sources.
+        for name in ["out", "plugins"] {
+            let dir = &config.directories[name];
+            assert_eq!(dir.max_depth, None, "{name}");
+            assert!(!dir.follow_links, "{name}");
+            assert_eq!(dir.name_transform, NameTransform::None, "{name}");
+        }
+        config.validate().unwrap();
+    }
+
+    #[test]
+    fn defaults_survive_load_save_round_trip() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("tome.toml");
+        std::fs::write(
+            &path,
+            format!(
+                r#"library_dir = "{}"
+
+[defaults]
+max_depth = 3
+follow_links = true
+
+[directories.plain]
+path = "{}"
+role = "source"
+
+[directories.custom]
+path = "{}"
+role = "source"
+follow_links = false
+"#,
+                tmp.path().join("library").display(),
+                tmp.path().join("plain").display(),
+                tmp.path().join("custom").display(),
+            ),
+        )
+        .unwrap();
+
+        let mut config = Config::load(&path).unwrap();
+        assert!(config.directories["plain"].follow_links);
+        // A directory added after load picks the defaults up on reload.
+        config.directories.insert(
+            DirectoryName::new("later").unwrap(),
+            DirectoryConfig {
+                path: tmp.path().join("later"),
+                directory_type: DirectoryType::Directory,
+                role: Some(DirectoryRole::Source),
+                git_ref: None,
+                subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
+                override_applied: false,
+                scan_origin: Default::default(),
+            },
+        );
+        config.save_checked(&path).unwrap();
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(
+            saved.contains("[defaults]\nmax_depth = 3\nfollow_links = true"),
+            "{saved}"
+        );
+        let saved_table: toml::Table = toml::from_str(&saved).unwrap();
+        let plain = saved_table["directories"]["plain"].as_table().unwrap();
+        assert!(!plain.contains_key("max_depth"), "{saved}");
+        assert!(!plain.contains_key("follow_links"), "{saved}");
+        // An explicit value that differs from `[defaults]` is kept.
+        let custom = saved_table["directories"]["custom"].as_table().unwrap();
+        assert_eq!(
+            custom.get("follow_links"),
+            Some(&toml::Value::Boolean(false))
+        );
+
+        let reloaded = Config::load(&path).unwrap();
+        assert_eq!(reloaded.directories["plain"].max_depth, Some(3));
+        assert!(!reloaded.directories["custom"].follow_links);
+        assert!(reloaded.directories["later"].follow_links);
+        assert_eq!(reloaded.directories["later"].max_depth, Some(3));
+    }
 }
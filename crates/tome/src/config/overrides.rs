@@ -261,7 +261,7 @@ pub(super) fn format_override_validation_error(
 #[cfg(test)]
 mod tests {
     use super::super::types::{
-        Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType,
+        Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, NameTransform,
     };
     use std::collections::BTreeMap;
     use std::path::PathBuf;
@@ -280,7 +280,14 @@ mod tests {
                     role: Some(DirectoryRole::Source),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Default::default()
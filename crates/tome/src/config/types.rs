@@ -4,7 +4,7 @@
 //! validation lives in [`super::validate`]; per-machine override application lives in
 //! [`super::overrides`]. This file holds the data shapes (and their derive impls) only.
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
@@ -103,6 +103,9 @@ pub enum DirectoryType {
     Directory,
     /// Clones/pulls a remote git repository
     Git,
+    /// A folder of Cursor rule files (`*.mdc`, e.g. `~/.cursor/rules`); each
+    /// rule becomes a skill whose `SKILL.md` is generated from the rule
+    CursorRules,
 }
 
 impl std::fmt::Display for DirectoryType {
@@ -111,6 +114,7 @@ impl std::fmt::Display for DirectoryType {
             DirectoryType::ClaudePlugins => write!(f, "claude-plugins"),
             DirectoryType::Directory => write!(f, "directory"),
             DirectoryType::Git => write!(f, "git"),
+            DirectoryType::CursorRules => write!(f, "cursor-rules"),
         }
     }
 }
@@ -121,7 +125,7 @@ impl DirectoryType {
         match self {
             DirectoryType::ClaudePlugins => DirectoryRole::Managed,
             DirectoryType::Directory => DirectoryRole::Synced,
-            DirectoryType::Git => DirectoryRole::Source,
+            DirectoryType::Git | DirectoryType::CursorRules => DirectoryRole::Source,
         }
     }
 
@@ -145,11 +149,109 @@ impl DirectoryType {
                     DirectoryRole::Target,
                 ]
             }
-            DirectoryType::Git => vec![DirectoryRole::Source],
+            // Rule files are converted on the way into the library; there is
+            // no skill directory to link back into a rules folder.
+            DirectoryType::Git | DirectoryType::CursorRules => vec![DirectoryRole::Source],
         }
     }
 }
 
+/// How discovery rewrites a skill's directory name into its library name.
+///
+/// Only the name changes: the library copy is still made from the original
+/// directory, and distribution links use the transformed name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NameTransform {
+    /// Use the directory name as-is.
+    #[default]
+    None,
+    /// Lowercase the directory name (`My-Skill` → `my-skill`).
+    Lowercase,
+    /// Lowercase, replace every run of characters outside `[a-z0-9]` with a
+    /// single `-`, and trim leading/trailing `-` (`My Skill!` → `my-skill`).
+    Slugify,
+}
+
+impl NameTransform {
+    /// Apply the transform to a directory name.
+    pub fn apply(&self, name: &str) -> String {
+        match self {
+            NameTransform::None => name.to_string(),
+            NameTransform::Lowercase => name.to_lowercase(),
+            NameTransform::Slugify => {
+                let mut slug = String::with_capacity(name.len());
+                for c in name.chars() {
+                    if c.is_ascii_alphanumeric() {
+                        slug.push(c.to_ascii_lowercase());
+                    } else if !slug.is_empty() && !slug.ends_with('-') {
+                        slug.push('-');
+                    }
+                }
+                while slug.ends_with('-') {
+                    slug.pop();
+                }
+                slug
+            }
+        }
+    }
+
+    fn is_none(&self) -> bool {
+        *self == NameTransform::None
+    }
+}
+
+/// What discovery does when the same skill name is found in more than one
+/// directory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConflictPolicy {
+    /// Keep the copy from the first directory (alphabetical order) and skip
+    /// the rest with a warning.
+    #[default]
+    FirstWins,
+    /// Keep every copy. The first keeps its name; each later one is renamed
+    /// `<name>@<directory>`.
+    KeepAll,
+}
+
+impl ConflictPolicy {
+    fn is_first_wins(&self) -> bool {
+        *self == ConflictPolicy::FirstWins
+    }
+}
+
+/// Which copy discovery keeps when the same skill name is found in more
+/// than one directory and `on_conflict` is `first-wins`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConflictResolution {
+    /// The copy from the first directory in alphabetical order.
+    #[default]
+    FirstSource,
+    /// The copy from the last directory in alphabetical order.
+    LastSource,
+    /// The copy whose skill file was modified most recently. Ties, and
+    /// copies whose modification time can't be read, fall back to
+    /// `first-source`.
+    NewestMtime,
+}
+
+impl ConflictResolution {
+    /// The `tome.toml` spelling, used in conflict warnings.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConflictResolution::FirstSource => "first-source",
+            ConflictResolution::LastSource => "last-source",
+            ConflictResolution::NewestMtime => "newest-mtime",
+        }
+    }
+
+    fn is_first_source(&self) -> bool {
+        *self == ConflictResolution::FirstSource
+    }
+}
+
 /// The role a directory plays in the sync pipeline.
 ///
 /// The `clap::ValueEnum` derive lets `tome add --role <ROLE>` accept these
@@ -288,6 +390,48 @@ pub struct DirectoryConfig {
     /// When set, discovery scans `<clone_path>/<subdir>/` instead of the repo root.
     pub subdir: Option<String>,
 
+    /// Deepest `SKILL.md` discovery looks for, counted from the scan root
+    /// (`path`, or `<clone>/<subdir>` for git). `None` means the default of
+    /// 2 (`<root>/<skill>/SKILL.md`); 3 reaches `<root>/<category>/<skill>/SKILL.md`.
+    /// Must be at least 1. Not valid for `claude-plugins` directories.
+    pub max_depth: Option<usize>,
+
+    /// Descend into symlinked subdirectories during discovery (`directory` /
+    /// `git` only). Default `false`.
+    ///
+    /// Security: when enabled, a symlink inside this directory can pull
+    /// skills — and everything else in the linked directory — in from
+    /// anywhere on the filesystem, and `tome sync` copies them into the
+    /// library. Only enable it for trees you control; a cloned git repo can
+    /// ship symlinks pointing at arbitrary absolute paths. Symlink loops are
+    /// detected and reported as discovery warnings.
+    pub follow_links: bool,
+
+    /// Rewrite skill directory names into library names during discovery
+    /// (`directory` / `git` only). Default [`NameTransform::None`]. Two
+    /// directories that transform to the same name conflict like any other
+    /// duplicate skill name.
+    pub name_transform: NameTransform,
+
+    /// Prepend `{prefix}-` to every skill name discovered in this directory
+    /// (after `name_transform`), so two sources that both ship e.g. `review`
+    /// land in the library as `work-review` and `oss-review` instead of one
+    /// shadowing the other. The source tree itself is untouched. Changing
+    /// or removing a prefix renames the skills: the old library entries and
+    /// their links are orphaned and removed by the next `tome sync` cleanup.
+    pub prefix: Option<String>,
+
+    /// Distribute only these library skills to this directory (distribution
+    /// roles only). `None` distributes every skill. Combined with
+    /// [`exclude`](Self::exclude): `include` narrows the set first, then
+    /// `exclude` subtracts from it. Links to skills that fall out of the set
+    /// are removed by the next `tome sync` cleanup.
+    pub include: Option<BTreeSet<SkillName>>,
+
+    /// Never distribute these library skills to this directory (distribution
+    /// roles only). Applied after [`include`](Self::include).
+    pub exclude: Option<BTreeSet<SkillName>>,
+
     /// True iff this directory's `path` was rewritten by a `[directory_overrides.<name>]`
     /// entry in `machine.toml` during config load. Set in `Config::apply_machine_overrides`.
     /// Never appears in `tome.toml` (it's machine-local state, not portable config) — see
@@ -298,6 +442,27 @@ pub struct DirectoryConfig {
     /// `status::gather` and `doctor::check` to render an `(override)` annotation
     /// in text output and an `override_applied: true|false` field in JSON output.
     pub(crate) override_applied: bool,
+
+    /// Which scan options `tome.toml` set on this entry and which were
+    /// filled in from `[defaults]`, so saving writes back what the file
+    /// said rather than the resolved values. Never serialized itself.
+    pub(crate) scan_origin: ScanOrigin,
+}
+
+/// Where a directory's scan options came from. See
+/// [`DirectoryConfig::scan_origin`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct ScanOrigin {
+    /// `follow_links` was written on the entry, even as `false`.
+    pub(crate) follow_links_set: bool,
+    /// `name_transform` was written on the entry, even as `"none"`.
+    pub(crate) name_transform_set: bool,
+    /// `max_depth` came from `[defaults]`.
+    pub(crate) default_max_depth: bool,
+    /// `follow_links` came from `[defaults]`.
+    pub(crate) default_follow_links: bool,
+    /// `name_transform` came from `[defaults]`.
+    pub(crate) default_name_transform: bool,
 }
 
 impl DirectoryConfig {
@@ -306,6 +471,32 @@ impl DirectoryConfig {
         self.role
             .unwrap_or_else(|| self.directory_type.default_role())
     }
+
+    /// True when `path` contains glob metacharacters (`*`, `?`, `[`), so the
+    /// entry fans out over every matching directory. Git paths are URLs and
+    /// never count as globs.
+    pub fn is_glob(&self) -> bool {
+        self.directory_type != DirectoryType::Git
+            && self.path.to_string_lossy().contains(['*', '?', '['])
+    }
+
+    /// Whether this directory's `include`/`exclude` lists let `skill` be
+    /// distributed here: it must be in `include` (when set) and not in
+    /// `exclude`. Machine-local filtering in `machine.toml` is applied
+    /// separately.
+    pub fn includes_skill(&self, skill: &str) -> bool {
+        self.include.as_ref().is_none_or(|set| set.contains(skill))
+            && !self.exclude.as_ref().is_some_and(|set| set.contains(skill))
+    }
+
+    /// Directories matching a glob `path`, in sorted order. Matches that are
+    /// not directories, or can't be read, are skipped.
+    pub fn glob_matches(&self) -> Result<Vec<PathBuf>> {
+        let pattern = self.path.to_string_lossy();
+        let matches =
+            glob::glob(&pattern).with_context(|| format!("invalid glob pattern '{pattern}'"))?;
+        Ok(matches.flatten().filter(|path| path.is_dir()).collect())
+    }
 }
 
 /// On-disk shape for `DirectoryConfig` — preserves the v0.6 TOML schema
@@ -328,6 +519,18 @@ struct DirectoryConfigRaw {
     rev: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     subdir: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_depth: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    follow_links: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name_transform: Option<NameTransform>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    include: Option<BTreeSet<SkillName>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    exclude: Option<BTreeSet<SkillName>>,
 }
 
 impl TryFrom<DirectoryConfigRaw> for DirectoryConfig {
@@ -367,7 +570,18 @@ impl TryFrom<DirectoryConfigRaw> for DirectoryConfig {
             role: raw.role,
             git_ref,
             subdir: raw.subdir,
+            max_depth: raw.max_depth,
+            follow_links: raw.follow_links.unwrap_or(false),
+            name_transform: raw.name_transform.unwrap_or_default(),
+            prefix: raw.prefix,
+            include: raw.include,
+            exclude: raw.exclude,
             override_applied: false,
+            scan_origin: ScanOrigin {
+                follow_links_set: raw.follow_links.is_some(),
+                name_transform_set: raw.name_transform.is_some(),
+                ..ScanOrigin::default()
+            },
         })
     }
 }
@@ -381,7 +595,17 @@ impl From<DirectoryConfig> for DirectoryConfigRaw {
             Some(GitRef::Rev(r)) => (None, None, Some(r)),
         };
         // `override_applied` is intentionally dropped: it's machine-local
-        // state, never written to portable `tome.toml`.
+        // state, never written to portable `tome.toml`. Scan options filled
+        // in from `[defaults]` are left unset so the table keeps applying;
+        // defaults written on the entry itself are kept.
+        let origin = d.scan_origin;
+        let max_depth = d.max_depth.filter(|_| !origin.default_max_depth);
+        let follow_links = (!origin.default_follow_links
+            && (d.follow_links || origin.follow_links_set))
+            .then_some(d.follow_links);
+        let name_transform = (!origin.default_name_transform
+            && (!d.name_transform.is_none() || origin.name_transform_set))
+            .then_some(d.name_transform);
         Self {
             path: d.path,
             directory_type: d.directory_type,
@@ -390,10 +614,38 @@ impl From<DirectoryConfig> for DirectoryConfigRaw {
             tag,
             rev,
             subdir: d.subdir,
+            max_depth,
+            follow_links,
+            name_transform,
+            prefix: d.prefix,
+            include: d.include,
+            exclude: d.exclude,
         }
     }
 }
 
+/// `[defaults]` — scan options for every `directory` / `git` source that
+/// doesn't set them itself. Applied to each [`DirectoryConfig`] when the
+/// config is loaded, so the rest of tome only ever sees resolved values;
+/// saving writes the table back and leaves the filled-in fields unset.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DirectoryDefaults {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) max_depth: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) follow_links: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) name_transform: Option<NameTransform>,
+}
+
+impl DirectoryDefaults {
+    /// True when no default is set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// Backup configuration -- controls git-backed snapshots of the skill library.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -411,10 +663,20 @@ impl Default for BackupConfig {
     }
 }
 
+/// Version of the `tome.toml` layout this build reads and writes. Bump it
+/// when the shape changes incompatibly and teach `tome migrate-config` to
+/// upgrade from the previous version.
+pub const CONFIG_SCHEMA_VERSION: u32 = 1;
+
 /// Top-level configuration for tome.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
+    /// Layout version of this file; see [`CONFIG_SCHEMA_VERSION`]. A file
+    /// without one is read as the current version.
+    #[serde(default = "super::defaults::schema_version")]
+    pub(crate) schema_version: u32,
+
     /// Where the consolidated skill library lives
     #[serde(default = "super::defaults::library_dir")]
     pub(crate) library_dir: PathBuf,
@@ -427,18 +689,83 @@ pub struct Config {
     #[serde(default)]
     pub(crate) directories: BTreeMap<DirectoryName, DirectoryConfig>,
 
+    /// Scan options for sources that don't set their own. Applied during
+    /// load and written back as-is on save.
+    #[serde(default, skip_serializing_if = "DirectoryDefaults::is_empty")]
+    pub(crate) defaults: DirectoryDefaults,
+
+    /// Path values as written in `tome.toml` before `$VAR` expansion,
+    /// keyed by what they expanded to. A path that still holds its expanded
+    /// value is saved in the written form. Never serialized.
+    #[serde(skip)]
+    pub(crate) env_paths: BTreeMap<PathBuf, PathBuf>,
+
     /// Backup settings
     #[serde(default)]
     pub(crate) backup: BackupConfig,
+
+    /// Create distribution symlinks with relative targets
+    /// (`../../.tome/skills/foo`) instead of absolute ones, so a dotfiles
+    /// tree holding both the library and the targets keeps working when it
+    /// is checked out under a different root. Default `false`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub(crate) relative_links: bool,
+
+    /// How discovery handles a skill name found in several directories.
+    /// Default `first-wins`.
+    #[serde(default, skip_serializing_if = "ConflictPolicy::is_first_wins")]
+    pub(crate) on_conflict: ConflictPolicy,
+
+    /// Which copy wins a name collision under `on_conflict = "first-wins"`.
+    /// Default `first-source`.
+    #[serde(default, skip_serializing_if = "ConflictResolution::is_first_source")]
+    pub(crate) conflict_resolution: ConflictResolution,
+
+    /// File names that mark a directory as a skill, in priority order: the
+    /// first one present is the skill's file. Default `["SKILL.md"]`.
+    #[serde(
+        default = "super::defaults::skill_file_names",
+        skip_serializing_if = "super::defaults::is_default_skill_file_names"
+    )]
+    pub(crate) skill_file_names: Vec<String>,
+
+    /// Write an `INDEX.md` listing every library skill to the library root
+    /// on each sync. Default `false`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub(crate) generate_index: bool,
+
+    /// Record the links sync creates in a `.tome-links` file in each
+    /// distribution directory, so cleanup only removes links listed there.
+    /// Default `false`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub(crate) link_marker: bool,
+
+    /// Frontmatter fields every skill should set; `tome doctor` warns about
+    /// skills missing one. Default `["name"]`.
+    #[serde(
+        default = "super::defaults::required_frontmatter",
+        skip_serializing_if = "super::defaults::is_default_required_frontmatter"
+    )]
+    pub(crate) required_frontmatter: Vec<String>,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
+            schema_version: CONFIG_SCHEMA_VERSION,
             library_dir: super::defaults::library_dir(),
             exclude: BTreeSet::new(),
             directories: BTreeMap::new(),
+            defaults: DirectoryDefaults::default(),
+            env_paths: BTreeMap::new(),
             backup: BackupConfig::default(),
+            relative_links: false,
+            on_conflict: ConflictPolicy::default(),
+            conflict_resolution: ConflictResolution::default(),
+            skill_file_names: super::defaults::skill_file_names(),
+            generate_index: false,
+            link_marker: false,
+            required_frontmatter: super::defaults::required_frontmatter(),
         }
     }
 }
@@ -487,6 +814,47 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // --- NameTransform tests ---
+
+    #[test]
+    fn name_transform_slugify() {
+        assert_eq!(NameTransform::Slugify.apply("My Skill"), "my-skill");
+        assert_eq!(NameTransform::Slugify.apply("My_Skill"), "my-skill");
+        assert_eq!(
+            NameTransform::Slugify.apply("  Foo__Bar!! v2 "),
+            "foo-bar-v2"
+        );
+        assert_eq!(NameTransform::Slugify.apply("already-fine"), "already-fine");
+        assert_eq!(NameTransform::Slugify.apply("!!!"), "");
+    }
+
+    #[test]
+    fn name_transform_lowercase_and_none() {
+        assert_eq!(NameTransform::Lowercase.apply("My Skill"), "my skill");
+        assert_eq!(NameTransform::None.apply("My Skill"), "My Skill");
+    }
+
+    #[test]
+    fn name_transform_parses_from_toml() {
+        let cfg: DirectoryConfig =
+            toml::from_str("path = \"/tmp/skills\"\nname_transform = \"slugify\"\n").unwrap();
+        assert_eq!(cfg.name_transform, NameTransform::Slugify);
+        let cfg: DirectoryConfig = toml::from_str("path = \"/tmp/skills\"\n").unwrap();
+        assert_eq!(cfg.name_transform, NameTransform::None);
+    }
+
+    #[test]
+    fn prefix_round_trips_through_toml() {
+        let cfg: DirectoryConfig =
+            toml::from_str("path = \"/tmp/skills\"\nprefix = \"work\"\n").unwrap();
+        assert_eq!(cfg.prefix.as_deref(), Some("work"));
+        let out = toml::to_string(&cfg).unwrap();
+        assert!(out.contains("prefix = \"work\""), "{out}");
+        let cfg: DirectoryConfig = toml::from_str("path = \"/tmp/skills\"\n").unwrap();
+        assert_eq!(cfg.prefix, None);
+        assert!(!toml::to_string(&cfg).unwrap().contains("prefix"));
+    }
+
     // --- DirectoryType tests ---
 
     #[test]
@@ -668,6 +1036,14 @@ bogus = true
         );
     }
 
+    #[test]
+    fn config_rejects_misspelled_library_dir_naming_the_key() {
+        let err = toml::from_str::<Config>("libary_dir = \"/tmp/skills\"\n").unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("unknown field `libary_dir`"), "{msg}");
+        assert!(msg.contains("`library_dir`"), "{msg}");
+    }
+
     #[test]
     fn empty_directories_is_detectable() {
         let config = Config::default();
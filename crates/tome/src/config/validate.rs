@@ -2,26 +2,57 @@
 //!
 //! Hosts:
 //! - `Config::validate` — the public entry point called by `Config::load` and `save_checked`.
+//! - `Config::path_problems` + `ConfigInvalid` — on-demand filesystem checks for `tome config --validate`.
 //! - `path_contains` — lexical-only path-prefix helper (no canonicalisation).
 //!
 //! Cases A/B/C overlap detection (Phase 4 WHARD-01) is the bulk of `validate()`:
 //! library_dir vs distribution-dir equality (A), library inside dist (B),
-//! dist inside library (C).
+//! dist inside library (C). Case D rejects a library_dir that resolves to a
+//! discovery-only directory.
+//!
+//! Two distribution directories resolving to the same path are deliberately
+//! *not* a `validate()` error: `Config::duplicate_distribution_dirs` finds
+//! them so `tome doctor` and `tome config --validate` can report them, and
+//! only `tome sync` refuses to distribute (`ensure_distinct_distribution_dirs`).
 
 use anyhow::Result;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use super::types::{Config, DirectoryRole, DirectoryType};
+use super::types::{
+    CONFIG_SCHEMA_VERSION, Config, DirectoryName, DirectoryRole, DirectoryType, NameTransform,
+};
 use crate::errors::{DomainErrorKind, WithDomainKind};
 use crate::paths::expand_tilde;
 
+/// Typed error returned by `tome config --validate` when the config fails
+/// to load or any directory path is unusable. The problems themselves have
+/// already been printed; `main.rs` downcasts to this type and exits with
+/// code 1 (HARD-04 pattern, see `LintFailed`).
+#[derive(Debug)]
+pub struct ConfigInvalid {
+    pub problems: usize,
+}
+
+impl std::fmt::Display for ConfigInvalid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "config invalid: {} problem(s)", self.problems)
+    }
+}
+
+impl std::error::Error for ConfigInvalid {}
+
 impl Config {
     /// Validate config for common misconfigurations.
     ///
     /// Checks:
+    /// - `schema_version` is the current [`CONFIG_SCHEMA_VERSION`]
     /// - library_dir is not a file
     /// - Role/type combos are valid (Managed only for ClaudePlugins, Target not for Git)
     /// - Git fields (branch/tag/rev) only on Git type directories
+    /// - `prefix` is a valid name fragment and only set on discovery directories
+    /// - `include`/`exclude` only set on distribution directories
+    /// - `max_depth` is at least 1; scan options (`max_depth`, `follow_links`,
+    ///   `name_transform`) not set on ClaudePlugins or CursorRules directories
     ///
     /// CORE-05 / D-14: input-validation failures (role/type, git-field misuse,
     /// library_dir-is-a-file) carry the `Validation` sentinel; library_dir vs
@@ -38,6 +69,30 @@ impl Config {
 
     /// Role/type-combo + git-field + library_dir-is-a-file checks (`Validation`).
     fn validate_roles_and_fields(&self) -> Result<()> {
+        // schema_version must be one this build understands
+        if self.schema_version > CONFIG_SCHEMA_VERSION {
+            anyhow::bail!(
+                "schema_version {} is newer than this tome supports\n\
+                 Conflict: tome.toml declares schema_version = {} but this build reads up to {}\n\
+                 Why: the file was written by a newer tome and may use fields this build would misread.\n\
+                 hint: upgrade tome.",
+                self.schema_version,
+                self.schema_version,
+                CONFIG_SCHEMA_VERSION,
+            );
+        }
+        if self.schema_version < CONFIG_SCHEMA_VERSION {
+            anyhow::bail!(
+                "schema_version {} is out of date\n\
+                 Conflict: tome.toml declares schema_version = {} but this build expects {}\n\
+                 Why: the file uses an older layout.\n\
+                 hint: run `tome migrate-config` to upgrade it.",
+                self.schema_version,
+                self.schema_version,
+                CONFIG_SCHEMA_VERSION,
+            );
+        }
+
         // library_dir exists but is a file, not a directory
         if self.library_dir.exists() && !self.library_dir.is_dir() {
             anyhow::bail!(
@@ -46,6 +101,30 @@ impl Config {
             );
         }
 
+        // skill_file_names must name at least one plain file
+        if self.skill_file_names.is_empty() {
+            anyhow::bail!(
+                "skill_file_names is empty\n\
+                 Conflict: no file name marks a directory as a skill\n\
+                 Why: discovery would find nothing in any directory.\n\
+                 hint: remove skill_file_names to use the default [\"SKILL.md\"], or list at least one name."
+            );
+        }
+        for file_name in &self.skill_file_names {
+            let plain = !file_name.is_empty()
+                && file_name != "."
+                && file_name != ".."
+                && !file_name.contains(['/', '\\']);
+            if !plain {
+                anyhow::bail!(
+                    "skill_file_names: invalid entry '{file_name}'\n\
+                     Conflict: '{file_name}' is not a plain file name\n\
+                     Why: each entry is looked up directly inside a skill directory, so it cannot contain a path separator.\n\
+                     hint: use a bare name such as 'AGENTS.md'."
+                );
+            }
+        }
+
         for (name, dir) in &self.directories {
             let role = dir.role();
 
@@ -117,6 +196,110 @@ impl Config {
                     dir.directory_type,
                 );
             }
+
+            // max_depth must be at least 1
+            if dir.max_depth == Some(0) {
+                anyhow::bail!(
+                    "directory '{name}': invalid max_depth\n\
+                     Conflict: max_depth is 0\n\
+                     Why: max_depth counts how many levels below the directory discovery looks for SKILL.md; 0 would scan nothing.\n\
+                     hint: remove 'max_depth' to use the default of 2, or set it to 3 for <category>/<skill>/SKILL.md layouts.",
+                );
+            }
+
+            // prefix must keep every prefixed skill name valid, and only
+            // means something where skills are discovered
+            if let Some(prefix) = &dir.prefix {
+                if let Err(e) = crate::validation::validate_identifier(prefix, "prefix") {
+                    anyhow::bail!(
+                        "directory '{name}': invalid prefix\n\
+                         Conflict: {e}\n\
+                         Why: the prefix becomes part of every skill name from this directory ('{{prefix}}-{{name}}'), and skill names are directory names in the library.\n\
+                         hint: use a short name such as 'work' — letters, digits, and hyphens.",
+                    );
+                }
+                if !role.is_discovery() {
+                    anyhow::bail!(
+                        "directory '{name}': prefix on non-discovery directory\n\
+                         Conflict: prefix is set but role is {}\n\
+                         Why: prefix renames skills discovered in this directory; skills are only distributed here, never discovered.\n\
+                         hint: remove 'prefix' from this directory, or set it on the source the skills come from.",
+                        role.description(),
+                    );
+                }
+            }
+
+            // include/exclude filter what is linked into a directory, so
+            // they only mean something where skills are distributed
+            if (dir.include.is_some() || dir.exclude.is_some()) && !role.is_distribution() {
+                anyhow::bail!(
+                    "directory '{name}': include/exclude on non-distribution directory\n\
+                     Conflict: include or exclude is set but role is {}\n\
+                     Why: include and exclude choose which library skills are linked into this directory; skills are only discovered here, never distributed.\n\
+                     hint: remove 'include'/'exclude' from this directory, or set them on the target the skills are linked into.",
+                    role.description(),
+                );
+            }
+
+            // Glob paths fan out over many directories, which only makes
+            // sense for scanned, read-only sources
+            if dir.is_glob() {
+                if dir.directory_type != DirectoryType::Directory {
+                    anyhow::bail!(
+                        "directory '{name}': glob path on {} directory\n\
+                         Conflict: path '{}' contains glob characters but type is '{}'\n\
+                         Why: a glob path expands to several directories that are each scanned for SKILL.md; a claude-plugins cache is a single registry location.\n\
+                         hint: change type to 'directory', or point 'path' at one concrete directory.",
+                        dir.directory_type,
+                        dir.path.display(),
+                        dir.directory_type,
+                    );
+                }
+                if role.is_distribution() {
+                    anyhow::bail!(
+                        "directory '{name}': glob path on distribution directory\n\
+                         Conflict: path '{}' contains glob characters but role is {}\n\
+                         Why: tome links skills into a distribution directory, and a glob does not name one directory to link into.\n\
+                         hint: set role to 'source' (or 'managed'), or list each distribution directory separately.",
+                        dir.path.display(),
+                        role.description(),
+                    );
+                }
+            }
+
+            // Scan options only apply to scanned (directory/git) types
+            let discovered_from = match dir.directory_type {
+                DirectoryType::ClaudePlugins => Some("installed_plugins.json"),
+                DirectoryType::CursorRules => Some("the *.mdc rule files at its top level"),
+                DirectoryType::Directory | DirectoryType::Git => None,
+            };
+            if let Some(discovered_from) = discovered_from {
+                let scan_fields: Vec<&str> = [
+                    ("max_depth", dir.max_depth.is_some()),
+                    ("follow_links", dir.follow_links),
+                    ("name_transform", dir.name_transform != NameTransform::None),
+                ]
+                .into_iter()
+                .filter_map(|(field, set)| set.then_some(field))
+                .collect();
+                if !scan_fields.is_empty() {
+                    anyhow::bail!(
+                        "directory '{name}': scan options on {} directory\n\
+                         Conflict: {} set but type is '{}'\n\
+                         Why: {} directories are discovered from {discovered_from}, not by scanning the tree.\n\
+                         hint: remove {} from this directory.",
+                        dir.directory_type,
+                        scan_fields.join(" and "),
+                        dir.directory_type,
+                        dir.directory_type,
+                        scan_fields
+                            .iter()
+                            .map(|f| format!("'{f}'"))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    );
+                }
+            }
         }
 
         Ok(())
@@ -128,8 +311,9 @@ impl Config {
         // --- Path overlap between library_dir and distribution directories ---
         // Lexical only: tilde-expand both sides, normalize trailing '/', compare
         // without hitting the filesystem. Scope is library_dir vs each
-        // distribution (Synced or Target) directory — Source dirs are read-only
-        // and never written to, so they cannot self-loop at sync time.
+        // distribution (Synced or Target) directory. Source dirs are read-only
+        // and never written to, so nesting either way is fine; only Case D
+        // below, the library being the source itself, is rejected.
         let lib = expand_tilde(&self.library_dir)?;
         for (name, dir) in self.distribution_dirs() {
             let dist = expand_tilde(&dir.path)?;
@@ -177,8 +361,132 @@ impl Config {
             }
         }
 
+        // Case D: library_dir is a discovery-only directory. Nothing is
+        // distributed there, but discovery would scan the library and
+        // consolidate would copy every skill onto itself. Compared after
+        // canonicalization when both paths exist, so a symlinked alias of
+        // the same directory is caught too.
+        let lib_canonical = std::fs::canonicalize(&lib).ok();
+        for (name, dir) in self.discovery_dirs() {
+            if dir.role().is_distribution()
+                || dir.directory_type == DirectoryType::Git
+                || dir.is_glob()
+            {
+                continue;
+            }
+            let src = expand_tilde(&dir.path)?;
+            let same = lib.to_string_lossy().trim_end_matches('/')
+                == src.to_string_lossy().trim_end_matches('/')
+                || lib_canonical
+                    .as_ref()
+                    .is_some_and(|l| std::fs::canonicalize(&src).ok().as_ref() == Some(l));
+            if same {
+                anyhow::bail!(
+                    "library_dir is the same path as source directory '{name}'\n\
+                     Conflict: library_dir ({}) resolves to directory '{name}' ({})\n\
+                     Why: tome would discover skills in the library itself and copy each one onto itself at consolidate time.\n\
+                     hint: choose a library_dir outside any source directory, such as '~/.tome/skills'.",
+                    lib.display(),
+                    src.display(),
+                );
+            }
+        }
+
         Ok(())
     }
+
+    /// Pairs of distribution directories that resolve to the same path, as
+    /// `(earlier, later)` in name order.
+    ///
+    /// Both entries would link into one directory and each one's cleanup
+    /// would treat the other's links as its own. Paths compare lexically
+    /// (ignoring a trailing '/') and, when both exist, after
+    /// canonicalization, so a symlinked alias is caught too. Not part of
+    /// [`Config::validate`]: a config with such a pair still loads, so
+    /// `tome doctor` can report it.
+    pub(crate) fn duplicate_distribution_dirs(&self) -> Vec<(&DirectoryName, &DirectoryName)> {
+        let mut seen: Vec<(&DirectoryName, String, Option<PathBuf>)> = Vec::new();
+        let mut duplicates = Vec::new();
+        for (name, dir) in self.distribution_dirs() {
+            let dist = expand_tilde(&dir.path).unwrap_or_else(|_| dir.path.clone());
+            let canonical = std::fs::canonicalize(&dist).ok();
+            let lexical = dist.to_string_lossy().trim_end_matches('/').to_string();
+            if let Some((other, _, _)) = seen.iter().find(|(_, other_lexical, other_canonical)| {
+                *other_lexical == lexical || canonical.is_some() && *other_canonical == canonical
+            }) {
+                duplicates.push((*other, name));
+            }
+            seen.push((name, lexical, canonical));
+        }
+        duplicates
+    }
+
+    /// Refuse to distribute when two distribution directories resolve to the
+    /// same path (see [`Config::duplicate_distribution_dirs`]). Called by
+    /// `tome sync` before any stage runs.
+    pub(crate) fn ensure_distinct_distribution_dirs(&self) -> Result<()> {
+        let Some((other, name)) = self.duplicate_distribution_dirs().into_iter().next() else {
+            return Ok(());
+        };
+        Err(anyhow::anyhow!(
+            "distribution directories '{other}' and '{name}' are the same path\n\
+             Conflict: directory '{other}' ({}) and directory '{name}' ({}) resolve to one directory\n\
+             Why: both would distribute into it, and cleanup for one would remove links the other just made.\n\
+             hint: point each distribution directory at its own skills directory, or remove one of them.",
+            self.directories[other].path.display(),
+            self.directories[name].path.display(),
+        ))
+        .with_domain_kind(DomainErrorKind::Conflict)
+    }
+
+    /// Check that every non-git directory path exists and is a directory.
+    ///
+    /// Unlike [`Config::validate`] this touches the filesystem, so it is
+    /// only run on demand by `tome config --validate`. A missing
+    /// distribution directory is reported too: `tome sync` would silently
+    /// create it, which is exactly what a mistyped path looks like. Returns
+    /// one human-readable problem per offending directory.
+    pub fn path_problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        for (name, dir) in &self.directories {
+            if dir.directory_type == DirectoryType::Git {
+                continue;
+            }
+            let path = &dir.path;
+            if dir.is_glob() {
+                match dir.glob_matches() {
+                    Ok(matches) if matches.is_empty() => problems.push(format!(
+                        "directory '{name}' ({}): glob matches no directories",
+                        path.display()
+                    )),
+                    Ok(_) => {}
+                    Err(e) => problems.push(format!("directory '{name}': {e:#}")),
+                }
+            } else if !path.exists() {
+                let hint = if dir.role().is_distribution() {
+                    " (sync would create it)"
+                } else {
+                    ""
+                };
+                problems.push(format!(
+                    "directory '{name}' ({}): path does not exist{hint}",
+                    path.display()
+                ));
+            } else if !path.is_dir() {
+                problems.push(format!(
+                    "directory '{name}' ({}): path is not a directory",
+                    path.display()
+                ));
+            }
+        }
+        for (other, name) in self.duplicate_distribution_dirs() {
+            problems.push(format!(
+                "directory '{name}' ({}): same path as distribution directory '{other}' (sync refuses to distribute)",
+                self.directories[name].path.display()
+            ));
+        }
+        problems
+    }
 }
 
 /// Check whether `ancestor` is a path-prefix of `descendant` (or equal),
@@ -204,10 +512,10 @@ fn path_contains(ancestor: &Path, descendant: &Path) -> bool {
 #[cfg(test)]
 mod tests {
     use super::super::types::{
-        Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, GitRef,
+        Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, GitRef, NameTransform,
     };
     use std::collections::BTreeMap;
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
 
     // --- Config validation tests ---
 
@@ -227,7 +535,14 @@ mod tests {
                     role: Some(DirectoryRole::Managed),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Default::default()
@@ -252,7 +567,14 @@ mod tests {
                     role: Some(DirectoryRole::Managed),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Default::default()
@@ -265,6 +587,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_rejects_bad_skill_file_names() {
+        for names in [vec![], vec!["docs/SKILL.md".to_string()]] {
+            let config = Config {
+                skill_file_names: names,
+                ..Default::default()
+            };
+            let msg = config.validate().unwrap_err().to_string();
+            assert!(msg.contains("skill_file_names"), "{msg}");
+        }
+        let config = Config {
+            skill_file_names: vec!["SKILL.md".to_string(), "AGENTS.md".to_string()],
+            ..Default::default()
+        };
+        config.validate().unwrap();
+    }
+
     #[test]
     fn validate_rejects_target_with_git_type() {
         let config = Config {
@@ -276,7 +615,14 @@ mod tests {
                     role: Some(DirectoryRole::Target),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Default::default()
@@ -302,7 +648,14 @@ mod tests {
                     role: None,
                     git_ref: Some(GitRef::Branch("main".to_string())),
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Default::default()
@@ -328,7 +681,14 @@ mod tests {
                     role: None,
                     git_ref: None,
                     subdir: Some("nested".to_string()),
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Default::default()
@@ -340,6 +700,122 @@ mod tests {
         assert!(msg.contains("hint:"), "missing hint line: {msg}");
     }
 
+    #[test]
+    fn validate_rejects_zero_max_depth() {
+        let mut dir = dir_cfg("/tmp/skills", DirectoryType::Directory, None);
+        dir.max_depth = Some(0);
+        let config = Config {
+            directories: BTreeMap::from([(DirectoryName::new("bad").unwrap(), dir)]),
+            ..Default::default()
+        };
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(msg.contains("max_depth is 0"), "unexpected error: {msg}");
+        assert!(msg.contains("hint:"), "missing hint line: {msg}");
+    }
+
+    #[test]
+    fn validate_rejects_max_depth_on_claude_plugins() {
+        let mut dir = dir_cfg("/tmp/plugins", DirectoryType::ClaudePlugins, None);
+        dir.max_depth = Some(3);
+        let config = Config {
+            directories: BTreeMap::from([(DirectoryName::new("plugins").unwrap(), dir)]),
+            ..Default::default()
+        };
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(msg.contains("max_depth"), "unexpected error: {msg}");
+        assert!(msg.contains("claude-plugins"), "missing type name: {msg}");
+    }
+
+    #[test]
+    fn validate_rejects_follow_links_on_claude_plugins() {
+        let mut dir = dir_cfg("/tmp/plugins", DirectoryType::ClaudePlugins, None);
+        dir.follow_links = true;
+        let config = Config {
+            directories: BTreeMap::from([(DirectoryName::new("plugins").unwrap(), dir)]),
+            ..Default::default()
+        };
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(msg.contains("follow_links"), "unexpected error: {msg}");
+    }
+
+    #[test]
+    fn validate_rejects_newer_schema_version() {
+        let config = Config {
+            schema_version: super::CONFIG_SCHEMA_VERSION + 1,
+            ..Default::default()
+        };
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(
+            msg.contains("is newer than this tome supports"),
+            "unexpected error: {msg}"
+        );
+        assert!(msg.contains("hint: upgrade tome"), "missing hint: {msg}");
+    }
+
+    #[test]
+    fn validate_rejects_name_transform_on_cursor_rules() {
+        let mut dir = dir_cfg("/tmp/rules", DirectoryType::CursorRules, None);
+        dir.name_transform = NameTransform::Lowercase;
+        let config = Config {
+            directories: BTreeMap::from([(DirectoryName::new("rules").unwrap(), dir)]),
+            ..Default::default()
+        };
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(msg.contains("name_transform"), "unexpected error: {msg}");
+        assert!(msg.contains("cursor-rules"), "missing type name: {msg}");
+    }
+
+    #[test]
+    fn validate_rejects_prefix_with_path_separator() {
+        let mut dir = dir_cfg("/tmp/skills", DirectoryType::Directory, None);
+        dir.prefix = Some("work/team".to_string());
+        let config = Config {
+            directories: BTreeMap::from([(DirectoryName::new("work").unwrap(), dir)]),
+            ..Default::default()
+        };
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(msg.contains("invalid prefix"), "unexpected error: {msg}");
+        assert!(msg.contains("path separator"), "missing cause: {msg}");
+    }
+
+    #[test]
+    fn validate_rejects_prefix_on_target() {
+        let mut dir = dir_cfg(
+            "/tmp/target",
+            DirectoryType::Directory,
+            Some(DirectoryRole::Target),
+        );
+        dir.prefix = Some("work".to_string());
+        let config = Config {
+            directories: BTreeMap::from([(DirectoryName::new("tool").unwrap(), dir)]),
+            ..Default::default()
+        };
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(
+            msg.contains("prefix on non-discovery directory"),
+            "unexpected error: {msg}"
+        );
+    }
+
+    #[test]
+    fn validate_rejects_include_on_source() {
+        let mut dir = dir_cfg(
+            "/tmp/source",
+            DirectoryType::Directory,
+            Some(DirectoryRole::Source),
+        );
+        dir.include = Some([crate::discover::SkillName::new("alpha").unwrap()].into());
+        let config = Config {
+            directories: BTreeMap::from([(DirectoryName::new("local").unwrap(), dir)]),
+            ..Default::default()
+        };
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(
+            msg.contains("include/exclude on non-distribution directory"),
+            "unexpected error: {msg}"
+        );
+    }
+
     #[test]
     fn validate_passes_for_valid_config() {
         let config = Config {
@@ -354,7 +830,14 @@ mod tests {
                         git_ref: None,
 
                         subdir: None,
+                        max_depth: None,
+                        follow_links: false,
+                        name_transform: NameTransform::None,
+                        prefix: None,
+                        include: None,
+                        exclude: None,
                         override_applied: false,
+                        scan_origin: Default::default(),
                     },
                 ),
                 (
@@ -366,7 +849,14 @@ mod tests {
                         git_ref: None,
 
                         subdir: None,
+                        max_depth: None,
+                        follow_links: false,
+                        name_transform: NameTransform::None,
+                        prefix: None,
+                        include: None,
+                        exclude: None,
                         override_applied: false,
+                        scan_origin: Default::default(),
                     },
                 ),
             ]),
@@ -401,7 +891,14 @@ mod tests {
             role,
             git_ref: None,
             subdir: None,
+            max_depth: None,
+            follow_links: false,
+            name_transform: NameTransform::None,
+            prefix: None,
+            include: None,
+            exclude: None,
             override_applied: false,
+            scan_origin: Default::default(),
         }
     }
 
@@ -534,6 +1031,142 @@ mod tests {
             .expect("Source-role nesting must not trigger overlap");
     }
 
+    #[test]
+    fn validate_rejects_library_equals_source() {
+        let config = Config {
+            library_dir: PathBuf::from("/tmp/my-skills/"),
+            directories: BTreeMap::from([(
+                DirectoryName::new("mine").unwrap(),
+                dir_cfg(
+                    "/tmp/my-skills",
+                    DirectoryType::Directory,
+                    Some(DirectoryRole::Source),
+                ),
+            )]),
+            ..Default::default()
+        };
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(
+            msg.contains("same path as source directory 'mine'"),
+            "missing source name: {msg}"
+        );
+        assert!(msg.contains("hint:"), "missing hint: {msg}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn validate_rejects_library_symlinked_to_source() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let source = tmp.path().join("skills");
+        std::fs::create_dir(&source).unwrap();
+        let alias = tmp.path().join("library");
+        std::os::unix::fs::symlink(&source, &alias).unwrap();
+        let config = Config {
+            library_dir: alias,
+            directories: BTreeMap::from([(
+                DirectoryName::new("mine").unwrap(),
+                dir_cfg(
+                    source.to_str().unwrap(),
+                    DirectoryType::Directory,
+                    Some(DirectoryRole::Source),
+                ),
+            )]),
+            ..Default::default()
+        };
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(msg.contains("source directory 'mine'"), "{msg}");
+    }
+
+    #[test]
+    fn two_targets_sharing_a_path_load_but_block_sync() {
+        let config = Config {
+            library_dir: PathBuf::from("/tmp/library"),
+            directories: BTreeMap::from([
+                (
+                    DirectoryName::new("claude").unwrap(),
+                    dir_cfg(
+                        "/tmp/agent-skills",
+                        DirectoryType::Directory,
+                        Some(DirectoryRole::Target),
+                    ),
+                ),
+                (
+                    DirectoryName::new("codex").unwrap(),
+                    dir_cfg(
+                        "/tmp/agent-skills/",
+                        DirectoryType::Directory,
+                        Some(DirectoryRole::Target),
+                    ),
+                ),
+            ]),
+            ..Default::default()
+        };
+        config
+            .validate()
+            .expect("duplicate targets are reported by doctor, not validate");
+        let pairs: Vec<(&str, &str)> = config
+            .duplicate_distribution_dirs()
+            .into_iter()
+            .map(|(a, b)| (a.as_str(), b.as_str()))
+            .collect();
+        assert_eq!(pairs, vec![("claude", "codex")]);
+        let msg = config
+            .ensure_distinct_distribution_dirs()
+            .unwrap_err()
+            .to_string();
+        assert!(
+            msg.contains("distribution directories 'claude' and 'codex' are the same path"),
+            "{msg}"
+        );
+        assert!(msg.contains("hint:"), "missing hint: {msg}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn duplicate_distribution_dirs_sees_through_symlinks() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let skills = tmp.path().join("skills");
+        std::fs::create_dir(&skills).unwrap();
+        let alias = tmp.path().join("alias");
+        std::os::unix::fs::symlink(&skills, &alias).unwrap();
+        let target = |path: &Path| {
+            dir_cfg(
+                path.to_str().unwrap(),
+                DirectoryType::Directory,
+                Some(DirectoryRole::Target),
+            )
+        };
+        let mut config = Config {
+            library_dir: tmp.path().join("library"),
+            directories: BTreeMap::from([
+                (DirectoryName::new("a").unwrap(), target(&skills)),
+                (DirectoryName::new("b").unwrap(), target(&alias)),
+            ]),
+            ..Default::default()
+        };
+        let pairs = config.duplicate_distribution_dirs();
+        assert_eq!(pairs.len(), 1);
+        assert_eq!((pairs[0].0.as_str(), pairs[0].1.as_str()), ("a", "b"));
+        assert!(
+            config
+                .path_problems()
+                .iter()
+                .any(|p| p.contains("same path as distribution directory 'a'")),
+            "{:?}",
+            config.path_problems()
+        );
+
+        // Distinct directories that don't exist yet compare lexically.
+        config.directories.insert(
+            DirectoryName::new("b").unwrap(),
+            target(&tmp.path().join("other")),
+        );
+        assert!(config.duplicate_distribution_dirs().is_empty());
+        config
+            .ensure_distinct_distribution_dirs()
+            .expect("distinct targets are fine");
+    }
+
     #[test]
     fn validate_rejects_tilde_equal_paths() {
         // Both library_dir and directory path use tilde; must expand before compare.
@@ -605,7 +1238,14 @@ mod tests {
                 role: Some(role),
                 git_ref: None,
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
         Config {
@@ -747,4 +1387,99 @@ mod tests {
             }
         }
     }
+
+    // --- path_problems ---
+
+    #[test]
+    fn path_problems_empty_when_paths_are_directories() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(tmp.path().join("entry")).unwrap();
+        let config =
+            build_single_entry_config(tmp.path(), DirectoryType::Directory, DirectoryRole::Source);
+        assert!(config.path_problems().is_empty());
+    }
+
+    #[test]
+    fn path_problems_reports_missing_and_non_directory_paths() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let config =
+            build_single_entry_config(tmp.path(), DirectoryType::Directory, DirectoryRole::Target);
+        let problems = config.path_problems();
+        assert_eq!(problems.len(), 1);
+        assert!(
+            problems[0].contains("does not exist (sync would create it)"),
+            "{problems:?}"
+        );
+
+        std::fs::write(tmp.path().join("entry"), "not a dir").unwrap();
+        let problems = config.path_problems();
+        assert!(
+            problems[0].contains("'combo'") && problems[0].contains("not a directory"),
+            "{problems:?}"
+        );
+    }
+
+    #[test]
+    fn path_problems_skips_git_directories() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let config =
+            build_single_entry_config(tmp.path(), DirectoryType::Git, DirectoryRole::Source);
+        assert!(config.path_problems().is_empty());
+    }
+
+    #[test]
+    fn validate_rejects_glob_path_outside_directory_sources() {
+        let config_with = |dir: DirectoryConfig| Config {
+            directories: BTreeMap::from([(DirectoryName::new("projects").unwrap(), dir)]),
+            ..Default::default()
+        };
+
+        let source = dir_cfg(
+            "/tmp/dev/*/skills",
+            DirectoryType::Directory,
+            Some(DirectoryRole::Source),
+        );
+        config_with(source).validate().unwrap();
+
+        let synced = dir_cfg("/tmp/dev/*/skills", DirectoryType::Directory, None);
+        let err = config_with(synced).validate().unwrap_err().to_string();
+        assert!(err.contains("glob path on distribution directory"), "{err}");
+
+        let plugins = dir_cfg(
+            "/tmp/plugins-*",
+            DirectoryType::ClaudePlugins,
+            Some(DirectoryRole::Managed),
+        );
+        let err = config_with(plugins).validate().unwrap_err().to_string();
+        assert!(
+            err.contains("glob path on claude-plugins directory"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn path_problems_reports_glob_without_matches() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let pattern = tmp.path().join("*/skills");
+        let config = Config {
+            directories: BTreeMap::from([(
+                DirectoryName::new("projects").unwrap(),
+                dir_cfg(
+                    pattern.to_str().unwrap(),
+                    DirectoryType::Directory,
+                    Some(DirectoryRole::Source),
+                ),
+            )]),
+            ..Default::default()
+        };
+        let problems = config.path_problems();
+        assert_eq!(problems.len(), 1);
+        assert!(
+            problems[0].contains("glob matches no directories"),
+            "{problems:?}"
+        );
+
+        std::fs::create_dir_all(tmp.path().join("app/skills")).unwrap();
+        assert!(config.path_problems().is_empty());
+    }
 }
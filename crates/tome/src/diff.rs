@@ -0,0 +1,334 @@
+//! `tome diff` — preview, skill by skill, what `tome sync` would change.
+//!
+//! Runs discover → consolidate → library cleanup → distribute → target
+//! cleanup, every stage in dry-run mode, and turns the per-skill results
+//! into a changelog grouped by the library and each distribution directory.
+//! Reconcile (plugin install/update) and git fetches are skipped: git
+//! directories are read from their existing cache, as `tome sync --dry-run`
+//! does.
+
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+
+use anyhow::Result;
+use console::style;
+use tracing::warn;
+
+use crate::config::Config;
+use crate::machine::MachinePrefs;
+use crate::paths::{TomePaths, collapse_home};
+use crate::progress::{CancelToken, NullSink};
+use crate::{cleanup, discover, distribute, library};
+
+/// What a sync would do to one skill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum ChangeKind {
+    Added,
+    Changed,
+    Removed,
+}
+
+impl ChangeKind {
+    fn sigil(self) -> &'static str {
+        match self {
+            ChangeKind::Added => "+",
+            ChangeKind::Changed => "~",
+            ChangeKind::Removed => "-",
+        }
+    }
+}
+
+/// One line of the diff: `+ skill-a (new)`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct SkillChange {
+    pub(crate) name: String,
+    pub(crate) kind: ChangeKind,
+    pub(crate) reason: &'static str,
+}
+
+impl SkillChange {
+    fn new(kind: ChangeKind, name: impl Into<String>, reason: &'static str) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for SkillChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sigil = match self.kind {
+            ChangeKind::Added => style(self.kind.sigil()).green(),
+            ChangeKind::Changed => style(self.kind.sigil()).yellow(),
+            ChangeKind::Removed => style(self.kind.sigil()).red(),
+        };
+        write!(f, "{sigil} {} ({})", self.name, self.reason)
+    }
+}
+
+/// Changes for the library or one distribution directory.
+#[derive(Debug)]
+pub(crate) struct DiffGroup {
+    /// `Library (~/.tome/skills)` or `<directory> (<path>)`.
+    pub(crate) label: String,
+    /// Sorted by skill name.
+    pub(crate) changes: Vec<SkillChange>,
+}
+
+/// Compute the per-skill changes a sync would make. Touches nothing on disk.
+///
+/// `exclude_sources` mirrors `tome sync --exclude-source`: those directories
+/// are left out of discovery, their library entries are kept as they are,
+/// and they are not distributed to.
+pub(crate) fn compute(
+    config: &Config,
+    paths: &TomePaths,
+    machine_prefs: &MachinePrefs,
+    exclude_sources: &[String],
+) -> Result<Vec<DiffGroup>> {
+    let library_dir = paths.library_dir();
+    let (discovery_config, unknown) = config.without_sources(exclude_sources);
+    for name in &unknown {
+        warn!("{}", crate::unknown_source_warning(name));
+    }
+    let excluded_dirs: HashSet<&str> = config
+        .directories
+        .keys()
+        .filter(|name| !discovery_config.directories.contains_key(*name))
+        .map(|name| name.as_str())
+        .collect();
+    let resolved = crate::resolve_git_directories(
+        &discovery_config,
+        paths,
+        true,
+        &NullSink,
+        &CancelToken::new(),
+    );
+    let mut warnings = Vec::new();
+    let skills = discover::discover_all(&discovery_config, &resolved, &mut warnings)?;
+    for w in &warnings {
+        warn!("{}", w);
+    }
+
+    let (consolidated, mut manifest) = library::consolidate(&skills, paths, true, false, false)?;
+    // As in sync: entries owned by an excluded directory weren't scanned,
+    // so count them as discovered and cleanup leaves them alone.
+    let discovered_names: HashSet<String> = skills
+        .iter()
+        .map(|s| s.name.as_str().to_string())
+        .chain(
+            manifest
+                .iter()
+                .filter(|(_, entry)| {
+                    entry
+                        .source_name()
+                        .is_some_and(|source| excluded_dirs.contains(source.as_str()))
+                })
+                .map(|(name, _)| name.as_str().to_string()),
+        )
+        .collect();
+    let cleaned = cleanup::cleanup_library(
+        library_dir,
+        &discovered_names,
+        &mut manifest,
+        config,
+        true,
+        true,
+        true,
+    )?;
+
+    let mut library_changes: Vec<SkillChange> = Vec::new();
+    for name in &consolidated.created_names {
+        library_changes.push(SkillChange::new(ChangeKind::Added, name.as_str(), "new"));
+    }
+    for name in &consolidated.updated_names {
+        library_changes.push(SkillChange::new(
+            ChangeKind::Changed,
+            name.as_str(),
+            "content changed",
+        ));
+    }
+    for stale in &cleaned.bucket_a_removed_from_config {
+        library_changes.push(SkillChange::new(
+            ChangeKind::Changed,
+            stale.name.as_str(),
+            "source removed from config, kept as unowned",
+        ));
+    }
+    // Skills whose library copy goes away; their links become stale.
+    let mut removed_from_library: BTreeSet<&str> = BTreeSet::new();
+    for stale in &cleaned.bucket_b_missing_from_disk {
+        library_changes.push(SkillChange::new(
+            ChangeKind::Removed,
+            stale.name.as_str(),
+            "missing from source",
+        ));
+        removed_from_library.insert(stale.name.as_str());
+    }
+
+    let mut groups = vec![DiffGroup {
+        label: format!("Library ({})", collapse_home(library_dir)),
+        changes: sorted(library_changes),
+    }];
+
+    for (dir_name, dir_config) in config.distribution_dirs() {
+        if machine_prefs.is_directory_disabled(dir_name.as_str())
+            || excluded_dirs.contains(dir_name.as_str())
+        {
+            continue;
+        }
+        let target_dir = &dir_config.path;
+        let mut changes: Vec<SkillChange> = Vec::new();
+
+        let distributed = distribute::distribute_to_directory(
+            library_dir,
+            dir_name,
+            dir_config,
+            &manifest,
+            machine_prefs,
+            true,
+            false,
+            false,
+            config.relative_links,
+        )?;
+        for name in &distributed.changed_names {
+            if removed_from_library.contains(name.as_str()) {
+                continue;
+            }
+            let (kind, reason) = if target_dir.join(name.as_str()).is_symlink() {
+                (ChangeKind::Changed, "relinked")
+            } else {
+                (ChangeKind::Added, "new")
+            };
+            changes.push(SkillChange::new(kind, name.as_str(), reason));
+        }
+
+        // Dry-run consolidate leaves new skills out of the library on disk,
+        // so distribute cannot see them; apply its filters here instead.
+        for name in &consolidated.created_names {
+            let from_this_dir = manifest
+                .get(name.as_str())
+                .and_then(|entry| entry.source_name())
+                .is_some_and(|source| source == dir_name);
+            if !from_this_dir
+                && machine_prefs.is_skill_allowed(name.as_str(), dir_name.as_str())
+                && dir_config.includes_skill(name.as_str())
+            {
+                changes.push(SkillChange::new(ChangeKind::Added, name.as_str(), "new"));
+            }
+        }
+
+        for link in cleanup::stale_target_links(target_dir, library_dir, None)? {
+            if let Some(name) = link.file_name() {
+                changes.push(SkillChange::new(
+                    ChangeKind::Removed,
+                    name.to_string_lossy(),
+                    "stale",
+                ));
+            }
+        }
+        for name in &removed_from_library {
+            if target_dir.join(name).is_symlink() {
+                changes.push(SkillChange::new(ChangeKind::Removed, *name, "stale"));
+            }
+        }
+
+        let (_, excluded, _) = crate::cleanup_disabled_from_target(
+            dir_config,
+            library_dir,
+            dir_name,
+            machine_prefs,
+            true,
+        )?;
+        for skill in excluded {
+            changes.push(SkillChange::new(
+                ChangeKind::Removed,
+                skill.name.as_str(),
+                "disabled",
+            ));
+        }
+
+        groups.push(DiffGroup {
+            label: format!("{dir_name} ({})", collapse_home(target_dir)),
+            changes: sorted(changes),
+        });
+    }
+
+    Ok(groups)
+}
+
+/// Sort by skill name and drop duplicates (a link can be both stale and
+/// belong to a skill removed from the library).
+fn sorted(mut changes: Vec<SkillChange>) -> Vec<SkillChange> {
+    changes.sort();
+    changes.dedup_by(|a, b| a.name == b.name && a.kind == b.kind);
+    changes
+}
+
+/// Render `groups` for the terminal. Groups without changes are omitted.
+pub(crate) fn render(groups: &[DiffGroup]) -> String {
+    let mut out = String::new();
+    for group in groups.iter().filter(|g| !g.changes.is_empty()) {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&format!("{}\n", style(&group.label).bold()));
+        for change in &group.changes {
+            out.push_str(&format!("  {change}\n"));
+        }
+    }
+    if out.is_empty() {
+        out.push_str("No changes — library and directories are up to date.\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_groups_changes_and_skips_empty_groups() {
+        let groups = vec![
+            DiffGroup {
+                label: "Library (/lib)".to_string(),
+                changes: sorted(vec![
+                    SkillChange::new(ChangeKind::Removed, "c", "missing from source"),
+                    SkillChange::new(ChangeKind::Added, "a", "new"),
+                    SkillChange::new(ChangeKind::Changed, "b", "content changed"),
+                ]),
+            },
+            DiffGroup {
+                label: "idle (/idle)".to_string(),
+                changes: Vec::new(),
+            },
+            DiffGroup {
+                label: "claude (/claude)".to_string(),
+                changes: sorted(vec![
+                    SkillChange::new(ChangeKind::Removed, "c", "stale"),
+                    SkillChange::new(ChangeKind::Removed, "c", "stale"),
+                ]),
+            },
+        ];
+        let out = console::strip_ansi_codes(&render(&groups)).to_string();
+        assert_eq!(
+            out,
+            "Library (/lib)\n  + a (new)\n  ~ b (content changed)\n  - c (missing from source)\n\
+             \n\
+             claude (/claude)\n  - c (stale)\n"
+        );
+    }
+
+    #[test]
+    fn render_reports_no_changes() {
+        let groups = vec![DiffGroup {
+            label: "Library (/lib)".to_string(),
+            changes: Vec::new(),
+        }];
+        assert_eq!(
+            render(&groups),
+            "No changes — library and directories are up to date.\n"
+        );
+    }
+}
@@ -1,13 +1,17 @@
-//! Skill discovery from configured directories. Supports `ClaudePlugins`, `Directory`, and `Git`
-//! directory types, with deduplication (BTreeMap alphabetical order, first-seen-wins) and
-//! exclusion filtering.
+//! Skill discovery from configured directories. Supports `ClaudePlugins`, `CursorRules`,
+//! `Directory`, and `Git` directory types, with deduplication (BTreeMap alphabetical order, first-seen-wins unless
+//! `conflict_resolution` picks another copy) and exclusion filtering.
 
 use anyhow::{Context, Result};
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
-use crate::config::{Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType};
+use crate::config::{
+    Config, ConflictPolicy, ConflictResolution, DirectoryConfig, DirectoryName, DirectoryRole,
+    DirectoryType, NameTransform,
+};
+use crate::discovery_cache::DiscoveryCache;
 
 /// A validated skill name.
 ///
@@ -109,7 +113,7 @@ impl<'de> serde::Deserialize<'de> for SkillName {
 }
 
 /// Provenance metadata from package manager sources.
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "bindings", derive(specta::Type))]
 pub struct SkillProvenance {
     /// Registry identifier (e.g. "my-plugin@npm")
@@ -126,7 +130,7 @@ pub struct SkillProvenance {
 /// `{ "kind": "managed" | "local", ... }` so the GUI can pattern-match
 /// the kind without parsing strings — same shape as `LockfileState`
 /// from plan 26-01.
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "bindings", derive(specta::Type))]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum SkillOrigin {
@@ -236,12 +240,31 @@ pub struct DiscoveredSkill {
     /// Skills view (D-16). The sort semantic itself lives in plan 27-02b.
     #[serde(default)]
     pub synced_at: Option<String>,
+    /// File that marked the directory as a skill: the first entry of
+    /// `skill_file_names` present in it (`SKILL.md` by default). Readers of
+    /// the skill's frontmatter and body use this rather than assuming
+    /// `SKILL.md`. Not serialized across the IPC boundary.
+    #[serde(skip)]
+    #[cfg_attr(feature = "bindings", specta(skip))]
+    pub skill_file: String,
+}
+
+impl DiscoveredSkill {
+    /// Whether the skill's frontmatter says `enabled: false`. Such a skill
+    /// is still discovered and listed, but sync neither consolidates nor
+    /// distributes it.
+    pub fn is_disabled_in_frontmatter(&self) -> bool {
+        self.frontmatter
+            .as_ref()
+            .is_some_and(crate::skill::SkillFrontmatter::is_disabled)
+    }
 }
 
 /// Discover all skills from configured directories.
 ///
 /// Returns deduplicated skills — BTreeMap iteration order provides alphabetical priority
-/// (first-seen-wins on name conflicts). Applies exclusion list from config.
+/// (first-seen-wins on name conflicts unless `conflict_resolution` says otherwise, or
+/// with `on_conflict = "keep-all"`, later copies renamed `<name>@<directory>`). Applies exclusion list from config.
 ///
 /// `resolved_paths` maps git directory names to `(local_path, Option<git_commit_sha>)`.
 /// For git-type directories, the resolved path is used instead of the config path (which is a URL).
@@ -251,21 +274,213 @@ pub fn discover_all(
     resolved_paths: &BTreeMap<DirectoryName, (PathBuf, Option<String>)>,
     warnings: &mut Vec<String>,
 ) -> Result<Vec<DiscoveredSkill>> {
+    let (skills, conflicts) = discover_all_inner(config, resolved_paths, None, warnings)?;
+    push_conflict_warnings(&conflicts, config.conflict_resolution, warnings);
+    Ok(skills)
+}
+
+/// One discovered skill in the [`discover_all_json`] wire shape. Kept
+/// separate from [`DiscoveredSkill`] so that struct can grow fields without
+/// changing what scripts and IPC consumers parse.
+#[derive(serde::Serialize)]
+struct SkillWire<'a> {
+    name: &'a SkillName,
+    path: &'a Path,
+    source: &'a DirectoryName,
+}
+
+/// Discover every skill and return them as a JSON array of
+/// `{"name", "path", "source"}` objects, sorted by name.
+///
+/// Like `tome list`, git directories are not cloned or fetched; only skills
+/// already reachable on disk are found. Non-fatal discovery warnings are
+/// logged rather than returned.
+pub fn discover_all_json(config: &Config) -> Result<String> {
+    let mut warnings = Vec::new();
+    let mut skills = discover_all(config, &BTreeMap::new(), &mut warnings)?;
+    for w in &warnings {
+        tracing::warn!("{w}");
+    }
+    skills.sort_by(|a, b| a.name.cmp(&b.name));
+    let wire: Vec<SkillWire<'_>> = skills
+        .iter()
+        .map(|s| SkillWire {
+            name: &s.name,
+            path: &s.path,
+            source: &s.source_name,
+        })
+        .collect();
+    serde_json::to_string(&wire).context("failed to serialize discovered skills")
+}
+
+/// [`discover_all`] backed by a [`DiscoveryCache`]: directories whose
+/// fingerprint is unchanged reuse their cached scan, and `cache` is updated
+/// with every fresh scan and pruned of directories no longer configured.
+pub(crate) fn discover_all_cached(
+    config: &Config,
+    resolved_paths: &BTreeMap<DirectoryName, (PathBuf, Option<String>)>,
+    cache: &mut DiscoveryCache,
+    warnings: &mut Vec<String>,
+) -> Result<Vec<DiscoveredSkill>> {
+    let (skills, conflicts) = discover_all_inner(config, resolved_paths, Some(cache), warnings)?;
+    push_conflict_warnings(&conflicts, config.conflict_resolution, warnings);
+    Ok(skills)
+}
+
+fn push_conflict_warnings(
+    conflicts: &[SkillConflict],
+    resolution: ConflictResolution,
+    warnings: &mut Vec<String>,
+) {
+    for conflict in conflicts {
+        if conflict.is_within_directory() {
+            warnings.push(format!(
+                "skill directories '{}' and '{}' in '{}' both map to the name '{}', using '{}' ({})",
+                dir_label(&conflict.winner_path),
+                dir_label(&conflict.loser_path),
+                conflict.winner,
+                conflict.name,
+                dir_label(&conflict.winner_path),
+                resolution.as_str()
+            ));
+            continue;
+        }
+        warnings.push(format!(
+            "skill '{}' found in both '{}' and '{}', using '{}' ({})",
+            conflict.name,
+            conflict.winner,
+            conflict.loser,
+            conflict.winner,
+            resolution.as_str()
+        ));
+    }
+}
+
+/// A skill name discovered in more than one directory. Discovery keeps the
+/// copy from `winner` (chosen by `conflict_resolution`, first in
+/// alphabetical directory order by default) and ignores the one from
+/// `loser`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SkillConflict {
+    pub(crate) name: SkillName,
+    pub(crate) winner: DirectoryName,
+    pub(crate) loser: DirectoryName,
+    /// Source directories of the two copies. They differ in name from
+    /// `name` when a `prefix` or `name_transform` produced it.
+    pub(crate) winner_path: PathBuf,
+    pub(crate) loser_path: PathBuf,
+}
+
+impl SkillConflict {
+    fn new(winner: &DiscoveredSkill, loser: &DiscoveredSkill) -> Self {
+        Self {
+            name: winner.name.clone(),
+            winner: winner.source_name.clone(),
+            loser: loser.source_name.clone(),
+            winner_path: winner.path.clone(),
+            loser_path: loser.path.clone(),
+        }
+    }
+
+    /// Both copies come from one directory: two of its skill directories
+    /// map to the same name, e.g. `My_Skill` and `my-skill` under
+    /// `name_transform = "slugify"`.
+    pub(crate) fn is_within_directory(&self) -> bool {
+        self.winner == self.loser
+    }
+}
+
+/// Last path component of `path`, for naming a skill's source directory.
+fn dir_label(path: &Path) -> std::borrow::Cow<'_, str> {
+    path.file_name()
+        .map(|n| n.to_string_lossy())
+        .unwrap_or_else(|| path.to_string_lossy())
+}
+
+/// [`discover_all`] without the conflict warnings: returns the deduplicated
+/// skills plus the structured conflict list, for callers (`tome doctor`)
+/// that report conflicts themselves. Other discovery warnings still land in
+/// `warnings`.
+pub(crate) fn discover_all_with_conflicts(
+    config: &Config,
+    resolved_paths: &BTreeMap<DirectoryName, (PathBuf, Option<String>)>,
+    warnings: &mut Vec<String>,
+) -> Result<(Vec<DiscoveredSkill>, Vec<SkillConflict>)> {
+    discover_all_inner(config, resolved_paths, None, warnings)
+}
+
+fn discover_all_inner(
+    config: &Config,
+    resolved_paths: &BTreeMap<DirectoryName, (PathBuf, Option<String>)>,
+    mut cache: Option<&mut DiscoveryCache>,
+    warnings: &mut Vec<String>,
+) -> Result<(Vec<DiscoveredSkill>, Vec<SkillConflict>)> {
     let mut seen: HashMap<String, usize> = HashMap::new();
+    // Lowercased name -> index of the first skill with that spelling, for
+    // names that differ only in case.
+    let mut seen_folded: HashMap<String, usize> = HashMap::new();
     let mut skills: Vec<DiscoveredSkill> = Vec::new();
-    let mut conflicts: Vec<(String, DirectoryName, DirectoryName)> = Vec::new();
+    let mut conflicts: Vec<SkillConflict> = Vec::new();
+
+    let mut cached_dirs: Vec<&DirectoryName> = Vec::new();
 
     for (dir_name, dir_config) in config.discovery_dirs() {
         // For git directories, use the resolved local path instead of the URL
-        let dir_skills = if let Some((resolved_path, _sha)) = resolved_paths.get(dir_name) {
-            let is_managed = dir_config.role() == DirectoryRole::Managed;
-            discover_flat_directory(dir_name, resolved_path, is_managed, warnings)?
+        let flat_root = if let Some((resolved_path, _sha)) = resolved_paths.get(dir_name) {
+            Some(resolved_path.as_path())
         } else if dir_config.directory_type == DirectoryType::Git {
             // Git directory not in resolved_paths — it failed to clone/update
             // and has no cached state. Skip silently (warning already emitted).
             continue;
+        } else if matches!(
+            dir_config.directory_type,
+            DirectoryType::ClaudePlugins | DirectoryType::CursorRules
+        ) || dir_config.is_glob()
+        {
+            // Not cached: the plugin registry, rule files, and glob expansion
+            // aren't covered by `directory_fingerprint`.
+            None
         } else {
-            discover_directory_entry(dir_name, dir_config, warnings)?
+            Some(dir_config.path.as_path())
+        };
+        let dir_skills = match flat_root {
+            Some(root) => {
+                let is_managed = dir_config.role() == DirectoryRole::Managed;
+                let scan = ScanOptions::for_directory(
+                    dir_name,
+                    dir_config,
+                    config.skill_file_names(),
+                    warnings,
+                );
+                match (
+                    cache.as_deref_mut(),
+                    directory_fingerprint(root, is_managed, scan),
+                ) {
+                    (Some(cache), Some(fingerprint)) => {
+                        cached_dirs.push(dir_name);
+                        if let Some((skills, cached_warnings)) = cache.get(dir_name, &fingerprint) {
+                            warnings.extend(cached_warnings);
+                            skills
+                        } else {
+                            let mut scan_warnings = Vec::new();
+                            let skills = discover_flat_directory(
+                                dir_name,
+                                root,
+                                is_managed,
+                                scan,
+                                &mut scan_warnings,
+                            )?;
+                            cache.insert(dir_name, fingerprint, &skills, &scan_warnings);
+                            warnings.extend(scan_warnings);
+                            skills
+                        }
+                    }
+                    _ => discover_flat_directory(dir_name, root, is_managed, scan, warnings)?,
+                }
+            }
+            None => {
+                discover_directory_entry(dir_name, dir_config, config.skill_file_names(), warnings)?
+            }
         };
 
         // Layer 3 (v0.13+): zero-skills warn + auto-detect hint. When a
@@ -275,17 +490,21 @@ pub fn discover_all(
         // concrete `--subdir <foo>` hint when a match is found.
         //
         // Skipped for ClaudePlugins (has its own discovery flow — empty
-        // result there means "no plugins installed", not "wrong path").
+        // result there means "no plugins installed", not "wrong path") and
+        // CursorRules (rules are files, not SKILL.md subdirectories).
         if dir_skills.is_empty()
             && dir_config.subdir.is_none()
-            && dir_config.directory_type != DirectoryType::ClaudePlugins
+            && !matches!(
+                dir_config.directory_type,
+                DirectoryType::ClaudePlugins | DirectoryType::CursorRules
+            )
         {
             let probe_path = resolved_paths
                 .get(dir_name)
                 .map(|(p, _)| p.clone())
                 .unwrap_or_else(|| dir_config.path.clone());
             if probe_path.is_dir() {
-                match suggest_subdir_for_zero_skill_dir(&probe_path) {
+                match suggest_subdir_for_zero_skill_dir(&probe_path, config.skill_file_names()) {
                     Some(suggested) => warnings.push(format!(
                         "directory '{}' has 0 SKILL.md subdirectories at its root, \
                          but skills appear under '{}/' — set `subdir = \"{}\"` in \
@@ -309,6 +528,21 @@ pub fn discover_all(
             .and_then(|(_path, sha)| sha.clone());
 
         for mut skill in dir_skills {
+            // `prefix` renames before `exclude` and dedup so both see the
+            // library name.
+            if let Some(prefix) = &dir_config.prefix {
+                match SkillName::new(format!("{prefix}-{}", skill.name)) {
+                    Ok(name) => skill.name = name,
+                    Err(e) => {
+                        warnings.push(format!(
+                            "skipping skill '{}' in directory '{}': prefixed name is invalid: {}",
+                            skill.name, dir_name, e
+                        ));
+                        continue;
+                    }
+                }
+            }
+
             if config.exclude.contains(&skill.name) {
                 continue;
             }
@@ -354,36 +588,143 @@ pub fn discover_all(
                 }
             }
 
-            let name_str = skill.name.as_str().to_string();
+            let mut name_str = skill.name.as_str().to_string();
             if let Some(&existing_idx) = seen.get(&name_str) {
+                // `keep-all`: rename the later copy instead of dropping it.
+                // Falls back to first-wins if even the renamed form is taken.
+                let renamed = match config.on_conflict {
+                    ConflictPolicy::KeepAll => {
+                        SkillName::new(format!("{}@{}", skill.name, skill.source_name))
+                            .ok()
+                            .filter(|name| !seen.contains_key(name.as_str()))
+                    }
+                    ConflictPolicy::FirstWins => None,
+                };
+                let Some(renamed) = renamed else {
+                    let existing = &skills[existing_idx];
+                    let replace = match config.conflict_resolution {
+                        ConflictResolution::FirstSource => false,
+                        ConflictResolution::LastSource => true,
+                        ConflictResolution::NewestMtime => {
+                            match (skill_file_modified(&skill), skill_file_modified(existing)) {
+                                (Some(new), Some(old)) => new > old,
+                                _ => false,
+                            }
+                        }
+                    };
+                    if !replace {
+                        conflicts.push(SkillConflict::new(existing, &skill));
+                        continue;
+                    }
+                    // Earlier losers now lose to the new copy.
+                    for c in conflicts.iter_mut().filter(|c| c.name == skill.name) {
+                        c.winner = skill.source_name.clone();
+                        c.winner_path = skill.path.clone();
+                    }
+                    conflicts.push(SkillConflict::new(&skill, existing));
+                    skills[existing_idx] = skill;
+                    continue;
+                };
+                // The renamed form can be excluded on its own.
+                if config.exclude.contains(&renamed) {
+                    continue;
+                }
+                skill.name = renamed;
+                name_str = skill.name.as_str().to_string();
+            }
+
+            let folded = name_str.to_lowercase();
+            if let Some(&existing_idx) = seen_folded.get(&folded) {
                 let existing = &skills[existing_idx];
-                conflicts.push((
-                    name_str,
-                    existing.source_name.clone(),
-                    skill.source_name.clone(),
+                warnings.push(format!(
+                    "skill names '{}' (directory '{}') and '{}' (directory '{}') differ \
+                     only in case — they collide on case-insensitive filesystems \
+                     (the default on macOS and Windows)",
+                    existing.name, existing.source_name, skill.name, skill.source_name
                 ));
             } else {
-                seen.insert(name_str, skills.len());
-                skills.push(skill);
+                seen_folded.insert(folded, skills.len());
             }
+            seen.insert(name_str, skills.len());
+            skills.push(skill);
         }
     }
 
-    for (name, winner, loser) in &conflicts {
-        warnings.push(format!(
-            "skill '{}' found in both '{}' and '{}', using '{}'",
-            name, winner, loser, winner
-        ));
+    if let Some(cache) = cache {
+        cache.retain(&cached_dirs);
     }
 
-    Ok(skills)
+    Ok((skills, conflicts))
+}
+
+/// Modification time of `skill`'s skill file, or `None` when it can't be
+/// read.
+pub(crate) fn skill_file_modified(skill: &DiscoveredSkill) -> Option<std::time::SystemTime> {
+    std::fs::metadata(skill.path.join(&skill.skill_file))
+        .and_then(|m| m.modified())
+        .ok()
+}
+
+/// Skills whose frontmatter `requires` names a skill missing from `skills`,
+/// each with the missing names, in discovery order. Skills disabled in
+/// their frontmatter neither satisfy nor make requirements.
+pub(crate) fn missing_requirements(
+    skills: &[DiscoveredSkill],
+) -> Vec<(&DiscoveredSkill, Vec<String>)> {
+    let enabled = || skills.iter().filter(|s| !s.is_disabled_in_frontmatter());
+    let names: HashSet<&str> = enabled().map(|s| s.name.as_str()).collect();
+    enabled()
+        .filter_map(|skill| {
+            let missing: Vec<String> = skill
+                .frontmatter
+                .as_ref()?
+                .requires()
+                .into_iter()
+                .filter(|required| !names.contains(required.as_str()))
+                .collect();
+            (!missing.is_empty()).then_some((skill, missing))
+        })
+        .collect()
+}
+
+/// "skill 'a' requires 'b', 'c', which no configured directory provides" —
+/// shared by the sync warning and the doctor finding.
+pub(crate) fn missing_requirements_message(skill: &DiscoveredSkill, missing: &[String]) -> String {
+    let missing: Vec<String> = missing.iter().map(|name| format!("'{name}'")).collect();
+    format!(
+        "skill '{}' requires {}, which no configured directory provides",
+        skill.name,
+        missing.join(", ")
+    )
+}
+
+/// Drop every skill whose name matches an earlier one except for case, for
+/// a library on a case-insensitive filesystem where both would land in the
+/// same directory and one link would clobber the other. Keeps the first
+/// spelling — the same first-seen-wins order as cross-directory dedup.
+pub(crate) fn drop_case_collisions(skills: &mut Vec<DiscoveredSkill>, warnings: &mut Vec<String>) {
+    let mut kept: HashMap<String, SkillName> = HashMap::new();
+    skills.retain(|skill| {
+        let folded = skill.name.as_str().to_lowercase();
+        if let Some(first) = kept.get(&folded) {
+            warnings.push(format!(
+                "skipping skill '{}' from directory '{}': the library is on a \
+                 case-insensitive filesystem and '{}' already uses that name",
+                skill.name, skill.source_name, first
+            ));
+            return false;
+        }
+        kept.insert(folded, skill.name.clone());
+        true
+    });
 }
 
 /// Probe a directory for likely-skill-containing subdirectories when the
 /// top-level scan found nothing.
 ///
 /// Returns the first subdir (relative to `dir_path`) that contains at
-/// least one `<entry>/SKILL.md`, or `None` if no candidate matched.
+/// least one `<entry>/SKILL.md` (or another of `skill_file_names`), or
+/// `None` if no candidate matched.
 /// Used by [`discover_all`] to emit an actionable hint when a configured
 /// directory yields zero skills (Layer 3 from the v0.13 `tome add`
 /// improvements).
@@ -394,7 +735,10 @@ pub fn discover_all(
 ///
 /// Returns `None` if `dir_path` itself doesn't exist or no candidate has
 /// at least one valid skill directory inside.
-fn suggest_subdir_for_zero_skill_dir(dir_path: &Path) -> Option<String> {
+fn suggest_subdir_for_zero_skill_dir(
+    dir_path: &Path,
+    skill_file_names: &[String],
+) -> Option<String> {
     const CANDIDATES: &[&str] = &["skills", ".claude-plugin/skills"];
     for candidate in CANDIDATES {
         let candidate_path = dir_path.join(candidate);
@@ -408,7 +752,7 @@ fn suggest_subdir_for_zero_skill_dir(dir_path: &Path) -> Option<String> {
             .filter_map(|e| e.ok())
             .any(|entry| {
                 let p = entry.path();
-                p.is_dir() && p.join("SKILL.md").is_file()
+                p.is_dir() && crate::skill::find_skill_file(&p, skill_file_names).is_some()
             });
         if has_skill_md {
             return Some((*candidate).to_string());
@@ -421,11 +765,14 @@ fn suggest_subdir_for_zero_skill_dir(dir_path: &Path) -> Option<String> {
 ///
 /// Dispatches by directory type:
 /// - `ClaudePlugins` -> reads `installed_plugins.json` for plugin-based discovery
-/// - `Directory` -> scans for `*/SKILL.md` directly
+/// - `Directory` -> scans for `*/SKILL.md` directly, or each directory a glob
+///   `path` matches
+/// - `CursorRules` -> one skill per `*.mdc` rule file
 /// - `Git` -> same as Directory (git clone/pull happens pre-discovery in Phase 2)
 pub fn discover_directory_entry(
     dir_name: &DirectoryName,
     dir_config: &DirectoryConfig,
+    skill_file_names: &[String],
     warnings: &mut Vec<String>,
 ) -> Result<Vec<DiscoveredSkill>> {
     let is_managed = dir_config.role() == DirectoryRole::Managed;
@@ -433,12 +780,134 @@ pub fn discover_directory_entry(
         DirectoryType::ClaudePlugins => {
             discover_claude_plugins(dir_name, &dir_config.path, is_managed, warnings)
         }
-        DirectoryType::Directory | DirectoryType::Git => {
-            discover_flat_directory(dir_name, &dir_config.path, is_managed, warnings)
+        DirectoryType::CursorRules => discover_cursor_rules(dir_name, &dir_config.path, warnings),
+        DirectoryType::Directory if dir_config.is_glob() => {
+            discover_glob_directory(dir_name, dir_config, is_managed, skill_file_names, warnings)
         }
+        DirectoryType::Directory | DirectoryType::Git => discover_flat_directory(
+            dir_name,
+            &dir_config.path,
+            is_managed,
+            ScanOptions::for_directory(dir_name, dir_config, skill_file_names, warnings),
+            warnings,
+        ),
     }
 }
 
+/// Discover skills from every directory a glob `path` matches, attributing
+/// them all to `dir_name`. Matches are scanned in sorted order; a skill name
+/// found under two matches keeps the first and warns about the second.
+fn discover_glob_directory(
+    dir_name: &DirectoryName,
+    dir_config: &DirectoryConfig,
+    is_managed: bool,
+    skill_file_names: &[String],
+    warnings: &mut Vec<String>,
+) -> Result<Vec<DiscoveredSkill>> {
+    let matches = dir_config.glob_matches()?;
+    if matches.is_empty() {
+        warnings.push(format!(
+            "directory '{}' glob matches no directories: {}",
+            dir_name,
+            dir_config.path.display()
+        ));
+        return Ok(Vec::new());
+    }
+
+    let scan = ScanOptions::for_directory(dir_name, dir_config, skill_file_names, warnings);
+    let mut seen: HashMap<SkillName, PathBuf> = HashMap::new();
+    let mut skills = Vec::new();
+    for root in matches {
+        for skill in discover_flat_directory(dir_name, &root, is_managed, scan, warnings)? {
+            if let Some(first) = seen.get(&skill.name) {
+                warnings.push(format!(
+                    "skill '{}' found at both {} and {} in directory '{}', using {}",
+                    skill.name,
+                    first.display(),
+                    skill.path.display(),
+                    dir_name,
+                    first.display()
+                ));
+                continue;
+            }
+            seen.insert(skill.name.clone(), skill.path.clone());
+            skills.push(skill);
+        }
+    }
+    Ok(skills)
+}
+
+/// Discover skills from a folder of Cursor rule files.
+///
+/// Each `*.mdc` file directly inside `dir_path` becomes a skill named after
+/// its file stem, with `path` pointing at the rule file itself. The skill's
+/// `SKILL.md` is generated from the rule when it's copied into the library
+/// (see [`crate::skill::cursor_rule_to_skill_md`]).
+fn discover_cursor_rules(
+    dir_name: &DirectoryName,
+    dir_path: &Path,
+    warnings: &mut Vec<String>,
+) -> Result<Vec<DiscoveredSkill>> {
+    if !dir_path.is_dir() {
+        warnings.push(format!(
+            "directory '{}' path does not exist: {}",
+            dir_name,
+            dir_path.display()
+        ));
+        return Ok(Vec::new());
+    }
+
+    let mut rule_files: Vec<PathBuf> = std::fs::read_dir(dir_path)
+        .with_context(|| format!("failed to read directory {}", dir_path.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.is_file()
+                && path.extension().and_then(|e| e.to_str())
+                    == Some(crate::skill::CURSOR_RULE_EXTENSION)
+        })
+        .collect();
+    rule_files.sort();
+
+    let mut skills = Vec::new();
+    for rule_file in rule_files {
+        let Some(stem) = rule_file.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let name = match SkillName::new(stem) {
+            Ok(name) => name,
+            Err(e) => {
+                warnings.push(format!("skipping rule {}: {}", rule_file.display(), e));
+                continue;
+            }
+        };
+        let frontmatter = match std::fs::read_to_string(&rule_file) {
+            Ok(rule) => {
+                let content = crate::skill::cursor_rule_to_skill_md(stem, &rule);
+                crate::skill::parse(&content).ok().map(|(fm, _body)| fm)
+            }
+            Err(e) => {
+                warnings.push(format!(
+                    "could not read rule in source '{}' at {}: {}",
+                    dir_name,
+                    rule_file.display(),
+                    e
+                ));
+                None
+            }
+        };
+        skills.push(DiscoveredSkill {
+            name,
+            path: rule_file,
+            source_name: dir_name.clone(),
+            origin: SkillOrigin::Local,
+            frontmatter,
+            synced_at: None,
+            skill_file: crate::skill::SKILL_FILE_NAME.to_string(),
+        });
+    }
+    Ok(skills)
+}
+
 /// Discover skills from a Claude plugins cache directory.
 ///
 /// Reads `installed_plugins.json` from the directory path or its parent,
@@ -482,10 +951,18 @@ fn discover_claude_plugins_from_json(
         .with_context(|| format!("failed to parse {}", json_path.display()))?;
 
     let mut raw_skills = Vec::new();
+    let mut unlocated = 0;
 
     if let Some(arr) = plugins.as_array() {
         // v1 format: flat array of plugin objects with "installPath"
-        scan_install_records(arr, source_name, None, &mut raw_skills, warnings)?;
+        scan_install_records(
+            arr,
+            source_name,
+            None,
+            &mut raw_skills,
+            &mut unlocated,
+            warnings,
+        )?;
     } else if let Some(obj) = plugins.get("plugins").and_then(|v| v.as_object()) {
         // v2 format: { "version": 2, "plugins": { "name@registry": [records...] } }
         for (plugin_name, records) in obj {
@@ -495,6 +972,7 @@ fn discover_claude_plugins_from_json(
                     source_name,
                     Some(plugin_name),
                     &mut raw_skills,
+                    &mut unlocated,
                     warnings,
                 )?;
             } else {
@@ -512,6 +990,18 @@ fn discover_claude_plugins_from_json(
         ));
     }
 
+    if unlocated > 0 {
+        warnings.push(format!(
+            "{unlocated} install record(s) in {} have no {} field, skipping them",
+            json_path.display(),
+            INSTALL_PATH_FIELDS
+                .iter()
+                .map(|path| path.join("."))
+                .collect::<Vec<_>>()
+                .join(" / ")
+        ));
+    }
+
     // Deduplicate within a single source — multiple install records can point to the
     // same installPath, which would otherwise surface as spurious same-source conflicts.
     let mut seen: HashSet<String> = HashSet::new();
@@ -523,7 +1013,26 @@ fn discover_claude_plugins_from_json(
     Ok(skills)
 }
 
-/// Scan an array of plugin install records for skills at each `installPath`.
+/// Install-record fields that may hold a plugin's root directory, in the
+/// order they are tried. Each entry is a path of nested keys.
+const INSTALL_PATH_FIELDS: &[&[&str]] = &[&["installPath"], &["pluginRoot"], &["location", "path"]];
+
+/// Root directories named by an install record, in [`INSTALL_PATH_FIELDS`]
+/// order. Empty when the record has none of the fields.
+fn install_record_roots(record: &serde_json::Value) -> Vec<&str> {
+    INSTALL_PATH_FIELDS
+        .iter()
+        .filter_map(|path| {
+            path.iter()
+                .try_fold(record, |value, key| value.get(key))
+                .and_then(|v| v.as_str())
+        })
+        .collect()
+}
+
+/// Scan an array of plugin install records for skills under each record's
+/// root (see [`INSTALL_PATH_FIELDS`]). Records with no recognized root field
+/// are counted in `unlocated` so the caller can warn once.
 ///
 /// When `registry_id` is provided (v2 format), provenance metadata (registry ID + version)
 /// is attached to each discovered skill for lockfile generation.
@@ -532,46 +1041,127 @@ fn scan_install_records(
     source_name: &DirectoryName,
     registry_id: Option<&str>,
     skills: &mut Vec<DiscoveredSkill>,
+    unlocated: &mut usize,
     warnings: &mut Vec<String>,
 ) -> Result<()> {
     for record in records {
-        if let Some(install_path) = record.get("installPath").and_then(|v| v.as_str()) {
-            let skills_dir = PathBuf::from(install_path).join("skills");
-            if skills_dir.is_dir() {
-                let provenance = registry_id.map(|reg_id| {
-                    let version = record
-                        .get("version")
-                        .and_then(|v| v.as_str())
-                        .filter(|v| !v.is_empty())
-                        .map(|v| v.to_string());
-                    let git_commit_sha = record
-                        .get("gitCommitSha")
-                        .and_then(|v| v.as_str())
-                        .filter(|v| !v.is_empty())
-                        .map(|v| v.to_string());
-                    SkillProvenance {
-                        registry_id: reg_id.to_string(),
-                        version,
-                        git_commit_sha,
-                    }
-                });
-                let mode = match provenance {
-                    Some(p) => ScanMode::ManagedWith(p),
-                    None => ScanMode::ManagedNoProvenance,
-                };
-                let mut found = scan_for_skills(&skills_dir, source_name, mode, warnings)?;
-                skills.append(&mut found);
-            }
+        let roots = install_record_roots(record);
+        if roots.is_empty() {
+            *unlocated += 1;
+            continue;
         }
+        // The first root that has a `skills/` directory wins.
+        let Some(skills_dir) = roots
+            .into_iter()
+            .map(|root| PathBuf::from(root).join("skills"))
+            .find(|dir| dir.is_dir())
+        else {
+            continue;
+        };
+        let provenance = registry_id.map(|reg_id| {
+            let version = record
+                .get("version")
+                .and_then(|v| v.as_str())
+                .filter(|v| !v.is_empty())
+                .map(|v| v.to_string());
+            let git_commit_sha = record
+                .get("gitCommitSha")
+                .and_then(|v| v.as_str())
+                .filter(|v| !v.is_empty())
+                .map(|v| v.to_string());
+            SkillProvenance {
+                registry_id: reg_id.to_string(),
+                version,
+                git_commit_sha,
+            }
+        });
+        let mode = match provenance {
+            Some(p) => ScanMode::ManagedWith(p),
+            None => ScanMode::ManagedNoProvenance,
+        };
+        let mut found = scan_for_skills(
+            &skills_dir,
+            source_name,
+            mode,
+            ScanOptions::default(),
+            warnings,
+        )?;
+        skills.append(&mut found);
     }
     Ok(())
 }
 
-/// Discover skills from a flat directory (scan for */SKILL.md).
+/// Default `WalkDir` depth for skill scans: finds `<dir>/<skill>/SKILL.md`.
+const DEFAULT_MAX_DEPTH: usize = 2;
+
+/// Configured `max_depth` values above this draw a discovery warning — a
+/// deep walk over a large tree (a home directory, a monorepo) can be slow.
+const MAX_DEPTH_WARN_THRESHOLD: usize = 10;
+
+/// `skill_file_names` for scans with no config behind them (plugin caches).
+static DEFAULT_SKILL_FILE_NAMES: std::sync::LazyLock<Vec<String>> =
+    std::sync::LazyLock::new(crate::config::defaults::skill_file_names);
+
+/// Per-directory knobs for the `WalkDir` skill scan in [`scan_for_skills`].
+#[derive(Debug, Clone, Copy)]
+struct ScanOptions<'a> {
+    /// Deepest `SKILL.md` considered, counted from the scan root
+    /// (2 = `<root>/<skill>/SKILL.md`).
+    max_depth: usize,
+    /// Descend into symlinked directories. See `DirectoryConfig::follow_links`.
+    follow_links: bool,
+    /// Rewrite directory names before `SkillName::new`.
+    name_transform: NameTransform,
+    /// File names that mark a skill, in priority order. See
+    /// `Config::skill_file_names`.
+    skill_file_names: &'a [String],
+}
+
+impl Default for ScanOptions<'static> {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_DEPTH,
+            follow_links: false,
+            name_transform: NameTransform::None,
+            skill_file_names: &DEFAULT_SKILL_FILE_NAMES,
+        }
+    }
+}
+
+impl<'a> ScanOptions<'a> {
+    /// Scan options for a configured directory. Warns when `max_depth` is
+    /// suspiciously large; `max_depth = 0` is rejected earlier by
+    /// `Config::validate`.
+    fn for_directory(
+        dir_name: &DirectoryName,
+        dir_config: &DirectoryConfig,
+        skill_file_names: &'a [String],
+        warnings: &mut Vec<String>,
+    ) -> Self {
+        let max_depth = dir_config.max_depth.unwrap_or(DEFAULT_MAX_DEPTH);
+        if max_depth > MAX_DEPTH_WARN_THRESHOLD {
+            warnings.push(format!(
+                "directory '{}' has max_depth = {} — scans this deep can be slow on \
+                 large trees; most layouts need 2 or 3",
+                dir_name, max_depth
+            ));
+        }
+        Self {
+            max_depth,
+            follow_links: dir_config.follow_links,
+            name_transform: dir_config.name_transform,
+            skill_file_names,
+        }
+    }
+}
+
+/// Discover skills from a flat directory (scan for `*/SKILL.md`, or deeper
+/// when `scan.max_depth` exceeds [`DEFAULT_MAX_DEPTH`]).
 fn discover_flat_directory(
     dir_name: &DirectoryName,
     dir_path: &Path,
     is_managed: bool,
+    scan: ScanOptions,
     warnings: &mut Vec<String>,
 ) -> Result<Vec<DiscoveredSkill>> {
     if !dir_path.exists() {
@@ -597,17 +1187,158 @@ fn discover_flat_directory(
     } else {
         ScanMode::Local
     };
-    scan_for_skills(dir_path, dir_name, mode, warnings)
+    let mut skills = scan_for_skills(dir_path, dir_name, mode, scan, warnings)?;
+
+    // Per-source `.tomeignore`: drop matching skills here, before
+    // `discover_all` dedups across directories, so an ignored skill never
+    // shadows (or is reported as conflicting with) a same-named skill from
+    // another directory.
+    let ignore_patterns = read_tomeignore(dir_path, dir_name, warnings);
+    if !ignore_patterns.is_empty() {
+        skills.retain(|skill| {
+            !ignore_patterns
+                .iter()
+                .any(|pattern| pattern.matches(skill.name.as_str()))
+        });
+    }
+
+    Ok(skills)
+}
+
+/// Fingerprint of everything [`discover_flat_directory`] depends on for
+/// `dir_path`: the scan settings plus the modification time of every
+/// directory the walk lists, and the mtime and size of each candidate
+/// `SKILL.md` and of `.tomeignore`.
+///
+/// Adding, removing, or renaming an entry bumps its parent directory's
+/// mtime, and editing a `SKILL.md` bumps its own, so an unchanged
+/// fingerprint means a rescan would return the same result. Files inside a
+/// skill directory other than `SKILL.md` are never read. Returns `None` —
+/// don't cache — when the root is missing or any part of the walk fails.
+fn directory_fingerprint(dir_path: &Path, is_managed: bool, scan: ScanOptions) -> Option<String> {
+    use sha2::{Digest, Sha256};
+
+    fn stamp(hasher: &mut Sha256, path: &Path) {
+        match std::fs::metadata(path).and_then(|m| Ok((m.modified()?, m.len()))) {
+            Ok((mtime, len)) => hasher.update(format!("{mtime:?}:{len}\0")),
+            Err(_) => hasher.update("-\0"),
+        }
+    }
+
+    if !dir_path.is_dir() {
+        return None;
+    }
+    let mut hasher = Sha256::new();
+    hasher.update(format!(
+        "{}\0{is_managed}\0{}\0{}\0{:?}\0{:?}\0",
+        dir_path.display(),
+        scan.max_depth,
+        scan.follow_links,
+        scan.name_transform,
+        scan.skill_file_names
+    ));
+    stamp(&mut hasher, &dir_path.join(TOMEIGNORE_FILENAME));
+
+    // Only directories that can hold a `SKILL.md` within `max_depth` are
+    // listed; their own mtimes cover entries being added or removed.
+    let walk = WalkDir::new(dir_path)
+        .follow_links(scan.follow_links)
+        .max_depth(scan.max_depth.saturating_sub(1))
+        .sort_by_file_name();
+    for entry in walk {
+        let entry = entry.ok()?;
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+        let rel = entry.path().strip_prefix(dir_path).ok()?;
+        hasher.update(format!("{}\0", rel.display()));
+        // The deepest level is never listed by the scan — only its
+        // `SKILL.md` is read — so its own mtime doesn't matter.
+        if entry.depth() + 1 < scan.max_depth {
+            let mtime = entry.metadata().ok()?.modified().ok()?;
+            hasher.update(format!("{mtime:?}\0"));
+        }
+        if entry.depth() > 0 {
+            for file_name in scan.skill_file_names {
+                stamp(&mut hasher, &entry.path().join(file_name));
+            }
+        }
+    }
+    Some(
+        hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect(),
+    )
 }
 
-/// Scan a directory for skill subdirectories containing SKILL.md.
+/// File name of the per-directory ignore list, read from a directory's root.
+pub(crate) const TOMEIGNORE_FILENAME: &str = ".tomeignore";
+
+/// Read the `.tomeignore` patterns at the root of `dir_path`.
+///
+/// One skill name or glob per line, in the same [`glob::Pattern`] syntax as
+/// `glob` directory paths (`*`, `?`, `[...]`). Blank lines and lines starting
+/// with `#` are skipped; surrounding whitespace is trimmed. A missing file
+/// yields no patterns; an unreadable file or an invalid pattern yields a
+/// warning, and discovery proceeds without it rather than failing the whole
+/// sync.
+fn read_tomeignore(
+    dir_path: &Path,
+    dir_name: &DirectoryName,
+    warnings: &mut Vec<String>,
+) -> Vec<glob::Pattern> {
+    let path = dir_path.join(TOMEIGNORE_FILENAME);
+    if !path.is_file() {
+        return Vec::new();
+    }
+    match std::fs::read_to_string(&path) {
+        Ok(content) => content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| match glob::Pattern::new(line) {
+                Ok(pattern) => Some(pattern),
+                Err(e) => {
+                    warnings.push(format!(
+                        "ignoring invalid pattern '{}' in {} for directory '{}': {}",
+                        line,
+                        path.display(),
+                        dir_name,
+                        e
+                    ));
+                    None
+                }
+            })
+            .collect(),
+        Err(e) => {
+            warnings.push(format!(
+                "could not read {} for directory '{}': {}",
+                path.display(),
+                dir_name,
+                e
+            ));
+            Vec::new()
+        }
+    }
+}
+
+/// Scan a directory for skill subdirectories containing a skill file
+/// (`SKILL.md`, or the first of `scan.skill_file_names` present).
 ///
 /// `mode` encodes whether discovered skills are `Local` or `Managed` (with or
 /// without provenance metadata). See [`ScanMode`] for the per-variant semantic.
+/// `scan` bounds the walk (depth, symlink following); see [`ScanOptions`].
+///
+/// With `follow_links`, `WalkDir`'s own ancestor tracking detects symlink
+/// loops and reports them as walk errors, which land in `warnings` like any
+/// other unreadable entry rather than failing discovery.
 fn scan_for_skills(
     dir: &Path,
     source_name: &DirectoryName,
     mode: ScanMode,
+    scan: ScanOptions,
     warnings: &mut Vec<String>,
 ) -> Result<Vec<DiscoveredSkill>> {
     let mut skills = Vec::new();
@@ -615,9 +1346,9 @@ fn scan_for_skills(
     // Collect walkdir results into entries and walk errors separately,
     // so that the mutable borrow of `warnings` isn't held across the loop body.
     let (entries, walk_errors): (Vec<_>, Vec<_>) = WalkDir::new(dir)
-        .follow_links(false)
+        .follow_links(scan.follow_links)
         .min_depth(1)
-        .max_depth(2)
+        .max_depth(scan.max_depth)
         .into_iter()
         .partition(|e| e.is_ok());
 
@@ -639,15 +1370,35 @@ fn scan_for_skills(
         }
     }
 
+    // Skill directories in walk order, each with the index in
+    // `skill_file_names` of its highest-priority skill file.
+    let mut skill_dirs: Vec<(PathBuf, usize)> = Vec::new();
+    let mut positions: HashMap<PathBuf, usize> = HashMap::new();
     for entry in entries {
         let entry = entry.expect("BUG: entries vec was filtered to Ok variants by partition above");
-        if entry.file_name() == "SKILL.md"
-            && entry.file_type().is_file()
+        if entry.file_type().is_file()
+            && let Some(rank) = scan
+                .skill_file_names
+                .iter()
+                .position(|n| entry.file_name() == n.as_str())
             && let Some(skill_dir) = entry.path().parent()
-            && skill_dir != dir // skip SKILL.md at source root
-            && let Some(name_str) = skill_dir.file_name().and_then(|n| n.to_str())
+            && skill_dir != dir
+        // skip skill files at source root
         {
-            match SkillName::new(name_str) {
+            match positions.get(skill_dir) {
+                Some(&pos) => skill_dirs[pos].1 = skill_dirs[pos].1.min(rank),
+                None => {
+                    positions.insert(skill_dir.to_path_buf(), skill_dirs.len());
+                    skill_dirs.push((skill_dir.to_path_buf(), rank));
+                }
+            }
+        }
+    }
+
+    for (skill_dir, rank) in &skill_dirs {
+        let skill_file = &scan.skill_file_names[*rank];
+        if let Some(name_str) = skill_dir.file_name().and_then(|n| n.to_str()) {
+            match SkillName::new(scan.name_transform.apply(name_str)) {
                 Ok(name) => {
                     let origin = match &mode {
                         ScanMode::Local => SkillOrigin::Local,
@@ -656,14 +1407,15 @@ fn scan_for_skills(
                             provenance: Some(p.clone()),
                         },
                     };
-                    // Parse frontmatter if SKILL.md exists and is readable
-                    let frontmatter = match std::fs::read_to_string(skill_dir.join("SKILL.md")) {
+                    // Parse frontmatter if the skill file is readable
+                    let frontmatter = match std::fs::read_to_string(skill_dir.join(skill_file)) {
                         Ok(content) => match crate::skill::parse(&content) {
                             Ok((fm, _body)) => Some(fm),
                             Err(e) => {
                                 warnings.push(format!(
-                                    "could not parse frontmatter in {}/SKILL.md: {}",
+                                    "could not parse frontmatter in {}/{}: {}",
                                     skill_dir.display(),
+                                    skill_file,
                                     e
                                 ));
                                 None
@@ -671,7 +1423,8 @@ fn scan_for_skills(
                         },
                         Err(e) => {
                             warnings.push(format!(
-                                "could not read SKILL.md in source '{}' at {}: {}",
+                                "could not read {} in source '{}' at {}: {}",
+                                skill_file,
                                 source_name,
                                 skill_dir.display(),
                                 e
@@ -691,6 +1444,7 @@ fn scan_for_skills(
                         // lib.rs::sync joins in the manifest value after
                         // discover_all returns.
                         synced_at: None,
+                        skill_file: skill_file.clone(),
                     });
                 }
                 Err(e) => {
@@ -734,63 +1488,520 @@ mod tests {
                     git_ref: None,
 
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             );
         }
-        Config {
-            directories,
-            ..Config::default()
-        }
+        Config {
+            directories,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn discover_flat_directory_finds_skills() {
+        let tmp = TempDir::new().unwrap();
+        create_skill(tmp.path(), "skill-a");
+        create_skill(tmp.path(), "skill-b");
+        // Not a skill — no SKILL.md
+        std::fs::create_dir_all(tmp.path().join("not-a-skill")).unwrap();
+        std::fs::write(tmp.path().join("not-a-skill/README.md"), "hi").unwrap();
+
+        let dir_name = DirectoryName::new("test").unwrap();
+        let skills = discover_flat_directory(
+            &dir_name,
+            tmp.path(),
+            false,
+            ScanOptions::default(),
+            &mut Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(skills.len(), 2);
+    }
+
+    #[test]
+    fn discover_flat_directory_warns_on_missing_path() {
+        let mut warnings = Vec::new();
+        let skills = discover_flat_directory(
+            &DirectoryName::new("missing").unwrap(),
+            Path::new("/nonexistent/path"),
+            false,
+            ScanOptions::default(),
+            &mut warnings,
+        )
+        .unwrap();
+        assert!(skills.is_empty());
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("does not exist"));
+    }
+
+    #[test]
+    fn discover_flat_directory_skips_skill_md_at_root() {
+        let tmp = TempDir::new().unwrap();
+        // SKILL.md directly at the directory root (not inside a subdirectory)
+        std::fs::write(
+            tmp.path().join("SKILL.md"),
+            "---\nname: root-skill\n---\n# Root",
+        )
+        .unwrap();
+        // A legitimate skill in a subdirectory
+        create_skill(tmp.path(), "real-skill");
+
+        let dir_name = DirectoryName::new("test").unwrap();
+        let skills = discover_flat_directory(
+            &dir_name,
+            tmp.path(),
+            false,
+            ScanOptions::default(),
+            &mut Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].name, "real-skill");
+    }
+
+    #[test]
+    fn discover_max_depth_reaches_category_layout() {
+        let tmp = TempDir::new().unwrap();
+        create_skill(&tmp.path().join("writing"), "nested-skill");
+        create_skill(tmp.path(), "top-skill");
+        let dir_name = DirectoryName::new("test").unwrap();
+
+        let default = discover_flat_directory(
+            &dir_name,
+            tmp.path(),
+            false,
+            ScanOptions::default(),
+            &mut Vec::new(),
+        )
+        .unwrap();
+        let names: Vec<&str> = default.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["top-skill"]);
+
+        let scan = ScanOptions {
+            max_depth: 3,
+            ..ScanOptions::default()
+        };
+        let mut deep =
+            discover_flat_directory(&dir_name, tmp.path(), false, scan, &mut Vec::new()).unwrap();
+        deep.sort_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
+        let names: Vec<&str> = deep.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["nested-skill", "top-skill"]);
+    }
+
+    #[test]
+    fn discover_skill_file_names_picks_first_listed_file() {
+        let tmp = TempDir::new().unwrap();
+        create_skill(tmp.path(), "plain-skill");
+        let agents = tmp.path().join("agents-skill");
+        std::fs::create_dir_all(&agents).unwrap();
+        std::fs::write(
+            agents.join("AGENTS.md"),
+            "---\nname: agents-skill\ndescription: from AGENTS.md\n---\n",
+        )
+        .unwrap();
+        // Both files: the one listed first wins, and it's still one skill.
+        create_skill(tmp.path(), "both-skill");
+        std::fs::write(tmp.path().join("both-skill/AGENTS.md"), "not read").unwrap();
+        let dir_name = DirectoryName::new("test").unwrap();
+
+        let default = discover_flat_directory(
+            &dir_name,
+            tmp.path(),
+            false,
+            ScanOptions::default(),
+            &mut Vec::new(),
+        )
+        .unwrap();
+        assert!(default.iter().all(|s| s.name != "agents-skill"));
+
+        let names = ["SKILL.md".to_string(), "AGENTS.md".to_string()];
+        let scan = ScanOptions {
+            skill_file_names: &names,
+            ..ScanOptions::default()
+        };
+        let mut skills =
+            discover_flat_directory(&dir_name, tmp.path(), false, scan, &mut Vec::new()).unwrap();
+        skills.sort_by(|a, b| a.name.as_str().cmp(b.name.as_str()));
+        let found: Vec<(&str, &str)> = skills
+            .iter()
+            .map(|s| (s.name.as_str(), s.skill_file.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("agents-skill", "AGENTS.md"),
+                ("both-skill", "SKILL.md"),
+                ("plain-skill", "SKILL.md"),
+            ]
+        );
+        assert_eq!(
+            skills[0]
+                .frontmatter
+                .as_ref()
+                .unwrap()
+                .description
+                .as_deref(),
+            Some("from AGENTS.md")
+        );
+    }
+
+    #[test]
+    fn discover_follow_links_finds_symlinked_skill_dirs() {
+        let tmp = TempDir::new().unwrap();
+        let elsewhere = tmp.path().join("elsewhere");
+        create_skill(&elsewhere, "linked-skill");
+        let source = tmp.path().join("source");
+        std::fs::create_dir_all(&source).unwrap();
+        std::os::unix::fs::symlink(elsewhere.join("linked-skill"), source.join("linked-skill"))
+            .unwrap();
+        let dir_name = DirectoryName::new("test").unwrap();
+
+        let default = discover_flat_directory(
+            &dir_name,
+            &source,
+            false,
+            ScanOptions::default(),
+            &mut Vec::new(),
+        )
+        .unwrap();
+        assert!(default.is_empty(), "symlinked dirs are skipped by default");
+
+        let scan = ScanOptions {
+            follow_links: true,
+            ..ScanOptions::default()
+        };
+        let found =
+            discover_flat_directory(&dir_name, &source, false, scan, &mut Vec::new()).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].name, "linked-skill");
+        assert_eq!(found[0].path, source.join("linked-skill"));
+    }
+
+    #[test]
+    fn discover_follow_links_reports_loops_as_warnings() {
+        let tmp = TempDir::new().unwrap();
+        create_skill(tmp.path(), "real-skill");
+        // `loop/` points back at the scan root.
+        std::os::unix::fs::symlink(tmp.path(), tmp.path().join("loop")).unwrap();
+
+        let scan = ScanOptions {
+            max_depth: 4,
+            follow_links: true,
+            ..ScanOptions::default()
+        };
+        let mut warnings = Vec::new();
+        let skills = discover_flat_directory(
+            &DirectoryName::new("test").unwrap(),
+            tmp.path(),
+            false,
+            scan,
+            &mut warnings,
+        )
+        .unwrap();
+        assert!(skills.iter().any(|s| s.name == "real-skill"));
+        assert!(
+            warnings.iter().any(|w| w.contains("loop")),
+            "expected a loop warning, got: {warnings:?}"
+        );
+    }
+
+    #[test]
+    fn discover_name_transform_slugifies_directory_names() {
+        let tmp = TempDir::new().unwrap();
+        create_skill(tmp.path(), "My Skill");
+        let scan = ScanOptions {
+            name_transform: NameTransform::Slugify,
+            ..ScanOptions::default()
+        };
+        let skills = discover_flat_directory(
+            &DirectoryName::new("test").unwrap(),
+            tmp.path(),
+            false,
+            scan,
+            &mut Vec::new(),
+        )
+        .unwrap();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].name, "my-skill");
+        // The original directory is still the copy source.
+        assert_eq!(skills[0].path, tmp.path().join("My Skill"));
+    }
+
+    #[test]
+    fn discover_all_name_transform_collision_warns() {
+        let tmp1 = TempDir::new().unwrap();
+        let tmp2 = TempDir::new().unwrap();
+        create_skill(tmp1.path(), "Code Review");
+        create_skill(tmp2.path(), "code-review");
+
+        let mut config = config_with_dirs(vec![
+            (
+                "alpha",
+                tmp1.path().to_path_buf(),
+                DirectoryType::Directory,
+                None,
+            ),
+            (
+                "beta",
+                tmp2.path().to_path_buf(),
+                DirectoryType::Directory,
+                None,
+            ),
+        ]);
+        for dir in config.directories.values_mut() {
+            dir.name_transform = NameTransform::Slugify;
+        }
+        let mut warnings = Vec::new();
+        let skills = discover_all(&config, &BTreeMap::new(), &mut warnings).unwrap();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].source_name, "alpha");
+        assert!(
+            warnings
+                .iter()
+                .any(|w| w.contains("skill 'code-review' found in both 'alpha' and 'beta'")),
+            "expected conflict warning, got: {warnings:?}"
+        );
+    }
+
+    #[test]
+    fn discover_all_name_transform_collision_within_directory_names_both_originals() {
+        let tmp = TempDir::new().unwrap();
+        create_skill(tmp.path(), "My_Skill");
+        create_skill(tmp.path(), "my-skill");
+        create_skill(tmp.path(), "Other_Skill");
+
+        let mut config = config_with_dirs(vec![(
+            "local",
+            tmp.path().to_path_buf(),
+            DirectoryType::Directory,
+            None,
+        )]);
+        for dir in config.directories.values_mut() {
+            dir.name_transform = NameTransform::Slugify;
+        }
+        let mut warnings = Vec::new();
+        let skills = discover_all(&config, &BTreeMap::new(), &mut warnings).unwrap();
+        let mut names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        names.sort_unstable();
+        assert_eq!(names, vec!["my-skill", "other-skill"]);
+        // The source directory keeps its original name.
+        let other = skills.iter().find(|s| s.name == "other-skill").unwrap();
+        assert_eq!(other.path, tmp.path().join("Other_Skill"));
+
+        let collision = warnings
+            .iter()
+            .find(|w| w.contains("both map to the name 'my-skill'"))
+            .unwrap_or_else(|| panic!("expected collision warning, got: {warnings:?}"));
+        assert!(collision.contains("'My_Skill'"), "{collision}");
+        assert!(collision.contains("'my-skill'"), "{collision}");
+        assert!(collision.contains("in 'local'"), "{collision}");
+    }
+
+    #[test]
+    fn discover_all_keep_all_renames_later_duplicates() {
+        let tmp1 = TempDir::new().unwrap();
+        let tmp2 = TempDir::new().unwrap();
+        let tmp3 = TempDir::new().unwrap();
+        for tmp in [&tmp1, &tmp2, &tmp3] {
+            create_skill(tmp.path(), "shared");
+        }
+        let mut config = config_with_dirs(vec![
+            (
+                "alpha",
+                tmp1.path().to_path_buf(),
+                DirectoryType::Directory,
+                None,
+            ),
+            (
+                "beta",
+                tmp2.path().to_path_buf(),
+                DirectoryType::Directory,
+                None,
+            ),
+            (
+                "gamma",
+                tmp3.path().to_path_buf(),
+                DirectoryType::Directory,
+                None,
+            ),
+        ]);
+        config.on_conflict = ConflictPolicy::KeepAll;
+        config
+            .exclude
+            .insert(SkillName::new("shared@gamma").unwrap());
+
+        let mut warnings = Vec::new();
+        let skills = discover_all(&config, &BTreeMap::new(), &mut warnings).unwrap();
+        let names: Vec<(&str, &str)> = skills
+            .iter()
+            .map(|s| (s.name.as_str(), s.source_name.as_str()))
+            .collect();
+        assert_eq!(names, vec![("shared", "alpha"), ("shared@beta", "beta")]);
+        assert!(
+            !warnings.iter().any(|w| w.contains("found in both")),
+            "keep-all must not report conflicts: {warnings:?}"
+        );
+    }
+
+    #[test]
+    fn discover_all_json_emits_name_path_source() {
+        let tmp = TempDir::new().unwrap();
+        create_skill(tmp.path(), "zeta");
+        create_skill(tmp.path(), "alpha");
+        let config = config_with_dirs(vec![(
+            "mine",
+            tmp.path().to_path_buf(),
+            DirectoryType::Directory,
+            None,
+        )]);
+
+        let json = discover_all_json(&config).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            parsed,
+            serde_json::json!([
+                {"name": "alpha", "path": tmp.path().join("alpha"), "source": "mine"},
+                {"name": "zeta", "path": tmp.path().join("zeta"), "source": "mine"},
+            ])
+        );
+    }
+
+    #[test]
+    fn discover_all_conflict_resolution_picks_the_winning_copy() {
+        let dirs = [
+            TempDir::new().unwrap(),
+            TempDir::new().unwrap(),
+            TempDir::new().unwrap(),
+        ];
+        // beta's copy is the newest, so each strategy picks a different one.
+        let now = std::time::SystemTime::now();
+        for (tmp, age_hours) in dirs.iter().zip([3, 1, 2]) {
+            create_skill(tmp.path(), "shared");
+            std::fs::File::options()
+                .write(true)
+                .open(tmp.path().join("shared/SKILL.md"))
+                .unwrap()
+                .set_modified(now - std::time::Duration::from_secs(age_hours * 3600))
+                .unwrap();
+        }
+        let mut config = config_with_dirs(
+            ["alpha", "beta", "gamma"]
+                .into_iter()
+                .zip(&dirs)
+                .map(|(name, tmp)| {
+                    (
+                        name,
+                        tmp.path().to_path_buf(),
+                        DirectoryType::Directory,
+                        None,
+                    )
+                })
+                .collect(),
+        );
+
+        for (resolution, winner) in [
+            (ConflictResolution::FirstSource, "alpha"),
+            (ConflictResolution::LastSource, "gamma"),
+            (ConflictResolution::NewestMtime, "beta"),
+        ] {
+            config.conflict_resolution = resolution;
+            let mut warnings = Vec::new();
+            let (skills, conflicts) =
+                discover_all_with_conflicts(&config, &BTreeMap::new(), &mut warnings).unwrap();
+            assert_eq!(skills.len(), 1, "{resolution:?}");
+            assert_eq!(skills[0].source_name, winner, "{resolution:?}");
+            assert_eq!(conflicts.len(), 2, "{resolution:?}");
+            assert!(
+                conflicts.iter().all(|c| c.winner == winner),
+                "{resolution:?}: {conflicts:?}"
+            );
+
+            let mut warnings = Vec::new();
+            discover_all(&config, &BTreeMap::new(), &mut warnings).unwrap();
+            let expected = format!("using '{winner}' ({})", resolution.as_str());
+            assert!(
+                warnings.iter().any(|w| w.contains(&expected)),
+                "expected {expected:?} in {warnings:?}"
+            );
+        }
     }
 
     #[test]
-    fn discover_flat_directory_finds_skills() {
-        let tmp = TempDir::new().unwrap();
-        create_skill(tmp.path(), "skill-a");
-        create_skill(tmp.path(), "skill-b");
-        // Not a skill — no SKILL.md
-        std::fs::create_dir_all(tmp.path().join("not-a-skill")).unwrap();
-        std::fs::write(tmp.path().join("not-a-skill/README.md"), "hi").unwrap();
+    fn discover_all_prefix_keeps_same_named_skills_apart() {
+        let tmp1 = TempDir::new().unwrap();
+        let tmp2 = TempDir::new().unwrap();
+        create_skill(tmp1.path(), "review");
+        create_skill(tmp2.path(), "review");
 
-        let dir_name = DirectoryName::new("test").unwrap();
-        let skills =
-            discover_flat_directory(&dir_name, tmp.path(), false, &mut Vec::new()).unwrap();
-        assert_eq!(skills.len(), 2);
+        let mut config = config_with_dirs(vec![
+            (
+                "oss",
+                tmp1.path().to_path_buf(),
+                DirectoryType::Directory,
+                None,
+            ),
+            (
+                "work",
+                tmp2.path().to_path_buf(),
+                DirectoryType::Directory,
+                None,
+            ),
+        ]);
+        config
+            .directories
+            .get_mut(&DirectoryName::new("work").unwrap())
+            .unwrap()
+            .prefix = Some("work".to_string());
+        let mut warnings = Vec::new();
+        let skills = discover_all(&config, &BTreeMap::new(), &mut warnings).unwrap();
+
+        let mut names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["review", "work-review"]);
+        let prefixed = skills.iter().find(|s| s.name == "work-review").unwrap();
+        // The source directory on disk keeps its original name.
+        assert_eq!(prefixed.path, tmp2.path().join("review"));
+        assert!(warnings.is_empty(), "unexpected warnings: {warnings:?}");
     }
 
     #[test]
-    fn discover_flat_directory_warns_on_missing_path() {
+    fn scan_options_warn_on_large_max_depth() {
+        let dir_name = DirectoryName::new("huge").unwrap();
+        let mut dir = DirectoryConfig {
+            path: PathBuf::from("/tmp"),
+            directory_type: DirectoryType::Directory,
+            role: None,
+            git_ref: None,
+            subdir: None,
+            max_depth: None,
+            follow_links: false,
+            name_transform: NameTransform::None,
+            prefix: None,
+            include: None,
+            exclude: None,
+            override_applied: false,
+            scan_origin: Default::default(),
+        };
         let mut warnings = Vec::new();
-        let skills = discover_flat_directory(
-            &DirectoryName::new("missing").unwrap(),
-            Path::new("/nonexistent/path"),
-            false,
-            &mut warnings,
-        )
-        .unwrap();
-        assert!(skills.is_empty());
+        let scan =
+            ScanOptions::for_directory(&dir_name, &dir, &DEFAULT_SKILL_FILE_NAMES, &mut warnings);
+        assert_eq!(scan.max_depth, DEFAULT_MAX_DEPTH);
+        assert!(warnings.is_empty());
+
+        dir.max_depth = Some(25);
+        let scan =
+            ScanOptions::for_directory(&dir_name, &dir, &DEFAULT_SKILL_FILE_NAMES, &mut warnings);
+        assert_eq!(scan.max_depth, 25);
         assert_eq!(warnings.len(), 1);
-        assert!(warnings[0].contains("does not exist"));
-    }
-
-    #[test]
-    fn discover_flat_directory_skips_skill_md_at_root() {
-        let tmp = TempDir::new().unwrap();
-        // SKILL.md directly at the directory root (not inside a subdirectory)
-        std::fs::write(
-            tmp.path().join("SKILL.md"),
-            "---\nname: root-skill\n---\n# Root",
-        )
-        .unwrap();
-        // A legitimate skill in a subdirectory
-        create_skill(tmp.path(), "real-skill");
-
-        let dir_name = DirectoryName::new("test").unwrap();
-        let skills =
-            discover_flat_directory(&dir_name, tmp.path(), false, &mut Vec::new()).unwrap();
-        assert_eq!(skills.len(), 1);
-        assert_eq!(skills[0].name, "real-skill");
+        assert!(warnings[0].contains("max_depth = 25"), "{}", warnings[0]);
     }
 
     #[test]
@@ -843,6 +2054,161 @@ mod tests {
         assert_eq!(skills[0].name, "keep-me");
     }
 
+    // -- .tomeignore --
+
+    #[test]
+    fn tomeignore_pattern_literal_and_wildcards() {
+        let glob_match = |p: &str, n: &str| glob::Pattern::new(p).unwrap().matches(n);
+        assert!(glob_match("my-skill", "my-skill"));
+        assert!(!glob_match("my-skill", "my-skill-2"));
+        assert!(glob_match("draft-*", "draft-foo"));
+        assert!(glob_match("draft-*", "draft-"));
+        assert!(!glob_match("draft-*", "final-foo"));
+        assert!(glob_match("*-wip", "thing-wip"));
+        assert!(glob_match("a*b*c", "axxbyyc"));
+        assert!(!glob_match("a*b*c", "axxbyy"));
+        assert!(glob_match("skill-?", "skill-1"));
+        assert!(!glob_match("skill-?", "skill-12"));
+        assert!(glob_match("*", "anything"));
+        assert!(glob_match("skill-[ab]", "skill-a"));
+        assert!(!glob_match("skill-[ab]", "skill-c"));
+    }
+
+    #[test]
+    fn tomeignore_drops_matching_skills() {
+        let tmp = TempDir::new().unwrap();
+        create_skill(tmp.path(), "keep-me");
+        create_skill(tmp.path(), "vendored-a");
+        create_skill(tmp.path(), "vendored-b");
+        create_skill(tmp.path(), "exact-drop");
+        std::fs::write(
+            tmp.path().join(TOMEIGNORE_FILENAME),
+            "# third-party entries we don't want\n\nvendored-*\n  exact-drop  \n",
+        )
+        .unwrap();
+
+        let mut warnings = Vec::new();
+        let skills = discover_flat_directory(
+            &DirectoryName::new("test").unwrap(),
+            tmp.path(),
+            false,
+            ScanOptions::default(),
+            &mut warnings,
+        )
+        .unwrap();
+        let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["keep-me"]);
+        assert!(warnings.is_empty(), "unexpected warnings: {warnings:?}");
+    }
+
+    #[test]
+    fn tomeignore_invalid_pattern_warns_and_is_skipped() {
+        let tmp = TempDir::new().unwrap();
+        create_skill(tmp.path(), "keep-me");
+        create_skill(tmp.path(), "draft-a");
+        std::fs::write(tmp.path().join(TOMEIGNORE_FILENAME), "draft-*\nbad-[\n").unwrap();
+
+        let mut warnings = Vec::new();
+        let skills = discover_flat_directory(
+            &DirectoryName::new("test").unwrap(),
+            tmp.path(),
+            false,
+            ScanOptions::default(),
+            &mut warnings,
+        )
+        .unwrap();
+        let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["keep-me"]);
+        assert_eq!(warnings.len(), 1, "warnings: {warnings:?}");
+        assert!(warnings[0].contains("invalid pattern 'bad-['"));
+    }
+
+    #[test]
+    fn tomeignore_applies_before_dedup() {
+        // "alpha" wins name conflicts alphabetically, but its .tomeignore
+        // drops "shared" — so beta's copy is used and no conflict is reported.
+        let tmp1 = TempDir::new().unwrap();
+        let tmp2 = TempDir::new().unwrap();
+        create_skill(tmp1.path(), "shared");
+        create_skill(tmp1.path(), "alpha-only");
+        create_skill(tmp2.path(), "shared");
+        std::fs::write(tmp1.path().join(TOMEIGNORE_FILENAME), "shared\n").unwrap();
+
+        let config = config_with_dirs(vec![
+            (
+                "alpha",
+                tmp1.path().to_path_buf(),
+                DirectoryType::Directory,
+                Some(DirectoryRole::Source),
+            ),
+            (
+                "beta",
+                tmp2.path().to_path_buf(),
+                DirectoryType::Directory,
+                Some(DirectoryRole::Source),
+            ),
+        ]);
+
+        let mut warnings = Vec::new();
+        let skills = discover_all(&config, &BTreeMap::new(), &mut warnings).unwrap();
+        let shared = skills.iter().find(|s| s.name == "shared").unwrap();
+        assert_eq!(shared.source_name, "beta");
+        assert!(
+            warnings.iter().all(|w| !w.contains("found in both")),
+            "ignored skill must not produce a conflict warning: {warnings:?}"
+        );
+    }
+
+    #[test]
+    fn discover_cursor_rules_maps_each_rule_file_to_a_skill() {
+        let tmp = TempDir::new().unwrap();
+        let rules = tmp.path().join(".cursor/rules");
+        std::fs::create_dir_all(&rules).unwrap();
+        std::fs::write(
+            rules.join("react-hooks.mdc"),
+            "---\ndescription: Hook conventions\nglobs: \"*.tsx\"\nalwaysApply: false\n---\nUse hooks.\n",
+        )
+        .unwrap();
+        std::fs::write(rules.join("commit-style.mdc"), "Write short subjects.\n").unwrap();
+        std::fs::write(rules.join("README.md"), "not a rule").unwrap();
+        create_skill(&rules, "nested");
+
+        let config = config_with_dirs(vec![(
+            "cursor",
+            rules.clone(),
+            DirectoryType::CursorRules,
+            None,
+        )]);
+        let mut warnings = Vec::new();
+        let skills = discover_all(&config, &BTreeMap::new(), &mut warnings).unwrap();
+
+        let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["commit-style", "react-hooks"]);
+        let hooks = &skills[1];
+        assert_eq!(hooks.path, rules.join("react-hooks.mdc"));
+        assert_eq!(hooks.source_name, "cursor");
+        assert_eq!(hooks.skill_file, "SKILL.md");
+        assert_eq!(
+            hooks.frontmatter.as_ref().unwrap().description.as_deref(),
+            Some("Hook conventions")
+        );
+        assert!(
+            warnings.iter().all(|w| !w.contains("0 SKILL.md")),
+            "cursor-rules must skip the zero-skill probe: {warnings:?}"
+        );
+    }
+
+    #[test]
+    fn discover_cursor_rules_missing_dir_warns() {
+        let tmp = TempDir::new().unwrap();
+        let dir_name = DirectoryName::new("cursor").unwrap();
+        let mut warnings = Vec::new();
+        let skills =
+            discover_cursor_rules(&dir_name, &tmp.path().join("missing"), &mut warnings).unwrap();
+        assert!(skills.is_empty());
+        assert!(warnings[0].contains("does not exist"), "{warnings:?}");
+    }
+
     #[test]
     fn discover_claude_plugins_reads_json() {
         let tmp = TempDir::new().unwrap();
@@ -864,6 +2230,50 @@ mod tests {
         assert_eq!(skills[0].name, "plugin-skill");
     }
 
+    #[test]
+    fn discover_claude_plugins_reads_alternate_root_fields() {
+        let tmp = TempDir::new().unwrap();
+        let root_plugin = tmp.path().join("root-plugin");
+        create_skill(&root_plugin.join("skills"), "root-skill");
+        let nested_plugin = tmp.path().join("nested-plugin");
+        create_skill(&nested_plugin.join("skills"), "nested-skill");
+
+        let json = serde_json::json!([
+            { "pluginRoot": root_plugin.to_str().unwrap() },
+            { "location": { "path": nested_plugin.to_str().unwrap() } },
+            // `installPath` without skills falls through to `pluginRoot`.
+            {
+                "installPath": tmp.path().join("empty").to_str().unwrap(),
+                "pluginRoot": root_plugin.to_str().unwrap()
+            },
+            { "id": "no-path@x" },
+            { "id": "also-no-path@x" }
+        ]);
+        std::fs::write(
+            tmp.path().join("installed_plugins.json"),
+            serde_json::to_string(&json).unwrap(),
+        )
+        .unwrap();
+
+        let dir_name = DirectoryName::new("plugins").unwrap();
+        let mut warnings = Vec::new();
+        let skills = discover_claude_plugins(&dir_name, tmp.path(), true, &mut warnings).unwrap();
+        let mut names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["nested-skill", "root-skill"]);
+        let unlocated: Vec<&String> = warnings
+            .iter()
+            .filter(|w| w.contains("install record(s)"))
+            .collect();
+        assert_eq!(
+            unlocated.len(),
+            1,
+            "warn once, not per record: {warnings:?}"
+        );
+        assert!(unlocated[0].starts_with("2 install record(s)"));
+        assert!(unlocated[0].contains("installPath / pluginRoot / location.path"));
+    }
+
     #[test]
     fn discover_claude_plugins_reads_v2_json() {
         let tmp = TempDir::new().unwrap();
@@ -1182,6 +2592,7 @@ mod tests {
             &DirectoryName::new("managed-dir").unwrap(),
             tmp.path(),
             true,
+            ScanOptions::default(),
             &mut Vec::new(),
         )
         .unwrap();
@@ -1202,6 +2613,7 @@ mod tests {
             &DirectoryName::new("source-dir").unwrap(),
             tmp.path(),
             false,
+            ScanOptions::default(),
             &mut Vec::new(),
         )
         .unwrap();
@@ -1228,6 +2640,7 @@ mod tests {
             tmp.path(),
             &DirectoryName::new("dir").unwrap(),
             ScanMode::Local,
+            ScanOptions::default(),
             &mut Vec::new(),
         )
         .unwrap();
@@ -1244,6 +2657,7 @@ mod tests {
             tmp.path(),
             &DirectoryName::new("dir").unwrap(),
             ScanMode::ManagedNoProvenance,
+            ScanOptions::default(),
             &mut Vec::new(),
         )
         .unwrap();
@@ -1268,6 +2682,7 @@ mod tests {
             tmp.path(),
             &DirectoryName::new("dir").unwrap(),
             ScanMode::ManagedWith(prov.clone()),
+            ScanOptions::default(),
             &mut Vec::new(),
         )
         .unwrap();
@@ -1293,7 +2708,7 @@ mod tests {
         create_skill(&skills_dir, "first");
 
         assert_eq!(
-            suggest_subdir_for_zero_skill_dir(tmp.path()),
+            suggest_subdir_for_zero_skill_dir(tmp.path(), &DEFAULT_SKILL_FILE_NAMES),
             Some("skills".to_string())
         );
     }
@@ -1306,7 +2721,7 @@ mod tests {
         create_skill(&nested, "alt");
 
         assert_eq!(
-            suggest_subdir_for_zero_skill_dir(tmp.path()),
+            suggest_subdir_for_zero_skill_dir(tmp.path(), &DEFAULT_SKILL_FILE_NAMES),
             Some(".claude-plugin/skills".to_string())
         );
     }
@@ -1315,7 +2730,10 @@ mod tests {
     fn suggest_subdir_returns_none_when_no_candidate_matches() {
         let tmp = TempDir::new().unwrap();
         // Empty directory — no skills anywhere
-        assert_eq!(suggest_subdir_for_zero_skill_dir(tmp.path()), None);
+        assert_eq!(
+            suggest_subdir_for_zero_skill_dir(tmp.path(), &DEFAULT_SKILL_FILE_NAMES),
+            None
+        );
     }
 
     #[test]
@@ -1326,7 +2744,10 @@ mod tests {
         // skills/ exists but contains a regular file, not a skill dir
         std::fs::write(skills_dir.join("not-a-skill.txt"), "data").unwrap();
 
-        assert_eq!(suggest_subdir_for_zero_skill_dir(tmp.path()), None);
+        assert_eq!(
+            suggest_subdir_for_zero_skill_dir(tmp.path(), &DEFAULT_SKILL_FILE_NAMES),
+            None
+        );
     }
 
     #[test]
@@ -1451,7 +2872,14 @@ mod tests {
                 role: Some(DirectoryRole::Source),
                 git_ref: None,
                 subdir: Some("skills".to_string()),
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
         let config = Config {
@@ -1472,4 +2900,194 @@ mod tests {
             "no zero-skill hint when subdir is set: {warnings:?}"
         );
     }
+
+    /// Pin `path`'s mtime so fingerprint tests don't depend on filesystem
+    /// timestamp granularity.
+    fn set_mtime(path: &Path, secs: u64) {
+        std::fs::File::open(path)
+            .unwrap()
+            .set_modified(std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs))
+            .unwrap();
+    }
+
+    #[test]
+    fn directory_fingerprint_tracks_skill_md_and_listing_changes() {
+        let tmp = TempDir::new().unwrap();
+        create_skill(tmp.path(), "alpha");
+        set_mtime(&tmp.path().join("alpha/SKILL.md"), 1_000);
+        set_mtime(tmp.path(), 1_000);
+        let fingerprint = || directory_fingerprint(tmp.path(), false, ScanOptions::default());
+        let base = fingerprint().unwrap();
+        assert_eq!(fingerprint().unwrap(), base, "stable when nothing changed");
+        assert_ne!(
+            directory_fingerprint(tmp.path(), true, ScanOptions::default()).unwrap(),
+            base,
+            "role is part of the fingerprint"
+        );
+
+        // Files other than SKILL.md inside a skill are never looked at.
+        std::fs::write(tmp.path().join("alpha/notes.md"), "x").unwrap();
+        assert_eq!(fingerprint().unwrap(), base);
+
+        set_mtime(&tmp.path().join("alpha/SKILL.md"), 2_000);
+        let edited = fingerprint().unwrap();
+        assert_ne!(edited, base, "editing SKILL.md changes the fingerprint");
+
+        set_mtime(tmp.path(), 2_000);
+        assert_ne!(fingerprint().unwrap(), edited, "root listing change");
+
+        assert!(
+            directory_fingerprint(&tmp.path().join("missing"), false, ScanOptions::default())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn discover_all_cached_reuses_unchanged_directories_and_drops_removed_ones() {
+        let tmp = TempDir::new().unwrap();
+        let source = tmp.path().join("source");
+        create_skill(&source, "alpha");
+        let skill_md = source.join("alpha/SKILL.md");
+        set_mtime(&skill_md, 1_000);
+        let config = config_with_dirs(vec![(
+            "source",
+            source.clone(),
+            DirectoryType::Directory,
+            Some(DirectoryRole::Source),
+        )]);
+        let mut cache = DiscoveryCache::default();
+        let first =
+            discover_all_cached(&config, &BTreeMap::new(), &mut cache, &mut Vec::new()).unwrap();
+        assert_eq!(
+            first[0].frontmatter.as_ref().unwrap().name.as_deref(),
+            Some("alpha")
+        );
+
+        // Rewrite SKILL.md at the same size and restore its mtime: the
+        // fingerprint is unchanged, so the cached frontmatter is served.
+        std::fs::write(&skill_md, "---\nname: omega\n---\n# omega").unwrap();
+        set_mtime(&skill_md, 1_000);
+        let cached =
+            discover_all_cached(&config, &BTreeMap::new(), &mut cache, &mut Vec::new()).unwrap();
+        assert_eq!(
+            cached[0].frontmatter.as_ref().unwrap().name.as_deref(),
+            Some("alpha")
+        );
+
+        // A real edit bumps the mtime and forces a rescan.
+        set_mtime(&skill_md, 2_000);
+        let rescanned =
+            discover_all_cached(&config, &BTreeMap::new(), &mut cache, &mut Vec::new()).unwrap();
+        assert_eq!(
+            rescanned[0].frontmatter.as_ref().unwrap().name.as_deref(),
+            Some("omega")
+        );
+
+        // Removing the directory from the config drops its cache entry.
+        discover_all_cached(
+            &Config::default(),
+            &BTreeMap::new(),
+            &mut cache,
+            &mut Vec::new(),
+        )
+        .unwrap();
+        assert!(
+            cache
+                .get(&DirectoryName::new("source").unwrap(), "")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn discover_glob_path_scans_every_match_under_one_source() {
+        let tmp = TempDir::new().unwrap();
+        create_skill(&tmp.path().join("app-a/skills"), "alpha");
+        create_skill(&tmp.path().join("app-a/skills"), "shared");
+        create_skill(&tmp.path().join("app-b/skills"), "beta");
+        create_skill(&tmp.path().join("app-b/skills"), "shared");
+        std::fs::create_dir_all(tmp.path().join("app-c")).unwrap();
+        let config = config_with_dirs(vec![(
+            "projects",
+            tmp.path().join("*/skills"),
+            DirectoryType::Directory,
+            Some(DirectoryRole::Source),
+        )]);
+
+        let mut warnings = Vec::new();
+        let skills = discover_all(&config, &BTreeMap::new(), &mut warnings).unwrap();
+        let mut names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["alpha", "beta", "shared"]);
+        assert!(skills.iter().all(|s| s.source_name.as_str() == "projects"));
+        let shared = skills.iter().find(|s| s.name.as_str() == "shared").unwrap();
+        assert!(shared.path.starts_with(tmp.path().join("app-a")));
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert!(
+            warnings[0].contains("skill 'shared' found at both"),
+            "{warnings:?}"
+        );
+    }
+
+    #[test]
+    fn discover_glob_path_without_matches_warns() {
+        let tmp = TempDir::new().unwrap();
+        let config = config_with_dirs(vec![(
+            "projects",
+            tmp.path().join("*/skills"),
+            DirectoryType::Directory,
+            Some(DirectoryRole::Source),
+        )]);
+
+        let mut warnings = Vec::new();
+        let skills = discover_all(&config, &BTreeMap::new(), &mut warnings).unwrap();
+        assert!(skills.is_empty());
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert!(
+            warnings[0].contains("glob matches no directories"),
+            "{warnings:?}"
+        );
+    }
+
+    #[test]
+    fn discover_warns_on_names_differing_only_in_case() {
+        let a = TempDir::new().unwrap();
+        let b = TempDir::new().unwrap();
+        create_skill(a.path(), "My-Skill");
+        create_skill(b.path(), "my-skill");
+        let config = config_with_dirs(vec![
+            (
+                "a",
+                a.path().to_path_buf(),
+                DirectoryType::Directory,
+                Some(DirectoryRole::Source),
+            ),
+            (
+                "b",
+                b.path().to_path_buf(),
+                DirectoryType::Directory,
+                Some(DirectoryRole::Source),
+            ),
+        ]);
+
+        let mut warnings = Vec::new();
+        let mut skills = discover_all(&config, &BTreeMap::new(), &mut warnings).unwrap();
+        assert_eq!(skills.len(), 2, "both spellings are distinct skill names");
+        assert!(
+            warnings.iter().any(|w| w.contains(
+                "'My-Skill' (directory 'a') and 'my-skill' (directory 'b') differ only in case"
+            )),
+            "{warnings:?}"
+        );
+
+        // Simulate a case-insensitive library: the second spelling is dropped.
+        let mut warnings = Vec::new();
+        drop_case_collisions(&mut skills, &mut warnings);
+        let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["My-Skill"]);
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0].contains("skipping skill 'my-skill' from directory 'b'"),
+            "{warnings:?}"
+        );
+    }
 }
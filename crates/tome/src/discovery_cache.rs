@@ -0,0 +1,223 @@
+//! Per-directory discovery cache for incremental `tome sync`.
+//!
+//! Discovery re-walks every source and re-reads each `SKILL.md` on every
+//! sync. The cache stores each directory's scan result in
+//! `<tome_home>/.tome-discovery-cache.json`, keyed by a fingerprint of the
+//! directory's scan settings and the modification times of everything the
+//! scan would look at (see `discover::directory_fingerprint`). When the
+//! fingerprint matches, the cached skills and warnings are reused instead of
+//! rescanning.
+//!
+//! The cache is best-effort: a missing, unreadable, or stale-format file is
+//! treated as empty, and entries for directories no longer in the config are
+//! dropped on the next save. `tome sync --force` / `--no-cache` ignore it.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::config::DirectoryName;
+use crate::discover::{DiscoveredSkill, SkillName, SkillOrigin};
+use crate::skill::SkillFrontmatter;
+
+/// Cache file name, stored in the tome home next to the manifest.
+pub(crate) const DISCOVERY_CACHE_FILENAME: &str = ".tome-discovery-cache.json";
+
+/// Cached discovery results for every scanned directory.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct DiscoveryCache {
+    /// Version of tome that wrote the cache. A different version discards
+    /// it, so format or scan changes never reuse old results.
+    tome_version: String,
+    directories: BTreeMap<DirectoryName, CachedDirectory>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedDirectory {
+    fingerprint: String,
+    skills: Vec<CachedSkill>,
+    /// Warnings the scan produced, replayed on a cache hit so a broken
+    /// `SKILL.md` keeps being reported.
+    warnings: Vec<String>,
+}
+
+/// [`DiscoveredSkill`] as stored on disk. Unlike the IPC serialization,
+/// this keeps the frontmatter.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedSkill {
+    name: SkillName,
+    path: PathBuf,
+    source_name: DirectoryName,
+    origin: SkillOrigin,
+    frontmatter: Option<SkillFrontmatter>,
+    skill_file: String,
+}
+
+impl DiscoveryCache {
+    /// Cached skills and warnings for `dir_name`, if its fingerprint still
+    /// matches.
+    pub(crate) fn get(
+        &self,
+        dir_name: &DirectoryName,
+        fingerprint: &str,
+    ) -> Option<(Vec<DiscoveredSkill>, Vec<String>)> {
+        let cached = self.directories.get(dir_name)?;
+        if cached.fingerprint != fingerprint {
+            return None;
+        }
+        let skills = cached
+            .skills
+            .iter()
+            .map(|s| DiscoveredSkill {
+                name: s.name.clone(),
+                path: s.path.clone(),
+                source_name: s.source_name.clone(),
+                origin: s.origin.clone(),
+                frontmatter: s.frontmatter.clone(),
+                synced_at: None,
+                skill_file: s.skill_file.clone(),
+            })
+            .collect();
+        Some((skills, cached.warnings.clone()))
+    }
+
+    /// Record the scan result for `dir_name`. Skipped when the frontmatter
+    /// holds YAML that has no JSON form (e.g. non-string mapping keys), so
+    /// one odd `SKILL.md` can't make the whole cache unwritable.
+    pub(crate) fn insert(
+        &mut self,
+        dir_name: &DirectoryName,
+        fingerprint: String,
+        skills: &[DiscoveredSkill],
+        warnings: &[String],
+    ) {
+        let skills = skills
+            .iter()
+            .map(|s| CachedSkill {
+                name: s.name.clone(),
+                path: s.path.clone(),
+                source_name: s.source_name.clone(),
+                origin: s.origin.clone(),
+                frontmatter: s.frontmatter.clone(),
+                skill_file: s.skill_file.clone(),
+            })
+            .collect();
+        let entry = CachedDirectory {
+            fingerprint,
+            skills,
+            warnings: warnings.to_vec(),
+        };
+        if serde_json::to_value(&entry).is_err() {
+            self.directories.remove(dir_name);
+            return;
+        }
+        self.directories.insert(dir_name.clone(), entry);
+    }
+
+    /// Drop entries for directories not in `keep` — removed from the
+    /// config, or no longer cacheable.
+    pub(crate) fn retain(&mut self, keep: &[&DirectoryName]) {
+        self.directories.retain(|name, _| keep.contains(&name));
+    }
+}
+
+/// Load the cache from `tome_home`. Any problem reading it yields an empty
+/// cache: the worst case is a full rescan.
+pub(crate) fn load(tome_home: &Path) -> DiscoveryCache {
+    let path = tome_home.join(DISCOVERY_CACHE_FILENAME);
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return DiscoveryCache::default();
+    };
+    match serde_json::from_str::<DiscoveryCache>(&content) {
+        Ok(cache) if cache.tome_version == env!("CARGO_PKG_VERSION") => cache,
+        Ok(_) => DiscoveryCache::default(),
+        Err(e) => {
+            tracing::debug!("ignoring unreadable {}: {e}", path.display());
+            DiscoveryCache::default()
+        }
+    }
+}
+
+/// Write the cache to `tome_home`.
+pub(crate) fn save(cache: &mut DiscoveryCache, tome_home: &Path) -> Result<()> {
+    cache.tome_version = env!("CARGO_PKG_VERSION").to_string();
+    let path = tome_home.join(DISCOVERY_CACHE_FILENAME);
+    let content = serde_json::to_string(cache)?;
+    std::fs::write(&path, content).with_context(|| format!("failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skill(name: &str, source: &DirectoryName) -> DiscoveredSkill {
+        let (frontmatter, _) =
+            crate::skill::parse(&format!("---\nname: {name}\ndescription: d\n---\n")).unwrap();
+        DiscoveredSkill {
+            name: SkillName::new(name).unwrap(),
+            path: PathBuf::from(format!("/src/{name}")),
+            source_name: source.clone(),
+            origin: SkillOrigin::Local,
+            frontmatter: Some(frontmatter),
+            synced_at: None,
+            skill_file: "SKILL.md".to_string(),
+        }
+    }
+
+    #[test]
+    fn round_trips_through_disk_and_checks_fingerprint() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let dir = DirectoryName::new("local").unwrap();
+        let mut cache = DiscoveryCache::default();
+        cache.insert(
+            &dir,
+            "fp1".to_string(),
+            &[skill("alpha", &dir)],
+            &["a warning".to_string()],
+        );
+        save(&mut cache, tmp.path()).unwrap();
+
+        let loaded = load(tmp.path());
+        let (skills, warnings) = loaded.get(&dir, "fp1").unwrap();
+        assert_eq!(skills[0].name.as_str(), "alpha");
+        assert_eq!(
+            skills[0]
+                .frontmatter
+                .as_ref()
+                .unwrap()
+                .description
+                .as_deref(),
+            Some("d")
+        );
+        assert_eq!(warnings, vec!["a warning"]);
+        assert!(loaded.get(&dir, "fp2").is_none());
+    }
+
+    #[test]
+    fn load_discards_other_versions_and_garbage() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join(DISCOVERY_CACHE_FILENAME);
+        std::fs::write(
+            &path,
+            r#"{"tome_version":"0.0.0","directories":{"local":{"fingerprint":"x","skills":[],"warnings":[]}}}"#,
+        )
+        .unwrap();
+        assert!(load(tmp.path()).directories.is_empty());
+        std::fs::write(&path, "not json").unwrap();
+        assert!(load(tmp.path()).directories.is_empty());
+    }
+
+    #[test]
+    fn retain_drops_removed_directories() {
+        let keep = DirectoryName::new("keep").unwrap();
+        let gone = DirectoryName::new("gone").unwrap();
+        let mut cache = DiscoveryCache::default();
+        cache.insert(&keep, "a".to_string(), &[], &[]);
+        cache.insert(&gone, "b".to_string(), &[], &[]);
+        cache.retain(&[&keep]);
+        assert!(cache.get(&keep, "a").is_some());
+        assert!(cache.get(&gone, "b").is_none());
+    }
+}
@@ -1,36 +1,95 @@
 //! Distribute library skills to configured directories via symlinks.
 
 use anyhow::{Context, Result};
-use std::os::unix::fs as unix_fs;
-use std::path::Path;
-use tracing::{info, warn};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+use tracing::{debug, info, warn};
 
 use crate::change_cause::ChangeCause;
 use crate::config::{DirectoryConfig, DirectoryName};
+use crate::discover::SkillName;
 use crate::machine::MachinePrefs;
 use crate::manifest::Manifest;
-use crate::paths::symlink_points_to;
+use crate::paths::{
+    BACKUP_SUFFIX, back_up_collision, create_dir_symlink, symlink_points_to, symlink_target_for,
+};
 
 /// Result of distributing skills to a single directory.
-#[derive(Debug)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct DistributeResult {
     pub changed: usize,
+    /// Names of the skills counted in `changed`: links created or recreated.
+    pub changed_names: Vec<SkillName>,
     pub unchanged: usize,
     /// Skills skipped because a non-symlink file already exists at the destination.
     pub skipped: usize,
+    /// Names of the skills counted in `skipped`.
+    pub skipped_names: Vec<SkillName>,
+    /// Destination paths whose existing entry caused each skip.
+    pub skipped_paths: Vec<PathBuf>,
     /// Skills skipped because they are disabled in machine preferences.
     pub disabled: usize,
     /// Skills skipped because they originate from the same directory (prevents circular symlinks).
     pub skipped_managed: usize,
+    /// Backup paths (`<name>.tome-bak`) of non-symlink entries moved aside
+    /// by `--force --adopt` before the symlink was created.
+    pub backed_up: Vec<PathBuf>,
     pub directory_name: DirectoryName,
 }
 
+thread_local! {
+    /// `--verbose` lines held back while an [`across_directories`] worker
+    /// distributes to one directory, so parallel directories don't
+    /// interleave. `None` logs each line straight away.
+    static HELD_LOG: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Per-skill detail for `--verbose` (debug level): what distribute did
+/// with the link at `link` to the library entry `library_skill`.
+fn log_action(dir_name: &DirectoryName, action: &str, library_skill: &Path, link: &Path) {
+    if !tracing::enabled!(tracing::Level::DEBUG) {
+        return;
+    }
+    let line = format!(
+        "{dir_name}: {action} {} ({} -> {})",
+        library_skill
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy(),
+        library_skill.display(),
+        link.display()
+    );
+    HELD_LOG.with_borrow_mut(|held| match held {
+        Some(lines) => lines.push(line),
+        None => debug!("{line}"),
+    });
+}
+
+/// Record a library entry name in one of the `*_names` lists. Library
+/// directory names that aren't valid skill names are counted but not named.
+fn push_name(names: &mut Vec<SkillName>, entry_name: &str) {
+    if let Ok(name) = SkillName::new(entry_name) {
+        names.push(name);
+    }
+}
+
 /// Distribute skills from the library to a configured directory.
 ///
 /// Creates symlinks in `dir_config.path` pointing to library entries.
 /// When `force` is true, all symlinks are recreated even if they already point to the correct target.
+/// When `force` and `adopt` are both true, a real file or directory in the way is renamed to
+/// `<name>.tome-bak` and replaced by the symlink instead of being skipped; an existing backup
+/// is never overwritten.
 /// The `manifest` is used to check whether a skill's source originated from this directory
 /// (to prevent circular symlinks when a directory is both a source and target).
+/// With `relative_links`, link targets are relative to the link's directory, and existing
+/// absolute links to the right skill are rewritten once.
+#[allow(clippy::too_many_arguments)]
 pub fn distribute_to_directory(
     library_dir: &Path,
     dir_name: &DirectoryName,
@@ -39,21 +98,64 @@ pub fn distribute_to_directory(
     machine_prefs: &MachinePrefs,
     dry_run: bool,
     force: bool,
+    adopt: bool,
+    relative_links: bool,
+) -> Result<DistributeResult> {
+    distribute_selected_to_directory(
+        library_dir,
+        dir_name,
+        dir_config,
+        manifest,
+        machine_prefs,
+        dry_run,
+        force,
+        adopt,
+        relative_links,
+        None,
+        &mut |_| {},
+    )
+}
+
+/// [`distribute_to_directory`] restricted to the library entries named in
+/// `only` (`tome sync --only`). Other entries are neither linked nor
+/// counted. `None` distributes everything.
+///
+/// `on_skill` is called with each library skill's name as it is reached,
+/// selected or not, so the caller can report progress against
+/// [`library_skill_count`].
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn distribute_selected_to_directory(
+    library_dir: &Path,
+    dir_name: &DirectoryName,
+    dir_config: &DirectoryConfig,
+    manifest: &Manifest,
+    machine_prefs: &MachinePrefs,
+    dry_run: bool,
+    force: bool,
+    adopt: bool,
+    relative_links: bool,
+    only: Option<&HashSet<String>>,
+    on_skill: &mut dyn FnMut(&str),
 ) -> Result<DistributeResult> {
     let skills_dir = &dir_config.path;
+    let adopt = force && adopt;
 
     if !dry_run {
-        std::fs::create_dir_all(skills_dir)
+        retry_transient(|| std::fs::create_dir_all(skills_dir))
             .with_context(|| format!("failed to create target dir {}", skills_dir.display()))?;
     }
 
     let mut result = DistributeResult {
         directory_name: dir_name.clone(),
         changed: 0,
+        changed_names: Vec::new(),
         unchanged: 0,
         skipped: 0,
+        skipped_names: Vec::new(),
+        skipped_paths: Vec::new(),
         disabled: 0,
         skipped_managed: 0,
+        backed_up: Vec::new(),
     };
 
     // Library may not exist yet on a first dry-run (consolidate skips creating it).
@@ -76,13 +178,20 @@ pub fn distribute_to_directory(
         let library_skill_path = entry.path();
         let target_link = skills_dir.join(&skill_name);
 
-        // Skip non-directory entries (e.g. .tome-manifest.json, .gitignore)
-        if !library_skill_path.is_dir() {
+        if !is_library_skill(&library_skill_path, &skill_name_str) {
             continue;
         }
+        on_skill(&skill_name_str);
 
-        // Skip skills not allowed for this directory (global disabled + per-directory filtering)
-        if !machine_prefs.is_skill_allowed(&skill_name_str, dir_name.as_str()) {
+        if only.is_some_and(|only| !only.contains(skill_name_str.as_ref())) {
+            continue;
+        }
+
+        // Skip skills not allowed for this directory (global disabled + per-directory
+        // filtering in machine.toml, plus the directory's include/exclude lists)
+        if !machine_prefs.is_skill_allowed(&skill_name_str, dir_name.as_str())
+            || !dir_config.includes_skill(&skill_name_str)
+        {
             result.disabled += 1;
             continue;
         }
@@ -116,9 +225,13 @@ pub fn distribute_to_directory(
         let was_symlink = target_link.is_symlink();
         let in_manifest = manifest.get(skill_name_str.as_ref()).is_some();
 
+        let link_target = symlink_target_for(&target_link, &library_skill_path, relative_links);
         if target_link.is_symlink() {
-            if symlink_points_to(&target_link, &library_skill_path) && !force {
+            let in_wanted_form = !relative_links
+                || std::fs::read_link(&target_link).is_ok_and(|raw| raw == link_target);
+            if symlink_points_to(&target_link, &library_skill_path) && in_wanted_form && !force {
                 result.unchanged += 1;
+                log_action(dir_name, "unchanged", &library_skill_path, &target_link);
                 continue;
             }
             // HARD-09 / D-DIST-1: foreign-symlink protection. If the
@@ -144,33 +257,57 @@ pub fn distribute_to_directory(
                     actual_target.display(),
                 );
                 result.skipped += 1;
+                push_name(&mut result.skipped_names, &skill_name_str);
+                log_action(dir_name, "skipped", &library_skill_path, &target_link);
+                result.skipped_paths.push(target_link.clone());
                 continue;
             }
             // Update stale link (or force-recreating)
             if !dry_run {
-                std::fs::remove_file(&target_link).with_context(|| {
+                retry_transient(|| std::fs::remove_file(&target_link)).with_context(|| {
                     format!("failed to remove stale symlink {}", target_link.display())
                 })?;
             }
         } else if target_link.exists() {
-            warn!(
-                "{} exists in target and is not a symlink, skipping",
-                target_link.display()
+            let backup = if adopt {
+                back_up_collision(&target_link, dry_run)?
+            } else {
+                None
+            };
+            let Some(backup) = backup else {
+                warn!(
+                    "{} exists in target and is not a symlink, skipping",
+                    target_link.display()
+                );
+                result.skipped += 1;
+                push_name(&mut result.skipped_names, &skill_name_str);
+                log_action(dir_name, "skipped", &library_skill_path, &target_link);
+                result.skipped_paths.push(target_link.clone());
+                continue;
+            };
+            info!(
+                "backed up {} to {}",
+                target_link.display(),
+                backup.display()
             );
-            result.skipped += 1;
-            continue;
+            result.backed_up.push(backup);
         }
 
         if !dry_run {
-            unix_fs::symlink(&library_skill_path, &target_link).with_context(|| {
-                format!(
-                    "failed to symlink {} -> {}",
-                    target_link.display(),
-                    library_skill_path.display()
-                )
-            })?;
+            retry_transient(|| create_dir_symlink(&link_target, &target_link)).with_context(
+                || {
+                    format!(
+                        "failed to symlink {} -> {}",
+                        target_link.display(),
+                        link_target.display()
+                    )
+                },
+            )?;
         }
         result.changed += 1;
+        push_name(&mut result.changed_names, &skill_name_str);
+        let action = if was_symlink { "updated" } else { "created" };
+        log_action(dir_name, action, &library_skill_path, &target_link);
 
         // OBS-04 emission. Classification per RESEARCH §Open Question 2:
         // - was_symlink: an existing symlink was replaced (stale link update) → HashChanged
@@ -197,6 +334,180 @@ pub fn distribute_to_directory(
     Ok(result)
 }
 
+/// Dry-run stand-in for linking library entries that a real run would
+/// have just created: a dry run never copies new skills into the library,
+/// so [`distribute_selected_to_directory`] cannot see them. Each of
+/// `pending` is counted the way distribution would treat it — disabled,
+/// from this same directory, blocked by a real entry or foreign link, or
+/// linked.
+pub(crate) fn count_pending_links(
+    result: &mut DistributeResult,
+    library_dir: &Path,
+    dir_config: &DirectoryConfig,
+    manifest: &Manifest,
+    machine_prefs: &MachinePrefs,
+    force: bool,
+    pending: &[SkillName],
+) {
+    let dir_name = &result.directory_name;
+    for name in pending {
+        if !machine_prefs.is_skill_allowed(name.as_str(), dir_name.as_str())
+            || !dir_config.includes_skill(name.as_str())
+        {
+            result.disabled += 1;
+            continue;
+        }
+        if manifest
+            .get(name.as_str())
+            .and_then(|e| e.source_name())
+            .is_some_and(|s| s == dir_name.as_str())
+        {
+            result.skipped_managed += 1;
+            continue;
+        }
+        let target_link = dir_config.path.join(name.as_str());
+        let blocked = if target_link.is_symlink() {
+            !force && is_foreign_symlink(&target_link, library_dir)
+        } else {
+            target_link.exists()
+        };
+        if blocked {
+            result.skipped += 1;
+            result.skipped_names.push(name.clone());
+            result.skipped_paths.push(target_link);
+        } else {
+            result.changed += 1;
+            result.changed_names.push(name.clone());
+        }
+    }
+}
+
+/// Whether a library entry is a skill: a directory that is neither hidden
+/// (e.g. `.tome-cache/` from --dump-plan-on-error) nor a `<name>.tome-bak`
+/// backup from `--adopt`. Files such as `.gitignore` are skipped too.
+fn is_library_skill(path: &Path, name: &str) -> bool {
+    path.is_dir() && !name.starts_with('.') && !name.ends_with(BACKUP_SUFFIX)
+}
+
+/// Run `distribute` once per entry of `dirs`, up to `parallel` entries at a
+/// time on scoped threads (`tome sync --parallel`). Outcomes come back in
+/// `dirs` order. With `parallel <= 1` the entries run one after another on
+/// the calling thread and stop at the first error, as the serial loop did;
+/// in parallel every entry runs to completion, and each entry's `--verbose`
+/// lines are held back and logged together, in `dirs` order, at the end.
+pub(crate) fn across_directories<D: Sync, R: Send>(
+    dirs: &[D],
+    parallel: usize,
+    distribute: impl Fn(&D) -> Result<R> + Sync,
+) -> Vec<Result<R>> {
+    if parallel <= 1 || dirs.len() <= 1 {
+        let mut outcomes = Vec::with_capacity(dirs.len());
+        for dir in dirs {
+            let outcome = distribute(dir);
+            let failed = outcome.is_err();
+            outcomes.push(outcome);
+            if failed {
+                break;
+            }
+        }
+        return outcomes;
+    }
+
+    type Slot<R> = Mutex<Option<(Result<R>, Vec<String>)>>;
+    let next = AtomicUsize::new(0);
+    let slots: Vec<Slot<R>> = dirs.iter().map(|_| Mutex::new(None)).collect();
+    std::thread::scope(|scope| {
+        for _ in 0..parallel.min(dirs.len()) {
+            scope.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(dir) = dirs.get(i) else {
+                        break;
+                    };
+                    HELD_LOG.set(Some(Vec::new()));
+                    let outcome = distribute(dir);
+                    let lines = HELD_LOG.take().unwrap_or_default();
+                    *slots[i].lock().unwrap_or_else(PoisonError::into_inner) =
+                        Some((outcome, lines));
+                }
+            });
+        }
+    });
+    slots
+        .into_iter()
+        .filter_map(|slot| slot.into_inner().unwrap_or_else(PoisonError::into_inner))
+        .map(|(outcome, lines)| {
+            for line in lines {
+                debug!("{line}");
+            }
+            outcome
+        })
+        .collect()
+}
+
+/// Number of skills in the library, counted the way distribution walks it.
+/// Zero when the library does not exist yet.
+pub(crate) fn library_skill_count(library_dir: &Path) -> usize {
+    std::fs::read_dir(library_dir).map_or(0, |entries| {
+        entries
+            .flatten()
+            .filter(|e| is_library_skill(&e.path(), &e.file_name().to_string_lossy()))
+            .count()
+    })
+}
+
+/// Environment variable overriding how many times a transient filesystem
+/// error is retried during distribution. `0` disables retries.
+const RETRIES_ENV: &str = "TOME_FS_RETRIES";
+
+/// Retries after the first attempt when `TOME_FS_RETRIES` is unset.
+const DEFAULT_RETRIES: u32 = 2;
+
+/// Delay before the first retry; doubled for each one after.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(50);
+
+/// Run a filesystem operation, retrying errors that networked and
+/// cloud-synced directories report transiently (busy, timed out,
+/// interrupted) with exponential backoff. Anything else, such as permission
+/// denied or not found, fails on the first attempt.
+fn retry_transient<T>(op: impl FnMut() -> io::Result<T>) -> io::Result<T> {
+    let retries = std::env::var(RETRIES_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(DEFAULT_RETRIES);
+    retry_with_backoff(retries, RETRY_BASE_DELAY, op)
+}
+
+fn retry_with_backoff<T>(
+    retries: u32,
+    base_delay: Duration,
+    mut op: impl FnMut() -> io::Result<T>,
+) -> io::Result<T> {
+    let mut delay = base_delay;
+    let mut attempt = 0;
+    loop {
+        match op() {
+            Err(e) if attempt < retries && is_transient(&e) => {
+                attempt += 1;
+                debug!("transient filesystem error ({e}), retry {attempt}/{retries} in {delay:?}");
+                std::thread::sleep(delay);
+                delay *= 2;
+            }
+            result => return result,
+        }
+    }
+}
+
+fn is_transient(e: &io::Error) -> bool {
+    matches!(
+        e.kind(),
+        io::ErrorKind::ResourceBusy
+            | io::ErrorKind::TimedOut
+            | io::ErrorKind::Interrupted
+            | io::ErrorKind::WouldBlock
+    )
+}
+
 /// HARD-09 / D-DIST-1: classify whether `link_path` is a symlink whose
 /// target resolves OUTSIDE `library_dir`. Returns false when the link
 /// is missing, can't be read, or points anywhere under (or equal to)
@@ -268,11 +579,91 @@ pub(crate) fn is_foreign_symlink(link_path: &Path, library_dir: &Path) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{DirectoryConfig, DirectoryName, DirectoryType};
+    use crate::config::{DirectoryConfig, DirectoryName, DirectoryType, NameTransform};
     use crate::machine::MachinePrefs;
     use crate::manifest::SkillEntry;
+    use std::os::unix::fs as unix_fs;
     use tempfile::TempDir;
 
+    #[test]
+    fn across_directories_keeps_order_and_matches_serial() {
+        let dirs: Vec<usize> = (0..10).collect();
+        let square = |n: &usize| -> Result<usize> { Ok(n * n) };
+        let serial: Vec<usize> = across_directories(&dirs, 1, square)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        let parallel: Vec<usize> = across_directories(&dirs, 4, square)
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(serial, parallel);
+        assert_eq!(parallel[9], 81);
+
+        // Serially, the first error stops the loop; in parallel every
+        // directory still runs and the error keeps its position.
+        let fail_at_2 = |n: &usize| -> Result<usize> {
+            anyhow::ensure!(*n != 2, "boom");
+            Ok(*n)
+        };
+        assert_eq!(across_directories(&dirs, 1, fail_at_2).len(), 3);
+        let outcomes = across_directories(&dirs, 4, fail_at_2);
+        assert_eq!(outcomes.len(), 10);
+        assert!(outcomes[2].is_err());
+        assert!(outcomes[3].is_ok());
+    }
+
+    #[test]
+    fn across_directories_parallel_distribution_matches_serial() {
+        let library = TempDir::new().unwrap();
+        let skills = ["skill-a", "skill-b", "skill-c", "skill-d"];
+        setup_library(library.path(), &skills);
+        let run = |parallel: usize| {
+            let targets = TempDir::new().unwrap();
+            let dirs: Vec<(DirectoryName, DirectoryConfig)> = (0..4)
+                .map(|i| {
+                    let name = DirectoryName::new(format!("t{i}")).unwrap();
+                    (name, make_dir_config(targets.path().join(format!("t{i}"))))
+                })
+                .collect();
+            let outcomes = across_directories(&dirs, parallel, |(name, dir_config)| {
+                distribute_to_directory(
+                    library.path(),
+                    name,
+                    dir_config,
+                    &empty_manifest(),
+                    &MachinePrefs::default(),
+                    false,
+                    false,
+                    false,
+                    false,
+                )
+            });
+            let summary: Vec<(String, Vec<SkillName>, usize)> = outcomes
+                .into_iter()
+                .map(Result::unwrap)
+                .map(|r| (r.directory_name.to_string(), r.changed_names, r.unchanged))
+                .collect();
+            for (name, _) in &dirs {
+                for skill in skills {
+                    let link = targets.path().join(name.as_str()).join(skill);
+                    assert_eq!(
+                        std::fs::read_link(&link).unwrap(),
+                        library.path().join(skill),
+                        "{}",
+                        link.display()
+                    );
+                }
+            }
+            summary
+        };
+
+        let serial = run(1);
+        assert_eq!(serial.len(), 4);
+        assert_eq!(serial[0].1.len(), skills.len());
+        assert_eq!(run(3), serial);
+    }
+
     fn setup_library(dir: &std::path::Path, skill_names: &[&str]) {
         for name in skill_names {
             let skill_dir = dir.join(name);
@@ -293,7 +684,14 @@ mod tests {
             git_ref: None,
 
             subdir: None,
+            max_depth: None,
+            follow_links: false,
+            name_transform: NameTransform::None,
+            prefix: None,
+            include: None,
+            exclude: None,
             override_applied: false,
+            scan_origin: Default::default(),
         }
     }
 
@@ -314,13 +712,117 @@ mod tests {
             &MachinePrefs::default(),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
         assert_eq!(result.changed, 2);
+        let mut names: Vec<&str> = result.changed_names.iter().map(SkillName::as_str).collect();
+        names.sort();
+        assert_eq!(names, ["skill-a", "skill-b"]);
         assert!(target_dir.path().join("skill-a").is_symlink());
         assert!(target_dir.path().join("skill-b").is_symlink());
     }
 
+    #[test]
+    fn distribute_reports_each_library_skill_for_progress() {
+        let library = TempDir::new().unwrap();
+        let target_dir = TempDir::new().unwrap();
+        setup_library(library.path(), &["skill-a", "skill-b", "skill-c"]);
+        std::fs::create_dir_all(library.path().join(".tome-cache")).unwrap();
+        std::fs::create_dir_all(library.path().join("skill-a.tome-bak")).unwrap();
+        assert_eq!(library_skill_count(library.path()), 3);
+
+        let only: HashSet<String> = HashSet::from(["skill-b".to_string()]);
+        let mut seen = Vec::new();
+        let result = distribute_selected_to_directory(
+            library.path(),
+            &DirectoryName::new("test").unwrap(),
+            &make_dir_config(target_dir.path().to_path_buf()),
+            &empty_manifest(),
+            &MachinePrefs::default(),
+            false,
+            false,
+            false,
+            false,
+            Some(&only),
+            &mut |name| seen.push(name.to_string()),
+        )
+        .unwrap();
+        assert_eq!(result.changed, 1);
+        seen.sort();
+        assert_eq!(seen, ["skill-a", "skill-b", "skill-c"]);
+    }
+
+    #[test]
+    fn distribute_relative_links_are_relative_and_idempotent() {
+        let tmp = TempDir::new().unwrap();
+        let library = tmp.path().join("tome/skills");
+        let target_dir = tmp.path().join("claude/skills");
+        std::fs::create_dir_all(&target_dir).unwrap();
+        setup_library(&library, &["skill-a"]);
+
+        let dir_name = DirectoryName::new("test").unwrap();
+        let dir_config = make_dir_config(target_dir.clone());
+        let distribute = || {
+            distribute_to_directory(
+                &library,
+                &dir_name,
+                &dir_config,
+                &empty_manifest(),
+                &MachinePrefs::default(),
+                false,
+                false,
+                false,
+                true,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(distribute().changed, 1);
+        let link = target_dir.join("skill-a");
+        assert_eq!(
+            std::fs::read_link(&link).unwrap(),
+            std::path::PathBuf::from("../../tome/skills/skill-a")
+        );
+        assert!(link.join("SKILL.md").is_file());
+
+        let result = distribute();
+        assert_eq!(result.changed, 0);
+        assert_eq!(result.unchanged, 1);
+    }
+
+    #[test]
+    fn distribute_relative_links_rewrites_absolute_link_once() {
+        let tmp = TempDir::new().unwrap();
+        let library = tmp.path().join("library");
+        let target_dir = tmp.path().join("target");
+        std::fs::create_dir_all(&target_dir).unwrap();
+        setup_library(&library, &["skill-a"]);
+        unix_fs::symlink(library.join("skill-a"), target_dir.join("skill-a")).unwrap();
+
+        let dir_name = DirectoryName::new("test").unwrap();
+        let dir_config = make_dir_config(target_dir.clone());
+        let result = distribute_to_directory(
+            &library,
+            &dir_name,
+            &dir_config,
+            &empty_manifest(),
+            &MachinePrefs::default(),
+            false,
+            false,
+            false,
+            true,
+        )
+        .unwrap();
+        assert_eq!(result.changed, 1);
+        assert!(
+            std::fs::read_link(target_dir.join("skill-a"))
+                .unwrap()
+                .is_relative()
+        );
+    }
+
     #[test]
     fn distribute_idempotent() {
         let library = TempDir::new().unwrap();
@@ -339,6 +841,8 @@ mod tests {
             &MachinePrefs::default(),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
         let result = distribute_to_directory(
@@ -349,6 +853,8 @@ mod tests {
             &MachinePrefs::default(),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
         assert_eq!(result.changed, 0);
@@ -373,6 +879,8 @@ mod tests {
             &MachinePrefs::default(),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
         let result = distribute_to_directory(
@@ -383,6 +891,8 @@ mod tests {
             &MachinePrefs::default(),
             false,
             true,
+            false,
+            false,
         )
         .unwrap();
         assert_eq!(result.changed, 1, "force should recreate unchanged link");
@@ -420,6 +930,8 @@ mod tests {
             &MachinePrefs::default(),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
         assert_eq!(
@@ -448,6 +960,8 @@ mod tests {
             &MachinePrefs::default(),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
 
@@ -473,6 +987,8 @@ mod tests {
             &MachinePrefs::default(),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
         assert_eq!(result.changed, 1, "stale link should be updated");
@@ -500,6 +1016,8 @@ mod tests {
             &MachinePrefs::default(),
             true,
             false,
+            false,
+            false,
         )
         .unwrap();
         assert_eq!(result.changed, 0);
@@ -524,6 +1042,8 @@ mod tests {
             &MachinePrefs::default(),
             true,
             false,
+            false,
+            false,
         )
         .unwrap();
         assert_eq!(result.changed, 1);
@@ -549,15 +1069,87 @@ mod tests {
             &MachinePrefs::default(),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
         assert_eq!(result.changed, 0);
         assert_eq!(result.unchanged, 0);
+        assert_eq!(
+            result.skipped_names,
+            vec![SkillName::new("skill-a").unwrap()]
+        );
+        assert_eq!(
+            result.skipped_paths,
+            vec![target_dir.path().join("skill-a")]
+        );
 
         let content = std::fs::read_to_string(target_dir.path().join("skill-a")).unwrap();
         assert_eq!(content, "not a symlink");
     }
 
+    #[test]
+    fn distribute_adopt_backs_up_non_symlink_collision() {
+        let library = TempDir::new().unwrap();
+        let target_dir = TempDir::new().unwrap();
+        setup_library(library.path(), &["skill-a"]);
+
+        let collision = target_dir.path().join("skill-a");
+        std::fs::create_dir_all(&collision).unwrap();
+        std::fs::write(collision.join("SKILL.md"), "imported copy").unwrap();
+
+        let dir_name = DirectoryName::new("test").unwrap();
+        let dir_config = make_dir_config(target_dir.path().to_path_buf());
+
+        let result = distribute_to_directory(
+            library.path(),
+            &dir_name,
+            &dir_config,
+            &empty_manifest(),
+            &MachinePrefs::default(),
+            false, // dry_run
+            true,  // force
+            true,  // adopt
+            false,
+        )
+        .unwrap();
+
+        let backup = target_dir.path().join("skill-a.tome-bak");
+        assert_eq!(result.changed, 1);
+        assert_eq!(result.skipped, 0);
+        assert_eq!(result.backed_up, vec![backup.clone()]);
+        assert!(collision.is_symlink());
+        assert_eq!(
+            std::fs::read_to_string(backup.join("SKILL.md")).unwrap(),
+            "imported copy"
+        );
+    }
+
+    #[test]
+    fn distribute_ignores_library_backups() {
+        let library = TempDir::new().unwrap();
+        let target_dir = TempDir::new().unwrap();
+        setup_library(library.path(), &["skill-a", "skill-a.tome-bak"]);
+
+        let dir_name = DirectoryName::new("test").unwrap();
+        let dir_config = make_dir_config(target_dir.path().to_path_buf());
+
+        let result = distribute_to_directory(
+            library.path(),
+            &dir_name,
+            &dir_config,
+            &empty_manifest(),
+            &MachinePrefs::default(),
+            false,
+            false,
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(result.changed, 1);
+        assert!(!target_dir.path().join("skill-a.tome-bak").exists());
+    }
+
     #[test]
     fn distribute_skips_manifest_file() {
         let library = TempDir::new().unwrap();
@@ -578,6 +1170,8 @@ mod tests {
             &MachinePrefs::default(),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
 
@@ -626,6 +1220,8 @@ mod tests {
             &MachinePrefs::default(),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
         assert_eq!(result.skipped_managed, 1);
@@ -670,6 +1266,8 @@ mod tests {
             &MachinePrefs::default(),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
         assert_eq!(result.changed, 1);
@@ -699,6 +1297,8 @@ mod tests {
             &prefs,
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
         assert_eq!(result.changed, 1);
@@ -749,6 +1349,8 @@ mod tests {
             &MachinePrefs::default(),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
         assert_eq!(result.skipped_managed, 1);
@@ -826,6 +1428,8 @@ mod tests {
             &MachinePrefs::default(),
             false, // dry_run
             false, // force
+            false, // adopt
+            false,
         )
         .unwrap();
 
@@ -867,6 +1471,8 @@ mod tests {
             &MachinePrefs::default(),
             false, // dry_run
             true,  // force
+            false, // adopt
+            false,
         )
         .unwrap();
 
@@ -877,4 +1483,37 @@ mod tests {
         let actual = std::fs::read_link(target_dir.path().join("skill-a")).unwrap();
         assert_eq!(actual, library.path().join("skill-a"));
     }
+
+    #[test]
+    fn retry_with_backoff_retries_transient_errors_until_success() {
+        let mut calls = 0;
+        let result = retry_with_backoff(2, Duration::ZERO, || {
+            calls += 1;
+            if calls < 3 {
+                Err(io::Error::from(io::ErrorKind::ResourceBusy))
+            } else {
+                Ok(calls)
+            }
+        });
+        assert_eq!(result.unwrap(), 3);
+
+        let mut calls = 0;
+        let result: io::Result<()> = retry_with_backoff(1, Duration::ZERO, || {
+            calls += 1;
+            Err(io::Error::from(io::ErrorKind::TimedOut))
+        });
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::TimedOut);
+        assert_eq!(calls, 2, "gives up after the configured retries");
+    }
+
+    #[test]
+    fn retry_with_backoff_fails_fast_on_permanent_errors() {
+        let mut calls = 0;
+        let result: io::Result<()> = retry_with_backoff(2, Duration::ZERO, || {
+            calls += 1;
+            Err(io::Error::from(io::ErrorKind::PermissionDenied))
+        });
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::PermissionDenied);
+        assert_eq!(calls, 1);
+    }
 }
@@ -6,13 +6,16 @@ use console::style;
 use dialoguer::Confirm;
 use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::cleanup;
+use crate::cli::DoctorFix;
 use crate::config::{Config, DirectoryName};
 use crate::discover::SkillName;
 use crate::manifest;
-use crate::paths::{TomePaths, resolve_symlink_target};
+use crate::paths::{
+    TomePaths, back_up_collision, backup_path, create_dir_symlink, resolve_symlink_target,
+};
 
 // -- Data structs --
 
@@ -34,25 +37,70 @@ pub enum IssueSeverity {
 /// (e.g. doctor JSON output, future repair routines).
 ///
 /// HARD-09 / D-DIST-2 introduces the first variant:
-/// [`DiagnosticIssueKind::ForeignSymlink`].
+/// [`DiagnosticIssueKind::ForeignSymlink`]. The remaining variants back the
+/// flat `issues` array of `tome doctor --json`, where CI scripts filter on
+/// `kind`.
+///
+/// JSON serialisation is snake_case (`"broken_library_symlink"`, ...),
+/// matching [`IssueCategory`]. `ForeignSymlink` predates that and keeps
+/// its original `"ForeignSymlink"` wire string.
 ///
 /// Future variants must extend [`DiagnosticIssueKind::ALL`] and the
 /// compile-time exhaustiveness sentinel below (POLISH-04 pattern).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DiagnosticIssueKind {
     /// A distribution-directory entry is a symlink whose target lives
     /// outside the active `library_dir` — typically left behind by a
     /// different tome install or a hand-edited dotfiles workflow.
     /// Renders as [`IssueSeverity::Warning`] and contributes to
     /// [`DoctorReport::total_issues`].
+    #[serde(rename = "ForeignSymlink")]
     ForeignSymlink,
+    /// A library entry is a symlink whose target no longer exists
+    /// (uninstalled managed source, or a leftover from the pre-v0.10
+    /// symlink layout).
+    BrokenLibrarySymlink,
+    /// A distribution-directory symlink points into the library at a
+    /// skill that no longer exists.
+    StaleTargetSymlink,
+    /// A discovery directory's configured path does not exist.
+    MissingSource,
+    /// A distribution directory's configured path does not exist.
+    MissingTargetDir,
+    /// A library or distribution-directory symlink whose chain of links
+    /// loops back on itself, typically because one directory is
+    /// configured as both a source and a target.
+    SymlinkCycle,
+    /// A skill's frontmatter `requires` names a skill that no configured
+    /// directory provides.
+    MissingRequirement,
+    /// A skill's frontmatter lacks a field listed in `required_frontmatter`.
+    MissingFrontmatterField,
+    /// A skill's frontmatter `name` differs from its directory name.
+    FrontmatterNameMismatch,
+    /// Two distribution directories resolve to the same path, so each
+    /// one's cleanup would remove the other's links. `tome sync` refuses to
+    /// distribute until one is repointed or removed.
+    DuplicateDistributionDir,
 }
 
 impl DiagnosticIssueKind {
     /// Compile-time-validated enumeration of every variant. Mirrors
     /// `crate::remove::FailureKind::ALL` and
     /// `crate::marketplace::InstallFailureKind::ALL`.
-    pub const ALL: [DiagnosticIssueKind; 1] = [DiagnosticIssueKind::ForeignSymlink];
+    pub const ALL: [DiagnosticIssueKind; 10] = [
+        DiagnosticIssueKind::ForeignSymlink,
+        DiagnosticIssueKind::BrokenLibrarySymlink,
+        DiagnosticIssueKind::StaleTargetSymlink,
+        DiagnosticIssueKind::MissingSource,
+        DiagnosticIssueKind::MissingTargetDir,
+        DiagnosticIssueKind::SymlinkCycle,
+        DiagnosticIssueKind::MissingRequirement,
+        DiagnosticIssueKind::MissingFrontmatterField,
+        DiagnosticIssueKind::FrontmatterNameMismatch,
+        DiagnosticIssueKind::DuplicateDistributionDir,
+    ];
 }
 
 /// Compile-time drift guard for [`DiagnosticIssueKind::ALL`] (POLISH-04).
@@ -65,10 +113,19 @@ const fn _diagnostic_issue_kind_exhaustiveness_sentinel(kind: DiagnosticIssueKin
         // If this fails: DiagnosticIssueKind::ALL is missing or has extra
         // variants. Update the array and this match arm together.
         DiagnosticIssueKind::ForeignSymlink => {}
+        DiagnosticIssueKind::BrokenLibrarySymlink => {}
+        DiagnosticIssueKind::StaleTargetSymlink => {}
+        DiagnosticIssueKind::MissingSource => {}
+        DiagnosticIssueKind::MissingTargetDir => {}
+        DiagnosticIssueKind::SymlinkCycle => {}
+        DiagnosticIssueKind::MissingRequirement => {}
+        DiagnosticIssueKind::MissingFrontmatterField => {}
+        DiagnosticIssueKind::FrontmatterNameMismatch => {}
+        DiagnosticIssueKind::DuplicateDistributionDir => {}
     }
 }
 const _: () = {
-    assert!(DiagnosticIssueKind::ALL.len() == 1);
+    assert!(DiagnosticIssueKind::ALL.len() == 10);
 };
 
 /// Category of a [`DiagnosticIssue`]. Derived at construction from the
@@ -76,7 +133,8 @@ const _: () = {
 /// promoted regardless of source field per D-CAT-1.
 ///
 /// JSON serialisation is snake_case (`"library"`, `"directory"`,
-/// `"config"`, `"foreign_symlink"`), matching the project convention.
+/// `"config"`, `"foreign_symlink"`, `"conflict"`), matching the project
+/// convention.
 ///
 /// Per POLISH-04: `ALL` array + compile-time exhaustiveness sentinel
 /// keep every variant pinned. Adding a variant without updating `ALL`
@@ -89,16 +147,20 @@ pub enum IssueCategory {
     Directory,
     Config,
     ForeignSymlink,
+    /// The same skill name is discovered in more than one directory; one
+    /// copy shadows the other. Never auto-repairable.
+    Conflict,
 }
 
 impl IssueCategory {
     /// Compile-time-validated enumeration of every variant. Mirrors
     /// `DiagnosticIssueKind::ALL` and other POLISH-04 patterns.
-    pub const ALL: [Self; 4] = [
+    pub const ALL: [Self; 5] = [
         Self::Library,
         Self::Directory,
         Self::Config,
         Self::ForeignSymlink,
+        Self::Conflict,
     ];
 }
 
@@ -112,10 +174,11 @@ const fn _issue_category_exhaustiveness_sentinel(c: IssueCategory) {
         IssueCategory::Directory => {}
         IssueCategory::Config => {}
         IssueCategory::ForeignSymlink => {}
+        IssueCategory::Conflict => {}
     }
 }
 const _: () = {
-    assert!(IssueCategory::ALL.len() == 4);
+    assert!(IssueCategory::ALL.len() == 5);
 };
 
 /// Categorises the auto-repair available for a [`DiagnosticIssue`]
@@ -167,19 +230,43 @@ pub enum RepairKind {
     /// content (should be a symlink)". Diverging content stays a
     /// no-repair Warning (the user must reconcile).
     ConsolidateTargetRealDirToSymlink,
+    /// A real file or diverging directory in a distribution dir that
+    /// blocks the link to a same-named library skill (the entry sync
+    /// reports as "skipped (path conflict)"). Repair moves it aside to
+    /// `<name>.tome-bak` so the next sync can create the link; nothing
+    /// is deleted, and an existing backup is never overwritten. Emit
+    /// site: `check_distribution_dir` "blocks the library link".
+    BackUpTargetCollision,
 }
 
 impl RepairKind {
     /// Compile-time-validated enumeration of every variant. Mirrors
     /// `DiagnosticIssueKind::ALL` and other POLISH-04 patterns.
-    pub const ALL: [Self; 4] = [
+    pub const ALL: [Self; 5] = [
         Self::RemoveStaleManifestEntry,
         Self::RemoveBrokenLibrarySymlink,
         Self::RemoveStaleTargetSymlink,
         Self::ConsolidateTargetRealDirToSymlink,
+        Self::BackUpTargetCollision,
     ];
 }
 
+impl RepairKind {
+    /// The `tome doctor --fix` category that repairs this kind. Both
+    /// library kinds share one category because `repair_library` fixes
+    /// them in a single pass.
+    pub(crate) fn fix_category(self) -> DoctorFix {
+        match self {
+            Self::RemoveStaleManifestEntry | Self::RemoveBrokenLibrarySymlink => {
+                DoctorFix::BrokenLibraryLinks
+            }
+            Self::RemoveStaleTargetSymlink => DoctorFix::StaleTargetLinks,
+            Self::ConsolidateTargetRealDirToSymlink => DoctorFix::TargetCopies,
+            Self::BackUpTargetCollision => DoctorFix::TargetCollisions,
+        }
+    }
+}
+
 /// Compile-time drift guard for [`RepairKind::ALL`] (POLISH-04).
 /// Adding a variant without updating `ALL` and this match fails to
 /// compile (`non-exhaustive patterns`) or trips the const-len assert.
@@ -190,10 +277,11 @@ const fn _repair_kind_exhaustiveness_sentinel(k: RepairKind) {
         RepairKind::RemoveBrokenLibrarySymlink => {}
         RepairKind::RemoveStaleTargetSymlink => {}
         RepairKind::ConsolidateTargetRealDirToSymlink => {}
+        RepairKind::BackUpTargetCollision => {}
     }
 }
 const _: () = {
-    assert!(RepairKind::ALL.len() == 4);
+    assert!(RepairKind::ALL.len() == 5);
 };
 
 /// Stable, content-aware identifier for a single doctor finding (Phase 26
@@ -205,9 +293,10 @@ const _: () = {
 /// content-aware enum (variants carry the identifying data inline) avoids the
 /// hash-collision class hash-style IDs would invite (T-26-05-03).
 ///
-/// Variants align 1:1 with the 4 [`RepairKind`] auto-fix arms plus 2
-/// informational categories ([`Self::UnparsableFrontmatter`],
-/// [`Self::DivergingTarget`]) the UI surfaces with manual remediation hints.
+/// Variants align 1:1 with the 5 [`RepairKind`] auto-fix arms plus the
+/// informational [`Self::UnparsableFrontmatter`] category the UI surfaces
+/// with a manual remediation hint. [`Self::DivergingTarget`] is only
+/// informational when its `.tome-bak` backup slot is already taken.
 ///
 /// JSON wire-shape: `{ "kind": "library_stale_manifest", "skill": "name" }` etc.
 ///
@@ -238,9 +327,9 @@ pub enum FindingId {
     /// Library skill whose `SKILL.md` YAML frontmatter does not parse
     /// (informational; user must edit the file). Phase 23.
     UnparsableFrontmatter { skill: SkillName },
-    /// Real directory in a distribution directory whose content diverges from
-    /// the matching library skill (informational; user must reconcile
-    /// manually).
+    /// Real file or directory in a distribution directory that blocks the link
+    /// to a same-named library skill (a file, or a directory whose content
+    /// diverges). Auto-fixable by moving it aside to `<name>.tome-bak`.
     DivergingTarget {
         directory: DirectoryName,
         path: PathBuf,
@@ -283,12 +372,16 @@ const _: () = {
 pub struct DiagnosticIssue {
     pub severity: IssueSeverity,
     pub message: String,
-    /// Optional typed classification. Existing diagnostic emit sites
+    /// Optional typed classification. Most diagnostic emit sites
     /// leave this `None` (the free-form `message` carries the detail);
-    /// HARD-09 D-DIST-2 ForeignSymlink is the first emitter to set it.
+    /// HARD-09 D-DIST-2 ForeignSymlink was the first emitter to set it.
     /// Serialised JSON shape: omitted when `None`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub kind: Option<DiagnosticIssueKind>,
+    /// Filesystem path the issue is about, for issues that have one
+    /// (set alongside `kind`). Omitted from JSON when `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
     /// Category bucket for the OBS-06 categorised summary line and
     /// `tome doctor --json` per-issue category field. Computed at
     /// construction from the [`DoctorReport`] field the issue lives
@@ -304,9 +397,8 @@ pub struct DiagnosticIssue {
     pub repair_kind: Option<RepairKind>,
     /// Stable, content-aware identifier for the GUI's per-item fix
     /// dispatch (Phase 26 plan 26-05, OQ-2). `Some` for any issue the
-    /// Health view surfaces — the 4 auto-fixable [`RepairKind`] variants
-    /// and the 2 informational categories (UnparsableFrontmatter,
-    /// DivergingTarget). `None` for issues that have no dedicated UI
+    /// Health view surfaces — the 5 auto-fixable [`RepairKind`] variants
+    /// and the informational UnparsableFrontmatter category. `None` for issues that have no dedicated UI
     /// surface (orphan directories — interactive-only; missing-SKILL.md
     /// warnings; config issues; ForeignSymlink Warnings — those flow
     /// through the CLI `tome doctor` text path only).
@@ -333,6 +425,7 @@ impl DiagnosticIssue {
             severity,
             message: message.into(),
             kind: None,
+            path: None,
             category: IssueCategory::Library,
             repair_kind: None,
             finding_id: None,
@@ -352,6 +445,7 @@ impl DiagnosticIssue {
             severity,
             message: message.into(),
             kind: None,
+            path: None,
             category: IssueCategory::Library,
             repair_kind: Some(repair_kind),
             finding_id: None,
@@ -364,6 +458,7 @@ impl DiagnosticIssue {
             severity,
             message: message.into(),
             kind: None,
+            path: None,
             category: IssueCategory::Directory,
             repair_kind: None,
             finding_id: None,
@@ -381,6 +476,7 @@ impl DiagnosticIssue {
             severity,
             message: message.into(),
             kind: None,
+            path: None,
             category: IssueCategory::Directory,
             repair_kind: Some(repair_kind),
             finding_id: None,
@@ -399,6 +495,7 @@ impl DiagnosticIssue {
             severity,
             message: message.into(),
             kind: Some(DiagnosticIssueKind::ForeignSymlink),
+            path: None,
             category: IssueCategory::ForeignSymlink,
             repair_kind: None,
             finding_id: None,
@@ -412,12 +509,28 @@ impl DiagnosticIssue {
             severity,
             message: message.into(),
             kind: None,
+            path: None,
             category: IssueCategory::Config,
             repair_kind: None,
             finding_id: None,
         }
     }
 
+    /// Build a Conflict-category issue (a skill name shadowed across
+    /// directories). Not auto-repairable: the fix is renaming, excluding,
+    /// or `.tomeignore`-ing one copy.
+    pub(crate) fn conflict(message: impl Into<String>) -> Self {
+        Self {
+            severity: IssueSeverity::Warning,
+            message: message.into(),
+            kind: None,
+            path: None,
+            category: IssueCategory::Conflict,
+            repair_kind: None,
+            finding_id: None,
+        }
+    }
+
     /// Builder: stamp a [`FindingId`] onto an existing issue. Used at the
     /// `check_library` / `check_distribution_dir` emit sites for the 6 GUI-
     /// surfaced finding categories so `repair_one` can locate the issue on
@@ -427,6 +540,14 @@ impl DiagnosticIssue {
         self
     }
 
+    /// Builder: stamp a typed [`DiagnosticIssueKind`] and the path it
+    /// refers to onto an existing issue.
+    pub(crate) fn with_kind(mut self, kind: DiagnosticIssueKind, path: impl Into<PathBuf>) -> Self {
+        self.kind = Some(kind);
+        self.path = Some(path.into());
+        self
+    }
+
     /// Return the stable [`FindingId`] for this issue, if any.
     ///
     /// Phase 26 plan 26-05 (OQ-2). Used by `repair_one` to locate the live
@@ -459,11 +580,18 @@ pub struct DoctorReport {
     pub library_issues: Vec<DiagnosticIssue>,
     pub directory_issues: Vec<DirectoryDiagnostic>,
     pub config_issues: Vec<DiagnosticIssue>,
+    /// Skill names discovered in more than one directory (one issue per
+    /// shadowed copy). Counted in `total_issues`; never auto-repaired.
+    pub conflict_issues: Vec<DiagnosticIssue>,
     /// Unowned skills (UNOWN-03 / D-D3). INFORMATIONAL section — these
     /// entries do NOT contribute to `total_issues` and do NOT affect
     /// `tome doctor` exit code. They surface in text rendering as a
     /// parallel "Unowned skills" section after the issue checks.
     pub unowned_skills: Vec<crate::summary::SkillSummary>,
+    /// Informational notes about the library (e.g. a git index that has
+    /// not caught up with the disk yet). Like `unowned_skills`, these do
+    /// NOT contribute to `total_issues`.
+    pub library_notes: Vec<String>,
 }
 
 impl DoctorReport {
@@ -479,9 +607,10 @@ impl DoctorReport {
                 .map(|d| d.issues.len())
                 .sum::<usize>()
             + self.config_issues.len()
+            + self.conflict_issues.len()
     }
 
-    /// Flatten the three issue buckets into a single iterator.
+    /// Flatten the four issue buckets into a single iterator.
     /// Used by the OBS-06 categorised summary and the FIX-01 repair
     /// dispatcher (D-REPAIR-3 — replaces substring matching).
     pub fn all_issues(&self) -> impl Iterator<Item = &DiagnosticIssue> {
@@ -489,6 +618,7 @@ impl DoctorReport {
             .iter()
             .chain(self.directory_issues.iter().flat_map(|d| d.issues.iter()))
             .chain(self.config_issues.iter())
+            .chain(self.conflict_issues.iter())
     }
 
     /// Number of issues for which the dispatcher has an auto-repair
@@ -519,8 +649,19 @@ impl DoctorReport {
 
 // -- Data gathering (pure computation, no I/O) --
 
+/// How many levels below a distribution directory `tome doctor` and
+/// `tome status` look for links by default: `1` classifies only its
+/// immediate children.
+pub const DEFAULT_SCAN_DEPTH: usize = 1;
+
 /// Run all diagnostic checks and return a structured report.
 pub fn check(config: &Config, paths: &TomePaths) -> Result<DoctorReport> {
+    check_at_depth(config, paths, DEFAULT_SCAN_DEPTH)
+}
+
+/// [`check`], classifying links down to `depth` levels below each
+/// distribution directory (`tome doctor --depth`).
+pub fn check_at_depth(config: &Config, paths: &TomePaths, depth: usize) -> Result<DoctorReport> {
     let configured = paths.library_dir().is_dir() || !config.directories.is_empty();
 
     if !configured {
@@ -529,15 +670,28 @@ pub fn check(config: &Config, paths: &TomePaths) -> Result<DoctorReport> {
             library_issues: Vec::new(),
             directory_issues: Vec::new(),
             config_issues: Vec::new(),
+            conflict_issues: Vec::new(),
             unowned_skills: Vec::new(),
+            library_notes: Vec::new(),
         });
     }
 
-    let library_issues = check_library(paths)?;
+    let mut library_issues = check_library(paths, config.skill_file_names())?;
+    let (tracked_symlink_issue, library_notes) = check_tracked_symlinks(paths.library_dir());
+    library_issues.extend(tracked_symlink_issue);
 
+    // An unreadable manifest is reported by `check_library`; the repairs
+    // reload it strictly before touching anything.
+    let manifest = manifest::load(paths.config_dir()).unwrap_or_default();
     let mut directory_issues = Vec::new();
     for (name, dir_config) in config.distribution_dirs() {
-        let issues = check_distribution_dir(name.as_str(), &dir_config.path, paths.library_dir())?;
+        let issues = check_distribution_dir(
+            name.as_str(),
+            &dir_config.path,
+            paths.library_dir(),
+            &manifest,
+            depth,
+        )?;
         directory_issues.push(DirectoryDiagnostic {
             name: name.as_str().to_string(),
             issues,
@@ -546,6 +700,9 @@ pub fn check(config: &Config, paths: &TomePaths) -> Result<DoctorReport> {
     }
 
     let config_issues = check_config(config)?;
+    let (conflict_issues, discovered) = check_conflicts(config, paths)?;
+    library_issues.extend(check_requirements(&discovered));
+    library_issues.extend(check_frontmatter(&discovered, &config.required_frontmatter));
 
     // UNOWN-03 / D-D3: collect Unowned skills from the manifest.
     // Manifest read errors degrade gracefully to an empty Vec — the
@@ -565,37 +722,77 @@ pub fn check(config: &Config, paths: &TomePaths) -> Result<DoctorReport> {
         library_issues,
         directory_issues,
         config_issues,
+        conflict_issues,
         unowned_skills,
+        library_notes,
     })
 }
 
 // -- Rendering + control flow --
 
+/// `tome doctor` finished with issues still present (HARD-04 sibling):
+/// any issue in `--json` or `--dry-run` mode, or whatever survived the
+/// repair pass otherwise.
+///
+/// Bubbled through `anyhow::Result` from [`diagnose`] so `main.rs` can
+/// downcast and exit 1 instead of the library calling `process::exit(1)`.
+#[derive(Debug)]
+pub struct DoctorIssuesFound {
+    pub total: usize,
+}
+
+impl std::fmt::Display for DoctorIssuesFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "doctor: {} issue(s) unresolved", self.total)
+    }
+}
+
+impl std::error::Error for DoctorIssuesFound {}
+
 /// Diagnose and optionally repair issues.
+///
+/// A non-empty `fix` skips the prompts and repairs only issues in those
+/// categories. `depth` is passed to [`check_at_depth`].
+#[allow(clippy::too_many_arguments)]
 pub fn diagnose(
     config: &Config,
     paths: &TomePaths,
     dry_run: bool,
     no_input: bool,
     json: bool,
+    fix: &[DoctorFix],
+    yes: bool,
+    depth: usize,
 ) -> Result<()> {
-    let report = check(config, paths)?;
+    let report = check_at_depth(config, paths, depth)?;
 
     if json {
         // OBS-06: emit the report alongside a `summary` object that
         // exposes total + per-category + auto-fixable counts. Helper
         // builds a JSON `Value` so the per-issue `category` /
         // `repair_kind` fields (struct-derived) compose with the
-        // computed summary in one document.
+        // computed summary in one document. `issues` + `total` are the
+        // flat CI-facing view: every issue once, filterable by `kind`.
         let payload = serde_json::json!({
+            "total": report.total_issues(),
+            "issues": report.all_issues().collect::<Vec<_>>(),
             "configured": report.configured,
             "library_issues": report.library_issues,
             "directory_issues": report.directory_issues,
             "config_issues": report.config_issues,
+            "conflict_issues": report.conflict_issues,
             "unowned_skills": report.unowned_skills,
+            "library_notes": report.library_notes,
             "summary": render_summary_json(&report),
         });
         println!("{}", serde_json::to_string_pretty(&payload)?);
+        // JSON mode never prompts or repairs, so any issue is still
+        // present — fail the process so CI can gate on it.
+        if report.total_issues() > 0 {
+            bail!(DoctorIssuesFound {
+                total: report.total_issues(),
+            });
+        }
         return Ok(());
     }
 
@@ -614,6 +811,9 @@ pub fn diagnose(
     // Render results
     println!("{}", style("Checking library...").bold());
     render_issues(&report.library_issues, "library");
+    for note in &report.library_notes {
+        println!("  {} {}", style("i").cyan(), note);
+    }
 
     println!("{}", style("Checking directories...").bold());
     for d in &report.directory_issues {
@@ -623,6 +823,9 @@ pub fn diagnose(
     println!("{}", style("Checking config...").bold());
     render_issues(&report.config_issues, "config");
 
+    println!("{}", style("Checking skill conflicts...").bold());
+    render_issues(&report.conflict_issues, "conflicts");
+
     // UNOWN-03 / D-D3: parallel informational section. Does NOT affect
     // `total_issues` or `tome doctor` exit code. Section omits cleanly
     // when the Unowned set is empty.
@@ -630,6 +833,10 @@ pub fn diagnose(
 
     let total = report.total_issues();
     let auto_fixable = report.auto_fixable_count();
+    // Issues still present once the (possibly declined) repair pass is
+    // done. Dry-run and non-interactive runs fix nothing, so it stays
+    // at `total`.
+    let mut remaining = total;
 
     println!();
     if total == 0 {
@@ -642,7 +849,39 @@ pub fn diagnose(
 
         let interactive = !no_input && std::io::stdin().is_terminal();
 
-        if !dry_run && interactive {
+        if !fix.is_empty() {
+            // `--fix <kind>`: the caller already chose, so no prompt.
+            let kinds: Vec<RepairKind> = RepairKind::ALL
+                .into_iter()
+                .filter(|k| fix.contains(&k.fix_category()))
+                .collect();
+            let selected = report
+                .all_issues()
+                .filter(|i| i.repair_kind.is_some_and(|k| kinds.contains(&k)))
+                .count();
+            println!();
+            if selected == 0 {
+                println!("No issues of the requested kind(s) to fix.");
+            } else if dry_run {
+                println!(
+                    "  (dry run — {selected} issue(s) of the requested kind(s) would be fixed)"
+                );
+            } else {
+                dispatch_repairs(&report, config, paths, &kinds)?;
+                remaining = check_at_depth(config, paths, depth)?.total_issues();
+            }
+        } else if !dry_run && yes {
+            // `--yes`: apply every auto-fixable repair without the prompt.
+            // Orphan directories need a human decision, so they stay.
+            if auto_fixable > 0 {
+                println!();
+                println!("{} auto-fixable issue(s):", style(auto_fixable).bold());
+                render_repair_plan_auto(&report);
+                println!();
+                dispatch_repairs(&report, config, paths, &RepairKind::ALL)?;
+                remaining = check_at_depth(config, paths, depth)?.total_issues();
+            }
+        } else if !dry_run && interactive {
             // Collect orphan-directory issues (interactive-only, no
             // repair_kind). Routed through the per-item Select prompt
             // below.
@@ -669,7 +908,7 @@ pub fn diagnose(
 
                 if confirmed {
                     println!();
-                    dispatch_repairs(&report, config, paths)?;
+                    dispatch_repairs(&report, config, paths, &RepairKind::ALL)?;
                 } else {
                     // D-REPAIR-3 / OBS-01-shaped tracing: user
                     // declined. Logged so `tome doctor --verbose`
@@ -772,13 +1011,25 @@ pub fn diagnose(
                     }
                 }
             }
+
+            // Re-check rather than subtracting: a repair can fail
+            // partway, and keep/skip leave orphans in place.
+            remaining = check_at_depth(config, paths, depth)?.total_issues();
         } else if !dry_run {
             eprintln!("info: non-interactive mode — skipping repair prompt");
+            if auto_fixable > 0 {
+                eprintln!(
+                    "hint: pass `--yes` to apply the {auto_fixable} auto-fixable repair(s) without a prompt"
+                );
+            }
         } else {
             println!("  (dry run — no changes made)");
         }
     }
 
+    if remaining > 0 {
+        bail!(DoctorIssuesFound { total: remaining });
+    }
     Ok(())
 }
 
@@ -791,6 +1042,7 @@ fn category_display_name(c: IssueCategory) -> &'static str {
         IssueCategory::Directory => "Directory",
         IssueCategory::Config => "Config",
         IssueCategory::ForeignSymlink => "Foreign-symlink",
+        IssueCategory::Conflict => "Conflict",
     }
 }
 
@@ -814,6 +1066,9 @@ pub fn repair_kind_action_label(k: RepairKind) -> &'static str {
         RepairKind::ConsolidateTargetRealDirToSymlink => {
             "will delete the real directory and replace it with a symlink into the library"
         }
+        RepairKind::BackUpTargetCollision => {
+            "will move the entry aside to <name>.tome-bak so the next sync can link the library skill"
+        }
     }
 }
 
@@ -992,10 +1247,16 @@ fn render_summary_line(report: &DoctorReport) -> String {
 }
 
 /// Dispatch auto-repairs via exhaustive match on `Option<RepairKind>`.
+/// Issues whose kind is not in `kinds` are left alone.
 ///
 /// D-REPAIR-3: substring matching is gone. Adding a `RepairKind`
 /// variant without an arm here is a compile-time error.
-fn dispatch_repairs(report: &DoctorReport, config: &Config, paths: &TomePaths) -> Result<()> {
+fn dispatch_repairs(
+    report: &DoctorReport,
+    config: &Config,
+    paths: &TomePaths,
+    kinds: &[RepairKind],
+) -> Result<()> {
     // Track which kinds we've seen so we only call the
     // batch-repair helpers once per kind. The handlers operate over
     // the whole report (e.g. `repair_library` processes every stale
@@ -1004,8 +1265,12 @@ fn dispatch_repairs(report: &DoctorReport, config: &Config, paths: &TomePaths) -
     let mut ran_library_repair = false;
     let mut ran_target_cleanup = false;
     let mut ran_target_consolidation = false;
+    let mut ran_collision_backup = false;
 
     for issue in report.all_issues() {
+        if issue.repair_kind.is_some_and(|k| !kinds.contains(&k)) {
+            continue;
+        }
         match issue.repair_kind {
             Some(RepairKind::RemoveStaleManifestEntry)
             | Some(RepairKind::RemoveBrokenLibrarySymlink) => {
@@ -1017,8 +1282,12 @@ fn dispatch_repairs(report: &DoctorReport, config: &Config, paths: &TomePaths) -
             Some(RepairKind::RemoveStaleTargetSymlink) => {
                 if !ran_target_cleanup {
                     for (name, dir_config) in config.distribution_dirs() {
-                        let removed =
-                            cleanup::cleanup_target(&dir_config.path, paths.library_dir(), false)?;
+                        let removed = cleanup::cleanup_target(
+                            &dir_config.path,
+                            paths.library_dir(),
+                            None,
+                            false,
+                        )?;
                         if removed > 0 {
                             println!(
                                 "  {} Removed {} stale symlink(s) from {}",
@@ -1030,6 +1299,12 @@ fn dispatch_repairs(report: &DoctorReport, config: &Config, paths: &TomePaths) -
                     }
                     ran_target_cleanup = true;
                 }
+                // The batch cleanup only sees top-level links; ones found
+                // deeper by `--depth` are removed individually.
+                if issue.finding_id.is_some() && issue.path.as_deref().is_some_and(Path::is_symlink)
+                {
+                    repair_target_one(config, paths, issue)?;
+                }
             }
             Some(RepairKind::ConsolidateTargetRealDirToSymlink) => {
                 // Phase 24: batch handler — re-discover real-dir
@@ -1038,9 +1313,14 @@ fn dispatch_repairs(report: &DoctorReport, config: &Config, paths: &TomePaths) -
                 // pattern: the issue carries no per-instance state, the
                 // handler re-runs the same scan over `config`.
                 if !ran_target_consolidation {
+                    let manifest = manifest::load(paths.config_dir())?;
                     for (name, dir_config) in config.distribution_dirs() {
-                        let converted =
-                            consolidate_target_real_dirs(&dir_config.path, paths.library_dir())?;
+                        let converted = consolidate_target_real_dirs(
+                            name.as_str(),
+                            &dir_config.path,
+                            paths.library_dir(),
+                            &manifest,
+                        )?;
                         if converted > 0 {
                             println!(
                                 "  {} Converted {} real director{} in {} to symlink(s)",
@@ -1054,6 +1334,32 @@ fn dispatch_repairs(report: &DoctorReport, config: &Config, paths: &TomePaths) -
                     ran_target_consolidation = true;
                 }
             }
+            Some(RepairKind::BackUpTargetCollision) => {
+                // Same batch shape as the consolidation arm: re-scan each
+                // distribution dir and move every collision aside. The
+                // link itself is left to the next `tome sync`.
+                if !ran_collision_backup {
+                    let manifest = manifest::load(paths.config_dir())?;
+                    for (name, dir_config) in config.distribution_dirs() {
+                        let backed_up = back_up_target_collisions(
+                            name.as_str(),
+                            &dir_config.path,
+                            paths.library_dir(),
+                            &manifest,
+                        )?;
+                        if backed_up > 0 {
+                            println!(
+                                "  {} Backed up {} entr{} in {} to *.tome-bak — run `tome sync` to link the library skill(s)",
+                                style("fixed").green(),
+                                backed_up,
+                                if backed_up == 1 { "y" } else { "ies" },
+                                name
+                            );
+                        }
+                    }
+                    ran_collision_backup = true;
+                }
+            }
             None => {
                 // Interactive-only or informational. The orphan-dir
                 // and (still-present, deleted in Task 3) git-tracked
@@ -1180,7 +1486,7 @@ pub(crate) fn consolidate_target_one(
     }
     std::fs::remove_dir_all(path)
         .with_context(|| format!("failed to remove {}", path.display()))?;
-    std::os::unix::fs::symlink(&library_skill, path).with_context(|| {
+    create_dir_symlink(&library_skill, path).with_context(|| {
         format!(
             "failed to create symlink {} -> {}",
             path.display(),
@@ -1190,6 +1496,26 @@ pub(crate) fn consolidate_target_one(
     Ok(())
 }
 
+/// Move a single colliding target entry aside to `<name>.tome-bak`
+/// (`DivergingTarget`). Refuses when the backup slot is already taken.
+pub(crate) fn back_up_target_one(
+    _config: &Config,
+    _paths: &TomePaths,
+    issue: &DiagnosticIssue,
+) -> Result<()> {
+    let Some(FindingId::DivergingTarget { path, .. }) = issue.finding_id.as_ref() else {
+        bail!("internal: back_up_target_one called with wrong FindingId");
+    };
+    if back_up_collision(path, false)?.is_none() {
+        bail!(
+            "{} already exists — refusing to overwrite an earlier backup of {}",
+            backup_path(path).display(),
+            path.display()
+        );
+    }
+    Ok(())
+}
+
 /// Per-item repair dispatch (Phase 26 plan 26-05 / VIEW-05 / NF-04).
 ///
 /// Locates the live `DiagnosticIssue` matching `finding_id` by re-running
@@ -1222,6 +1548,7 @@ pub fn repair_one(finding_id: &FindingId, config: &Config, paths: &TomePaths) ->
         RepairKind::ConsolidateTargetRealDirToSymlink => {
             consolidate_target_one(config, paths, issue)?
         }
+        RepairKind::BackUpTargetCollision => back_up_target_one(config, paths, issue)?,
     }
     Ok(())
 }
@@ -1431,7 +1758,7 @@ fn render_unowned_skills(unowned: &[crate::summary::SkillSummary]) {
 
 // -- Check functions (return structured data) --
 
-fn check_library(paths: &TomePaths) -> Result<Vec<DiagnosticIssue>> {
+fn check_library(paths: &TomePaths, skill_file_names: &[String]) -> Result<Vec<DiagnosticIssue>> {
     let library_dir = paths.library_dir();
     let config_dir = paths.config_dir();
     let mut issues = Vec::new();
@@ -1458,7 +1785,8 @@ fn check_library(paths: &TomePaths) -> Result<Vec<DiagnosticIssue>> {
     // Check manifest entries exist on disk
     for name in m.keys() {
         let entry_path = library_dir.join(name.as_str());
-        if !entry_path.is_dir() {
+        // Looping symlinks are reported by the disk walk below.
+        if !entry_path.is_dir() && symlink_cycle(&entry_path).is_none() {
             let entry = m.get(name.as_str());
             let is_managed = entry.is_some_and(|e| e.managed);
             if is_managed && entry_path.is_symlink() {
@@ -1474,6 +1802,7 @@ fn check_library(paths: &TomePaths) -> Result<Vec<DiagnosticIssue>> {
                         ),
                         RepairKind::RemoveStaleManifestEntry,
                     )
+                    .with_kind(DiagnosticIssueKind::BrokenLibrarySymlink, &entry_path)
                     .with_id(FindingId::LibraryStaleManifest { skill: name.clone() }),
                 );
             } else {
@@ -1501,7 +1830,10 @@ fn check_library(paths: &TomePaths) -> Result<Vec<DiagnosticIssue>> {
         let path = entry.path();
         let name = entry.file_name().to_string_lossy().to_string();
 
-        if path.is_dir() && !name.starts_with('.') && !m.contains_key(&name) {
+        // `<name>.tome-bak` entries are `sync --adopt` backups, reported by
+        // the sync that made them — not orphans to claim.
+        let is_backup = name.ends_with(crate::paths::BACKUP_SUFFIX);
+        if path.is_dir() && !name.starts_with('.') && !is_backup && !m.contains_key(&name) {
             // Orphan directories are interactive-only — the user
             // decides keep/delete/skip per item. No `repair_kind` so
             // the global "Apply N auto-fixable repairs?" prompt does
@@ -1512,6 +1844,23 @@ fn check_library(paths: &TomePaths) -> Result<Vec<DiagnosticIssue>> {
             ));
         }
 
+        if let Some(chain) = symlink_cycle(&path) {
+            issues.push(
+                DiagnosticIssue::library(IssueSeverity::Error, cycle_message(&chain))
+                    .with_kind(DiagnosticIssueKind::SymlinkCycle, &path),
+            );
+            continue;
+        }
+
+        // Linked skills outside the manifest get the SKILL.md check here;
+        // manifest entries get it in the frontmatter pass below.
+        if path.is_symlink()
+            && !m.contains_key(&name)
+            && let Some(issue) = gutted_link_issue(&name, &path, skill_file_names)
+        {
+            issues.push(issue);
+        }
+
         // Check for broken symlinks — managed skill whose source was deleted, or orphan from a previous layout
         if path.is_symlink() && !path.exists() {
             let is_managed = m.get(&name).is_some_and(|e| e.managed);
@@ -1528,6 +1877,7 @@ fn check_library(paths: &TomePaths) -> Result<Vec<DiagnosticIssue>> {
                         ),
                         RepairKind::RemoveBrokenLibrarySymlink,
                     )
+                    .with_kind(DiagnosticIssueKind::BrokenLibrarySymlink, &path)
                     .with_id(FindingId::LibraryBrokenSymlink { path: path.clone() }),
                 );
             }
@@ -1539,8 +1889,9 @@ fn check_library(paths: &TomePaths) -> Result<Vec<DiagnosticIssue>> {
     // (managed skills cannot be symlinks any more — the detection
     // criterion can never fire on a clean v0.10 library). The check,
     // its render/Confirm flow, and the supporting git-shellout
-    // helper are deleted entirely. If a real failure mode emerges, a
-    // new ticket will scope it.
+    // helper are deleted entirely. Its replacement,
+    // `check_tracked_symlinks`, counts symlinks on disk rather than in
+    // git's index and runs from `check`.
 
     // Phase 23 (v0.16+): unparsable SKILL.md frontmatter in library
     // skills. Walks each manifest-tracked skill, reads SKILL.md, and
@@ -1557,14 +1908,23 @@ fn check_library(paths: &TomePaths) -> Result<Vec<DiagnosticIssue>> {
             // Missing-directory diagnostic already emitted above.
             continue;
         }
-        let skill_md = skill_dir.join("SKILL.md");
-        match std::fs::read_to_string(&skill_md) {
+        if skill_dir.is_symlink()
+            && let Some(issue) = gutted_link_issue(name.as_str(), &skill_dir, skill_file_names)
+        {
+            issues.push(issue);
+            continue;
+        }
+        // A directory with none of the skill files reads as SKILL.md so
+        // the not-found arm below reports it.
+        let skill_file = crate::skill::find_skill_file(&skill_dir, skill_file_names)
+            .unwrap_or(crate::skill::SKILL_FILE_NAME);
+        match std::fs::read_to_string(skill_dir.join(skill_file)) {
             Ok(content) => {
                 if let Err(e) = crate::skill::parse(&content) {
                     issues.push(
                         DiagnosticIssue::library(
                             IssueSeverity::Warning,
-                            format!("'{name}' has unparsable SKILL.md frontmatter: {e}"),
+                            format!("'{name}' has unparsable {skill_file} frontmatter: {e}"),
                         )
                         .with_id(FindingId::UnparsableFrontmatter {
                             skill: name.clone(),
@@ -1581,7 +1941,7 @@ fn check_library(paths: &TomePaths) -> Result<Vec<DiagnosticIssue>> {
             Err(e) => {
                 issues.push(DiagnosticIssue::library(
                     IssueSeverity::Error,
-                    format!("'{name}' SKILL.md is unreadable: {e}"),
+                    format!("'{name}' {skill_file} is unreadable: {e}"),
                 ));
             }
         }
@@ -1590,10 +1950,120 @@ fn check_library(paths: &TomePaths) -> Result<Vec<DiagnosticIssue>> {
     Ok(issues)
 }
 
+/// Check for symlinks committed to a git-tracked library. Symlinks point at
+/// machine-specific paths, so committing them breaks the library on every
+/// other machine.
+///
+/// The count comes from walking `library_dir` on disk; git's index is only
+/// consulted to decide whether those symlinks are tracked. No symlinks on
+/// disk means no warning. Symlinks the index still records but the disk no
+/// longer has (e.g. after `tome migrate-library`) are a pending commit,
+/// returned as an informational note instead.
+fn check_tracked_symlinks(library_dir: &Path) -> (Option<DiagnosticIssue>, Vec<String>) {
+    if !library_dir.is_dir() {
+        return (None, Vec::new());
+    }
+    let Some(indexed) = crate::git::indexed_symlinks(library_dir) else {
+        return (None, Vec::new());
+    };
+    let indexed: std::collections::BTreeSet<PathBuf> = indexed.into_iter().collect();
+    let on_disk: std::collections::BTreeSet<PathBuf> = walkdir::WalkDir::new(library_dir)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|e| e.file_name() != ".git")
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path_is_symlink())
+        .filter_map(|e| {
+            e.path()
+                .strip_prefix(library_dir)
+                .ok()
+                .map(Path::to_path_buf)
+        })
+        .collect();
+
+    let committed = on_disk.intersection(&indexed).count();
+    let issue = (committed > 0).then(|| {
+        DiagnosticIssue::library(
+            IssueSeverity::Warning,
+            format!(
+                "{committed} symlink(s) in the library are tracked in git — they point at machine-specific paths (run `tome migrate-library`, then commit)"
+            ),
+        )
+    });
+
+    let mut notes = Vec::new();
+    let pending = indexed.difference(&on_disk).count();
+    if pending > 0 {
+        notes.push(format!(
+            "git index still records {pending} library symlink(s) that are no longer symlinks on disk — commit the library to record the change"
+        ));
+    }
+    (issue, notes)
+}
+
+/// An issue when the library symlink `link` resolves to a directory that
+/// no longer holds a regular `SKILL.md` (or another of `skill_file_names`)
+/// — the skill was gutted at its source but the directory remains. Not
+/// auto-repaired: the source may be mid-edit, so removing the link could be
+/// premature.
+fn gutted_link_issue(
+    name: &str,
+    link: &Path,
+    skill_file_names: &[String],
+) -> Option<DiagnosticIssue> {
+    if !link.is_dir() || crate::skill::find_skill_file(link, skill_file_names).is_some() {
+        return None;
+    }
+    let target = std::fs::canonicalize(link).unwrap_or_else(|_| link.to_path_buf());
+    Some(DiagnosticIssue::library(
+        IssueSeverity::Warning,
+        format!(
+            "linked skill '{name}' no longer contains a SKILL.md ({} -> {}) — \
+             re-run `tome sync` or remove the source",
+            link.display(),
+            target.display()
+        ),
+    ))
+}
+
+/// Links followed before a chain counts as a loop even without revisiting
+/// the same spelling of a path (Linux's `MAXSYMLINKS`).
+const MAX_SYMLINK_HOPS: usize = 40;
+
+/// The chain of paths visited from `path` when following its symlinks
+/// loops, or `None` when the chain ends at a real entry or a missing one.
+/// Broken and looping links both fail `exists()`, but a loop is fixed by
+/// untangling the directory layout rather than deleting one stale link.
+fn symlink_cycle(path: &Path) -> Option<Vec<PathBuf>> {
+    let mut chain = vec![path.to_path_buf()];
+    let mut visited = std::collections::HashSet::from([path.to_path_buf()]);
+    let mut current = path.to_path_buf();
+    while current.is_symlink() {
+        let raw_target = std::fs::read_link(&current).ok()?;
+        let next = resolve_symlink_target(&current, &raw_target);
+        chain.push(next.clone());
+        if !visited.insert(next.clone()) || chain.len() > MAX_SYMLINK_HOPS {
+            return Some(chain);
+        }
+        current = next;
+    }
+    None
+}
+
+fn cycle_message(chain: &[PathBuf]) -> String {
+    let links: Vec<String> = chain.iter().map(|p| p.display().to_string()).collect();
+    format!(
+        "symlink cycle: {} (is a directory configured as both a source and a target?)",
+        links.join(" -> ")
+    )
+}
+
 fn check_distribution_dir(
     name: &str,
     skills_dir: &Path,
     library_dir: &Path,
+    manifest: &manifest::Manifest,
+    depth: usize,
 ) -> Result<Vec<DiagnosticIssue>> {
     let mut issues = Vec::new();
 
@@ -1607,10 +2077,13 @@ fn check_distribution_dir(
     let dir_name = DirectoryName::new(name).ok();
 
     if !skills_dir.is_dir() {
-        issues.push(DiagnosticIssue::directory(
-            IssueSeverity::Warning,
-            format!("directory path does not exist ({})", skills_dir.display()),
-        ));
+        issues.push(
+            DiagnosticIssue::directory(
+                IssueSeverity::Warning,
+                format!("directory path does not exist ({})", skills_dir.display()),
+            )
+            .with_kind(DiagnosticIssueKind::MissingTargetDir, skills_dir),
+        );
         return Ok(issues);
     }
 
@@ -1624,14 +2097,28 @@ fn check_distribution_dir(
         );
         library_dir.to_path_buf()
     });
+    // With a link ledger, only links it lists can be stale tome links.
+    let ledger = crate::link_marker::load(skills_dir)?;
 
-    let entries = std::fs::read_dir(skills_dir)
-        .with_context(|| format!("failed to read target dir {}", skills_dir.display()))?;
+    // Links are classified at every level down to `depth`; the walk never
+    // follows them, so it only descends through real directories.
+    let entries = walkdir::WalkDir::new(skills_dir)
+        .min_depth(1)
+        .max_depth(depth)
+        .sort_by_file_name();
 
     for entry in entries {
         let entry =
             entry.with_context(|| format!("failed to read entry in {}", skills_dir.display()))?;
-        let path = entry.path();
+        let path = entry.path().to_path_buf();
+
+        if let Some(chain) = symlink_cycle(&path) {
+            issues.push(
+                DiagnosticIssue::directory(IssueSeverity::Error, cycle_message(&chain))
+                    .with_kind(DiagnosticIssueKind::SymlinkCycle, &path),
+            );
+            continue;
+        }
 
         if path.is_symlink() {
             let raw_target = std::fs::read_link(&path)
@@ -1639,12 +2126,16 @@ fn check_distribution_dir(
             let target = resolve_symlink_target(&path, &raw_target);
             let points_into_library =
                 target.starts_with(library_dir) || target.starts_with(&canonical_library);
-            if points_into_library && !target.exists() {
+            let marked = path.strip_prefix(skills_dir).is_ok_and(|rel| {
+                crate::link_marker::is_marked(ledger.as_ref(), &rel.to_string_lossy())
+            });
+            if points_into_library && marked && !target.exists() {
                 let issue = DiagnosticIssue::directory_repairable(
                     IssueSeverity::Error,
                     format!("stale symlink {}", path.display()),
                     RepairKind::RemoveStaleTargetSymlink,
-                );
+                )
+                .with_kind(DiagnosticIssueKind::StaleTargetSymlink, &path);
                 let issue = if let Some(dn) = dir_name.clone() {
                     issue.with_id(FindingId::TargetStaleSymlink {
                         directory: dn,
@@ -1673,6 +2164,10 @@ fn check_distribution_dir(
                     ),
                 ));
             }
+        } else if entry.depth() > 1 {
+            // Below the top level, real entries are skill contents or the
+            // user's own layout — only links there are tome's business.
+            continue;
         } else if path.is_dir() {
             // Phase 24 (v0.16+): real directory in a distribution dir.
             // If the library has a same-named skill, hash-compare:
@@ -1686,7 +2181,9 @@ fn check_distribution_dir(
                 continue;
             };
             let library_skill = library_dir.join(basename);
-            if !library_skill.is_dir() {
+            // A `synced` directory's own skills are sources, not copies
+            // of the library: neither consolidation nor backup applies.
+            if !library_skill.is_dir() || is_own_skill(manifest, name, basename) {
                 continue;
             }
             match (
@@ -1712,46 +2209,103 @@ fn check_distribution_dir(
                     };
                     issues.push(issue);
                 }
-                (Ok(_), Ok(_)) => {
-                    let issue = DiagnosticIssue::directory(
-                        IssueSeverity::Warning,
-                        format!(
-                            "real directory in target diverges from library content — reconcile manually: {}",
-                            path.display()
-                        ),
-                    );
-                    let issue = if let Some(dn) = dir_name.clone() {
-                        issue.with_id(FindingId::DivergingTarget {
-                            directory: dn,
-                            path: path.clone(),
-                        })
-                    } else {
-                        issue
-                    };
-                    issues.push(issue);
-                }
+                (Ok(_), Ok(_)) => issues.push(target_collision_issue(
+                    "real directory in target diverges from library content",
+                    &path,
+                    dir_name.as_ref(),
+                )),
                 _ => {
                     // Hash failure on either side — skip silently;
                     // any real I/O problem here will surface via
                     // other doctor checks.
                 }
             }
+        } else if path.exists() {
+            // A real file named like a library skill: sync skips it as a
+            // path conflict, so surface it with the same backup repair
+            // as a diverging directory.
+            let Some(basename) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            if library_dir.join(basename).is_dir() && !is_own_skill(manifest, name, basename) {
+                issues.push(target_collision_issue(
+                    "file in target",
+                    &path,
+                    dir_name.as_ref(),
+                ));
+            }
         }
     }
 
     Ok(issues)
 }
 
+/// Whether `skill` was discovered from the distribution directory `dir_name`
+/// itself — a `synced` directory's own source skill. Distribute leaves
+/// those in place ("skipped (managed)"), so doctor must not treat them as
+/// entries blocking a library link.
+fn is_own_skill(manifest: &manifest::Manifest, dir_name: &str, skill: &str) -> bool {
+    manifest
+        .get(skill)
+        .and_then(|e| e.source_name())
+        .is_some_and(|s| s == dir_name)
+}
+
+/// Build the Warning for a non-symlink entry that blocks a library link.
+///
+/// Repairable via [`RepairKind::BackUpTargetCollision`] unless the
+/// `.tome-bak` slot is already taken — then the user must clear the old
+/// backup first, since doctor never overwrites one.
+fn target_collision_issue(
+    what: &str,
+    path: &Path,
+    dir_name: Option<&DirectoryName>,
+) -> DiagnosticIssue {
+    let backup = backup_path(path);
+    let issue = if backup.symlink_metadata().is_ok() {
+        DiagnosticIssue::directory(
+            IssueSeverity::Warning,
+            format!(
+                "{what} blocks the library link and {} already exists — reconcile manually: {}",
+                backup.display(),
+                path.display()
+            ),
+        )
+    } else {
+        DiagnosticIssue::directory_repairable(
+            IssueSeverity::Warning,
+            format!(
+                "{what} blocks the library link (back up to .tome-bak so sync can link it): {}",
+                path.display()
+            ),
+            RepairKind::BackUpTargetCollision,
+        )
+    };
+    match dir_name {
+        Some(dn) => issue.with_id(FindingId::DivergingTarget {
+            directory: dn.clone(),
+            path: path.to_path_buf(),
+        }),
+        None => issue,
+    }
+}
+
 /// Phase 24: convert real directories under `target_dir` to symlinks
 /// into `library_dir` when their content matches byte-for-byte.
 ///
 /// Same discovery logic as the corresponding `check_distribution_dir`
 /// branch — re-runs so the dispatcher does not need to carry per-issue
 /// path state. Diverging-content directories are skipped (the doctor
-/// check emits a no-repair Warning for those).
+/// check emits a no-repair Warning for those), and so are the directory's
+/// own skills ([`is_own_skill`]), which are sources rather than copies.
 ///
 /// Returns the count of directories converted.
-fn consolidate_target_real_dirs(target_dir: &Path, library_dir: &Path) -> Result<usize> {
+fn consolidate_target_real_dirs(
+    dir_name: &str,
+    target_dir: &Path,
+    library_dir: &Path,
+    manifest: &manifest::Manifest,
+) -> Result<usize> {
     if !target_dir.is_dir() {
         return Ok(0);
     }
@@ -1769,7 +2323,7 @@ fn consolidate_target_real_dirs(target_dir: &Path, library_dir: &Path) -> Result
             continue;
         };
         let library_skill = library_dir.join(basename);
-        if !library_skill.is_dir() {
+        if !library_skill.is_dir() || is_own_skill(manifest, dir_name, basename) {
             continue;
         }
         let target_hash = manifest::hash_directory(&path)?;
@@ -1779,7 +2333,7 @@ fn consolidate_target_real_dirs(target_dir: &Path, library_dir: &Path) -> Result
         }
         std::fs::remove_dir_all(&path)
             .with_context(|| format!("failed to remove {}", path.display()))?;
-        std::os::unix::fs::symlink(&library_skill, &path).with_context(|| {
+        create_dir_symlink(&library_skill, &path).with_context(|| {
             format!(
                 "failed to create symlink {} -> {}",
                 path.display(),
@@ -1791,25 +2345,225 @@ fn consolidate_target_real_dirs(target_dir: &Path, library_dir: &Path) -> Result
     Ok(fixed)
 }
 
-fn check_config(config: &Config) -> Result<Vec<DiagnosticIssue>> {
+/// Move every non-symlink entry under `target_dir` that blocks the link to
+/// a same-named library skill aside to `<name>.tome-bak` — real files, and
+/// real directories whose content diverges from the library (matching ones
+/// are [`consolidate_target_real_dirs`]'s job). The directory's own skills
+/// ([`is_own_skill`]) and entries whose backup slot is taken are left in
+/// place. The next sync creates the links.
+///
+/// Returns the count of entries backed up.
+fn back_up_target_collisions(
+    dir_name: &str,
+    target_dir: &Path,
+    library_dir: &Path,
+    manifest: &manifest::Manifest,
+) -> Result<usize> {
+    if !target_dir.is_dir() {
+        return Ok(0);
+    }
+    let mut backed_up = 0;
+    let entries = std::fs::read_dir(target_dir)
+        .with_context(|| format!("failed to read target dir {}", target_dir.display()))?;
+    for entry in entries {
+        let entry =
+            entry.with_context(|| format!("failed to read entry in {}", target_dir.display()))?;
+        let path = entry.path();
+        if path.is_symlink() {
+            continue;
+        }
+        let Some(basename) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let library_skill = library_dir.join(basename);
+        if !library_skill.is_dir() || is_own_skill(manifest, dir_name, basename) {
+            continue;
+        }
+        if path.is_dir()
+            && manifest::hash_directory(&path)? == manifest::hash_directory(&library_skill)?
+        {
+            continue;
+        }
+        match back_up_collision(&path, false)? {
+            Some(_) => backed_up += 1,
+            None => warn!(
+                "{} already exists, leaving {} in place",
+                backup_path(&path).display(),
+                path.display()
+            ),
+        }
+    }
+    Ok(backed_up)
+}
+
+pub(crate) fn check_config(config: &Config) -> Result<Vec<DiagnosticIssue>> {
     let mut issues = Vec::new();
 
     for (name, dir_config) in &config.directories {
+        if dir_config.is_glob() {
+            if dir_config.glob_matches().unwrap_or_default().is_empty() {
+                issues.push(
+                    DiagnosticIssue::config(
+                        IssueSeverity::Warning,
+                        format!(
+                            "directory '{}' glob matches no directories: {}",
+                            name,
+                            dir_config.path.display()
+                        ),
+                    )
+                    .with_kind(DiagnosticIssueKind::MissingSource, &dir_config.path),
+                );
+            }
+            continue;
+        }
         if !dir_config.path.exists() {
-            issues.push(DiagnosticIssue::config(
+            let issue = DiagnosticIssue::config(
                 IssueSeverity::Warning,
                 format!(
                     "directory '{}' path does not exist: {}",
                     name,
                     dir_config.path.display()
                 ),
-            ));
+            );
+            // Distribution-only directories get their typed kind from
+            // `check_distribution_dir` (MissingTargetDir) instead.
+            let issue = if dir_config.role().is_discovery() {
+                issue.with_kind(DiagnosticIssueKind::MissingSource, &dir_config.path)
+            } else {
+                issue
+            };
+            issues.push(issue);
         }
     }
 
+    for (other, name) in config.duplicate_distribution_dirs() {
+        let path = &config.directories[name].path;
+        issues.push(
+            DiagnosticIssue::config(
+                IssueSeverity::Error,
+                format!(
+                    "distribution directories '{}' and '{}' are the same path: {} (sync refuses to distribute)",
+                    other,
+                    name,
+                    path.display()
+                ),
+            )
+            .with_kind(DiagnosticIssueKind::DuplicateDistributionDir, path),
+        );
+    }
+
     Ok(issues)
 }
 
+/// Run discovery and report every skill name that appears in more than one
+/// directory. Discovery keeps the first occurrence (directories iterate in
+/// name order); each shadowed copy becomes one Conflict issue naming both sides.
+///
+/// Git directories resolve through the lockfile cache, same as the
+/// non-sync lockfile regen paths, so doctor stays offline. The discovered
+/// skills are returned too, so later checks reuse the scan.
+fn check_conflicts(
+    config: &Config,
+    paths: &TomePaths,
+) -> Result<(Vec<DiagnosticIssue>, Vec<crate::discover::DiscoveredSkill>)> {
+    let (resolved_paths, mut warnings) =
+        crate::lockfile::resolved_paths_from_lockfile_cache(config, paths);
+    let (skills, conflicts) =
+        crate::discover::discover_all_with_conflicts(config, &resolved_paths, &mut warnings)?;
+    for w in &warnings {
+        debug!("doctor conflict check: {}", w);
+    }
+
+    let issues = conflicts
+        .into_iter()
+        .map(|c| {
+            if c.is_within_directory() {
+                return DiagnosticIssue::conflict(format!(
+                    "skill directories {} and {} in '{}' both map to the name '{}' (the first is used)",
+                    c.winner_path.display(),
+                    c.loser_path.display(),
+                    c.winner,
+                    c.name
+                ));
+            }
+            DiagnosticIssue::conflict(format!(
+                "skill '{}' in '{}' is shadowed by '{}' (the '{}' copy is used)",
+                c.name, c.loser, c.winner, c.winner
+            ))
+        })
+        .collect();
+    Ok((issues, skills))
+}
+
+/// One warning per discovered skill whose frontmatter `requires` names a
+/// skill that isn't among `skills`.
+fn check_requirements(skills: &[crate::discover::DiscoveredSkill]) -> Vec<DiagnosticIssue> {
+    crate::discover::missing_requirements(skills)
+        .into_iter()
+        .map(|(skill, missing)| {
+            DiagnosticIssue::library(
+                IssueSeverity::Warning,
+                crate::discover::missing_requirements_message(skill, &missing),
+            )
+            .with_kind(DiagnosticIssueKind::MissingRequirement, &skill.path)
+        })
+        .collect()
+}
+
+/// Warnings for discovered skills whose frontmatter lacks one of the
+/// `required` fields, or whose `name` differs from the skill's directory
+/// name. Skills without parsable frontmatter are skipped — the library
+/// check reports those. Nothing here is auto-repairable.
+fn check_frontmatter(
+    skills: &[crate::discover::DiscoveredSkill],
+    required: &[String],
+) -> Vec<DiagnosticIssue> {
+    let mut issues = Vec::new();
+    for skill in skills {
+        let Some(frontmatter) = &skill.frontmatter else {
+            continue;
+        };
+        for field in required {
+            if !frontmatter.has_field(field) {
+                issues.push(
+                    DiagnosticIssue::library(
+                        IssueSeverity::Warning,
+                        format!(
+                            "'{}' is missing required frontmatter field '{field}' ({})",
+                            skill.name,
+                            skill.path.display()
+                        ),
+                    )
+                    .with_kind(DiagnosticIssueKind::MissingFrontmatterField, &skill.path),
+                );
+            }
+        }
+        // Cursor rules are single files with generated frontmatter.
+        let dir_name = skill
+            .path
+            .is_dir()
+            .then(|| skill.path.file_name())
+            .flatten()
+            .map(|n| n.to_string_lossy());
+        if let (Some(name), Some(dir_name)) = (&frontmatter.name, dir_name)
+            && *name != dir_name
+        {
+            issues.push(
+                DiagnosticIssue::library(
+                    IssueSeverity::Warning,
+                    format!(
+                        "'{}' has frontmatter name '{name}' but its directory is '{dir_name}' ({})",
+                        skill.name,
+                        skill.path.display()
+                    ),
+                )
+                .with_kind(DiagnosticIssueKind::FrontmatterNameMismatch, &skill.path),
+            );
+        }
+    }
+    issues
+}
+
 /// Repair library issues: remove orphan manifest entries and broken symlinks.
 fn repair_library(paths: &TomePaths) -> Result<()> {
     let library_dir = paths.library_dir();
@@ -1876,7 +2630,9 @@ fn repair_library(paths: &TomePaths) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType};
+    use crate::config::{
+        Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, NameTransform,
+    };
     use std::collections::BTreeMap;
     use std::os::unix::fs as unix_fs;
     use std::path::PathBuf;
@@ -1944,9 +2700,52 @@ mod tests {
     }
 
     #[test]
-    fn check_detects_orphan_directory() {
+    fn tracked_symlinks_counted_from_disk_not_index() {
         let lib = TempDir::new().unwrap();
-        std::fs::create_dir_all(lib.path().join("orphan")).unwrap();
+        let git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(lib.path())
+                .env_remove("GIT_DIR")
+                .env_remove("GIT_WORK_TREE")
+                .env_remove("GIT_INDEX_FILE")
+                .output()
+                .unwrap();
+        };
+
+        // Not a git repo: nothing to say.
+        unix_fs::symlink("/elsewhere/skill-a", lib.path().join("skill-a")).unwrap();
+        let (issue, notes) = check_tracked_symlinks(lib.path());
+        assert!(issue.is_none());
+        assert!(notes.is_empty());
+
+        // Symlink on disk and in the index: warn.
+        git(&["init"]);
+        git(&["add", "-A"]);
+        let (issue, notes) = check_tracked_symlinks(lib.path());
+        let issue = issue.expect("tracked symlink should warn");
+        assert_eq!(issue.severity, IssueSeverity::Warning);
+        assert!(
+            issue.message.starts_with("1 symlink(s)"),
+            "{}",
+            issue.message
+        );
+        assert!(notes.is_empty());
+
+        // Migrated to a real directory but not yet re-added: the index
+        // still says symlink, the disk does not — note, no warning.
+        std::fs::remove_file(lib.path().join("skill-a")).unwrap();
+        std::fs::create_dir(lib.path().join("skill-a")).unwrap();
+        let (issue, notes) = check_tracked_symlinks(lib.path());
+        assert!(issue.is_none());
+        assert_eq!(notes.len(), 1);
+        assert!(notes[0].contains("1 library symlink(s)"), "{}", notes[0]);
+    }
+
+    #[test]
+    fn check_detects_orphan_directory() {
+        let lib = TempDir::new().unwrap();
+        std::fs::create_dir_all(lib.path().join("orphan")).unwrap();
 
         let config = Config {
             library_dir: lib.path().to_path_buf(),
@@ -1978,7 +2777,14 @@ mod tests {
                     git_ref: None,
 
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Config::default()
@@ -2004,6 +2810,7 @@ mod tests {
                 Path::new("/nonexistent/library").to_path_buf(),
             )
             .unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         assert_eq!(result.len(), 1);
@@ -2038,6 +2845,7 @@ mod tests {
 
         let result = check_library(
             &TomePaths::new(lib.path().to_path_buf(), lib.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         assert!(result.is_empty());
@@ -2064,6 +2872,7 @@ mod tests {
 
         let result = check_library(
             &TomePaths::new(lib.path().to_path_buf(), lib.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         assert_eq!(result.len(), 1);
@@ -2077,6 +2886,7 @@ mod tests {
 
         let result = check_library(
             &TomePaths::new(lib.path().to_path_buf(), lib.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         assert_eq!(result.len(), 1);
@@ -2090,10 +2900,55 @@ mod tests {
 
         let result = check_library(
             &TomePaths::new(lib.path().to_path_buf(), lib.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].severity, IssueSeverity::Error);
+        assert_eq!(
+            result[0].kind,
+            Some(DiagnosticIssueKind::BrokenLibrarySymlink)
+        );
+        assert_eq!(result[0].path, Some(lib.path().join("broken")));
+    }
+
+    #[test]
+    fn check_library_reports_symlink_cycle_instead_of_broken_link() {
+        let lib = TempDir::new().unwrap();
+        let elsewhere = TempDir::new().unwrap();
+        let entry = lib.path().join("looped");
+        let back = elsewhere.path().join("looped");
+        unix_fs::symlink(&back, &entry).unwrap();
+        unix_fs::symlink(&entry, &back).unwrap();
+
+        let result = check_library(
+            &TomePaths::new(lib.path().to_path_buf(), lib.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
+        )
+        .unwrap();
+        assert_eq!(result.len(), 1, "{result:?}");
+        assert_eq!(result[0].kind, Some(DiagnosticIssueKind::SymlinkCycle));
+        assert_eq!(result[0].repair_kind, None);
+        assert!(result[0].message.contains("symlink cycle"));
+        assert!(result[0].message.contains(&back.display().to_string()));
+    }
+
+    #[test]
+    fn symlink_cycle_ignores_chains_that_end() {
+        let tmp = TempDir::new().unwrap();
+        let real = tmp.path().join("real");
+        std::fs::create_dir(&real).unwrap();
+        unix_fs::symlink(&real, tmp.path().join("a")).unwrap();
+        unix_fs::symlink(tmp.path().join("a"), tmp.path().join("b")).unwrap();
+        unix_fs::symlink("/nonexistent", tmp.path().join("broken")).unwrap();
+
+        assert_eq!(symlink_cycle(&tmp.path().join("b")), None);
+        assert_eq!(symlink_cycle(&tmp.path().join("broken")), None);
+        assert_eq!(symlink_cycle(&real), None);
+
+        unix_fs::symlink("self", tmp.path().join("self")).unwrap();
+        let chain = symlink_cycle(&tmp.path().join("self")).unwrap();
+        assert_eq!(chain.len(), 2);
     }
 
     // -- Phase 23: broken-frontmatter diagnostic --
@@ -2140,6 +2995,7 @@ mod tests {
 
         let issues = check_library(
             &TomePaths::new(tome_home.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         assert!(
@@ -2161,6 +3017,7 @@ mod tests {
 
         let issues = check_library(
             &TomePaths::new(tome_home.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         let matched: Vec<_> = issues
@@ -2193,6 +3050,7 @@ mod tests {
 
         let issues = check_library(
             &TomePaths::new(tome_home.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         let matched: Vec<_> = issues
@@ -2215,6 +3073,7 @@ mod tests {
 
         let issues = check_library(
             &TomePaths::new(tome_home.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         let matched: Vec<_> = issues
@@ -2233,14 +3092,96 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_library_flags_linked_skill_without_skill_md() {
+        let tome_home = TempDir::new().unwrap();
+        let library = TempDir::new().unwrap();
+        let sources = TempDir::new().unwrap();
+        // Tracked skill whose linked source lost its SKILL.md.
+        make_skill_with_skill_md(tome_home.path(), sources.path(), "gutted", None);
+        std::fs::rename(
+            sources.path().join("gutted"),
+            sources.path().join("gutted-src"),
+        )
+        .unwrap();
+        unix_fs::symlink(
+            sources.path().join("gutted-src"),
+            library.path().join("gutted"),
+        )
+        .unwrap();
+        // Untracked link whose SKILL.md is a directory, not a file.
+        std::fs::create_dir_all(sources.path().join("odd/SKILL.md")).unwrap();
+        unix_fs::symlink(sources.path().join("odd"), library.path().join("odd")).unwrap();
+        // Healthy link.
+        std::fs::create_dir_all(sources.path().join("fine")).unwrap();
+        std::fs::write(
+            sources.path().join("fine/SKILL.md"),
+            "---\nname: fine\n---\n",
+        )
+        .unwrap();
+        unix_fs::symlink(sources.path().join("fine"), library.path().join("fine")).unwrap();
+
+        let issues = check_library(
+            &TomePaths::new(tome_home.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
+        )
+        .unwrap();
+        let gutted: Vec<_> = issues
+            .iter()
+            .filter(|i| i.message.contains("no longer contains a SKILL.md"))
+            .collect();
+        assert_eq!(gutted.len(), 2, "{issues:?}");
+        assert!(gutted.iter().any(|i| i.message.contains("'gutted'")));
+        assert!(gutted.iter().any(|i| i.message.contains("'odd'")));
+        assert!(gutted[0].message.contains("re-run `tome sync`"));
+        assert!(gutted.iter().all(|i| i.repair_kind.is_none()));
+        assert!(
+            !issues
+                .iter()
+                .any(|i| i.message.contains("has no SKILL.md file")),
+            "the gutted-link issue replaces the generic one: {issues:?}"
+        );
+    }
+
     // -- check_distribution_dir --
 
     #[test]
     fn check_distribution_dir_missing_dir() {
         let lib = TempDir::new().unwrap();
-        let result =
-            check_distribution_dir("test-dir", Path::new("/nonexistent/dir"), lib.path()).unwrap();
+        let result = check_distribution_dir(
+            "test-dir",
+            Path::new("/nonexistent/dir"),
+            lib.path(),
+            &manifest::Manifest::default(),
+            1,
+        )
+        .unwrap();
         assert_eq!(result.len(), 1);
+        assert_eq!(result[0].kind, Some(DiagnosticIssueKind::MissingTargetDir));
+        assert_eq!(result[0].path, Some(PathBuf::from("/nonexistent/dir")));
+    }
+
+    #[test]
+    fn check_distribution_dir_reports_symlink_cycle() {
+        let lib = TempDir::new().unwrap();
+        let target_dir = TempDir::new().unwrap();
+        // target/skill -> library/skill -> target/skill
+        let link = target_dir.path().join("skill");
+        unix_fs::symlink(lib.path().join("skill"), &link).unwrap();
+        unix_fs::symlink(&link, lib.path().join("skill")).unwrap();
+
+        let result = check_distribution_dir(
+            "test-dir",
+            target_dir.path(),
+            lib.path(),
+            &manifest::Manifest::default(),
+            1,
+        )
+        .unwrap();
+        assert_eq!(result.len(), 1, "{result:?}");
+        assert_eq!(result[0].kind, Some(DiagnosticIssueKind::SymlinkCycle));
+        assert_eq!(result[0].path, Some(link));
+        assert_eq!(result[0].repair_kind, None);
     }
 
     #[test]
@@ -2251,8 +3192,51 @@ mod tests {
         let stale_target = lib.path().join("deleted-skill");
         unix_fs::symlink(&stale_target, target_dir.path().join("skill-link")).unwrap();
 
-        let result = check_distribution_dir("test", target_dir.path(), lib.path()).unwrap();
+        let result = check_distribution_dir(
+            "test",
+            target_dir.path(),
+            lib.path(),
+            &manifest::Manifest::default(),
+            1,
+        )
+        .unwrap();
         assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].kind,
+            Some(DiagnosticIssueKind::StaleTargetSymlink)
+        );
+        assert_eq!(result[0].path, Some(target_dir.path().join("skill-link")));
+    }
+
+    #[test]
+    fn check_distribution_dir_nested_stale_symlink_needs_depth() {
+        let lib = TempDir::new().unwrap();
+        let target_dir = TempDir::new().unwrap();
+        let group = target_dir.path().join("group");
+        std::fs::create_dir(&group).unwrap();
+        unix_fs::symlink(lib.path().join("deleted-skill"), group.join("skill-link")).unwrap();
+
+        let shallow = check_distribution_dir(
+            "test",
+            target_dir.path(),
+            lib.path(),
+            &manifest::Manifest::default(),
+            1,
+        )
+        .unwrap();
+        assert!(shallow.is_empty(), "{shallow:?}");
+
+        let deep = check_distribution_dir(
+            "test",
+            target_dir.path(),
+            lib.path(),
+            &manifest::Manifest::default(),
+            2,
+        )
+        .unwrap();
+        assert_eq!(deep.len(), 1, "{deep:?}");
+        assert_eq!(deep[0].kind, Some(DiagnosticIssueKind::StaleTargetSymlink));
+        assert_eq!(deep[0].path, Some(group.join("skill-link")));
     }
 
     /// HARD-09 / D-DIST-2 BEHAVIOUR CHANGE: external (foreign) symlinks
@@ -2267,7 +3251,14 @@ mod tests {
 
         unix_fs::symlink("/some/other/place", target_dir.path().join("external")).unwrap();
 
-        let result = check_distribution_dir("test", target_dir.path(), lib.path()).unwrap();
+        let result = check_distribution_dir(
+            "test",
+            target_dir.path(),
+            lib.path(),
+            &manifest::Manifest::default(),
+            1,
+        )
+        .unwrap();
         let foreign: Vec<_> = result
             .iter()
             .filter(|i| i.kind == Some(DiagnosticIssueKind::ForeignSymlink))
@@ -2314,7 +3305,14 @@ mod tests {
         let target = TempDir::new().unwrap();
         make_library_and_target_skill(library.path(), target.path(), "twin", false);
 
-        let result = check_distribution_dir("test", target.path(), library.path()).unwrap();
+        let result = check_distribution_dir(
+            "test",
+            target.path(),
+            library.path(),
+            &manifest::Manifest::default(),
+            1,
+        )
+        .unwrap();
         let matched: Vec<_> = result
             .iter()
             .filter(|i| i.repair_kind == Some(RepairKind::ConsolidateTargetRealDirToSymlink))
@@ -2329,12 +3327,19 @@ mod tests {
     }
 
     #[test]
-    fn check_distribution_dir_real_dir_diverging_is_warning_no_repair() {
+    fn check_distribution_dir_real_dir_diverging_is_backup_repairable() {
         let library = TempDir::new().unwrap();
         let target = TempDir::new().unwrap();
         make_library_and_target_skill(library.path(), target.path(), "diverged", true);
 
-        let result = check_distribution_dir("test", target.path(), library.path()).unwrap();
+        let result = check_distribution_dir(
+            "test",
+            target.path(),
+            library.path(),
+            &manifest::Manifest::default(),
+            1,
+        )
+        .unwrap();
         let matched: Vec<_> = result
             .iter()
             .filter(|i| i.message.contains("diverges from library content"))
@@ -2345,10 +3350,103 @@ mod tests {
             "diverging real dir must surface as one Warning, got: {result:?}"
         );
         assert_eq!(matched[0].severity, IssueSeverity::Warning);
+        assert_eq!(
+            matched[0].repair_kind,
+            Some(RepairKind::BackUpTargetCollision),
+            "diverging real dir is repaired by backing it up, never by overwriting"
+        );
+    }
+
+    /// A manifest recording `name` as discovered from directory `source`.
+    fn manifest_owned_by(name: &str, source: &str) -> manifest::Manifest {
+        let mut m = manifest::Manifest::default();
+        m.insert(
+            SkillName::new(name).unwrap(),
+            manifest::SkillEntry::new(
+                PathBuf::from(format!("/tmp/src/{name}")),
+                DirectoryName::new(source).unwrap(),
+                crate::validation::test_hash(name),
+                false,
+            ),
+        );
+        m
+    }
+
+    #[test]
+    fn synced_dir_own_diverging_skill_is_not_a_collision() {
+        // In a `synced` directory the real `foo/` is the user's source;
+        // edits to it are not a collision with the library copy.
+        let library = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        make_library_and_target_skill(library.path(), target.path(), "foo", true);
+        let manifest = manifest_owned_by("foo", "test");
+
+        let result =
+            check_distribution_dir("test", target.path(), library.path(), &manifest, 1).unwrap();
         assert!(
-            matched[0].repair_kind.is_none(),
-            "diverging real dir must not be auto-repairable (user must reconcile)"
+            result.is_empty(),
+            "own skill must not be flagged: {result:?}"
+        );
+
+        let count =
+            back_up_target_collisions("test", target.path(), library.path(), &manifest).unwrap();
+        assert_eq!(count, 0);
+        assert!(target.path().join("foo/SKILL.md").is_file());
+        assert!(!target.path().join("foo.tome-bak").exists());
+
+        // The same entry in a different directory is still a collision.
+        let result =
+            check_distribution_dir("other", target.path(), library.path(), &manifest, 1).unwrap();
+        assert_eq!(result.len(), 1, "{result:?}");
+    }
+
+    #[test]
+    fn synced_dir_own_matching_skill_is_not_consolidated() {
+        // An unedited source skill in a `synced` directory hashes equal to
+        // its library copy; replacing it with a link would delete the
+        // source itself.
+        let library = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        make_library_and_target_skill(library.path(), target.path(), "foo", false);
+        let manifest = manifest_owned_by("foo", "test");
+
+        let result =
+            check_distribution_dir("test", target.path(), library.path(), &manifest, 1).unwrap();
+        assert!(
+            result.is_empty(),
+            "own skill must not be flagged: {result:?}"
         );
+
+        let count =
+            consolidate_target_real_dirs("test", target.path(), library.path(), &manifest).unwrap();
+        assert_eq!(count, 0);
+        assert!(!target.path().join("foo").is_symlink());
+        assert!(target.path().join("foo/SKILL.md").is_file());
+    }
+
+    #[test]
+    fn check_distribution_dir_collision_with_taken_backup_slot_is_not_repairable() {
+        let library = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        std::fs::create_dir_all(library.path().join("notes")).unwrap();
+        std::fs::write(target.path().join("notes"), "a file").unwrap();
+        std::fs::write(target.path().join("notes.tome-bak"), "older backup").unwrap();
+
+        let result = check_distribution_dir(
+            "test",
+            target.path(),
+            library.path(),
+            &manifest::Manifest::default(),
+            1,
+        )
+        .unwrap();
+        let matched: Vec<_> = result
+            .iter()
+            .filter(|i| i.message.contains("blocks the library link"))
+            .collect();
+        assert_eq!(matched.len(), 1, "got: {result:?}");
+        assert!(matched[0].message.contains("reconcile manually"));
+        assert!(matched[0].repair_kind.is_none());
     }
 
     #[test]
@@ -2359,7 +3457,14 @@ mod tests {
         std::fs::create_dir_all(target.path().join("stranger")).unwrap();
         std::fs::write(target.path().join("stranger/SKILL.md"), "stub").unwrap();
 
-        let result = check_distribution_dir("test", target.path(), library.path()).unwrap();
+        let result = check_distribution_dir(
+            "test",
+            target.path(),
+            library.path(),
+            &manifest::Manifest::default(),
+            1,
+        )
+        .unwrap();
         assert!(
             result.is_empty(),
             "real dirs with no library counterpart must be left alone, got: {result:?}"
@@ -2374,7 +3479,13 @@ mod tests {
         // Also add a diverging dir — must NOT be touched.
         make_library_and_target_skill(library.path(), target.path(), "left-alone", true);
 
-        let count = consolidate_target_real_dirs(target.path(), library.path()).unwrap();
+        let count = consolidate_target_real_dirs(
+            "test",
+            target.path(),
+            library.path(),
+            &manifest::Manifest::default(),
+        )
+        .unwrap();
         assert_eq!(count, 1, "only the matching twin gets converted");
 
         let twin_path = target.path().join("twin");
@@ -2392,6 +3503,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn back_up_target_collisions_moves_blockers_aside() {
+        let library = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        make_library_and_target_skill(library.path(), target.path(), "twin", false);
+        make_library_and_target_skill(library.path(), target.path(), "diverged", true);
+        std::fs::create_dir_all(library.path().join("as-file")).unwrap();
+        std::fs::write(target.path().join("as-file"), "mine").unwrap();
+        std::fs::create_dir_all(library.path().join("taken")).unwrap();
+        std::fs::write(target.path().join("taken"), "new").unwrap();
+        std::fs::write(target.path().join("taken.tome-bak"), "old").unwrap();
+        std::fs::write(target.path().join("unrelated.md"), "x").unwrap();
+
+        let count = back_up_target_collisions(
+            "test",
+            target.path(),
+            library.path(),
+            &manifest::Manifest::default(),
+        )
+        .unwrap();
+        assert_eq!(count, 2, "the diverging dir and the file are backed up");
+
+        assert!(target.path().join("diverged.tome-bak/SKILL.md").is_file());
+        assert!(!target.path().join("diverged").exists());
+        assert_eq!(
+            std::fs::read_to_string(target.path().join("as-file.tome-bak")).unwrap(),
+            "mine"
+        );
+        // Matching twins are the consolidation repair's job.
+        assert!(target.path().join("twin").is_dir());
+        // A taken backup slot is never overwritten.
+        assert_eq!(
+            std::fs::read_to_string(target.path().join("taken")).unwrap(),
+            "new"
+        );
+        assert_eq!(
+            std::fs::read_to_string(target.path().join("taken.tome-bak")).unwrap(),
+            "old"
+        );
+        assert!(target.path().join("unrelated.md").is_file());
+    }
+
     // -- check_config --
 
     #[test]
@@ -2406,7 +3559,14 @@ mod tests {
                     git_ref: None,
 
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Config::default()
@@ -2414,6 +3574,8 @@ mod tests {
 
         let result = check_config(&config).unwrap();
         assert_eq!(result.len(), 1);
+        assert_eq!(result[0].kind, Some(DiagnosticIssueKind::MissingSource));
+        assert_eq!(result[0].path, Some(PathBuf::from("/nonexistent/source")));
     }
 
     #[test]
@@ -2429,7 +3591,14 @@ mod tests {
                     git_ref: None,
 
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Config::default()
@@ -2439,6 +3608,201 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    #[test]
+    fn check_config_reports_duplicate_distribution_dirs() {
+        let skills = TempDir::new().unwrap();
+        let target = |path: &Path| DirectoryConfig {
+            role: Some(DirectoryRole::Target),
+            ..source_dir_config(path)
+        };
+        let config = Config {
+            directories: BTreeMap::from([
+                (DirectoryName::new("claude").unwrap(), target(skills.path())),
+                (DirectoryName::new("codex").unwrap(), target(skills.path())),
+            ]),
+            ..Config::default()
+        };
+
+        let result = check_config(&config).unwrap();
+        assert_eq!(result.len(), 1, "{result:?}");
+        assert_eq!(result[0].severity, IssueSeverity::Error);
+        assert_eq!(
+            result[0].kind,
+            Some(DiagnosticIssueKind::DuplicateDistributionDir)
+        );
+        assert!(
+            result[0].message.contains("'claude' and 'codex'"),
+            "{}",
+            result[0].message
+        );
+    }
+
+    fn source_dir_config(path: &Path) -> DirectoryConfig {
+        DirectoryConfig {
+            path: path.to_path_buf(),
+            directory_type: DirectoryType::Directory,
+            role: Some(DirectoryRole::Source),
+            git_ref: None,
+            subdir: None,
+            max_depth: None,
+            follow_links: false,
+            name_transform: NameTransform::None,
+            prefix: None,
+            include: None,
+            exclude: None,
+            override_applied: false,
+            scan_origin: Default::default(),
+        }
+    }
+
+    #[test]
+    fn check_conflicts_reports_shadowed_skill() {
+        let tmp = TempDir::new().unwrap();
+        let alpha = tmp.path().join("alpha");
+        let beta = tmp.path().join("beta");
+        for dir in [&alpha, &beta] {
+            std::fs::create_dir_all(dir.join("shared")).unwrap();
+            std::fs::write(dir.join("shared/SKILL.md"), "# shared").unwrap();
+        }
+        std::fs::create_dir_all(beta.join("solo")).unwrap();
+        std::fs::write(beta.join("solo/SKILL.md"), "# solo").unwrap();
+
+        let config = Config {
+            library_dir: tmp.path().join("library"),
+            directories: BTreeMap::from([
+                (
+                    DirectoryName::new("alpha").unwrap(),
+                    source_dir_config(&alpha),
+                ),
+                (
+                    DirectoryName::new("beta").unwrap(),
+                    source_dir_config(&beta),
+                ),
+            ]),
+            ..Config::default()
+        };
+        let paths = TomePaths::new(tmp.path().to_path_buf(), config.library_dir.clone()).unwrap();
+
+        let (issues, _) = check_conflicts(&config, &paths).unwrap();
+        assert_eq!(issues.len(), 1);
+        let issue = &issues[0];
+        assert_eq!(issue.category, IssueCategory::Conflict);
+        assert!(issue.repair_kind.is_none());
+        assert!(issue.message.contains("'shared'"));
+        assert!(issue.message.contains("in 'beta' is shadowed by 'alpha'"));
+    }
+
+    #[test]
+    fn check_requirements_warns_about_missing_dependency() {
+        let tmp = TempDir::new().unwrap();
+        let skills = tmp.path().join("skills");
+        std::fs::create_dir_all(skills.join("release-notes")).unwrap();
+        std::fs::write(
+            skills.join("release-notes/SKILL.md"),
+            "---\nname: release-notes\ndescription: Draft notes\nmetadata:\n  requires: [git-history, changelog-format]\n---\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(skills.join("git-history")).unwrap();
+        std::fs::write(skills.join("git-history/SKILL.md"), "# git-history").unwrap();
+
+        let config = Config {
+            library_dir: tmp.path().join("library"),
+            directories: BTreeMap::from([(
+                DirectoryName::new("skills").unwrap(),
+                source_dir_config(&skills),
+            )]),
+            ..Config::default()
+        };
+        let paths = TomePaths::new(tmp.path().to_path_buf(), config.library_dir.clone()).unwrap();
+
+        let (_, discovered) = check_conflicts(&config, &paths).unwrap();
+        let issues = check_requirements(&discovered);
+        assert_eq!(issues.len(), 1);
+        let issue = &issues[0];
+        assert_eq!(issue.kind, Some(DiagnosticIssueKind::MissingRequirement));
+        assert_eq!(issue.severity, IssueSeverity::Warning);
+        assert!(issue.repair_kind.is_none());
+        assert!(
+            issue
+                .message
+                .contains("'release-notes' requires 'changelog-format'"),
+            "{}",
+            issue.message
+        );
+        assert!(!issue.message.contains("git-history"), "{}", issue.message);
+    }
+
+    #[test]
+    fn check_frontmatter_flags_missing_field_and_name_mismatch() {
+        let tmp = TempDir::new().unwrap();
+        let skills = tmp.path().join("skills");
+        for (dir, content) in [
+            ("no-description", "---\nname: no-description\n---\n"),
+            ("renamed", "---\nname: old-name\ndescription: Moved\n---\n"),
+            ("fine", "---\nname: fine\ndescription: All set\n---\n"),
+        ] {
+            std::fs::create_dir_all(skills.join(dir)).unwrap();
+            std::fs::write(skills.join(dir).join("SKILL.md"), content).unwrap();
+        }
+        let config = Config {
+            library_dir: tmp.path().join("library"),
+            directories: BTreeMap::from([(
+                DirectoryName::new("skills").unwrap(),
+                source_dir_config(&skills),
+            )]),
+            ..Config::default()
+        };
+        let paths = TomePaths::new(tmp.path().to_path_buf(), config.library_dir.clone()).unwrap();
+        let (_, discovered) = check_conflicts(&config, &paths).unwrap();
+
+        // The default only requires `name`, which every skill sets.
+        let issues = check_frontmatter(&discovered, &config.required_frontmatter);
+        assert_eq!(issues.len(), 1, "{issues:?}");
+        assert_eq!(
+            issues[0].kind,
+            Some(DiagnosticIssueKind::FrontmatterNameMismatch)
+        );
+        assert!(
+            issues[0]
+                .message
+                .contains("frontmatter name 'old-name' but its directory is 'renamed'"),
+            "{}",
+            issues[0].message
+        );
+
+        let required = vec!["name".to_string(), "description".to_string()];
+        let missing: Vec<_> = check_frontmatter(&discovered, &required)
+            .into_iter()
+            .filter(|i| i.kind == Some(DiagnosticIssueKind::MissingFrontmatterField))
+            .collect();
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].severity, IssueSeverity::Warning);
+        assert!(missing[0].repair_kind.is_none());
+        assert!(
+            missing[0]
+                .message
+                .contains("'no-description' is missing required frontmatter field 'description'"),
+            "{}",
+            missing[0].message
+        );
+    }
+
+    #[test]
+    fn conflict_issues_count_toward_total_but_not_auto_fixable() {
+        let report = DoctorReport {
+            configured: true,
+            library_issues: Vec::new(),
+            directory_issues: Vec::new(),
+            config_issues: Vec::new(),
+            conflict_issues: vec![DiagnosticIssue::conflict("dup")],
+            unowned_skills: Vec::new(),
+            library_notes: Vec::new(),
+        };
+        assert_eq!(report.total_issues(), 1);
+        assert_eq!(report.auto_fixable_count(), 0);
+        assert_eq!(report.count_by_category(IssueCategory::Conflict), 1);
+    }
+
     // -- diagnose (pre-init guard) --
 
     #[test]
@@ -2455,6 +3819,9 @@ mod tests {
             true,
             true,
             false,
+            &[],
+            false,
+            DEFAULT_SCAN_DEPTH,
         );
         assert!(result.is_ok());
     }
@@ -2493,6 +3860,7 @@ mod tests {
 
         let issues = check_library(
             &TomePaths::new(tome_home.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         assert!(
@@ -2502,6 +3870,7 @@ mod tests {
 
         let issues = check_library(
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         assert_eq!(
@@ -2640,7 +4009,14 @@ mod tests {
                     role: Some(DirectoryRole::Target),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Config::default()
@@ -2672,7 +4048,14 @@ mod tests {
                     role: Some(DirectoryRole::Target),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: true,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Config::default()
@@ -2746,7 +4129,9 @@ mod tests {
                 },
             ],
             config_issues: vec![DiagnosticIssue::config(IssueSeverity::Warning, "cfg")],
+            conflict_issues: Vec::new(),
             unowned_skills: Vec::new(),
+            library_notes: Vec::new(),
         };
         // 1 (lib) + 1 (a) + 2 (b) + 1 (cfg) = 5
         assert_eq!(report.total_issues(), 5);
@@ -2890,7 +4275,9 @@ mod tests {
             library_issues: Vec::new(),
             directory_issues: Vec::new(),
             config_issues: Vec::new(),
+            conflict_issues: Vec::new(),
             unowned_skills: Vec::new(),
+            library_notes: Vec::new(),
         };
         let json = serde_json::to_string(&report).unwrap();
         assert!(
@@ -2902,13 +4289,14 @@ mod tests {
     // -- OBS-06 / D-CAT-1: IssueCategory enum --
 
     #[test]
-    fn issue_category_all_len_4() {
+    fn issue_category_all_len_5() {
         // POLISH-04 ALL-array contract: every variant enumerated.
-        assert_eq!(IssueCategory::ALL.len(), 4);
+        assert_eq!(IssueCategory::ALL.len(), 5);
         assert!(IssueCategory::ALL.contains(&IssueCategory::Library));
         assert!(IssueCategory::ALL.contains(&IssueCategory::Directory));
         assert!(IssueCategory::ALL.contains(&IssueCategory::Config));
         assert!(IssueCategory::ALL.contains(&IssueCategory::ForeignSymlink));
+        assert!(IssueCategory::ALL.contains(&IssueCategory::Conflict));
     }
 
     #[test]
@@ -2931,6 +4319,10 @@ mod tests {
             serde_json::to_string(&IssueCategory::ForeignSymlink).unwrap(),
             "\"foreign_symlink\""
         );
+        assert_eq!(
+            serde_json::to_string(&IssueCategory::Conflict).unwrap(),
+            "\"conflict\""
+        );
     }
 
     // -- FIX-01 / D-REPAIR-1: RepairKind enum --
@@ -2938,11 +4330,12 @@ mod tests {
     #[test]
     fn repair_kind_all_len_matches_variants() {
         // POLISH-04 ALL-array contract: every variant enumerated.
-        assert_eq!(RepairKind::ALL.len(), 4);
+        assert_eq!(RepairKind::ALL.len(), 5);
         assert!(RepairKind::ALL.contains(&RepairKind::RemoveStaleManifestEntry));
         assert!(RepairKind::ALL.contains(&RepairKind::RemoveBrokenLibrarySymlink));
         assert!(RepairKind::ALL.contains(&RepairKind::RemoveStaleTargetSymlink));
         assert!(RepairKind::ALL.contains(&RepairKind::ConsolidateTargetRealDirToSymlink));
+        assert!(RepairKind::ALL.contains(&RepairKind::BackUpTargetCollision));
     }
 
     #[test]
@@ -2994,7 +4387,9 @@ mod tests {
                 override_applied: false,
             }],
             config_issues: vec![DiagnosticIssue::config(IssueSeverity::Warning, "cfg")],
+            conflict_issues: Vec::new(),
             unowned_skills: Vec::new(),
+            library_notes: Vec::new(),
         };
 
         let total = report.total_issues();
@@ -3038,7 +4433,9 @@ mod tests {
                 IssueSeverity::Warning,
                 "directory 'x' path does not exist",
             )],
+            conflict_issues: Vec::new(),
             unowned_skills: Vec::new(),
+            library_notes: Vec::new(),
         };
         assert!(report.total_issues() > 0, "fixture sanity");
         assert_eq!(report.auto_fixable_count(), 0);
@@ -3066,7 +4463,9 @@ mod tests {
                 override_applied: false,
             }],
             config_issues: Vec::new(),
+            conflict_issues: Vec::new(),
             unowned_skills: Vec::new(),
+            library_notes: Vec::new(),
         };
         assert_eq!(report.auto_fixable_count(), 2);
     }
@@ -3083,7 +4482,9 @@ mod tests {
             )],
             directory_issues: Vec::new(),
             config_issues: Vec::new(),
+            conflict_issues: Vec::new(),
             unowned_skills: Vec::new(),
+            library_notes: Vec::new(),
         };
         let line = render_summary_line(&report);
         assert!(line.contains("Found 1 issue(s)."), "{line}");
@@ -3118,7 +4519,9 @@ mod tests {
                 override_applied: false,
             }],
             config_issues: Vec::new(),
+            conflict_issues: Vec::new(),
             unowned_skills: Vec::new(),
+            library_notes: Vec::new(),
         };
         let line = render_summary_line(&report);
         // Only categories with non-zero auto-fixable counts appear.
@@ -3153,7 +4556,9 @@ mod tests {
                 override_applied: false,
             }],
             config_issues: Vec::new(),
+            conflict_issues: Vec::new(),
             unowned_skills: Vec::new(),
+            library_notes: Vec::new(),
         };
         let summary = render_summary_json(&report);
         assert_eq!(summary["total_issues"], 2);
@@ -3196,12 +4601,36 @@ mod tests {
 
     #[test]
     fn diagnostic_issue_kind_all_contains_foreign_symlink() {
-        // POLISH-04 ALL-array contract: ForeignSymlink is enumerated
-        // exactly once.
-        assert_eq!(DiagnosticIssueKind::ALL.len(), 1);
+        // POLISH-04 ALL-array contract: every variant enumerated.
+        assert_eq!(DiagnosticIssueKind::ALL.len(), 10);
         assert!(DiagnosticIssueKind::ALL.contains(&DiagnosticIssueKind::ForeignSymlink));
     }
 
+    #[test]
+    fn diagnostic_issue_kind_serializes_snake_case() {
+        // `tome doctor --json` consumers filter the flat `issues` array
+        // on these strings. ForeignSymlink keeps its pre-existing form.
+        let names: Vec<String> = DiagnosticIssueKind::ALL
+            .iter()
+            .map(|k| serde_json::to_string(k).unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "\"ForeignSymlink\"",
+                "\"broken_library_symlink\"",
+                "\"stale_target_symlink\"",
+                "\"missing_source\"",
+                "\"missing_target_dir\"",
+                "\"symlink_cycle\"",
+                "\"missing_requirement\"",
+                "\"missing_frontmatter_field\"",
+                "\"frontmatter_name_mismatch\"",
+                "\"duplicate_distribution_dir\"",
+            ]
+        );
+    }
+
     #[test]
     fn foreign_symlink_renders_as_warning_severity() {
         // D-DIST-2: the ForeignSymlink variant always emits as Warning
@@ -3233,7 +4662,9 @@ mod tests {
                 override_applied: false,
             }],
             config_issues: Vec::new(),
+            conflict_issues: Vec::new(),
             unowned_skills: Vec::new(),
+            library_notes: Vec::new(),
         };
         assert_eq!(report.total_issues(), 1);
     }
@@ -3272,7 +4703,14 @@ mod tests {
         std::fs::create_dir_all(&foreign_target).unwrap();
         std::os::unix::fs::symlink(&foreign_target, dist.join("foo")).unwrap();
 
-        let issues = super::check_distribution_dir("test", &dist, &library).unwrap();
+        let issues = super::check_distribution_dir(
+            "test",
+            &dist,
+            &library,
+            &manifest::Manifest::default(),
+            1,
+        )
+        .unwrap();
         let foreign: Vec<_> = issues
             .iter()
             .filter(|i| i.kind == Some(DiagnosticIssueKind::ForeignSymlink))
@@ -3503,6 +4941,7 @@ mod tests {
 
         let issues = check_library(
             &TomePaths::new(lib.path().to_path_buf(), lib.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         let with_id: Vec<_> = issues.iter().filter(|i| i.id().is_some()).collect();
@@ -3519,6 +4958,7 @@ mod tests {
         unix_fs::symlink("/nonexistent/target", lib.path().join("legacy")).unwrap();
         let issues = check_library(
             &TomePaths::new(lib.path().to_path_buf(), lib.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         let with_id: Vec<_> = issues
@@ -3541,6 +4981,7 @@ mod tests {
 
         let issues = check_library(
             &TomePaths::new(tome_home.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
+            Config::default().skill_file_names(),
         )
         .unwrap();
         let matched: Vec<_> = issues
@@ -3557,7 +4998,14 @@ mod tests {
         let stale_target = lib.path().join("deleted-skill");
         unix_fs::symlink(&stale_target, target.path().join("skill-link")).unwrap();
 
-        let issues = check_distribution_dir("claude", target.path(), lib.path()).unwrap();
+        let issues = check_distribution_dir(
+            "claude",
+            target.path(),
+            lib.path(),
+            &manifest::Manifest::default(),
+            1,
+        )
+        .unwrap();
         let with_id: Vec<_> = issues
             .iter()
             .filter(|i| {
@@ -3575,7 +5023,14 @@ mod tests {
         let library = TempDir::new().unwrap();
         let target = TempDir::new().unwrap();
         make_library_and_target_skill(library.path(), target.path(), "diverged", true);
-        let issues = check_distribution_dir("codex", target.path(), library.path()).unwrap();
+        let issues = check_distribution_dir(
+            "codex",
+            target.path(),
+            library.path(),
+            &manifest::Manifest::default(),
+            1,
+        )
+        .unwrap();
         let matched: Vec<_> = issues
             .iter()
             .filter(|i| matches!(i.id(), Some(FindingId::DivergingTarget { directory, .. }) if directory.as_str() == "codex"))
@@ -0,0 +1,181 @@
+//! `tome edit <skill>` — open a skill's SKILL.md in the user's editor.
+//!
+//! The skill is resolved through discovery (see [`crate::info::find_skill`]),
+//! so the file opened is the real source file, never the library copy that
+//! the next sync would overwrite. The editor is `$EDITOR`, then `$VISUAL`,
+//! then the platform opener (`open` / `xdg-open`). It is spawned directly
+//! with the path as its own argument — no shell is involved, so neither the
+//! path nor the skill name can inject commands.
+//!
+//! `tome config edit` reuses the same launcher for `tome.toml`, but needs
+//! `$EDITOR`/`$VISUAL`: the platform opener returns before the file is
+//! saved, so there would be nothing to validate yet.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result};
+
+use crate::config::Config;
+use crate::paths::TomePaths;
+
+/// The program and leading arguments to launch, e.g. `code --wait`.
+#[derive(Debug, PartialEq, Eq)]
+struct Editor {
+    program: String,
+    args: Vec<String>,
+}
+
+impl Editor {
+    /// Split an `$EDITOR`-style value on whitespace. Editors whose path
+    /// contains spaces need a wrapper script, as with git's `core.editor`
+    /// when run without a shell.
+    fn parse(value: &str) -> Option<Self> {
+        let mut words = value.split_whitespace().map(str::to_string);
+        let program = words.next()?;
+        Some(Self {
+            program,
+            args: words.collect(),
+        })
+    }
+}
+
+/// `$EDITOR`, then `$VISUAL`. Unset and blank values are skipped.
+fn editor_from_env(lookup: impl Fn(&str) -> Option<String>) -> Option<Editor> {
+    ["EDITOR", "VISUAL"]
+        .into_iter()
+        .find_map(|var| lookup(var).as_deref().and_then(Editor::parse))
+}
+
+/// The system "open with default application" command, as used by
+/// `tome browse`.
+fn platform_opener() -> Editor {
+    let program = if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+    Editor {
+        program: program.to_string(),
+        args: Vec::new(),
+    }
+}
+
+/// Path to the skill file (`SKILL.md` unless `skill_file_names` says
+/// otherwise) with symlinks resolved, so editors that replace the file on
+/// save write to the source rather than breaking a link.
+fn skill_md_path(skill_dir: &Path, skill_file: &str) -> PathBuf {
+    // A `cursor-rules` skill is the rule file itself.
+    let path = if skill_dir.is_file() {
+        skill_dir.to_path_buf()
+    } else {
+        skill_dir.join(skill_file)
+    };
+    std::fs::canonicalize(&path).unwrap_or(path)
+}
+
+/// Open `name`'s SKILL.md. Without `$EDITOR`/`$VISUAL` the path is printed
+/// first, then handed to the platform opener when stdin is a terminal, so
+/// a headless or scripted run still gets the path.
+pub(crate) fn run(config: &Config, paths: &TomePaths, name: &str, no_input: bool) -> Result<()> {
+    let skill = crate::info::find_skill(config, paths, name)?;
+    let path = skill_md_path(&skill.path, &skill.skill_file);
+
+    if let Some(provenance) = skill.origin.provenance() {
+        eprintln!(
+            "note: '{name}' is managed by {}; edits are overwritten on the next update \
+             (run `tome fork {name} --to <directory>` to keep them)",
+            provenance.registry_id
+        );
+    }
+
+    let editor = match editor_from_env(|var| std::env::var(var).ok()) {
+        Some(editor) => editor,
+        None => {
+            println!("{}", path.display());
+            if no_input || !std::io::IsTerminal::is_terminal(&std::io::stdin()) {
+                return Ok(());
+            }
+            platform_opener()
+        }
+    };
+
+    launch(&editor, &path)
+}
+
+/// Open the config at `path` in `$EDITOR`/`$VISUAL` and wait for it to
+/// exit. A missing config is created with defaults first, so there is
+/// something to edit.
+pub(crate) fn open_config(path: &Path) -> Result<()> {
+    if !path.exists() {
+        Config::default().save(path)?;
+        eprintln!("note: created {} with defaults", path.display());
+    }
+    let editor = editor_from_env(|var| std::env::var(var).ok()).with_context(|| {
+        format!(
+            "set $EDITOR or $VISUAL to edit the config (it is at {})",
+            path.display()
+        )
+    })?;
+    launch(&editor, path)
+}
+
+/// Run `editor` on `path`, passed as its own argument, and wait for it.
+fn launch(editor: &Editor, path: &Path) -> Result<()> {
+    let status = Command::new(&editor.program)
+        .args(&editor.args)
+        .arg(path)
+        .status()
+        .with_context(|| format!("failed to launch editor '{}'", editor.program))?;
+    anyhow::ensure!(
+        status.success(),
+        "editor '{}' exited with {status} for {}",
+        editor.program,
+        path.display()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: Vec<(String, String)> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |key| vars.iter().find(|(k, _)| k == key).map(|(_, v)| v.clone())
+    }
+
+    #[test]
+    fn editor_prefers_editor_then_visual() {
+        let editor = editor_from_env(env(&[("EDITOR", "vim"), ("VISUAL", "code")])).unwrap();
+        assert_eq!(editor.program, "vim");
+        let editor = editor_from_env(env(&[("EDITOR", "  "), ("VISUAL", "code")])).unwrap();
+        assert_eq!(editor.program, "code");
+        assert!(editor_from_env(env(&[])).is_none());
+    }
+
+    #[test]
+    fn editor_splits_program_from_arguments() {
+        let editor = Editor::parse("code --wait --new-window").unwrap();
+        assert_eq!(editor.program, "code");
+        assert_eq!(editor.args, vec!["--wait", "--new-window"]);
+    }
+
+    #[test]
+    fn skill_md_path_follows_symlinked_skill_dir() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let real = tmp.path().join("real");
+        std::fs::create_dir(&real).unwrap();
+        std::fs::write(real.join("SKILL.md"), "---\nname: real\n---\n").unwrap();
+        let link = tmp.path().join("link");
+        std::os::unix::fs::symlink(&real, &link).unwrap();
+
+        assert_eq!(
+            skill_md_path(&link, "SKILL.md"),
+            std::fs::canonicalize(real.join("SKILL.md")).unwrap()
+        );
+    }
+}
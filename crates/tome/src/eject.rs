@@ -8,7 +8,7 @@ use anyhow::{Context, Result};
 use console::style;
 use std::path::PathBuf;
 
-use crate::config::{Config, DirectoryName};
+use crate::config::{Config, DirectoryConfig, DirectoryName};
 use crate::paths::TomePaths;
 
 /// Plan describing what eject will remove.
@@ -27,10 +27,20 @@ pub(crate) struct TargetEjectEntry {
 /// `pub` (CORE-01 / D-GUI-08): the GUI's eject preview calls this directly to
 /// render the plan in a confirm dialog, mirroring `remove::plan`.
 pub fn plan(config: &Config, paths: &TomePaths) -> Result<EjectPlan> {
+    plan_for(config.distribution_dirs(), paths)
+}
+
+/// Build a plan covering only `dirs`. Shared with `tome prune`, which
+/// restricts it to directories disabled on this machine. Directories with
+/// a link ledger (`link_marker`) only contribute the links it lists.
+pub(crate) fn plan_for<'a>(
+    dirs: impl Iterator<Item = (&'a DirectoryName, &'a DirectoryConfig)>,
+    paths: &TomePaths,
+) -> Result<EjectPlan> {
     let mut targets = Vec::new();
     let mut total = 0;
 
-    for (dir_name, dir_config) in config.distribution_dirs() {
+    for (dir_name, dir_config) in dirs {
         let skills_dir = &dir_config.path;
         if !skills_dir.is_dir() {
             continue;
@@ -44,6 +54,7 @@ pub fn plan(config: &Config, paths: &TomePaths) -> Result<EjectPlan> {
         // stderr on unrelated broken symlinks in the target dir. Contrast
         // with SAFE-03 (relocate.rs provenance recording), where a read_link
         // failure means silent data loss and deserves a warning.
+        let ledger = crate::link_marker::load(skills_dir)?;
         let mut symlinks = Vec::new();
         for entry in std::fs::read_dir(skills_dir)
             .with_context(|| format!("failed to read {}", skills_dir.display()))?
@@ -51,6 +62,10 @@ pub fn plan(config: &Config, paths: &TomePaths) -> Result<EjectPlan> {
             let entry = entry?;
             let path = entry.path();
             if path.is_symlink()
+                && crate::link_marker::is_marked(
+                    ledger.as_ref(),
+                    &entry.file_name().to_string_lossy(),
+                )
                 && let Ok(target) = std::fs::read_link(&path)
             {
                 let resolved = crate::paths::resolve_symlink_target(&path, &target);
@@ -115,7 +130,9 @@ pub(crate) fn execute(plan: &EjectPlan, dry_run: bool) -> Result<usize> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType};
+    use crate::config::{
+        Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, NameTransform,
+    };
     use std::collections::BTreeMap;
     use std::os::unix::fs as unix_fs;
     use std::path::PathBuf;
@@ -136,7 +153,14 @@ mod tests {
                 git_ref: None,
 
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
         Config {
@@ -0,0 +1,187 @@
+//! `tome export` — bundle the library into a self-contained `.tar.gz`.
+//!
+//! Each library skill is written under `skills/<name>/` with symlinks
+//! dereferenced, so the archive carries real files even for entries that
+//! point elsewhere. A `tome-export.json` at the archive root records every
+//! skill and the directory it was consolidated from.
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use serde::{Deserialize, Serialize};
+
+use crate::manifest;
+use crate::paths::{BACKUP_SUFFIX, TomePaths, collapse_home};
+
+/// Archive-root file describing the export.
+pub(crate) const EXPORT_MANIFEST: &str = "tome-export.json";
+
+/// Contents of [`EXPORT_MANIFEST`].
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ExportManifest {
+    pub(crate) tome_version: String,
+    pub(crate) skills: Vec<ExportedSkill>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ExportedSkill {
+    pub(crate) name: String,
+    /// Directory the skill was consolidated from; `None` for unowned skills.
+    pub(crate) source: Option<String>,
+}
+
+/// Library entries to export, sorted by name: directories (following
+/// symlinks), skipping hidden entries and `--adopt` backups.
+fn library_skills(library_dir: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let mut skills = Vec::new();
+    let entries = std::fs::read_dir(library_dir)
+        .with_context(|| format!("failed to read library {}", library_dir.display()))?;
+    for entry in entries {
+        let entry =
+            entry.with_context(|| format!("failed to read entry in {}", library_dir.display()))?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name.starts_with('.') || name.ends_with(BACKUP_SUFFIX) {
+            continue;
+        }
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let real = std::fs::canonicalize(&path)
+            .with_context(|| format!("failed to resolve {}", path.display()))?;
+        skills.push((name, real));
+    }
+    skills.sort();
+    Ok(skills)
+}
+
+/// Write the library to `output` as a gzipped tarball and return what was
+/// exported. Refuses to replace an existing `output` unless `force`; with
+/// `dry_run` only the manifest is computed.
+pub(crate) fn export(
+    paths: &TomePaths,
+    output: &Path,
+    force: bool,
+    dry_run: bool,
+) -> Result<ExportManifest> {
+    anyhow::ensure!(
+        force || !output.exists(),
+        "{} already exists (pass --force to overwrite)",
+        output.display()
+    );
+
+    let manifest = manifest::load(paths.config_dir())?;
+    let skills = library_skills(paths.library_dir())?;
+    let export_manifest = ExportManifest {
+        tome_version: env!("CARGO_PKG_VERSION").to_string(),
+        skills: skills
+            .iter()
+            .map(|(name, _)| ExportedSkill {
+                name: name.clone(),
+                source: manifest
+                    .get(name)
+                    .and_then(|entry| entry.source_name())
+                    .map(|source| source.to_string()),
+            })
+            .collect(),
+    };
+    if dry_run {
+        return Ok(export_manifest);
+    }
+
+    // Build next to the destination and rename into place, so a failed
+    // export never leaves a truncated archive at `output`.
+    let file_name = output
+        .file_name()
+        .with_context(|| format!("invalid output path {}", output.display()))?;
+    let tmp_path = output.with_file_name(format!(".{}.tmp", file_name.to_string_lossy()));
+    let written = write_archive(&tmp_path, &skills, &export_manifest).and_then(|()| {
+        std::fs::rename(&tmp_path, output).with_context(|| {
+            format!(
+                "failed to rename {} -> {}",
+                tmp_path.display(),
+                output.display()
+            )
+        })
+    });
+    if written.is_err() {
+        let _ = std::fs::remove_file(&tmp_path);
+    }
+    written?;
+    Ok(export_manifest)
+}
+
+fn write_archive(
+    path: &Path,
+    skills: &[(String, PathBuf)],
+    export_manifest: &ExportManifest,
+) -> Result<()> {
+    let file =
+        File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
+    let mut archive = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+    // Store what symlinks point at, not the links, so the archive is
+    // self-contained.
+    archive.follow_symlinks(true);
+
+    let json = serde_json::to_vec_pretty(export_manifest)?;
+    let mut header = tar::Header::new_gnu();
+    header.set_size(json.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    );
+    header.set_cksum();
+    archive
+        .append_data(&mut header, EXPORT_MANIFEST, json.as_slice())
+        .context("failed to write export manifest")?;
+
+    for (name, dir) in skills {
+        archive
+            .append_dir_all(format!("skills/{name}"), dir)
+            .with_context(|| format!("failed to archive skill '{name}'"))?;
+    }
+    archive
+        .into_inner()
+        .and_then(|gz| gz.finish())
+        .with_context(|| format!("failed to finish {}", path.display()))?;
+    Ok(())
+}
+
+/// One-line summary for the terminal.
+pub(crate) fn summary(export_manifest: &ExportManifest, output: &Path, dry_run: bool) -> String {
+    let verb = if dry_run { "Would export" } else { "Exported" };
+    format!(
+        "{verb} {} skill(s) to {}",
+        export_manifest.skills.len(),
+        collapse_home(output)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn library_skills_skips_hidden_backups_and_files_and_follows_symlinks() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let library = tmp.path().join("library");
+        let elsewhere = tmp.path().join("elsewhere/linked");
+        std::fs::create_dir_all(library.join("plain")).unwrap();
+        std::fs::create_dir_all(library.join(".tome-cache")).unwrap();
+        std::fs::create_dir_all(library.join("old.tome-bak")).unwrap();
+        std::fs::write(library.join(".tome-manifest.json"), "{}").unwrap();
+        std::fs::create_dir_all(&elsewhere).unwrap();
+        std::os::unix::fs::symlink(&elsewhere, library.join("linked")).unwrap();
+
+        let skills = library_skills(&library).unwrap();
+        let names: Vec<&str> = skills.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["linked", "plain"]);
+        assert_eq!(skills[0].1, std::fs::canonicalize(&elsewhere).unwrap());
+    }
+}
@@ -210,6 +210,29 @@ pub(crate) fn read_head_sha(repo_dir: &Path) -> Result<String> {
     git_stdout(repo_dir, &["rev-parse", "HEAD"])
 }
 
+/// Paths under `dir` that git's index records as symlinks (mode `120000`),
+/// relative to `dir`.
+///
+/// Returns `None` when `dir` is not inside a git work tree or git cannot be
+/// run. Reads only the index, so it reflects the last `git add`, not the
+/// current state of the working tree.
+pub(crate) fn indexed_symlinks(dir: &Path) -> Option<Vec<PathBuf>> {
+    let output = git_command(dir, &["ls-files", "--stage", "-z"]).ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Some(
+        stdout
+            .split('\0')
+            .filter_map(|record| {
+                let (meta, path) = record.split_once('\t')?;
+                meta.starts_with("120000 ").then(|| PathBuf::from(path))
+            })
+            .collect(),
+    )
+}
+
 /// Compute the effective discovery path for a git directory.
 ///
 /// If `subdir` is `Some`, returns `clone_path/<subdir>`. Otherwise returns `clone_path` unchanged.
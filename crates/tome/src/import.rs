@@ -0,0 +1,309 @@
+//! `tome import` — unpack a `tome export` archive into a directory.
+//!
+//! Only `skills/<name>/…` members holding regular files and directories are
+//! extracted; symlinks, hard links and any member whose path is absolute or
+//! climbs out of the archive root are refused, so a crafted archive cannot
+//! write outside `dest`. Members are unpacked into a staging directory
+//! inside `dest` first, and a skill is moved into place only when it carries
+//! a `SKILL.md` and does not collide with an existing entry.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{Context, Result};
+use flate2::read::GzDecoder;
+
+use crate::config::{
+    Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, NameTransform,
+};
+use crate::discover::SkillName;
+use crate::export::{EXPORT_MANIFEST, ExportManifest};
+use crate::paths::collapse_home;
+
+/// Staging directory created inside `dest` while extracting.
+const STAGING_DIR: &str = ".tome-import";
+
+/// What an import did (or, with `dry_run`, would do).
+#[derive(Debug, Default)]
+pub(crate) struct ImportReport {
+    pub(crate) imported: Vec<String>,
+    /// Rejected members and skills, one human-readable line each.
+    pub(crate) warnings: Vec<String>,
+    pub(crate) registered: Option<DirectoryName>,
+}
+
+/// Fold `.` and `..` in an archive member path. `None` when the path is
+/// absolute or climbs above the archive root.
+fn normalize_member_path(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => normalized.push(part),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    Some(normalized)
+}
+
+/// Split a normalized `skills/<name>/<rest>` path into `(name, rest)`;
+/// `rest` is empty for the skill directory itself.
+fn skill_member(path: &Path) -> Option<(String, PathBuf)> {
+    let mut components = path.components();
+    if components.next()? != Component::Normal("skills".as_ref()) {
+        return None;
+    }
+    let name = components.next()?.as_os_str().to_str()?.to_string();
+    Some((name, components.as_path().to_path_buf()))
+}
+
+/// Extract `archive` into `dest` and, with `register`, add `dest` to the
+/// config at `config_path` as a `directory`-type source named `register`.
+pub(crate) fn import(
+    archive: &Path,
+    dest: &Path,
+    register: Option<&str>,
+    config_path: &Path,
+    dry_run: bool,
+) -> Result<ImportReport> {
+    anyhow::ensure!(
+        !dest.exists() || dest.is_dir(),
+        "{} exists and is not a directory",
+        dest.display()
+    );
+
+    // Check the registration up front so a bad name fails before anything
+    // is written.
+    let register = match register {
+        Some(name) => {
+            let name = DirectoryName::new(name)?;
+            let loaded = Config::load(config_path)?;
+            anyhow::ensure!(
+                !loaded.directories.contains_key(&name),
+                "directory '{name}' already exists in config"
+            );
+            Some(name)
+        }
+        None => None,
+    };
+
+    let mut report = ImportReport::default();
+    let staging = dest.join(STAGING_DIR);
+    if !dry_run {
+        std::fs::create_dir_all(dest)
+            .with_context(|| format!("failed to create {}", dest.display()))?;
+        if staging.exists() {
+            std::fs::remove_dir_all(&staging)
+                .with_context(|| format!("failed to clear {}", staging.display()))?;
+        }
+    }
+    let extracted = extract(
+        archive,
+        (!dry_run).then_some(staging.as_path()),
+        &mut report,
+    );
+    let accepted = extracted.and_then(|has_skill_md| {
+        let mut accepted = Vec::new();
+        for (name, has_skill_md) in has_skill_md {
+            let target = dest.join(&name);
+            if !has_skill_md {
+                report
+                    .warnings
+                    .push(format!("skipping skill '{name}': no SKILL.md"));
+            } else if target.exists() {
+                report.warnings.push(format!(
+                    "skipping skill '{name}': {} already exists",
+                    collapse_home(&target)
+                ));
+            } else {
+                if !dry_run {
+                    let staged = staging.join("skills").join(&name);
+                    std::fs::rename(&staged, &target).with_context(|| {
+                        format!(
+                            "failed to move {} -> {}",
+                            staged.display(),
+                            target.display()
+                        )
+                    })?;
+                }
+                accepted.push(name);
+            }
+        }
+        Ok(accepted)
+    });
+    if !dry_run {
+        let _ = std::fs::remove_dir_all(&staging);
+    }
+    report.imported = accepted?;
+
+    if let Some(name) = register {
+        // Only the new table is added; the rest of tome.toml stays as the
+        // user wrote it.
+        if !dry_run {
+            let path = std::fs::canonicalize(dest)
+                .with_context(|| format!("failed to resolve {}", dest.display()))?;
+            crate::config::append_directory(
+                config_path,
+                &name,
+                &DirectoryConfig {
+                    path,
+                    directory_type: DirectoryType::Directory,
+                    role: Some(DirectoryRole::Source),
+                    git_ref: None,
+                    subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
+                    override_applied: false,
+                    scan_origin: Default::default(),
+                },
+            )?;
+        }
+        report.registered = Some(name);
+    }
+    Ok(report)
+}
+
+/// Walk the archive, unpacking accepted members under `staging` (nothing is
+/// written when `None`). Returns every skill seen and whether it has a
+/// `SKILL.md`.
+fn extract(
+    archive: &Path,
+    staging: Option<&Path>,
+    report: &mut ImportReport,
+) -> Result<BTreeMap<String, bool>> {
+    let file =
+        File::open(archive).with_context(|| format!("failed to open {}", archive.display()))?;
+    let mut tar = tar::Archive::new(GzDecoder::new(file));
+    let mut skills: BTreeMap<String, bool> = BTreeMap::new();
+    let mut manifest: Option<ExportManifest> = None;
+
+    let entries = tar
+        .entries()
+        .with_context(|| format!("failed to read {}", archive.display()))?;
+    for entry in entries {
+        let mut entry =
+            entry.with_context(|| format!("failed to read entry in {}", archive.display()))?;
+        let raw_path = entry.path()?.into_owned();
+        let Some(path) = normalize_member_path(&raw_path) else {
+            report.warnings.push(format!(
+                "rejected '{}': path escapes the destination",
+                raw_path.display()
+            ));
+            continue;
+        };
+        let kind = entry.header().entry_type();
+
+        if path == Path::new(EXPORT_MANIFEST) && kind.is_file() {
+            let mut json = String::new();
+            entry
+                .read_to_string(&mut json)
+                .context("failed to read export manifest")?;
+            manifest = Some(serde_json::from_str(&json).context("invalid export manifest")?);
+            continue;
+        }
+        let Some((name, rest)) = skill_member(&path) else {
+            continue;
+        };
+        if !(kind.is_file() || kind.is_dir()) {
+            report.warnings.push(format!(
+                "rejected '{}': only regular files and directories are imported",
+                raw_path.display()
+            ));
+            continue;
+        }
+        if SkillName::new(name.as_str()).is_err() {
+            if !skills.contains_key(&name) {
+                report
+                    .warnings
+                    .push(format!("skipping skill '{name}': invalid skill name"));
+            }
+            continue;
+        }
+        let has_skill_md = skills.entry(name.clone()).or_default();
+        if rest.as_os_str().is_empty() && !kind.is_dir() {
+            continue;
+        }
+        if kind.is_file() && rest == Path::new("SKILL.md") {
+            *has_skill_md = true;
+        }
+
+        let Some(staging) = staging else {
+            continue;
+        };
+        let target = staging.join(&path);
+        if kind.is_dir() {
+            std::fs::create_dir_all(&target)
+                .with_context(|| format!("failed to create {}", target.display()))?;
+        } else {
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("failed to create {}", parent.display()))?;
+            }
+            entry
+                .unpack(&target)
+                .with_context(|| format!("failed to extract {}", raw_path.display()))?;
+        }
+    }
+
+    anyhow::ensure!(
+        manifest.is_some(),
+        "{} is not a tome export (no {EXPORT_MANIFEST})",
+        archive.display()
+    );
+    Ok(skills)
+}
+
+/// One-line summary for the terminal.
+pub(crate) fn summary(report: &ImportReport, dest: &Path, dry_run: bool) -> String {
+    let verb = if dry_run { "Would import" } else { "Imported" };
+    let mut line = format!(
+        "{verb} {} skill(s) into {}",
+        report.imported.len(),
+        collapse_home(dest)
+    );
+    if let Some(name) = &report.registered {
+        let verb = if dry_run { "register" } else { "registered" };
+        line.push_str(&format!(" and {verb} it as directory '{name}'"));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_member_path_rejects_escapes() {
+        assert_eq!(
+            normalize_member_path(Path::new("skills/a/./b/../SKILL.md")),
+            Some(PathBuf::from("skills/a/SKILL.md"))
+        );
+        assert_eq!(normalize_member_path(Path::new("skills/../../evil")), None);
+        assert_eq!(normalize_member_path(Path::new("/etc/passwd")), None);
+    }
+
+    #[test]
+    fn skill_member_splits_name_from_rest() {
+        assert_eq!(
+            skill_member(Path::new("skills/a/docs/x.md")),
+            Some(("a".to_string(), PathBuf::from("docs/x.md")))
+        );
+        assert_eq!(
+            skill_member(Path::new("skills/a")),
+            Some(("a".to_string(), PathBuf::new()))
+        );
+        assert_eq!(skill_member(Path::new("tome-export.json")), None);
+        assert_eq!(skill_member(Path::new("other/a/SKILL.md")), None);
+    }
+}
@@ -0,0 +1,289 @@
+//! `tome info <skill>` — full details for one discovered skill.
+//!
+//! Runs discovery (git directories from their existing cache, no fetch),
+//! finds the named skill, and reports where it comes from, whether the
+//! library holds a copy, and whether each distribution directory receives it.
+//! An unknown name fails with the closest discovered name as a suggestion.
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use console::style;
+
+use crate::config::{Config, DirectoryName};
+use crate::discover::{self, DiscoveredSkill};
+use crate::machine::MachinePrefs;
+use crate::paths::{TomePaths, collapse_home, symlink_points_to};
+use crate::progress::{CancelToken, NullSink};
+
+/// Everything `tome info` prints about one skill.
+#[derive(Debug)]
+pub(crate) struct SkillInfo {
+    pub(crate) skill: DiscoveredSkill,
+    /// `<library_dir>/<name>` when it exists, `None` before the first sync.
+    pub(crate) library_path: Option<PathBuf>,
+    /// Every distribution directory, in config order, with whether it
+    /// receives the skill.
+    pub(crate) targets: Vec<(DirectoryName, Reach)>,
+    pub(crate) tags: Vec<String>,
+}
+
+/// Whether one distribution directory receives the skill, checked in the
+/// order `tome sync` applies its filters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Reach {
+    /// The directory's `<name>` link points at the library copy.
+    Linked,
+    /// Nothing filters the skill out, but the link is missing or points
+    /// elsewhere (or the skill isn't in the library yet): the next sync
+    /// creates it.
+    Pending,
+    /// The whole directory is disabled in machine.toml.
+    DirectoryDisabled,
+    /// machine.toml disables the skill, globally or for this directory.
+    SkillDisabled,
+    /// The directory's `include`/`exclude` in tome.toml leaves it out.
+    Filtered,
+    /// The skill comes from this (synced) directory, so it isn't linked back.
+    OwnSource,
+}
+
+impl Reach {
+    fn label(self) -> &'static str {
+        match self {
+            Reach::Linked => "linked",
+            Reach::Pending => "not linked yet (run `tome sync`)",
+            Reach::DirectoryDisabled => "directory disabled on this machine",
+            Reach::SkillDisabled => "disabled in machine.toml",
+            Reach::Filtered => "left out by include/exclude",
+            Reach::OwnSource => "skipped (the skill comes from this directory)",
+        }
+    }
+}
+
+/// Look up `name` among the discovered skills.
+pub(crate) fn gather(
+    config: &Config,
+    paths: &TomePaths,
+    machine_prefs: &MachinePrefs,
+    name: &str,
+) -> Result<SkillInfo> {
+    let skill = find_skill(config, paths, name)?;
+
+    let library_path = paths.library_dir().join(name);
+    let library_path = library_path.is_dir().then_some(library_path);
+
+    let targets = config
+        .distribution_dirs()
+        .map(|(dir_name, dir_config)| {
+            let reach = if machine_prefs.is_directory_disabled(dir_name.as_str()) {
+                Reach::DirectoryDisabled
+            } else if !machine_prefs.is_skill_allowed(name, dir_name.as_str()) {
+                Reach::SkillDisabled
+            } else if !dir_config.includes_skill(name) {
+                Reach::Filtered
+            } else if skill.source_name == *dir_name {
+                Reach::OwnSource
+            } else if library_path
+                .as_ref()
+                .is_some_and(|lib| symlink_points_to(&dir_config.path.join(name), lib))
+            {
+                Reach::Linked
+            } else {
+                Reach::Pending
+            };
+            (dir_name.clone(), reach)
+        })
+        .collect();
+
+    let tags = skill.frontmatter.as_ref().map(tags).unwrap_or_default();
+    Ok(SkillInfo {
+        skill,
+        library_path,
+        targets,
+        tags,
+    })
+}
+
+/// Discover skills and return the one named `name`. An unknown name fails
+/// with the closest discovered name as a suggestion. Shared with `tome edit`.
+pub(crate) fn find_skill(
+    config: &Config,
+    paths: &TomePaths,
+    name: &str,
+) -> Result<DiscoveredSkill> {
+    let resolved =
+        crate::resolve_git_directories(config, paths, true, &NullSink, &CancelToken::new());
+    let mut warnings = Vec::new();
+    let skills = discover::discover_all(config, &resolved, &mut warnings)?;
+
+    if let Some(skill) = skills.iter().find(|s| s.name == *name) {
+        return Ok(skill.clone());
+    }
+    let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+    match closest_match(name, &names) {
+        Some(suggestion) => {
+            anyhow::bail!("skill '{name}' not found — did you mean '{suggestion}'?")
+        }
+        None => anyhow::bail!("skill '{name}' not found (run `tome list` to see all skills)"),
+    }
+}
+
+/// Tags from `metadata.tags` or a top-level `tags` key, given either as a
+/// YAML list or a comma-separated string.
+pub(crate) fn tags(frontmatter: &crate::skill::SkillFrontmatter) -> Vec<String> {
+    frontmatter.list_field("tags")
+}
+
+/// The candidate with the smallest edit distance to `name`, if it is close
+/// enough to plausibly be a typo: within [`suggestion_limit`], and not a
+/// rewrite of every character (so `ab` never suggests `xy`). `None` when no
+/// candidate is that close — callers then fall back to a plain "not found".
+pub(crate) fn closest_match<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let len = name.chars().count();
+    let limit = suggestion_limit(len);
+    candidates
+        .iter()
+        .map(|c| (edit_distance(name, c), *c))
+        .filter(|(d, c)| *d <= limit && *d < len.max(c.chars().count()))
+        .min()
+        .map(|(_, c)| c)
+}
+
+/// Largest edit distance still offered as a suggestion for a name of `len`
+/// chars: a third of the length, and at least 2.
+fn suggestion_limit(len: usize) -> usize {
+    (len / 3).max(2)
+}
+
+/// Levenshtein distance over chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = prev[j] + usize::from(ca != *cb);
+            cur[j + 1] = substitute.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+/// Render `info` for the terminal.
+pub(crate) fn render(info: &SkillInfo) -> String {
+    let skill = &info.skill;
+    let mut rows: Vec<(&str, String)> = vec![
+        ("Source", skill.source_name.to_string()),
+        ("Path", collapse_home(&skill.path)),
+    ];
+    if skill.skill_file != crate::skill::SKILL_FILE_NAME {
+        rows.push(("File", skill.skill_file.clone()));
+    }
+    rows.push((
+        "Origin",
+        if skill.origin.is_managed() {
+            "managed".to_string()
+        } else {
+            "local".to_string()
+        },
+    ));
+    if let Some(p) = skill.origin.provenance() {
+        rows.push(("Registry", p.registry_id.clone()));
+        if let Some(version) = &p.version {
+            rows.push(("Version", version.clone()));
+        }
+        if let Some(sha) = &p.git_commit_sha {
+            rows.push(("Commit", sha.chars().take(12).collect()));
+        }
+    }
+    rows.push((
+        "Library",
+        match &info.library_path {
+            Some(p) => collapse_home(p),
+            None => "not consolidated (run `tome sync`)".to_string(),
+        },
+    ));
+    rows.push((
+        "Targets",
+        if info.targets.is_empty() {
+            "none".to_string()
+        } else {
+            info.targets
+                .iter()
+                .map(|(dir_name, reach)| format!("{dir_name}: {}", reach.label()))
+                .collect::<Vec<_>>()
+                .join("\n")
+        },
+    ));
+    if let Some(description) = skill
+        .frontmatter
+        .as_ref()
+        .and_then(|fm| fm.description.as_deref())
+    {
+        rows.push(("Description", description.trim().to_string()));
+    }
+    if !info.tags.is_empty() {
+        rows.push(("Tags", info.tags.join(", ")));
+    }
+
+    let width = rows.iter().map(|(k, _)| k.len()).max().unwrap_or(0) + 1;
+    let mut out = format!("{}\n", style(skill.name.as_str()).bold());
+    // Multi-line values continue under the first line's value column.
+    let continuation = format!("\n{}", " ".repeat(width + 3));
+    for (key, value) in rows {
+        out.push_str(&format!(
+            "  {:<width$} {}\n",
+            format!("{key}:"),
+            value.replace('\n', &continuation)
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distance_counts_single_edits() {
+        assert_eq!(edit_distance("review", "review"), 0);
+        assert_eq!(edit_distance("reveiw", "review"), 2);
+        assert_eq!(edit_distance("revie", "review"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn closest_match_suggests_only_plausible_typos() {
+        let names = ["code-review", "commit", "deploy"];
+        assert_eq!(closest_match("code-reveiw", &names), Some("code-review"));
+        assert_eq!(closest_match("comit", &names), Some("commit"));
+        assert_eq!(closest_match("unrelated", &names), None);
+    }
+
+    #[test]
+    fn closest_match_respects_distance_cutoff() {
+        assert_eq!(suggestion_limit(3), 2);
+        assert_eq!(suggestion_limit(9), 3);
+        // Nine chars allow three edits, not four.
+        assert_eq!(
+            closest_match("abcdefghi", &["abcdefxyz"]),
+            Some("abcdefxyz")
+        );
+        assert_eq!(closest_match("abcdefghi", &["abcdewxyz"]), None);
+        // Short names: the floor of 2 never turns into a full rewrite.
+        assert_eq!(closest_match("ab", &["xy"]), None);
+        assert_eq!(closest_match("ab", &["abc"]), Some("abc"));
+        assert_eq!(closest_match("anything", &[]), None);
+    }
+
+    #[test]
+    fn tags_accepts_metadata_list_or_top_level_string() {
+        let (fm, _) =
+            crate::skill::parse("---\nname: a\nmetadata:\n  tags: [git, review]\n---\n").unwrap();
+        assert_eq!(tags(&fm), vec!["git", "review"]);
+        let (fm, _) = crate::skill::parse("---\nname: a\ntags: git, review\n---\n").unwrap();
+        assert_eq!(tags(&fm), vec!["git", "review"]);
+    }
+}
@@ -55,7 +55,9 @@ pub(crate) mod change_cause;
 pub(crate) mod cleanup;
 pub mod cli;
 pub mod config;
+pub(crate) mod diff;
 pub(crate) mod discover;
+pub(crate) mod discovery_cache;
 pub(crate) mod distribute;
 // `doctor` is `pub` since Phase 26 plan 26-05: the GUI Health view's two
 // Tauri commands (`get_doctor_report` / `doctor_repair_one`) call into
@@ -69,10 +71,15 @@ pub mod doctor;
 // coarse `ErrorCode`. Re-exported as `tome::DomainErrorKind` below. The CLI
 // never names this type (the domain stays `anyhow::Result`); it is attached
 // at GUI-relevant failure sites via `.context()` and only read at the IPC edge.
+pub(crate) mod edit;
 pub(crate) mod eject;
 pub mod errors;
+pub(crate) mod export;
 pub(crate) mod git;
+pub(crate) mod import;
+pub(crate) mod info;
 pub(crate) mod library;
+pub(crate) mod link_marker;
 pub(crate) mod lint;
 // `list` is `pub` so `tome-desktop` can call `list::collect` directly from
 // the `list_skills` Tauri command (plan 26-02 Task 2 / VIEW-02). The
@@ -105,6 +112,8 @@ pub mod manifest;
 pub mod marketplace;
 pub(crate) mod migration_v010;
 pub(crate) mod paths;
+pub(crate) mod plan_dump;
+pub(crate) mod prune;
 // `progress` is `pub` because its trait + event vocabulary
 // (`ProgressSink`/`ProgressEvent`/`SyncStage`/`CancelToken`) is the domain
 // half of the "structure at the edge" pattern (D-09/D-11): the GUI's
@@ -134,19 +143,24 @@ pub(crate) mod summary;
 // boundary (Phase 27 plan 27-05 / SYNC-05). The CLI consumes
 // `tome::sync()` directly and does not need the outcome wrapping.
 pub mod sync_outcome;
+pub(crate) mod sync_stats;
 pub mod tracing_init;
+pub(crate) mod uninstall;
 // `update` is `pub` so `tome-desktop` can call `update::diff` and consume
 // `UpdateDiff`/`SkillChange` for the SYNC-02 lockfile-diff projection (plan
 // 27-02). The CLI's `present_changes` interactive triage stays in-crate
 // (not exported); the GUI substitutes its own visual triage flow.
 pub mod update;
 pub(crate) mod validation;
+pub(crate) mod watch;
 pub(crate) mod wizard;
 
 use std::collections::{BTreeMap, HashSet};
 use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 use std::process::Command as GitCommand;
+use std::sync::{Mutex, PoisonError};
+use std::time::Instant;
 
 use anyhow::{Context, Result};
 use console::style;
@@ -168,11 +182,14 @@ use progress::{CancelToken, NullSink, ProgressEvent, ProgressSink, SyncStage};
 /// directly via the crate path.
 pub use manifest::hash_directory;
 
-/// HARD-04: surface lint-failure and migrate-failure typed errors so the
-/// thin `main.rs` binary can downcast and map them to exit code 1 without
-/// the library calling `process::exit` itself.
+/// HARD-04: surface lint-failure, migrate-failure, doctor-issue,
+/// status-check, and config-validation typed errors so the thin `main.rs` binary can downcast and map them to exit
+/// code 1 without the library calling `process::exit` itself.
+pub use config::ConfigInvalid;
+pub use doctor::DoctorIssuesFound;
 pub use lint::LintFailed;
 pub use migration_v010::MigrationPartialOrFailed;
+pub use status::StatusCheckFailed;
 
 /// CORE-05 / D-14: the typed `DomainErrorKind` sentinels (and the transparent
 /// `DomainTagged` wrapper that carries one through the anyhow cause chain) the
@@ -223,7 +240,9 @@ pub use discover::SkillName;
 /// the Skills view already pattern-matches. The `discover_all` re-export
 /// lets `get_lockfile_diff` build a prospective lockfile from the current
 /// disk state without depending on the `pub(crate)` `discover` module path.
-pub use discover::{SkillOrigin, SkillProvenance, discover_all};
+/// `discover_all_json` is the stable `{name, path, source}` wire shape for
+/// scripts and IPC consumers that only need to know what was found.
+pub use discover::{SkillOrigin, SkillProvenance, discover_all, discover_all_json};
 
 /// Phase 27 plan 27-02 (SYNC-02) — `tome-desktop`'s SYNC-02 triage projection
 /// surfaces lockfile content hashes as boundary strings. Re-exporting
@@ -263,15 +282,22 @@ pub use sync_outcome::{
 ///   [`CleanupResult`] for read-only access.
 /// - [`removed_from_targets`](Self::removed_from_targets) — total stale
 ///   distribution symlinks pruned across all targets.
+/// - [`pruned_from_disabled`](Self::pruned_from_disabled) — links removed
+///   from disabled directories by `--prune-disabled`; 0 without it.
 /// - [`reconcile`](Self::reconcile) — `None` when sync ran without a
 ///   `MarketplaceAdapter` (no `claude-plugins` directory configured).
 ///   `Some(_)` when reconcile ran; counts may all be zero on a clean
 ///   match. See [`reconcile::ReconcileReport`] for the inner shape.
+#[derive(Default)]
 pub struct SyncReport {
     pub consolidate: ConsolidateResult,
     pub distributions: Vec<DistributeResult>,
     pub cleanup: CleanupResult,
     pub removed_from_targets: usize,
+    /// `removed_from_targets` broken down by distribution directory. A dry
+    /// run shows each next to that directory's link count.
+    pub removed_by_target: BTreeMap<DirectoryName, usize>,
+    pub pruned_from_disabled: usize,
     /// Phase 18 OBS-05: per-classification reconcile counts surfaced in
     /// the final summary block. `None` when the sync didn't invoke a
     /// reconcile pass (no Claude adapter configured).
@@ -291,6 +317,24 @@ fn spinner(msg: &str) -> ProgressBar {
     sp
 }
 
+/// Stage totals above this many units get a determinate progress bar in
+/// place of the spinner. Smaller stages finish before a bar would help.
+const PROGRESS_BAR_THRESHOLD: usize = 20;
+
+/// Create a determinate progress bar in the spinner's style.
+fn progress_bar(msg: &str, total: usize) -> ProgressBar {
+    let bar = ProgressBar::new(total as u64);
+    bar.set_style(
+        ProgressStyle::default_bar()
+            .template("{spinner:.cyan} {msg} [{bar:30.cyan/blue}] {pos}/{len}")
+            .expect("valid template")
+            .progress_chars("=> "),
+    );
+    bar.set_message(msg.to_string());
+    bar.enable_steady_tick(std::time::Duration::from_millis(80));
+    bar
+}
+
 /// The CLI [`ProgressSink`] (D-11): re-homes the `spinner()` / `finish_and_clear()`
 /// presentation that used to be inlined in `sync()`.
 ///
@@ -315,7 +359,9 @@ fn spinner(msg: &str) -> ProgressBar {
 /// `emit(&self, …)` takes `&self` (the trait is `Send + Sync` so a GUI sink can
 /// hold an `AppHandle`). The "currently-active spinner" therefore lives behind a
 /// `Mutex<Option<ProgressBar>>`: `SyncStageStarted` installs a fresh spinner,
-/// `SyncStageFinished` takes it back out and `finish_and_clear()`s it.
+/// `SyncStageFinished` takes it back out and `finish_and_clear()`s it. A stage
+/// reporting more than [`PROGRESS_BAR_THRESHOLD`] units swaps its spinner for
+/// a determinate bar in the same slot.
 struct IndicatifSink {
     current: std::sync::Mutex<Option<ProgressBar>>,
 }
@@ -374,9 +420,20 @@ impl ProgressSink for IndicatifSink {
                          // captured-output contract (no per-skill chrome in
                          // `insta`/`assert_cmd` snapshots) holds byte-for-byte.
             } => {
-                if let Some(sp) = current.as_ref()
-                    && total > 0
-                {
+                let Some(sp) = current.as_ref() else {
+                    return;
+                };
+                if total > PROGRESS_BAR_THRESHOLD {
+                    // Swap the stage's spinner for a bar on its first
+                    // progress event; later events just move it.
+                    if sp.length() != Some(total as u64) {
+                        sp.finish_and_clear();
+                        *current = Some(progress_bar(Self::stage_message(stage), total));
+                    }
+                    if let Some(bar) = current.as_ref() {
+                        bar.set_position(done as u64);
+                    }
+                } else if total > 0 {
                     sp.set_message(format!("{} ({done}/{total})", Self::stage_message(stage)));
                 }
             }
@@ -459,7 +516,21 @@ fn resolve_config_path(
 }
 
 /// Run the CLI with parsed arguments.
+///
+/// With `--warnings-as-errors`, a command that succeeded but emitted any
+/// warning (counted by [`tracing_init::warning_count`]) fails afterwards.
 pub fn run(cli: Cli) -> Result<()> {
+    let warnings_as_errors = cli.warnings_as_errors;
+    dispatch(cli)?;
+    let warnings = tracing_init::warning_count();
+    anyhow::ensure!(
+        !warnings_as_errors || warnings == 0,
+        "{warnings} warning(s) emitted (--warnings-as-errors)"
+    );
+    Ok(())
+}
+
+fn dispatch(cli: Cli) -> Result<()> {
     if matches!(cli.command, Command::Version) {
         println!("tome {}", env!("CARGO_PKG_VERSION"));
         return Ok(());
@@ -467,8 +538,8 @@ pub fn run(cli: Cli) -> Result<()> {
 
     let effective_config = resolve_config_path(cli.tome_home.as_deref(), cli.config.as_deref())?;
 
-    if matches!(cli.command, Command::Init) {
-        if let Err(e) = Config::load_or_default(effective_config.as_deref()) {
+    if let Command::Init { yes, .. } = cli.command {
+        if !yes && let Err(e) = Config::load_or_default(effective_config.as_deref()) {
             eprintln!(
                 "warning: existing config is malformed ({}), the wizard will create a new one",
                 e
@@ -494,6 +565,24 @@ pub fn run(cli: Cli) -> Result<()> {
             tome_home_source.label()
         );
 
+        // `--yes`: no wizard, no legacy/brownfield prompts. The config comes
+        // from flags alone; an existing tome.toml is backed up, not merged.
+        if let Command::Init {
+            yes: true,
+            sources,
+            targets,
+            library,
+        } = &cli.command
+        {
+            let config =
+                wizard::config_from_flags(sources, targets, library.as_deref(), &tome_home)?;
+            wizard::save_unattended(&config, &tome_home, cli.dry_run)?;
+            if !cli.dry_run {
+                post_init_sync(&cli, &config, tome_home, true)?;
+            }
+            return Ok(());
+        }
+
         // WUX-03: Detect and handle legacy pre-v0.6 ~/.config/tome/config.toml.
         // The legacy file is silently ignored by v0.6+ (only its `tome_home`
         // key is read); this warns the user and offers cleanup.
@@ -572,57 +661,35 @@ pub fn run(cli: Cli) -> Result<()> {
         )?;
         config.validate()?;
         if !cli.dry_run {
-            // Expand `~` in library_dir before passing to TomePaths, which
-            // requires absolute paths. The wizard preserves tilde-shaped paths
-            // so the on-disk TOML stays portable; here we resolve them for the
-            // post-init sync call.
-            let mut expanded = config.clone();
-            expanded
-                .expand_tildes()
-                .context("failed to expand ~ in wizard-produced config")?;
-            let paths = TomePaths::new(tome_home, expanded.library_dir.clone())?;
-            // Load machine prefs once at the top of the post-Init sync path
-            // (mirrors the canonical `run()` load order). Init does NOT use
-            // `Config::load_with_overrides` because the wizard runs against
-            // the bare tome.toml that the user is about to write — overrides
-            // would mask schema errors the wizard wants to surface.
-            let machine_path = resolve_machine_path(cli.machine.as_deref())?;
-            let machine_prefs = machine::load(&machine_path)?;
-            let verbose = cli.log_level().is_verbose();
-            let quiet = cli.log_level().is_quiet();
-            // Same front-end selection as cmd_sync (D-11): IndicatifSink for
-            // interactive post-init sync, NullSink under --quiet/--verbose.
-            let indicatif_sink;
-            let null_sink = NullSink;
-            let sink: &dyn ProgressSink = if !quiet && !verbose {
-                indicatif_sink = IndicatifSink::new();
-                &indicatif_sink
-            } else {
-                &null_sink
-            };
-            let cancel = CancelToken::new();
-            sync(
-                &expanded,
-                &paths,
-                SyncOptions {
-                    dry_run: cli.dry_run,
-                    force: false,
-                    no_triage: true, // skip on initial sync after init
-                    no_input: cli.no_input,
-                    no_install: false,
-                    verbose,
-                    quiet,
-                    machine_path: &machine_path,
-                    machine_prefs: &machine_prefs,
-                    start_stage: None,
-                },
-                sink,
-                &cancel,
-            )?;
+            post_init_sync(&cli, &config, tome_home, cli.no_input)?;
         }
         return Ok(());
     }
 
+    // `tome config --validate` must report a broken config instead of failing
+    // on it, so it loads the config itself rather than going through the
+    // `?`-propagating load below.
+    if let Command::Config { validate: true, .. } = cli.command {
+        let machine_path = resolve_machine_path(cli.machine.as_deref())?;
+        return cmd_config_validate(effective_config.as_deref(), &machine_path);
+    }
+
+    // `tome config edit` exists to fix a broken config, so it has the same
+    // constraint.
+    if let Command::Config {
+        sub: Some(cli::ConfigCommand::Edit),
+        ..
+    } = cli.command
+    {
+        let machine_path = resolve_machine_path(cli.machine.as_deref())?;
+        return cmd_config_edit(effective_config.as_deref(), &machine_path, cli.no_input);
+    }
+
+    // `tome migrate-config` upgrades a config the load below would reject.
+    if let Command::MigrateConfig = cli.command {
+        return cmd_migrate_config(effective_config.as_deref(), cli.dry_run);
+    }
+
     // Load per-machine preferences first — they may rewrite directory paths via
     // `[directory_overrides.<name>]` entries, which `Config::load_with_overrides`
     // applies between `expand_tildes()` and `validate()` (PORT-02 / I2 invariant).
@@ -637,7 +704,14 @@ pub fn run(cli: Cli) -> Result<()> {
     // Note: load_or_default_with_overrides already runs validate() internally —
     // no separate config.validate()? call here.
     let tome_home = resolve_tome_home(cli.tome_home.as_deref(), cli.config.as_deref())?;
-    let paths = TomePaths::new(tome_home, config.library_dir.clone())?;
+    // `--library` only redirects the paths; `config` keeps the configured
+    // `library_dir`, so commands that save the config never persist it.
+    let library_dir = match cli.library.as_deref() {
+        Some(dir) => std::path::absolute(config::expand_tilde(dir)?)
+            .with_context(|| format!("invalid --library path {}", dir.display()))?,
+        None => config.library_dir.clone(),
+    };
+    let paths = TomePaths::new(tome_home, library_dir)?;
 
     // HARD-02: dispatch via per-subcommand `cmd_<name>` helpers defined later
     // in this file. Each match arm is a one-line call into the helper, keeping
@@ -645,7 +719,8 @@ pub fn run(cli: Cli) -> Result<()> {
     // early-returns above, so the corresponding arms here are unreachable
     // contract guards.
     match cli.command {
-        Command::Init => unreachable_early_return("Command::Init"),
+        Command::Init { .. } => unreachable_early_return("Command::Init"),
+        Command::MigrateConfig => unreachable_early_return("Command::MigrateConfig"),
         Command::Version => unreachable_early_return("Command::Version"),
         Command::Add {
             url,
@@ -669,26 +744,100 @@ pub fn run(cli: Cli) -> Result<()> {
         ),
         Command::Sync {
             force,
+            adopt,
             no_triage,
             no_install,
+            dump_plan_on_error,
+            no_cache,
+            stats,
+            ref only,
+            ref exclude_source,
+            ref tag,
+            tag_mode,
+            prune_disabled,
+            check,
+            targets_only,
+            parallel,
+            format,
+            ref library_git_commit,
         } => {
             let log = cli.log_level();
+            if check {
+                return cmd_sync_check(
+                    &config,
+                    &paths,
+                    &machine_prefs,
+                    exclude_source,
+                    log.is_quiet(),
+                );
+            }
+            // `--format json` owns stdout: the text summary and prompts
+            // are silenced as under --quiet.
+            let json = format == cli::OutputFormat::Json;
             cmd_sync(
-                force,
-                no_triage,
-                no_install,
+                &config,
+                &paths,
+                SyncOptions {
+                    dry_run: cli.dry_run,
+                    force,
+                    adopt,
+                    no_triage: no_triage || cli.no_input,
+                    no_input: cli.no_input,
+                    no_install,
+                    verbose: log.is_verbose(),
+                    quiet: log.is_quiet() || json,
+                    machine_path: &machine_path,
+                    machine_prefs: &machine_prefs,
+                    start_stage: None,
+                    dump_plan_on_error,
+                    no_cache,
+                    stats,
+                    only,
+                    exclude_sources: exclude_source,
+                    tags: tag,
+                    tag_mode,
+                    prune_disabled,
+                    targets_only,
+                    parallel,
+                    json,
+                    library_git_commit: library_git_commit.as_ref().map(Option::as_deref),
+                },
+            )
+        }
+        Command::Diff => cmd_diff(&config, &paths, &machine_prefs),
+        Command::Watch => {
+            let log = cli.log_level();
+            cmd_watch(
                 &config,
                 &paths,
                 &machine_path,
                 &machine_prefs,
                 cli.dry_run,
-                cli.no_input,
                 log.is_verbose(),
                 log.is_quiet(),
             )
         }
-        Command::Status { json } => cmd_status(&config, &paths, json),
-        Command::Doctor { json } => cmd_doctor(&config, &paths, cli.dry_run, cli.no_input, json),
+        Command::Status {
+            json,
+            ref target,
+            check,
+            depth,
+        } => cmd_status(&config, &paths, json, target.as_deref(), check, depth),
+        Command::Doctor {
+            json,
+            ref fix,
+            yes,
+            depth,
+        } => cmd_doctor(
+            &config,
+            &paths,
+            cli.dry_run,
+            cli.no_input,
+            json,
+            fix,
+            yes,
+            depth,
+        ),
         Command::Lint { path, format } => cmd_lint(path, format, &paths),
         Command::Browse => {
             // HARD-21: thread per-machine prefs into browse so the
@@ -722,6 +871,16 @@ pub fn run(cli: Cli) -> Result<()> {
             cmd_migrate_library(&paths, dry_run || cli.dry_run, yes, cli.no_input)
         }
         Command::Eject => cmd_eject(&config, &paths, cli.dry_run),
+        Command::Uninstall { purge, yes } => cmd_uninstall(
+            &config,
+            &paths,
+            effective_config.as_deref(),
+            purge,
+            yes,
+            cli.dry_run,
+            cli.no_input,
+        ),
+        Command::Prune => cmd_prune(&config, &paths, &machine_prefs, cli.dry_run),
         Command::Relocate { new_path } => cmd_relocate(
             new_path,
             &config,
@@ -730,8 +889,40 @@ pub fn run(cli: Cli) -> Result<()> {
             cli.dry_run,
         ),
         Command::Completions { shell, print } => cmd_completions(shell, print),
-        Command::List { json } => cmd_list(&config, cli.log_level().is_quiet(), json),
-        Command::Config { path } => cmd_config(&config, path, &paths),
+        Command::List {
+            json,
+            long,
+            ref exclude_source,
+            ref sources,
+            ref tag,
+            tag_mode,
+            since,
+        } => cmd_list(
+            &config,
+            cli.log_level().is_quiet(),
+            json,
+            long,
+            exclude_source,
+            sources,
+            tag,
+            tag_mode,
+            since,
+        ),
+        Command::Info { name } => cmd_info(&config, &paths, &machine_prefs, &name),
+        Command::Edit { name } => cmd_edit(&config, &paths, &name, cli.no_input),
+        Command::Config { path, .. } => cmd_config(&config, path, &paths),
+        Command::Export { output, force } => cmd_export(&paths, &output, force, cli.dry_run),
+        Command::Import {
+            archive,
+            dest,
+            register,
+        } => cmd_import(
+            &archive,
+            &dest,
+            register.as_deref(),
+            effective_config.as_deref(),
+            cli.dry_run,
+        ),
         Command::Backup { sub } => cmd_backup(sub, &paths, cli.dry_run),
     }
 }
@@ -747,6 +938,73 @@ fn unreachable_early_return(variant: &str) -> Result<()> {
     )
 }
 
+/// The first sync after `tome init` saved a config: triage is skipped and
+/// everything else runs as a plain `tome sync` would. `tome init --yes`
+/// passes `no_input` so the sync never prompts either.
+fn post_init_sync(cli: &Cli, config: &Config, tome_home: PathBuf, no_input: bool) -> Result<()> {
+    // Expand `~` in library_dir before passing to TomePaths, which
+    // requires absolute paths. The wizard preserves tilde-shaped paths
+    // so the on-disk TOML stays portable; here we resolve them for the
+    // post-init sync call.
+    let mut expanded = config.clone();
+    expanded
+        .expand_tildes()
+        .context("failed to expand ~ in wizard-produced config")?;
+    let paths = TomePaths::new(tome_home, expanded.library_dir.clone())?;
+    // Load machine prefs once at the top of the post-Init sync path
+    // (mirrors the canonical `run()` load order). Init does NOT use
+    // `Config::load_with_overrides` because the wizard runs against
+    // the bare tome.toml that the user is about to write — overrides
+    // would mask schema errors the wizard wants to surface.
+    let machine_path = resolve_machine_path(cli.machine.as_deref())?;
+    let machine_prefs = machine::load(&machine_path)?;
+    let verbose = cli.log_level().is_verbose();
+    let quiet = cli.log_level().is_quiet();
+    // Same front-end selection as cmd_sync (D-11): IndicatifSink for
+    // interactive post-init sync, NullSink under --quiet/--verbose.
+    let indicatif_sink;
+    let null_sink = NullSink;
+    let sink: &dyn ProgressSink = if !quiet && !verbose {
+        indicatif_sink = IndicatifSink::new();
+        &indicatif_sink
+    } else {
+        &null_sink
+    };
+    let cancel = CancelToken::new();
+    sync(
+        &expanded,
+        &paths,
+        SyncOptions {
+            dry_run: cli.dry_run,
+            force: false,
+            adopt: false,
+            no_triage: true, // skip on initial sync after init
+            no_input,
+            no_install: false,
+            verbose,
+            quiet,
+            machine_path: &machine_path,
+            machine_prefs: &machine_prefs,
+            start_stage: None,
+            dump_plan_on_error: false,
+            no_cache: false,
+            stats: false,
+            only: &[],
+            exclude_sources: &[],
+            tags: &[],
+            tag_mode: Default::default(),
+            prune_disabled: false,
+            targets_only: false,
+            parallel: 1,
+            json: false,
+            library_git_commit: None,
+        },
+        sink,
+        &cancel,
+    )?;
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Per-subcommand dispatch helpers (HARD-02)
 //
@@ -788,75 +1046,118 @@ pub(crate) fn cmd_add(
 }
 
 /// `tome sync` — run the full discover → consolidate → distribute → cleanup pipeline.
-#[allow(clippy::too_many_arguments)]
-pub(crate) fn cmd_sync(
-    force: bool,
-    no_triage: bool,
-    no_install: bool,
-    config: &Config,
-    paths: &TomePaths,
-    machine_path: &Path,
-    machine_prefs: &machine::MachinePrefs,
-    dry_run: bool,
-    no_input: bool,
-    verbose: bool,
-    quiet: bool,
-) -> Result<()> {
+pub(crate) fn cmd_sync(config: &Config, paths: &TomePaths, opts: SyncOptions<'_>) -> Result<()> {
     // Front-end selection (D-11): use the spinner-driven IndicatifSink for
     // interactive runs, and a discarding NullSink under --quiet or --verbose —
     // exactly the previous `show_progress = !quiet && !verbose` gate, now
-    // expressed as a sink choice instead of an inline `if`. The CLI never
-    // cancels, so it passes a fresh, never-tripped CancelToken (D-12); the GUI
-    // (Phase 27) clones a live token into its cancel command.
+    // expressed as a sink choice instead of an inline `if`. `quiet` already
+    // covers `--format json`, so JSON output gets no spinner either. The CLI
+    // never cancels, so it passes a fresh, never-tripped CancelToken (D-12);
+    // the GUI (Phase 27) clones a live token into its cancel command.
     let indicatif_sink;
     let null_sink = NullSink;
-    let sink: &dyn ProgressSink = if !quiet && !verbose {
+    let sink: &dyn ProgressSink = if !opts.quiet && !opts.verbose {
         indicatif_sink = IndicatifSink::new();
         &indicatif_sink
     } else {
         &null_sink
     };
     let cancel = CancelToken::new();
-    sync(
+    sync(config, paths, opts, sink, &cancel)
+}
+
+/// `tome sync --check` — fail if a sync would change anything.
+pub(crate) fn cmd_sync_check(
+    config: &Config,
+    paths: &TomePaths,
+    machine_prefs: &machine::MachinePrefs,
+    exclude_sources: &[String],
+    quiet: bool,
+) -> Result<()> {
+    let groups = diff::compute(config, paths, machine_prefs, exclude_sources)?;
+    let pending: usize = groups.iter().map(|g| g.changes.len()).sum();
+    if !quiet {
+        print!("{}", diff::render(&groups));
+    }
+    anyhow::ensure!(
+        pending == 0,
+        "sync --check: {pending} change(s) pending; run `tome sync` to apply them"
+    );
+    Ok(())
+}
+
+/// `tome diff` — per-skill preview of what `tome sync` would change.
+pub(crate) fn cmd_diff(
+    config: &Config,
+    paths: &TomePaths,
+    machine_prefs: &machine::MachinePrefs,
+) -> Result<()> {
+    let groups = diff::compute(config, paths, machine_prefs, &[])?;
+    print!("{}", diff::render(&groups));
+    Ok(())
+}
+
+/// `tome watch` — re-sync whenever a watched source changes, until interrupted.
+pub(crate) fn cmd_watch(
+    config: &Config,
+    paths: &TomePaths,
+    machine_path: &Path,
+    machine_prefs: &machine::MachinePrefs,
+    dry_run: bool,
+    verbose: bool,
+    quiet: bool,
+) -> Result<()> {
+    watch::run(
         config,
         paths,
-        SyncOptions {
+        watch::WatchOptions {
             dry_run,
-            force,
-            no_triage: no_triage || no_input,
-            no_input,
-            no_install,
             verbose,
             quiet,
             machine_path,
             machine_prefs,
-            start_stage: None,
         },
-        sink,
-        &cancel,
     )
 }
 
-/// `tome status` — read-only summary of library, directories, and health.
-pub(crate) fn cmd_status(config: &Config, paths: &TomePaths, json: bool) -> Result<()> {
-    status::show(config, paths, json)
+/// `tome status` — read-only summary of library, directories, and health,
+/// or with `--target` the link health of one distribution directory.
+pub(crate) fn cmd_status(
+    config: &Config,
+    paths: &TomePaths,
+    json: bool,
+    target: Option<&str>,
+    check: bool,
+    depth: usize,
+) -> Result<()> {
+    if check {
+        return status::check(config, paths, depth);
+    }
+    match target {
+        Some(name) => status::show_target(config, paths, name, json),
+        None => status::show(config, paths, json, depth),
+    }
 }
 
 /// `tome doctor` — diagnose and (optionally) repair library/symlink issues.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn cmd_doctor(
     config: &Config,
     paths: &TomePaths,
     dry_run: bool,
     no_input: bool,
     json: bool,
+    fix: &[cli::DoctorFix],
+    yes: bool,
+    depth: usize,
 ) -> Result<()> {
-    doctor::diagnose(config, paths, dry_run, no_input, json)
+    doctor::diagnose(config, paths, dry_run, no_input, json, fix, yes, depth)
 }
 
 /// `tome lint` — validate skill frontmatter; exits 1 when errors are found.
 pub(crate) fn cmd_lint(
     path: Option<PathBuf>,
-    format: cli::LintFormat,
+    format: cli::OutputFormat,
     paths: &TomePaths,
 ) -> Result<()> {
     let report = match path {
@@ -871,8 +1172,8 @@ pub(crate) fn cmd_lint(
         None => lint::lint_library(paths.library_dir()),
     };
     match format {
-        cli::LintFormat::Text => lint::render_text(&report),
-        cli::LintFormat::Json => lint::render_json(&report),
+        cli::OutputFormat::Text => lint::render_text(&report),
+        cli::OutputFormat::Json => lint::render_json(&report),
     }
     // HARD-04: bubble up a downcastable error rather than `process::exit(1)`
     // so embedding callers can decide how to translate the failure.
@@ -1380,6 +1681,79 @@ pub(crate) fn cmd_migrate_library(
     Ok(())
 }
 
+/// `tome prune` — remove library symlinks from machine-disabled directories.
+pub(crate) fn cmd_prune(
+    config: &Config,
+    paths: &TomePaths,
+    machine_prefs: &machine::MachinePrefs,
+    dry_run: bool,
+) -> Result<()> {
+    let plan = prune::plan(config, paths, machine_prefs)?;
+    prune::run(&plan, dry_run)?;
+    Ok(())
+}
+
+/// `tome uninstall` — remove every tome symlink, and with `--purge` the
+/// config file. Sources and library copies are never touched.
+pub(crate) fn cmd_uninstall(
+    config: &Config,
+    paths: &TomePaths,
+    config_path: Option<&Path>,
+    purge: bool,
+    yes: bool,
+    dry_run: bool,
+    no_input: bool,
+) -> Result<()> {
+    let config_file = match (purge, config_path) {
+        (false, _) => None,
+        (true, Some(p)) => Some(p.to_path_buf()),
+        (true, None) => Some(config::default_config_path()?),
+    };
+    let plan = uninstall::plan(config, paths, config_file.as_deref())?;
+    uninstall::render_plan(&plan, paths.library_dir());
+
+    if plan.is_empty() {
+        return Ok(());
+    }
+
+    if dry_run {
+        println!("\n{}", style("Dry run — no changes made.").yellow());
+        return Ok(());
+    }
+
+    if !yes {
+        if !no_input && std::io::stdin().is_terminal() {
+            let confirmed = dialoguer::Confirm::new()
+                .with_prompt("Uninstall tome from these directories?")
+                .default(false)
+                .interact()?;
+            if !confirmed {
+                println!("Aborted.");
+                return Ok(());
+            }
+        } else {
+            anyhow::bail!(
+                "tome uninstall requires confirmation — use --yes in non-interactive mode"
+            );
+        }
+    }
+
+    let result = uninstall::execute(&plan)?;
+    println!(
+        "\n{} Removed {} symlink(s).",
+        style("✓").green(),
+        result.symlinks_removed
+    );
+    if let Some(config_file) = plan
+        .config_file
+        .as_deref()
+        .filter(|_| result.config_removed)
+    {
+        println!("{} Deleted {}.", style("✓").green(), config_file.display());
+    }
+    Ok(())
+}
+
 /// `tome eject` — remove tome's symlinks from all distribution directories.
 pub(crate) fn cmd_eject(config: &Config, paths: &TomePaths, dry_run: bool) -> Result<()> {
     let plan = eject::plan(config, paths)?;
@@ -1471,8 +1845,60 @@ pub(crate) fn cmd_completions(shell: clap_complete::Shell, print: bool) -> Resul
 }
 
 /// `tome list` — list all discovered skills (text or JSON).
-pub(crate) fn cmd_list(config: &Config, quiet: bool, json: bool) -> Result<()> {
-    list(config, quiet, json)
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn cmd_list(
+    config: &Config,
+    quiet: bool,
+    json: bool,
+    long: bool,
+    exclude_sources: &[String],
+    sources: &[String],
+    tags: &[String],
+    tag_mode: list::TagMode,
+    since: Option<std::time::Duration>,
+) -> Result<()> {
+    let known: Vec<&str> = config.discovery_dirs().map(|(n, _)| n.as_str()).collect();
+    for name in sources {
+        if known.contains(&name.as_str()) {
+            continue;
+        }
+        match info::closest_match(name, &known) {
+            Some(suggestion) => anyhow::bail!(
+                "--source: no source directory named '{name}' — did you mean '{suggestion}'?"
+            ),
+            None => anyhow::bail!(
+                "--source: no source directory named '{name}' (configured sources: {})",
+                known.join(", ")
+            ),
+        }
+    }
+    let (config, unknown) = config.without_sources(exclude_sources);
+    for name in &unknown {
+        warn!("{}", unknown_source_warning(name));
+    }
+    list(&config, quiet, json, long, sources, tags, tag_mode, since)
+}
+
+/// `tome info <skill>` — details for one discovered skill.
+pub(crate) fn cmd_info(
+    config: &Config,
+    paths: &TomePaths,
+    machine_prefs: &machine::MachinePrefs,
+    name: &str,
+) -> Result<()> {
+    let info = info::gather(config, paths, machine_prefs, name)?;
+    print!("{}", info::render(&info));
+    Ok(())
+}
+
+/// `tome edit <skill>` — open the skill's source SKILL.md in the editor.
+pub(crate) fn cmd_edit(
+    config: &Config,
+    paths: &TomePaths,
+    name: &str,
+    no_input: bool,
+) -> Result<()> {
+    edit::run(config, paths, name, no_input)
 }
 
 /// `tome config` — show resolved config (TOML) or just the path.
@@ -1480,40 +1906,199 @@ pub(crate) fn cmd_config(config: &Config, path: bool, paths: &TomePaths) -> Resu
     show_config(config, path, &paths.config_path())
 }
 
-/// `tome backup <sub>` — git-backed snapshot/restore for the library.
-pub(crate) fn cmd_backup(sub: cli::BackupCommand, paths: &TomePaths, dry_run: bool) -> Result<()> {
-    match sub {
-        cli::BackupCommand::Init => {
-            backup::init(paths.tome_home(), dry_run)?;
-            // Offer remote setup after successful init (interactive only)
-            if !dry_run && std::io::stdin().is_terminal() && !backup::has_remote(paths.tome_home())
-            {
-                offer_remote_setup(paths.tome_home())?;
-            }
-        }
-        cli::BackupCommand::Snapshot { message } => {
-            // The CLI backup commands present via direct `println!` inside
-            // `backup::*`; the BackupSnapshot events have no separate CLI
-            // surface today, so pass a discarding NullSink + a never-tripped
-            // CancelToken (D-11/D-12 plumbing is threaded for signature
-            // symmetry, exercised by the GUI in a later phase).
-            backup::snapshot(
-                paths.tome_home(),
-                message.as_deref(),
-                dry_run,
-                &NullSink,
-                &CancelToken::new(),
-            )?;
-        }
-        cli::BackupCommand::List { count } => {
-            let entries = backup::list(paths.tome_home(), count)?;
-            backup::render_list(&entries);
+/// `tome config --validate` — load the config (machine overrides applied),
+/// run `Config::validate`, check every directory path on disk, and print a
+/// pass/fail report. Writes nothing.
+pub(crate) fn cmd_config_validate(config_path: Option<&Path>, machine_path: &Path) -> Result<()> {
+    let mut problems: Vec<String> = Vec::new();
+    let machine_prefs = match machine::load(machine_path) {
+        Ok(prefs) => prefs,
+        Err(e) => {
+            problems.push(format!("{}: {e:#}", machine_path.display()));
+            machine::MachinePrefs::default()
         }
-        cli::BackupCommand::Restore { target, force } => {
-            if !force {
-                if std::io::stdin().is_terminal() {
-                    let confirmed = dialoguer::Confirm::new()
-                        .with_prompt(format!(
+    };
+    match Config::load_or_default_with_overrides(config_path, machine_path, &machine_prefs) {
+        Ok(config) => problems.extend(config.path_problems()),
+        Err(e) => problems.push(format!("{e:#}")),
+    }
+
+    let shown = match config_path {
+        Some(p) => paths::collapse_home(p),
+        None => config::default_config_path()
+            .map(|p| paths::collapse_home(&p))
+            .unwrap_or_else(|_| "config".to_string()),
+    };
+    if problems.is_empty() {
+        println!("{} {shown} is valid", console::style("ok").green());
+        return Ok(());
+    }
+    println!("{} {shown}", console::style("invalid").red());
+    for problem in &problems {
+        println!("  - {problem}");
+    }
+    Err(ConfigInvalid {
+        problems: problems.len(),
+    }
+    .into())
+}
+
+/// `tome config edit` — open the config in the editor, then re-validate it
+/// as `tome config --validate` does. The edited file is never rolled back:
+/// on errors an interactive run offers to re-open the editor, a
+/// non-interactive one returns the `ConfigInvalid` error.
+pub(crate) fn cmd_config_edit(
+    config_path: Option<&Path>,
+    machine_path: &Path,
+    no_input: bool,
+) -> Result<()> {
+    let path = match config_path {
+        Some(p) => p.to_path_buf(),
+        None => config::default_config_path()?,
+    };
+    loop {
+        edit::open_config(&path)?;
+        let Err(e) = cmd_config_validate(Some(&path), machine_path) else {
+            return Ok(());
+        };
+        if no_input || !std::io::stdin().is_terminal() {
+            return Err(e);
+        }
+        let reopen = dialoguer::Confirm::new()
+            .with_prompt("Re-open the editor to fix the config?")
+            .default(true)
+            .interact()?;
+        if !reopen {
+            return Err(e);
+        }
+    }
+}
+
+/// `tome migrate-config` — rewrite an old-layout config in the current shape,
+/// keeping the original as `<file>.bak`. Refuses to overwrite an existing
+/// backup so a second run can't clobber the only copy of the old file.
+pub(crate) fn cmd_migrate_config(config_path: Option<&Path>, dry_run: bool) -> Result<()> {
+    let path = match config_path {
+        Some(p) => p.to_path_buf(),
+        None => config::default_config_path()?,
+    };
+    anyhow::ensure!(
+        path.is_file(),
+        "no config at {}\nhint: run `tome init` to create one.",
+        path.display()
+    );
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let Some(migration) = config::migrate_config(&content)
+        .with_context(|| format!("cannot migrate {}", path.display()))?
+    else {
+        println!(
+            "{} is already in the current layout (schema_version {}); nothing to migrate.",
+            path.display(),
+            config::CONFIG_SCHEMA_VERSION
+        );
+        return Ok(());
+    };
+
+    println!("Migrating {}:", style(path.display()).cyan());
+    for change in &migration.changes {
+        println!("  {change}");
+    }
+    if dry_run {
+        println!("\n{}", style("Dry run — config left unchanged.").yellow());
+        return Ok(());
+    }
+
+    let mut backup = path.clone().into_os_string();
+    backup.push(".bak");
+    let backup = PathBuf::from(backup);
+    anyhow::ensure!(
+        !backup.exists(),
+        "backup {} already exists\nhint: move it aside, then re-run `tome migrate-config`.",
+        backup.display()
+    );
+    std::fs::copy(&path, &backup).with_context(|| {
+        format!(
+            "failed to back up {} to {}",
+            path.display(),
+            backup.display()
+        )
+    })?;
+    migration.config.save(&path)?;
+    println!(
+        "\nMigrated {} (original saved as {}). Comments were not carried over.",
+        path.display(),
+        backup.display()
+    );
+    Ok(())
+}
+
+/// `tome export <output>` — bundle the library into a `.tar.gz`.
+pub(crate) fn cmd_export(
+    paths: &TomePaths,
+    output: &Path,
+    force: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let exported = export::export(paths, output, force, dry_run)?;
+    println!("{}", export::summary(&exported, output, dry_run));
+    Ok(())
+}
+
+/// `tome import <archive> <dest>` — unpack a `tome export` archive.
+pub(crate) fn cmd_import(
+    archive: &Path,
+    dest: &Path,
+    register: Option<&str>,
+    config_path: Option<&Path>,
+    dry_run: bool,
+) -> Result<()> {
+    let config_path = match config_path {
+        Some(p) => p.to_path_buf(),
+        None => config::default_config_path()?,
+    };
+    let report = import::import(archive, dest, register, &config_path, dry_run)?;
+    for w in &report.warnings {
+        eprintln!("warning: {}", w);
+    }
+    println!("{}", import::summary(&report, dest, dry_run));
+    Ok(())
+}
+
+/// `tome backup <sub>` — git-backed snapshot/restore for the library.
+pub(crate) fn cmd_backup(sub: cli::BackupCommand, paths: &TomePaths, dry_run: bool) -> Result<()> {
+    match sub {
+        cli::BackupCommand::Init => {
+            backup::init(paths.tome_home(), dry_run)?;
+            // Offer remote setup after successful init (interactive only)
+            if !dry_run && std::io::stdin().is_terminal() && !backup::has_remote(paths.tome_home())
+            {
+                offer_remote_setup(paths.tome_home())?;
+            }
+        }
+        cli::BackupCommand::Snapshot { message } => {
+            // The CLI backup commands present via direct `println!` inside
+            // `backup::*`; the BackupSnapshot events have no separate CLI
+            // surface today, so pass a discarding NullSink + a never-tripped
+            // CancelToken (D-11/D-12 plumbing is threaded for signature
+            // symmetry, exercised by the GUI in a later phase).
+            backup::snapshot(
+                paths.tome_home(),
+                message.as_deref(),
+                dry_run,
+                &NullSink,
+                &CancelToken::new(),
+            )?;
+        }
+        cli::BackupCommand::List { count } => {
+            let entries = backup::list(paths.tome_home(), count)?;
+            backup::render_list(&entries);
+        }
+        cli::BackupCommand::Restore { target, force } => {
+            if !force {
+                if std::io::stdin().is_terminal() {
+                    let confirmed = dialoguer::Confirm::new()
+                        .with_prompt(format!(
                             "Restore to {}? This will overwrite current state",
                             target
                         ))
@@ -1572,6 +2157,11 @@ fn join_synced_at_from_manifest(
     }
 }
 
+/// Warning for an `--exclude-source` name that matches no source directory.
+fn unknown_source_warning(name: &str) -> String {
+    format!("--exclude-source: no source directory named '{name}', ignoring it")
+}
+
 /// Warn about `disabled_directories` entries in machine.toml that don't match any
 /// configured directory name. Helps catch typos and stale entries.
 fn warn_unknown_disabled_directories(machine_prefs: &machine::MachinePrefs, config: &Config) {
@@ -1595,6 +2185,10 @@ fn warn_unknown_disabled_directories(machine_prefs: &machine::MachinePrefs, conf
 pub struct SyncOptions<'a> {
     pub dry_run: bool,
     pub force: bool,
+    /// `tome sync --force --adopt`: back up real files/directories that
+    /// collide with a skill to `<name>.tome-bak` and replace them, instead
+    /// of skipping. Ignored unless `force` is also set.
+    pub adopt: bool,
     pub no_triage: bool,
     pub no_input: bool,
     pub no_install: bool,
@@ -1615,6 +2209,49 @@ pub struct SyncOptions<'a> {
     /// honored only by `sync_with_outcome`; the bare `sync()` ignores it
     /// (the CLI never sets it).
     pub start_stage: Option<progress::SyncStage>,
+    /// `tome sync --dump-plan-on-error`: when the pipeline fails, write the
+    /// plan-so-far to `<library_dir>/.tome-cache/sync-plan.json` and name
+    /// the file in the returned error. See [`plan_dump`].
+    pub dump_plan_on_error: bool,
+    /// `tome sync --no-cache`: rescan every directory instead of reusing
+    /// unchanged results from the discovery cache. `force` implies it.
+    pub no_cache: bool,
+    /// `tome sync --stats`: print per-phase wall-clock timing and skill
+    /// counts at the end of the run. See [`sync_stats`].
+    pub stats: bool,
+    /// `tome sync --only <skill>...`: consolidate and distribute just these
+    /// skills, skipping triage and both cleanup passes. Empty syncs
+    /// everything.
+    pub only: &'a [String],
+    /// `tome sync --exclude-source <name>...`: leave these discovery
+    /// directories out of this run. Their library entries and existing
+    /// links are kept, not pruned.
+    pub exclude_sources: &'a [String],
+    /// `tome sync --tag <tag>...`: like `only`, but selects skills by
+    /// frontmatter tag. Combined with `only`, a skill must pass both.
+    pub tags: &'a [String],
+    /// Whether `tags` requires every tag or any one.
+    pub tag_mode: list::TagMode,
+    /// `tome sync --prune-disabled`: remove links into the library from
+    /// distribution directories disabled in `machine.toml`. External links
+    /// and real entries there are left alone.
+    pub prune_disabled: bool,
+    /// `tome sync --targets-only`: skip reconcile, discovery, and
+    /// consolidation, and relink distribution directories from the library
+    /// as it is. Fails if the library is empty or missing.
+    pub targets_only: bool,
+    /// `tome sync --parallel <n>`: distribute to up to `n` directories at
+    /// once. Each directory is still linked serially. `1` (the default)
+    /// keeps the distribution loop on the calling thread.
+    pub parallel: usize,
+    /// `tome sync --format json`: print the summary as one JSON object on
+    /// stdout, even when `quiet` is set. Callers set `quiet` alongside it so
+    /// nothing else reaches stdout.
+    pub json: bool,
+    /// `tome sync --library-git-commit [MESSAGE]`: commit the library's git
+    /// repository after the sync without prompting. The inner `None` uses
+    /// the generated `tome sync: N created, ...` message.
+    pub library_git_commit: Option<Option<&'a str>>,
 }
 
 /// Pre-discovery step: clone or update git-type directories.
@@ -1885,9 +2522,53 @@ pub fn sync(
     sink: &dyn ProgressSink,
     cancel: &CancelToken,
 ) -> Result<()> {
+    if !opts.dump_plan_on_error {
+        return sync_pipeline(config, paths, opts, sink, cancel, None);
+    }
+    let mut plan = plan_dump::SyncPlanDump::default();
+
+    // Same stage-tracking trick as `sync_with_outcome`: the last stage
+    // announced before the Err is the stage that failed.
+    let tracker = sync_outcome::StageTrackingSink::new(sink);
+    let Err(e) = sync_pipeline(config, paths, opts, &tracker, cancel, Some(&mut plan)) else {
+        return Ok(());
+    };
+    plan.record_failure(tracker.last_started(), &e);
+    match plan_dump::write(paths.library_dir(), &plan) {
+        Ok(path) => Err(e.context(format!(
+            "sync failed; plan-so-far written to {}",
+            path.display()
+        ))),
+        Err(write_err) => {
+            // Never mask the pipeline error with a dump failure.
+            warn!("could not write sync plan dump: {write_err:#}");
+            Err(e)
+        }
+    }
+}
+
+/// The sync pipeline proper. [`sync`] is a thin wrapper that adds the
+/// `--dump-plan-on-error` handling; with that flag set, each stage records
+/// what it decided into `plan` so the wrapper can persist it if a later
+/// stage fails. Without it `plan` is `None` and nothing is copied.
+fn sync_pipeline(
+    config: &Config,
+    paths: &TomePaths,
+    opts: SyncOptions<'_>,
+    sink: &dyn ProgressSink,
+    cancel: &CancelToken,
+    mut plan: Option<&mut plan_dump::SyncPlanDump>,
+) -> Result<()> {
+    // Doctor reports this as a diagnostic; sync is where it becomes fatal,
+    // before anything is written.
+    config.ensure_distinct_distribution_dirs()?;
+    if opts.targets_only {
+        return sync_targets_only(config, paths, opts, sink, cancel);
+    }
     let SyncOptions {
         dry_run,
         force,
+        adopt,
         no_triage,
         no_input,
         no_install,
@@ -1901,7 +2582,21 @@ pub fn sync(
         // skipping Discover would break Consolidate). See the
         // `sync_with_outcome` doc comment for the rationale.
         start_stage: _,
+        // Handled by the `sync()` wrapper.
+        dump_plan_on_error: _,
+        no_cache,
+        stats: show_stats,
+        only,
+        exclude_sources,
+        tags,
+        tag_mode,
+        prune_disabled,
+        targets_only: _,
+        parallel,
+        json,
+        library_git_commit,
     } = opts;
+    let mut stats = sync_stats::SyncStats::new();
 
     // OBS-03 D-SPAN-1: top-level sync span. RAII via `.entered()`; the
     // returned guard `_sync_span` drops at function exit, emitting a
@@ -1963,6 +2658,26 @@ pub fn sync(
     // here so triage (below) can mutate locally without affecting the caller.
     let mut machine_prefs = prefs_in.clone();
 
+    // `--exclude-source`: reconcile, git resolution, and discovery run
+    // against the config without those directories. Everything after keeps
+    // the full config, so their library entries stay owned and are protected
+    // from cleanup below rather than pruned as missing.
+    let discovery_config: std::borrow::Cow<'_, Config> = if exclude_sources.is_empty() {
+        std::borrow::Cow::Borrowed(config)
+    } else {
+        let (filtered, unknown) = config.without_sources(exclude_sources);
+        for name in &unknown {
+            warn!("{}", unknown_source_warning(name));
+        }
+        std::borrow::Cow::Owned(filtered)
+    };
+    let excluded_dirs: HashSet<&str> = config
+        .directories
+        .keys()
+        .filter(|name| !discovery_config.directories.contains_key(*name))
+        .map(DirectoryName::as_str)
+        .collect();
+
     // Load existing lockfile for diffing and reconciliation
     let old_lockfile = lockfile::load(paths.config_dir())?;
     // Load manifest once for reconcile's edit-in-library detection. Held as
@@ -1989,6 +2704,7 @@ pub fn sync(
         anyhow::bail!("sync cancelled");
     }
     let mut reconcile_report: Option<reconcile::ReconcileReport> = None;
+    let phase_start = Instant::now();
     {
         let _span = info_span!("reconcile").entered();
         // D-09/D-11: the CLI's "Resolving git sources..." spinner is now driven
@@ -1996,7 +2712,7 @@ pub fn sync(
         sink.emit(ProgressEvent::SyncStageStarted {
             stage: SyncStage::Reconcile,
         });
-        if let Some(claude_adapter) = build_claude_adapter(config)? {
+        if let Some(claude_adapter) = build_claude_adapter(&discovery_config)? {
             let mut report = reconcile::reconcile_lockfile(
                 old_lockfile.as_ref(),
                 &manifest_for_reconcile,
@@ -2013,6 +2729,9 @@ pub fn sync(
                     verbose,
                 },
             )?;
+            if let Some(plan) = plan.as_deref_mut() {
+                plan.reconcile = Some(plan_dump::ReconcilePlan::from(&report));
+            }
 
             // Apply edit-in-library decisions to the manifest. The manifest
             // is owned by sync(); reconcile_lockfile only proposed the user's
@@ -2044,10 +2763,14 @@ pub fn sync(
             stage: SyncStage::Reconcile,
         });
     }
+    stats.record("reconcile", phase_start, None);
 
     // Safety guard: warn and skip cleanup when no directories are configured (CFG-06)
     if config.directories.is_empty() {
         warn!("no directories configured. Run `tome init` to set up directories.");
+        if json {
+            print_empty_sync_report_json(dry_run)?;
+        }
         return Ok(());
     }
 
@@ -2060,6 +2783,7 @@ pub fn sync(
     if cancel.is_cancelled() {
         anyhow::bail!("sync cancelled");
     }
+    let phase_start = Instant::now();
     let skills = {
         let _span = info_span!("discover").entered();
         // D-09/D-11: the Discover stage drives the "Discovering skills..."
@@ -2075,34 +2799,119 @@ pub fn sync(
         //    "step name + time.busy" event. sink/cancel are threaded into
         //    git::clone_repo/update_repo so each fetch emits GitCloneProgress
         //    and observes cancellation (D-11/D-12).
-        let resolved = resolve_git_directories(config, paths, dry_run, sink, cancel);
+        let resolved = resolve_git_directories(&discovery_config, paths, dry_run, sink, cancel);
 
         // 1. Discover
         let mut warnings = Vec::new();
-        let mut discovered = discover::discover_all(config, &resolved, &mut warnings)?;
+        let mut discovered = if force || no_cache {
+            discover::discover_all(&discovery_config, &resolved, &mut warnings)?
+        } else {
+            // Incremental: reuse the scan of every directory whose
+            // fingerprint is unchanged since the last sync.
+            let mut cache = discovery_cache::load(paths.config_dir());
+            let discovered = discover::discover_all_cached(
+                &discovery_config,
+                &resolved,
+                &mut cache,
+                &mut warnings,
+            )?;
+            if !dry_run && let Err(e) = discovery_cache::save(&mut cache, paths.config_dir()) {
+                warnings.push(format!("could not save discovery cache: {e:#}"));
+            }
+            discovered
+        };
+        // Names differing only in case share one library entry on a
+        // case-insensitive volume; keep the first instead of letting the
+        // second clobber it.
+        if paths::is_case_insensitive(paths.library_dir()) {
+            discover::drop_case_collisions(&mut discovered, &mut warnings);
+        }
+        for (skill, missing) in discover::missing_requirements(&discovered) {
+            warnings.push(discover::missing_requirements_message(skill, &missing));
+        }
+        // `enabled: false` in the frontmatter: leave the skill out of this
+        // sync entirely, as if it were in `exclude`.
+        discovered.retain(|skill| {
+            let disabled = skill.is_disabled_in_frontmatter();
+            if disabled {
+                debug!("skipping '{}': disabled in its frontmatter", skill.name);
+            }
+            !disabled
+        });
 
         // D-16: join in the manifest's per-skill `synced_at` timestamp.
         // Extracted into `join_synced_at_from_manifest` so the join logic is
         // directly unit-testable without spinning a full sync fixture.
         join_synced_at_from_manifest(&mut discovered, &manifest_for_reconcile);
+        if let Some(plan) = plan.as_deref_mut() {
+            plan.discovered = discovered.clone();
+        }
 
         sink.emit(ProgressEvent::SyncStageFinished {
             stage: SyncStage::Discover,
         });
 
         // Discover-warnings emission. EnvFilter handles the quiet vs warn
-        // discipline globally (LogLevel::Quiet → "warn" directive still
-        // fires warn-level events, matching the previous always-show
-        // semantics).
+        // discipline globally (LogLevel::Quiet → "error" directive hides
+        // them; they are still counted for `--warnings-as-errors`).
         for w in &warnings {
             warn!("{}", w);
         }
 
         discovered
     };
+    stats.record("discover", phase_start, Some(skills.len()));
+
+    // `--only`: every named skill must have been discovered. Consolidate and
+    // distribute then see just those; the lockfile still covers everything.
+    let only_names: Option<HashSet<String>> = if only.is_empty() {
+        None
+    } else {
+        let missing: Vec<&str> = only
+            .iter()
+            .filter(|name| !skills.iter().any(|s| s.name.as_str() == name.as_str()))
+            .map(String::as_str)
+            .collect();
+        anyhow::ensure!(
+            missing.is_empty(),
+            "--only: skill(s) not discovered: {} (run `tome list` to see discovered skills)",
+            missing.join(", ")
+        );
+        Some(only.iter().cloned().collect())
+    };
+    // `--tag`: narrow the selection (all skills, or the `--only` set) to
+    // tagged skills. Behaves like `--only` from here on, so cleanup and
+    // triage are skipped and untagged skills are left untouched.
+    let only_names = if tags.is_empty() {
+        only_names
+    } else {
+        Some(
+            skills
+                .iter()
+                .filter(|s| {
+                    only_names
+                        .as_ref()
+                        .is_none_or(|n| n.contains(s.name.as_str()))
+                })
+                .filter(|s| list::matches_tags(s, tags, tag_mode))
+                .map(|s| s.name.as_str().to_string())
+                .collect(),
+        )
+    };
+    let selected: std::borrow::Cow<'_, [discover::DiscoveredSkill]> = match &only_names {
+        Some(names) => skills
+            .iter()
+            .filter(|s| names.contains(s.name.as_str()))
+            .cloned()
+            .collect::<Vec<_>>()
+            .into(),
+        None => skills.as_slice().into(),
+    };
 
     if skills.is_empty() {
-        if !quiet {
+        if json {
+            print_empty_sync_report_json(dry_run)?;
+        } else if !quiet {
             println!("No skills found. Run `tome init` to configure sources.");
         }
         return Ok(());
@@ -2131,21 +2940,27 @@ pub fn sync(
         anyhow::bail!("sync cancelled");
     }
     // 2. Consolidate into library (copy). OBS-03: `consolidate` step span.
+    let phase_start = Instant::now();
     let (consolidate_result, mut manifest) = {
         let _span = info_span!("consolidate").entered();
         sink.emit(ProgressEvent::SyncStageStarted {
             stage: SyncStage::Consolidate,
         });
-        let result = library::consolidate(&skills, paths, dry_run, force)?;
+        let result = library::consolidate(&selected, paths, dry_run, force, adopt)?;
+        if let Some(plan) = plan.as_deref_mut() {
+            plan.consolidate = Some(result.0.clone());
+        }
         sink.emit(ProgressEvent::SyncStageFinished {
             stage: SyncStage::Consolidate,
         });
         result
     };
+    stats.record("consolidate", phase_start, Some(selected.len()));
 
     // 3. Diff lockfile and triage changes (pre-cleanup snapshot for diffing)
+    //    Skipped under `--only`: triage is about library-wide changes.
     let pre_cleanup_lockfile = lockfile::generate(&manifest, &skills);
-    if !no_triage && !quiet {
+    if !no_triage && !quiet && only_names.is_none() {
         if let Some(ref old) = old_lockfile {
             let d = update::diff(old, &pre_cleanup_lockfile);
             if !d.is_empty() {
@@ -2170,8 +2985,22 @@ pub fn sync(
         }
     }
 
-    let discovered_names: HashSet<String> =
-        skills.iter().map(|s| s.name.as_str().to_string()).collect();
+    // Library entries owned by an `--exclude-source` directory weren't
+    // scanned this run; count them as discovered so cleanup keeps them.
+    let kept_from_excluded: HashSet<String> = manifest
+        .iter()
+        .filter(|(_, entry)| {
+            entry
+                .source_name()
+                .is_some_and(|source| excluded_dirs.contains(source.as_str()))
+        })
+        .map(|(name, _)| name.as_str().to_string())
+        .collect();
+    let discovered_names: HashSet<String> = skills
+        .iter()
+        .map(|s| s.name.as_str().to_string())
+        .chain(kept_from_excluded.iter().cloned())
+        .collect();
 
     // Warn about disabled_directories that don't match any configured directory
     if !quiet {
@@ -2187,18 +3016,36 @@ pub fn sync(
     //    by the single `cleanup` step span at the end of the pipeline; the
     //    library-cleanup portion happens outside of any step span (small,
     //    fast, and naming-collision-free under the OBS-03 grep contract).
-    let cleanup_result = cleanup::cleanup_library(
-        paths.library_dir(),
-        &discovered_names,
-        &mut manifest,
-        config,
-        dry_run,
-        quiet,
-        no_input,
-    )?;
+    //    Skipped under `--only`, which must not touch unrelated skills.
+    let cleanup_result = if only_names.is_some() {
+        CleanupResult::default()
+    } else {
+        let phase_start = Instant::now();
+        let library_entries = manifest.len();
+        let result = cleanup::cleanup_library(
+            paths.library_dir(),
+            &discovered_names,
+            &mut manifest,
+            config,
+            dry_run,
+            quiet,
+            no_input,
+        )?;
+        stats.record("cleanup: library", phase_start, Some(library_entries));
+        result
+    };
 
-    // Regenerate lockfile after cleanup so it reflects removals
-    let new_lockfile = lockfile::generate(&manifest, &skills);
+    // Regenerate lockfile after cleanup so it reflects removals. Skills
+    // from excluded directories keep their previous entries, provenance
+    // included, since they weren't discovered this run.
+    let mut new_lockfile = lockfile::generate(&manifest, &skills);
+    if let Some(ref old) = old_lockfile {
+        for (name, entry) in &old.skills {
+            if kept_from_excluded.contains(name.as_str()) {
+                new_lockfile.skills.insert(name.clone(), entry.clone());
+            }
+        }
+    }
 
     // Stage boundary: cancellation checked before distribute begins (D-12).
     if cancel.is_cancelled() {
@@ -2206,40 +3053,75 @@ pub fn sync(
     }
     // 5. Distribute to directories with distribution roles. OBS-03:
     //    `distribute` step span. D-09/D-11: a single Distribute stage spans the
-    //    per-directory loop; SyncStageProgress reports per-directory progress
-    //    (current/total) so a GUI can show a determinate bar. The CLI's
-    //    IndicatifSink keeps one spinner for the whole stage (the per-directory
-    //    "Distributing to {name}..." message was TTY-transient and is not part
-    //    of captured output).
+    //    per-directory loop; SyncStageProgress reports per-skill progress
+    //    (current/total across every directory) so a GUI can show a
+    //    determinate bar. The CLI's IndicatifSink switches from its spinner
+    //    to a bar for large totals; either is TTY-transient and not part of
+    //    captured output.
+    // A dry run neither deletes the library entries cleanup flagged as
+    // gone nor copies new skills in. Distribute as if it had, so each
+    // directory's delta matches what a real run would do.
+    let (dry_run_selection, pending_links) = if dry_run {
+        let gone: HashSet<&str> = cleanup_result
+            .bucket_b_missing_from_disk
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        let selection: HashSet<String> = library_entry_names(paths.library_dir())?
+            .into_iter()
+            .filter(|n| !gone.contains(n.as_str()))
+            .filter(|n| only_names.as_ref().is_none_or(|only| only.contains(n)))
+            .collect();
+        let pending: Vec<SkillName> = consolidate_result
+            .created_names
+            .iter()
+            .filter(|n| !paths.library_dir().join(n.as_str()).is_dir())
+            .cloned()
+            .collect();
+        (Some(selection), pending)
+    } else {
+        (None, Vec::new())
+    };
     let distribute_results = {
         let _span = info_span!("distribute").entered();
         sink.emit(ProgressEvent::SyncStageStarted {
             stage: SyncStage::Distribute,
         });
         let mut results = Vec::new();
-        let dirs: Vec<_> = config.distribution_dirs().collect();
-        let total = dirs.len();
-        for (idx, (name, dir_config)) in dirs.into_iter().enumerate() {
-            if machine_prefs.is_directory_disabled(name.as_str()) {
-                debug!(
-                    "Skipping directory '{}' (disabled in machine preferences)",
-                    name
-                );
-                continue;
-            }
+        let dirs: Vec<_> = config
+            .distribution_dirs()
+            .filter(|(name, _)| {
+                if machine_prefs.is_directory_disabled(name.as_str()) {
+                    debug!(
+                        "Skipping directory '{}' (disabled in machine preferences)",
+                        name
+                    );
+                    return false;
+                }
+                if excluded_dirs.contains(name.as_str()) {
+                    debug!("Skipping directory '{}' (--exclude-source)", name);
+                    return false;
+                }
+                true
+            })
+            .collect();
+        let total = dirs.len() * distribute::library_skill_count(paths.library_dir());
+        let mut done = 0;
+        // D-08: per-stage subtitle. Distribute reports the skill being
+        // linked. Under `--parallel` the directories share one counter, so
+        // `current` still climbs steadily while `item` interleaves.
+        let on_skill = Mutex::new(|skill: &str| {
+            done += 1;
             sink.emit(ProgressEvent::SyncStageProgress {
                 stage: SyncStage::Distribute,
-                current: idx,
+                current: done,
                 total,
-                // D-08: per-stage subtitle. Distribute iterates per
-                // distribution directory; the current `name` is the
-                // DirectoryName receiving symlinks. Per-skill emission inside
-                // distribute::distribute_to_directory is a future-plan
-                // expansion — when it lands, set `item: Some(skill_name.to_string())`
-                // there instead.
-                item: Some(name.to_string()),
+                item: Some(skill.to_string()),
             });
-            let result = distribute::distribute_to_directory(
+        });
+        let outcomes = distribute::across_directories(&dirs, parallel, |(name, dir_config)| {
+            let phase_start = Instant::now();
+            let result = distribute::distribute_selected_to_directory(
                 paths.library_dir(),
                 name,
                 dir_config,
@@ -2247,7 +3129,44 @@ pub fn sync(
                 &machine_prefs,
                 dry_run,
                 force,
+                adopt,
+                config.relative_links,
+                dry_run_selection.as_ref().or(only_names.as_ref()),
+                &mut |skill| {
+                    let mut on_skill = on_skill.lock().unwrap_or_else(PoisonError::into_inner);
+                    on_skill(skill)
+                },
             )?;
+            if config.link_marker && !dry_run {
+                link_marker::record(&dir_config.path, paths.library_dir(), &result.changed_names)?;
+            }
+            Ok((result, phase_start.elapsed()))
+        });
+        for ((name, dir_config), outcome) in dirs.iter().zip(outcomes) {
+            let (mut result, elapsed) = outcome?;
+            distribute::count_pending_links(
+                &mut result,
+                paths.library_dir(),
+                dir_config,
+                &manifest,
+                &machine_prefs,
+                force,
+                &pending_links,
+            );
+            stats.record_elapsed(
+                format!("distribute: {name}"),
+                elapsed,
+                Some(
+                    result.changed
+                        + result.unchanged
+                        + result.skipped
+                        + result.disabled
+                        + result.skipped_managed,
+                ),
+            );
+            if let Some(plan) = plan.as_deref_mut() {
+                plan.distributions.push(result.clone());
+            }
             results.push(result);
         }
         sink.emit(ProgressEvent::SyncStageFinished {
@@ -2276,36 +3195,87 @@ pub fn sync(
     if cancel.is_cancelled() {
         anyhow::bail!("sync cancelled");
     }
-    let (removed_from_targets, distribution_cleanup_failures, excluded_skills) = {
+    let phase_start = Instant::now();
+    let (
+        removed_from_targets,
+        removed_by_target,
+        pruned_from_disabled,
+        distribution_cleanup_failures,
+        excluded_skills,
+    ) = {
         let _span = info_span!("cleanup").entered();
         sink.emit(ProgressEvent::SyncStageStarted {
             stage: SyncStage::Cleanup,
         });
         let mut removed: usize = 0;
+        let mut removed_by_target: BTreeMap<DirectoryName, usize> = BTreeMap::new();
         let mut excluded: Vec<cleanup::ExcludedSkill> = Vec::new();
         let mut failures: Vec<cleanup::DistributionCleanupFailure> = Vec::new();
-        for (name, dir_config) in config.distribution_dirs() {
+        // Library entries that still back a skill. Entries library cleanup
+        // flagged as missing from source (including newly excluded skills)
+        // are left out even when their copy survives — a declined deletion
+        // prompt, or a dry run — so links to them are removed too.
+        let gone: HashSet<&str> = cleanup_result
+            .bucket_b_missing_from_disk
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        let live_skills: HashSet<String> = library_entry_names(paths.library_dir())?
+            .into_iter()
+            .filter(|n| !gone.contains(n.as_str()))
+            .collect();
+        // `--only` leaves links to other skills alone, and an excluded
+        // directory is not touched at all.
+        let dirs = config
+            .distribution_dirs()
+            .filter(|(name, _)| only_names.is_none() && !excluded_dirs.contains(name.as_str()));
+        let mut pruned: usize = 0;
+        for (name, dir_config) in dirs {
             let skills_dir = &dir_config.path;
-            removed += cleanup::cleanup_target(skills_dir, paths.library_dir(), dry_run)?;
+            // `--prune-disabled`: with an empty live set every link into
+            // the library counts as stale; nothing else in the directory
+            // is touched.
+            if prune_disabled && machine_prefs.is_directory_disabled(name.as_str()) {
+                pruned += cleanup::cleanup_target(
+                    skills_dir,
+                    paths.library_dir(),
+                    Some(&HashSet::new()),
+                    dry_run,
+                )?;
+                continue;
+            }
+            let stale = cleanup::cleanup_target(
+                skills_dir,
+                paths.library_dir(),
+                Some(&live_skills),
+                dry_run,
+            )?;
             // Also clean up symlinks for disabled skills (global + per-directory).
             // The returned Vec<ExcludedSkill> seeds Bucket C of the unified
             // three-bucket cleanup renderer (UX-01 D-UX01-1 / D-UX01-2).
             let (n, dir_excluded, dir_failures) = cleanup_disabled_from_target(
-                skills_dir,
+                dir_config,
                 paths.library_dir(),
                 name,
                 &machine_prefs,
                 dry_run,
             )?;
-            removed += n;
+            removed += stale + n;
+            removed_by_target.insert(name.clone(), stale + n);
             excluded.extend(dir_excluded);
             failures.extend(dir_failures);
         }
         sink.emit(ProgressEvent::SyncStageFinished {
             stage: SyncStage::Cleanup,
         });
-        (removed, failures, excluded)
+        (removed, removed_by_target, pruned, failures, excluded)
     };
+    if only_names.is_none() {
+        stats.record("cleanup: targets", phase_start, Some(manifest.len()));
+    }
+    if let Some(plan) = plan {
+        plan.removed_from_targets = Some(removed_from_targets);
+    }
 
     // Stage boundary: cancellation checked before the Save stage begins (D-12).
     // This is the last safe-to-cancel point: every persist below is an atomic
@@ -2317,6 +3287,7 @@ pub fn sync(
         anyhow::bail!("sync cancelled");
     }
     // 7. Save manifest, gitignore, and lockfile
+    let phase_start = Instant::now();
     sink.emit(ProgressEvent::SyncStageStarted {
         stage: SyncStage::Save,
     });
@@ -2334,6 +3305,9 @@ pub fn sync(
     }
     if !dry_run && paths.library_dir().is_dir() {
         library::generate_gitignore(paths.library_dir(), &manifest)?;
+        if config.generate_index {
+            library::generate_index(paths.library_dir(), &manifest, config.skill_file_names())?;
+        }
     }
     if !dry_run && paths.config_dir().is_dir() {
         generate_tome_home_gitignore(paths.config_dir())?;
@@ -2343,17 +3317,25 @@ pub fn sync(
     sink.emit(ProgressEvent::SyncStageFinished {
         stage: SyncStage::Save,
     });
+    stats.record("save", phase_start, None);
 
     let report = SyncReport {
         consolidate: consolidate_result,
         distributions: distribute_results,
         cleanup: cleanup_result,
         removed_from_targets,
+        removed_by_target,
+        pruned_from_disabled,
         reconcile: reconcile_report,
     };
 
-    if !quiet {
-        render_sync_report(&report);
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&sync_report_json(&report, dry_run))?
+        );
+    } else if !quiet {
+        render_sync_report(&report, verbose, dry_run);
     }
 
     // 6b. Render the unified three-bucket cleanup output + any aggregated
@@ -2398,54 +3380,271 @@ pub fn sync(
         }
     }
 
-    // Offer git commit if tome home is a git repo with changes
-    let committed = if !dry_run && !quiet {
-        offer_git_commit(
-            paths.tome_home(),
-            report.consolidate.created,
-            report.consolidate.updated,
-            report.cleanup.removed_from_library,
-        )?
-    } else {
-        false
+    // Commit without asking under --library-git-commit; otherwise offer to
+    // commit if tome home is a git repo with changes.
+    let committed = if dry_run {
+        false
+    } else if let Some(message) = library_git_commit {
+        let message = message.map(str::to_string).unwrap_or_else(|| {
+            sync_commit_message(
+                report.consolidate.created,
+                report.consolidate.updated,
+                report.cleanup.removed_from_library,
+            )
+        });
+        commit_library(paths, &message, quiet)
+    } else if !quiet {
+        offer_git_commit(
+            paths.tome_home(),
+            report.consolidate.created,
+            report.consolidate.updated,
+            report.cleanup.removed_from_library,
+        )?
+    } else {
+        false
+    };
+
+    // Push to remote after commit (only if something was committed)
+    if committed && has_remote {
+        match backup::push(paths.tome_home()) {
+            Ok(()) => {
+                if !quiet {
+                    println!("  {} Pushed to remote", console::style("↑").cyan());
+                }
+            }
+            Err(e) => warn!("remote push failed: {e}"),
+        }
+    }
+
+    if show_stats {
+        println!("\n{}", stats.render());
+    }
+
+    // SAFE-01 mirror: surface non-zero exit when distribution-symlink
+    // cleanup hit per-symlink I/O failures. The grouped summary already
+    // printed via cleanup::render_distribution_cleanup_failures; this
+    // bail surfaces the exit code only.
+    if !distribution_cleanup_failures.is_empty() {
+        anyhow::bail!(
+            "{} distribution cleanup operation(s) failed during sync (see \
+             grouped summary above)",
+            distribution_cleanup_failures.len(),
+        );
+    }
+
+    // RESEARCH OQ-6: surface non-zero exit when reconcile failed any
+    // install/update. The grouped failure summary already printed via
+    // marketplace::render_install_failures; this bail surfaces the exit
+    // code only.
+    if !reconcile_install_failures.is_empty() {
+        anyhow::bail!(
+            "{} plugin install/update operation(s) failed during reconcile (see \
+             grouped summary above)",
+            reconcile_install_failures.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// `tome sync --targets-only`: relink every distribution directory from the
+/// library as it stands. Reconcile, discovery, consolidation, triage, and
+/// library cleanup are skipped, and the manifest and lockfile are left
+/// untouched, so this is only as current as the last full sync.
+fn sync_targets_only(
+    config: &Config,
+    paths: &TomePaths,
+    opts: SyncOptions<'_>,
+    sink: &dyn ProgressSink,
+    cancel: &CancelToken,
+) -> Result<()> {
+    let SyncOptions {
+        dry_run,
+        force,
+        adopt,
+        verbose,
+        quiet,
+        machine_prefs,
+        exclude_sources,
+        prune_disabled,
+        parallel,
+        json,
+        ..
+    } = opts;
+    let _sync_span = info_span!("sync", dry_run = dry_run, force = force).entered();
+
+    if dry_run && !quiet {
+        eprintln!(
+            "{}",
+            style("[dry-run] No changes will be made").yellow().bold()
+        );
+    }
+
+    let library_dir = paths.library_dir();
+    let live_skills: HashSet<String> = library_entry_names(library_dir)?
+        .into_iter()
+        .filter(|n| !n.starts_with('.') && library_dir.join(n).is_dir())
+        .collect();
+    anyhow::ensure!(
+        !live_skills.is_empty(),
+        "library at {} is empty or missing — run `tome sync` without --targets-only to populate it first",
+        library_dir.display()
+    );
+    let manifest = manifest::load(paths.config_dir())?;
+
+    let excluded_dirs: HashSet<&str> = if exclude_sources.is_empty() {
+        HashSet::new()
+    } else {
+        let (filtered, unknown) = config.without_sources(exclude_sources);
+        for name in &unknown {
+            warn!("{}", unknown_source_warning(name));
+        }
+        config
+            .directories
+            .keys()
+            .filter(|name| !filtered.directories.contains_key(*name))
+            .map(DirectoryName::as_str)
+            .collect()
+    };
+
+    if cancel.is_cancelled() {
+        anyhow::bail!("sync cancelled");
+    }
+    let distributions = {
+        let _span = info_span!("distribute").entered();
+        sink.emit(ProgressEvent::SyncStageStarted {
+            stage: SyncStage::Distribute,
+        });
+        let mut results = Vec::new();
+        let dirs: Vec<_> = config
+            .distribution_dirs()
+            .filter(|(name, _)| {
+                !machine_prefs.is_directory_disabled(name.as_str())
+                    && !excluded_dirs.contains(name.as_str())
+            })
+            .collect();
+        let total = dirs.len() * distribute::library_skill_count(library_dir);
+        let mut done = 0;
+        let on_skill = Mutex::new(|skill: &str| {
+            done += 1;
+            sink.emit(ProgressEvent::SyncStageProgress {
+                stage: SyncStage::Distribute,
+                current: done,
+                total,
+                item: Some(skill.to_string()),
+            });
+        });
+        let outcomes = distribute::across_directories(&dirs, parallel, |(name, dir_config)| {
+            let result = distribute::distribute_selected_to_directory(
+                library_dir,
+                name,
+                dir_config,
+                &manifest,
+                machine_prefs,
+                dry_run,
+                force,
+                adopt,
+                config.relative_links,
+                None,
+                &mut |skill| {
+                    let mut on_skill = on_skill.lock().unwrap_or_else(PoisonError::into_inner);
+                    on_skill(skill)
+                },
+            )?;
+            if config.link_marker && !dry_run {
+                link_marker::record(&dir_config.path, library_dir, &result.changed_names)?;
+            }
+            Ok(result)
+        });
+        for outcome in outcomes {
+            results.push(outcome?);
+        }
+        sink.emit(ProgressEvent::SyncStageFinished {
+            stage: SyncStage::Distribute,
+        });
+        results
+    };
+
+    if cancel.is_cancelled() {
+        anyhow::bail!("sync cancelled");
+    }
+    let mut removed_from_targets = 0;
+    let mut removed_by_target: BTreeMap<DirectoryName, usize> = BTreeMap::new();
+    let mut pruned_from_disabled = 0;
+    let mut excluded_skills: Vec<cleanup::ExcludedSkill> = Vec::new();
+    let mut failures: Vec<cleanup::DistributionCleanupFailure> = Vec::new();
+    {
+        let _span = info_span!("cleanup").entered();
+        sink.emit(ProgressEvent::SyncStageStarted {
+            stage: SyncStage::Cleanup,
+        });
+        let dirs = config
+            .distribution_dirs()
+            .filter(|(name, _)| !excluded_dirs.contains(name.as_str()));
+        for (name, dir_config) in dirs {
+            let skills_dir = &dir_config.path;
+            if prune_disabled && machine_prefs.is_directory_disabled(name.as_str()) {
+                pruned_from_disabled += cleanup::cleanup_target(
+                    skills_dir,
+                    library_dir,
+                    Some(&HashSet::new()),
+                    dry_run,
+                )?;
+                continue;
+            }
+            let stale =
+                cleanup::cleanup_target(skills_dir, library_dir, Some(&live_skills), dry_run)?;
+            let (n, dir_excluded, dir_failures) = cleanup_disabled_from_target(
+                dir_config,
+                library_dir,
+                name,
+                machine_prefs,
+                dry_run,
+            )?;
+            removed_from_targets += stale + n;
+            removed_by_target.insert(name.clone(), stale + n);
+            excluded_skills.extend(dir_excluded);
+            failures.extend(dir_failures);
+        }
+        sink.emit(ProgressEvent::SyncStageFinished {
+            stage: SyncStage::Cleanup,
+        });
+    }
+
+    let report = SyncReport {
+        consolidate: ConsolidateResult::default(),
+        distributions,
+        cleanup: CleanupResult::default(),
+        removed_from_targets,
+        removed_by_target,
+        pruned_from_disabled,
+        reconcile: None,
     };
-
-    // Push to remote after commit (only if something was committed)
-    if committed && has_remote {
-        match backup::push(paths.tome_home()) {
-            Ok(()) => {
-                if !quiet {
-                    println!("  {} Pushed to remote", console::style("↑").cyan());
-                }
-            }
-            Err(e) => warn!("remote push failed: {e}"),
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&sync_report_json(&report, dry_run))?
+        );
+    } else if !quiet {
+        render_sync_report(&report, verbose, dry_run);
+    }
+    if !quiet {
+        let mut stderr = std::io::stderr().lock();
+        if let Err(e) = cleanup::render_cleanup_buckets(&mut stderr, &[], &[], &excluded_skills) {
+            tracing::warn!("could not render cleanup buckets to stderr: {e}");
+        }
+        if let Err(e) = cleanup::render_distribution_cleanup_failures(&mut stderr, &failures) {
+            tracing::warn!("could not render distribution cleanup failures to stderr: {e}");
         }
     }
 
-    // SAFE-01 mirror: surface non-zero exit when distribution-symlink
-    // cleanup hit per-symlink I/O failures. The grouped summary already
-    // printed via cleanup::render_distribution_cleanup_failures; this
-    // bail surfaces the exit code only.
-    if !distribution_cleanup_failures.is_empty() {
+    if !failures.is_empty() {
         anyhow::bail!(
             "{} distribution cleanup operation(s) failed during sync (see \
              grouped summary above)",
-            distribution_cleanup_failures.len(),
-        );
-    }
-
-    // RESEARCH OQ-6: surface non-zero exit when reconcile failed any
-    // install/update. The grouped failure summary already printed via
-    // marketplace::render_install_failures; this bail surfaces the exit
-    // code only.
-    if !reconcile_install_failures.is_empty() {
-        anyhow::bail!(
-            "{} plugin install/update operation(s) failed during reconcile (see \
-             grouped summary above)",
-            reconcile_install_failures.len()
+            failures.len(),
         );
     }
-
     Ok(())
 }
 
@@ -2543,14 +3742,32 @@ pub fn retry_partial_failures(
     sync_with_outcome(config, paths, options, sink, cancel)
 }
 
+/// Names of the entries directly under `library_dir`; empty when it does
+/// not exist yet.
+fn library_entry_names(library_dir: &Path) -> Result<Vec<String>> {
+    if !library_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(library_dir)
+        .with_context(|| format!("failed to read library dir {}", library_dir.display()))?
+    {
+        let entry =
+            entry.with_context(|| format!("failed to read entry in {}", library_dir.display()))?;
+        names.push(entry.file_name().to_string_lossy().into_owned());
+    }
+    Ok(names)
+}
+
 /// Remove symlinks from a target directory that point to disabled skills,
 /// surfacing each removal as a `cleanup::ExcludedSkill` so `lib.rs::sync`
 /// can render them through the unified three-bucket cleanup output (UX-01
 /// Bucket C — D-UX01-1, D-UX01-2).
 ///
-/// Unlike `cleanup::cleanup_target` (which only removes *broken* symlinks),
-/// this removes symlinks even if their target still exists on disk — because
-/// the skill has been disabled in machine preferences.
+/// Unlike `cleanup::cleanup_target` (which removes symlinks whose library
+/// entry is gone or no longer backs a skill), this removes symlinks to live
+/// library entries — because the skill has been disabled in machine
+/// preferences, or filtered out by the directory's `include`/`exclude`.
 ///
 /// Only removes symlinks that point into the library directory, matching the
 /// origin check in `cleanup::cleanup_target`.
@@ -2561,6 +3778,9 @@ pub fn retry_partial_failures(
 /// - **Per-directory** — skill is in `directories.<dir>.disabled`
 ///   (blocklist) or absent from `directories.<dir>.enabled` (allowlist).
 ///   Reported as `ExcludedSkill { directory: Some(<dir>) }`.
+/// - **Config filter** — skill is outside the directory's `include` list or
+///   in its `exclude` list in `tome.toml`. Reported as
+///   `ExcludedSkill { directory: Some(<dir>), in_config: true }`.
 ///
 /// Global takes precedence in reporting when a skill is both globally and
 /// per-directory disabled (mirrors `MachinePrefs::is_skill_allowed`
@@ -2572,7 +3792,7 @@ pub fn retry_partial_failures(
 /// 2. Drain `excluded_skills` into `cleanup::render_cleanup_buckets`
 ///    Bucket C for the unified user-facing summary.
 fn cleanup_disabled_from_target(
-    target_dir: &Path,
+    dir_config: &config::DirectoryConfig,
     library_dir: &Path,
     dir_name: &config::DirectoryName,
     machine_prefs: &machine::MachinePrefs,
@@ -2584,6 +3804,7 @@ fn cleanup_disabled_from_target(
 )> {
     let mut excluded: Vec<cleanup::ExcludedSkill> = Vec::new();
     let mut failures: Vec<cleanup::DistributionCleanupFailure> = Vec::new();
+    let target_dir = &dir_config.path;
 
     if !target_dir.is_dir() {
         return Ok((0, excluded, failures));
@@ -2597,6 +3818,7 @@ fn cleanup_disabled_from_target(
         );
         library_dir.to_path_buf()
     });
+    let ledger = link_marker::load(target_dir)?;
 
     let mut removed = 0;
     let entries = std::fs::read_dir(target_dir)
@@ -2611,14 +3833,18 @@ fn cleanup_disabled_from_target(
         }
 
         let name_owned = entry.file_name().to_string_lossy().into_owned();
+        if !link_marker::is_marked(ledger.as_ref(), &name_owned) {
+            continue;
+        }
         let is_global = machine_prefs.is_disabled(&name_owned);
         let is_allowed = machine_prefs.is_skill_allowed(&name_owned, dir_name.as_str());
+        let in_config = is_allowed && !dir_config.includes_skill(&name_owned);
 
         // `is_skill_allowed` returns false for both global AND per-directory
         // exclusion. We split the cases for reporting — global takes
         // precedence in the bucket-C surface even though the underlying
         // removal logic is the same.
-        if is_global || !is_allowed {
+        if is_global || !is_allowed || in_config {
             // Only remove if symlink points into the tome library. Per-symlink
             // I/O failures aggregate into `failures` instead of bailing the
             // loop so one stale ENOENT/EACCES does not erase the user-facing
@@ -2672,6 +3898,7 @@ fn cleanup_disabled_from_target(
             excluded.push(cleanup::ExcludedSkill {
                 name: skill_name,
                 directory,
+                in_config: in_config && !is_global,
             });
         }
     }
@@ -2679,7 +3906,56 @@ fn cleanup_disabled_from_target(
     Ok((removed, excluded, failures))
 }
 
-fn render_sync_report(report: &SyncReport) {
+/// Print the end-of-sync summary. Under `--verbose`, each count line is
+/// followed by the names of the skills behind it; skipped entries are
+/// always listed by path.
+/// The `tome sync --format json` summary: library counts, one object per
+/// distribution directory, and cleanup counts.
+/// `--format json` output for a sync that stopped before touching anything
+/// (no directories, no skills): the all-zero summary, so a JSON consumer
+/// always gets exactly one object.
+fn print_empty_sync_report_json(dry_run: bool) -> Result<()> {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&sync_report_json(&SyncReport::default(), dry_run))?
+    );
+    Ok(())
+}
+
+fn sync_report_json(report: &SyncReport, dry_run: bool) -> serde_json::Value {
+    let targets: Vec<serde_json::Value> = report
+        .distributions
+        .iter()
+        .map(|dr| {
+            serde_json::json!({
+                "directory": dr.directory_name,
+                "linked": dr.changed,
+                "unchanged": dr.unchanged,
+                "skipped": dr.skipped,
+                "disabled": dr.disabled,
+                "skipped_managed": dr.skipped_managed,
+                "removed": report.removed_by_target.get(&dr.directory_name).copied().unwrap_or(0),
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "dry_run": dry_run,
+        "library": {
+            "created": report.consolidate.created,
+            "unchanged": report.consolidate.unchanged,
+            "updated": report.consolidate.updated,
+            "skipped": report.consolidate.skipped,
+        },
+        "targets": targets,
+        "cleanup": {
+            "removed_from_library": report.cleanup.removed_from_library,
+            "removed_from_targets": report.removed_from_targets,
+            "pruned_from_disabled": report.pruned_from_disabled,
+        },
+    })
+}
+
+fn render_sync_report(report: &SyncReport, verbose: bool, dry_run: bool) {
     println!("{}", style("Sync complete").green().bold());
     println!(
         "  Library: {} created, {} unchanged, {} updated{}",
@@ -2688,17 +3964,60 @@ fn render_sync_report(report: &SyncReport) {
         report.consolidate.updated,
         skipped_note(report.consolidate.skipped)
     );
+    if verbose {
+        print_skill_names("created", &report.consolidate.created_names);
+        print_skill_names("updated", &report.consolidate.updated_names);
+    }
+    print_skipped_paths(&report.consolidate.skipped_paths);
 
     for dr in &report.distributions {
+        // A dry run shows the net change per directory: links that would be
+        // created and stale links that would be removed.
+        let counts = if dry_run {
+            let removed = report
+                .removed_by_target
+                .get(&dr.directory_name)
+                .copied()
+                .unwrap_or(0);
+            format!(
+                "would link {}, would remove {}, unchanged {}",
+                style(dr.changed).cyan(),
+                style(removed).yellow(),
+                dr.unchanged
+            )
+        } else {
+            format!(
+                "{} linked, {} unchanged",
+                style(dr.changed).cyan(),
+                dr.unchanged
+            )
+        };
         println!(
-            "  {}: {} linked, {} unchanged{}{}{}",
+            "  {}: {}{}{}{}",
             style(&dr.directory_name).bold(),
-            style(dr.changed).cyan(),
-            dr.unchanged,
+            counts,
             skipped_note(dr.skipped),
             disabled_note(dr.disabled),
             managed_note(dr.skipped_managed)
         );
+        if verbose {
+            print_skill_names("linked", &dr.changed_names);
+        }
+        print_skipped_paths(&dr.skipped_paths);
+    }
+
+    // `--force --adopt`: name every entry that was moved aside so the user
+    // can inspect (and eventually delete) the old copy.
+    let backups = report
+        .consolidate
+        .backed_up
+        .iter()
+        .chain(report.distributions.iter().flat_map(|dr| &dr.backed_up));
+    for backup in backups {
+        println!(
+            "  Backed up existing entry to {}",
+            style(backup.display()).yellow()
+        );
     }
 
     if report.cleanup.removed_from_library > 0 {
@@ -2715,6 +4034,13 @@ fn render_sync_report(report: &SyncReport) {
         );
     }
 
+    if report.pruned_from_disabled > 0 {
+        println!(
+            "  Pruned {} link(s) from disabled directories",
+            style(report.pruned_from_disabled).yellow()
+        );
+    }
+
     // Phase 18 OBS-05 (D-ENV-4): reconcile classification line, emitted
     // immediately above the per-bucket cleanup summary block (rendered
     // separately to stderr by the caller AFTER this function returns).
@@ -2740,6 +4066,36 @@ fn render_sync_report(report: &SyncReport) {
             print!("{}", detail);
         }
     }
+
+    let warnings = tracing_init::warning_count();
+    if warnings > 0 {
+        println!("  {} warning(s)", style(warnings).yellow());
+    }
+}
+
+/// `    created: a, b` — one verbose detail line under a sync summary count,
+/// names sorted. Prints nothing for an empty list.
+fn print_skill_names(label: &str, names: &[SkillName]) {
+    if names.is_empty() {
+        return;
+    }
+    let mut sorted: Vec<&str> = names.iter().map(SkillName::as_str).collect();
+    sorted.sort_unstable();
+    let joined = sorted.join(", ");
+    println!("    {}", style(format!("{label}: {joined}")).dim());
+}
+
+/// `    skipped: ~/.claude/skills/foo (path conflict)` — one line per entry
+/// that blocked a skill, printed whether or not `--verbose` is set so the
+/// user can see what to move or remove.
+fn print_skipped_paths(paths: &[PathBuf]) {
+    for path in paths {
+        println!(
+            "    skipped: {} {}",
+            style(paths::collapse_home(path)).yellow(),
+            style("(path conflict)").dim()
+        );
+    }
 }
 
 /// List all discovered skills.
@@ -2748,13 +4104,48 @@ fn render_sync_report(report: &SyncReport) {
 /// `list::collect`; this function only formats the resulting [`list::ListReport`]
 /// as text or JSON. The GUI calls `list::collect` directly and renders the
 /// report without this CLI formatting.
-fn list(config: &Config, quiet: bool, json: bool) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+fn list(
+    config: &Config,
+    quiet: bool,
+    json: bool,
+    long: bool,
+    sources: &[String],
+    tags: &[String],
+    tag_mode: list::TagMode,
+    since: Option<std::time::Duration>,
+) -> Result<()> {
     let report = list::collect(config)?;
-    let skills = report.skills;
+    let now = std::time::SystemTime::now();
+    // Skills whose SKILL.md mtime can't be read stay in a `--since`
+    // listing; they're named in a note instead of silently dropped.
+    let mut undated = Vec::new();
+    let skills: Vec<_> = report
+        .skills
+        .into_iter()
+        .filter(|s| sources.is_empty() || sources.iter().any(|n| s.source_name == n.as_str()))
+        .filter(|s| list::matches_tags(s, tags, tag_mode))
+        .filter(|s| {
+            let Some(window) = since else {
+                return true;
+            };
+            list::modified_within(s, window, now).unwrap_or_else(|| {
+                undated.push(s.name.to_string());
+                true
+            })
+        })
+        .collect();
     if !quiet {
         for w in &report.warnings {
             eprintln!("warning: {}", w);
         }
+        if !undated.is_empty() {
+            eprintln!(
+                "note: included {} skill(s) whose modification time could not be read: {}",
+                undated.len(),
+                undated.join(", ")
+            );
+        }
     }
 
     if json {
@@ -2767,6 +4158,9 @@ fn list(config: &Config, quiet: bool, json: bool) -> Result<()> {
                     "path": s.path,
                     "managed": s.origin.is_managed(),
                 });
+                if s.is_disabled_in_frontmatter() {
+                    row["disabled"] = serde_json::json!(true);
+                }
                 if let Some(p) = s.origin.provenance() {
                     row["registry_id"] = serde_json::json!(p.registry_id);
                     if let Some(v) = &p.version {
@@ -2787,6 +4181,14 @@ fn list(config: &Config, quiet: bool, json: bool) -> Result<()> {
         return Ok(());
     }
 
+    if skills.is_empty() && !tags.is_empty() {
+        println!("No skills match the given tag(s).");
+        return Ok(());
+    }
+    if skills.is_empty() && since.is_some() {
+        println!("No skills modified within the --since window.");
+        return Ok(());
+    }
     if skills.is_empty() {
         println!("No skills found. Run `tome init` to configure sources.");
         return Ok(());
@@ -2794,13 +4196,17 @@ fn list(config: &Config, quiet: bool, json: bool) -> Result<()> {
 
     use tabled::settings::{Modify, Style, object::Rows};
 
-    let mut rows: Vec<[String; 4]> = Vec::with_capacity(skills.len() + 1);
-    rows.push([
+    let mut rows: Vec<Vec<String>> = Vec::with_capacity(skills.len() + 1);
+    let mut header = vec![
         "SKILL".to_string(),
         "SOURCE".to_string(),
         "VERSION".to_string(),
-        "PATH".to_string(),
-    ]);
+    ];
+    if long {
+        header.push("DESCRIPTION".to_string());
+    }
+    header.push("PATH".to_string());
+    rows.push(header);
     for s in &skills {
         let version = s
             .origin
@@ -2808,12 +4214,23 @@ fn list(config: &Config, quiet: bool, json: bool) -> Result<()> {
             .and_then(|p| p.version.as_deref())
             .unwrap_or("")
             .to_string();
-        rows.push([
-            s.name.to_string(),
-            s.source_name.as_str().to_string(),
-            version,
-            s.path.display().to_string(),
-        ]);
+        let name = if s.is_disabled_in_frontmatter() {
+            format!("{} (disabled)", s.name)
+        } else {
+            s.name.to_string()
+        };
+        let mut row = vec![name, s.source_name.as_str().to_string(), version];
+        if long {
+            let description = s
+                .frontmatter
+                .as_ref()
+                .and_then(|fm| fm.description.as_deref())
+                .map(|d| list::truncate_description(d, list::DESCRIPTION_WIDTH))
+                .filter(|d| !d.is_empty());
+            row.push(description.unwrap_or_else(|| style("—").dim().to_string()));
+        }
+        row.push(s.path.display().to_string());
+        rows.push(row);
     }
 
     let table = tabled::Table::from_iter(rows)
@@ -2864,7 +4281,9 @@ fn managed_note(count: usize) -> String {
 fn generate_tome_home_gitignore(tome_home: &Path) -> Result<()> {
     let content = "# Auto-generated by tome — do not edit\n\
                    # Internal manifest (recreated by tome sync)\n\
-                   .tome-manifest.json\n";
+                   .tome-manifest.json\n\
+                   # Machine-local discovery cache (rebuilt by tome sync)\n\
+                   .tome-discovery-cache.json\n";
     let gitignore_path = tome_home.join(".gitignore");
 
     // Only write if content would change
@@ -2894,27 +4313,7 @@ fn offer_git_commit(
     if !tome_home.join(".git").exists() || !std::io::stdin().is_terminal() {
         return Ok(false);
     }
-
-    let output = match GitCommand::new("git")
-        .args(["status", "--porcelain"])
-        .current_dir(tome_home)
-        .output()
-    {
-        Ok(o) => o,
-        Err(e) => {
-            eprintln!("warning: could not run git status: {e}");
-            return Ok(false);
-        }
-    };
-
-    if !output.status.success() {
-        eprintln!(
-            "warning: git status returned non-zero exit code {:?}",
-            output.status.code()
-        );
-        return Ok(false);
-    }
-    if output.stdout.is_empty() {
+    if !has_uncommitted_changes(tome_home) {
         return Ok(false);
     }
 
@@ -2929,41 +4328,102 @@ fn offer_git_commit(
         return Ok(false);
     }
 
-    // Stage all tracked files — .gitignore handles exclusions.
     // The repo is at tome_home (~/.tome/) and covers skills, config, and lockfile.
-    let add_output = GitCommand::new("git")
-        .args(["add", "-A"])
-        .current_dir(tome_home)
-        .output()?;
-    if !add_output.status.success() {
-        eprintln!(
-            "warning: git add failed (exit code {:?})",
-            add_output.status.code()
+    Ok(git_commit_all(tome_home, &msg))
+}
+
+/// `tome sync --library-git-commit`: commit the library's repository
+/// without prompting. The repository is `library_dir` itself, or tome home
+/// when the library lives inside it (the layout `tome init` sets up).
+/// Nothing happens when neither is a git repo or there is nothing to commit,
+/// and git failures are warnings, never errors.
+///
+/// Returns `true` if tome home was committed, so the caller can push it.
+fn commit_library(paths: &TomePaths, message: &str, quiet: bool) -> bool {
+    let library_dir = paths.library_dir();
+    let repo = if backup::has_repo(library_dir) {
+        library_dir
+    } else if library_dir.starts_with(paths.tome_home()) && backup::has_repo(paths.tome_home()) {
+        paths.tome_home()
+    } else {
+        debug!(
+            "--library-git-commit: {} is not in a git repository, skipping",
+            library_dir.display()
         );
-        let stderr = String::from_utf8_lossy(&add_output.stderr);
-        if !stderr.trim().is_empty() {
-            eprintln!("  git said: {}", stderr.trim());
-        }
-        return Ok(false);
+        return false;
+    };
+    if !has_uncommitted_changes(repo) || !git_commit_all(repo, message) {
+        return false;
     }
-
-    let commit_output = GitCommand::new("git")
-        .args(["commit", "-m", &msg])
-        .current_dir(tome_home)
-        .output()?;
-    if !commit_output.status.success() {
-        eprintln!(
-            "warning: git commit failed (exit code {:?})",
-            commit_output.status.code()
+    if !quiet {
+        println!(
+            "  {} Committed {} ({message})",
+            style("✓").green(),
+            repo.display()
         );
-        let stderr = String::from_utf8_lossy(&commit_output.stderr);
-        if !stderr.trim().is_empty() {
-            eprintln!("  git said: {}", stderr.trim());
+    }
+    repo == paths.tome_home()
+}
+
+/// Whether `git status --porcelain` in `repo` reports anything. A failing
+/// git is reported as a warning and treated as clean.
+fn has_uncommitted_changes(repo: &Path) -> bool {
+    let output = match GitCommand::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(repo)
+        .output()
+    {
+        Ok(o) => o,
+        Err(e) => {
+            warn!("could not run git status: {e}");
+            return false;
         }
-        return Ok(false);
+    };
+
+    if !output.status.success() {
+        warn!(
+            "git status returned non-zero exit code {:?}",
+            output.status.code()
+        );
+        return false;
     }
+    !output.stdout.is_empty()
+}
 
-    Ok(true)
+/// Stage everything in `repo` (its `.gitignore` handles exclusions) and
+/// commit it with `msg`. Failures are reported through `warn!` (so
+/// `--warnings-as-errors` sees them) and return `false`.
+fn git_commit_all(repo: &Path, msg: &str) -> bool {
+    for (step, args) in [
+        ("add", vec!["add", "-A"]),
+        ("commit", vec!["commit", "-m", msg]),
+    ] {
+        let output = match GitCommand::new("git")
+            .args(&args)
+            .current_dir(repo)
+            .output()
+        {
+            Ok(o) => o,
+            Err(e) => {
+                warn!("could not run git {step}: {e}");
+                return false;
+            }
+        };
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if stderr.trim().is_empty() {
+                warn!("git {step} failed (exit code {:?})", output.status.code());
+            } else {
+                warn!(
+                    "git {step} failed (exit code {:?}): {}",
+                    output.status.code(),
+                    stderr.trim()
+                );
+            }
+            return false;
+        }
+    }
+    true
 }
 
 /// Build a commit message summarizing sync changes.
@@ -3113,9 +4573,45 @@ mod tests {
     /// `SyncStageStarted` event. This pins the "≥1 event per stage" contract
     /// that the GUI (Phase 27) depends on for its per-stage progress UI, and
     /// guards against a future refactor silently dropping a stage's emit.
+    #[test]
+    fn indicatif_sink_switches_to_a_bar_for_large_stages() {
+        let sink = IndicatifSink::new();
+        let progress = |current, total| ProgressEvent::SyncStageProgress {
+            stage: SyncStage::Distribute,
+            current,
+            total,
+            item: None,
+        };
+        let length = || {
+            sink.current
+                .lock()
+                .unwrap()
+                .as_ref()
+                .and_then(ProgressBar::length)
+        };
+
+        sink.emit(ProgressEvent::SyncStageStarted {
+            stage: SyncStage::Distribute,
+        });
+        sink.emit(progress(1, PROGRESS_BAR_THRESHOLD));
+        assert_eq!(length(), None, "small stages keep the spinner");
+
+        sink.emit(ProgressEvent::SyncStageStarted {
+            stage: SyncStage::Distribute,
+        });
+        sink.emit(progress(1, 40));
+        sink.emit(progress(2, 40));
+        assert_eq!(length(), Some(40));
+        assert_eq!(sink.current.lock().unwrap().as_ref().unwrap().position(), 2);
+        sink.emit(ProgressEvent::SyncStageFinished {
+            stage: SyncStage::Distribute,
+        });
+        assert!(sink.current.lock().unwrap().is_none());
+    }
+
     #[test]
     fn sync_emits_at_least_one_event_per_stage() {
-        use crate::config::{DirectoryConfig, DirectoryRole, DirectoryType};
+        use crate::config::{DirectoryConfig, DirectoryRole, DirectoryType, NameTransform};
         use crate::progress::RecordingSink;
 
         let tmp = TempDir::new().unwrap();
@@ -3150,7 +4646,14 @@ mod tests {
                 role: Some(DirectoryRole::Source),
                 git_ref: None,
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
         config.directories.insert(
@@ -3161,7 +4664,14 @@ mod tests {
                 role: Some(DirectoryRole::Synced),
                 git_ref: None,
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
 
@@ -3176,6 +4686,7 @@ mod tests {
             SyncOptions {
                 dry_run: false,
                 force: false,
+                adopt: false,
                 no_triage: true,
                 no_input: true,
                 no_install: true,
@@ -3184,6 +4695,18 @@ mod tests {
                 machine_path: &machine_path,
                 machine_prefs: &machine_prefs,
                 start_stage: None,
+                dump_plan_on_error: false,
+                no_cache: false,
+                stats: false,
+                only: &[],
+                exclude_sources: &[],
+                tags: &[],
+                tag_mode: Default::default(),
+                prune_disabled: false,
+                targets_only: false,
+                parallel: 1,
+                json: false,
+                library_git_commit: None,
             },
             &sink,
             &CancelToken::new(),
@@ -3239,6 +4762,7 @@ mod tests {
                 origin: SkillOrigin::Local,
                 frontmatter: None,
                 synced_at: None,
+                skill_file: "SKILL.md".to_string(),
             },
             DiscoveredSkill {
                 name: SkillName::new("unknown").unwrap(),
@@ -3247,6 +4771,7 @@ mod tests {
                 origin: SkillOrigin::Local,
                 frontmatter: None,
                 synced_at: None,
+                skill_file: "SKILL.md".to_string(),
             },
         ];
 
@@ -3302,6 +4827,63 @@ mod tests {
         config::DirectoryName::new("test-dir").unwrap()
     }
 
+    fn test_dir_config(path: &std::path::Path) -> config::DirectoryConfig {
+        config::DirectoryConfig {
+            path: path.to_path_buf(),
+            directory_type: config::DirectoryType::Directory,
+            role: Some(config::DirectoryRole::Target),
+            git_ref: None,
+            subdir: None,
+            max_depth: None,
+            follow_links: false,
+            name_transform: config::NameTransform::None,
+            prefix: None,
+            include: None,
+            exclude: None,
+            override_applied: false,
+            scan_origin: Default::default(),
+        }
+    }
+
+    #[test]
+    fn cleanup_disabled_removes_links_filtered_by_include_exclude() {
+        let library = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        for name in ["coding", "writing", "review"] {
+            let skill_dir = library.path().join(name);
+            std::fs::create_dir_all(&skill_dir).unwrap();
+            unix_fs::symlink(&skill_dir, target.path().join(name)).unwrap();
+        }
+        let mut dir_config = test_dir_config(target.path());
+        dir_config.include = Some(
+            ["coding", "review"]
+                .into_iter()
+                .map(|n| SkillName::new(n).unwrap())
+                .collect(),
+        );
+        dir_config.exclude = Some([SkillName::new("review").unwrap()].into());
+
+        let prefs = machine::MachinePrefs::default();
+        let (removed, excluded, failures) = cleanup_disabled_from_target(
+            &dir_config,
+            library.path(),
+            &test_dir_name(),
+            &prefs,
+            false,
+        )
+        .unwrap();
+        assert!(failures.is_empty(), "{failures:?}");
+        assert_eq!(removed, 2, "writing is not included, review is excluded");
+        assert!(target.path().join("coding").is_symlink());
+        assert!(!target.path().join("writing").exists());
+        assert!(!target.path().join("review").exists());
+        assert!(
+            excluded
+                .iter()
+                .all(|e| e.in_config && e.directory.is_some())
+        );
+    }
+
     #[test]
     fn cleanup_disabled_removes_library_symlink() {
         let library = TempDir::new().unwrap();
@@ -3316,9 +4898,14 @@ mod tests {
         prefs.disable(SkillName::new("disabled-skill").unwrap());
 
         let dir_name = test_dir_name();
-        let (removed, excluded, failures) =
-            cleanup_disabled_from_target(target.path(), library.path(), &dir_name, &prefs, false)
-                .unwrap();
+        let (removed, excluded, failures) = cleanup_disabled_from_target(
+            &test_dir_config(target.path()),
+            library.path(),
+            &dir_name,
+            &prefs,
+            false,
+        )
+        .unwrap();
         assert!(
             failures.is_empty(),
             "no I/O failures expected: {failures:?}"
@@ -3348,9 +4935,14 @@ mod tests {
         prefs.disable(SkillName::new("disabled-skill").unwrap());
 
         let dir_name = test_dir_name();
-        let (removed, excluded, failures) =
-            cleanup_disabled_from_target(target.path(), library.path(), &dir_name, &prefs, false)
-                .unwrap();
+        let (removed, excluded, failures) = cleanup_disabled_from_target(
+            &test_dir_config(target.path()),
+            library.path(),
+            &dir_name,
+            &prefs,
+            false,
+        )
+        .unwrap();
         assert!(
             failures.is_empty(),
             "no I/O failures expected: {failures:?}"
@@ -3378,9 +4970,14 @@ mod tests {
         prefs.disable(SkillName::new("disabled-skill").unwrap());
 
         let dir_name = test_dir_name();
-        let (removed, excluded, failures) =
-            cleanup_disabled_from_target(target.path(), library.path(), &dir_name, &prefs, false)
-                .unwrap();
+        let (removed, excluded, failures) = cleanup_disabled_from_target(
+            &test_dir_config(target.path()),
+            library.path(),
+            &dir_name,
+            &prefs,
+            false,
+        )
+        .unwrap();
         assert!(
             failures.is_empty(),
             "no I/O failures expected: {failures:?}"
@@ -3395,7 +4992,7 @@ mod tests {
         let prefs = machine::MachinePrefs::default();
         let dir_name = test_dir_name();
         let (removed, excluded, failures) = cleanup_disabled_from_target(
-            std::path::Path::new("/nonexistent/target"),
+            &test_dir_config(std::path::Path::new("/nonexistent/target")),
             std::path::Path::new("/nonexistent/library"),
             &dir_name,
             &prefs,
@@ -3420,9 +5017,14 @@ mod tests {
         prefs.disable(SkillName::new("disabled-skill").unwrap());
 
         let dir_name = test_dir_name();
-        let (removed, excluded, failures) =
-            cleanup_disabled_from_target(target.path(), library.path(), &dir_name, &prefs, true)
-                .unwrap();
+        let (removed, excluded, failures) = cleanup_disabled_from_target(
+            &test_dir_config(target.path()),
+            library.path(),
+            &dir_name,
+            &prefs,
+            true,
+        )
+        .unwrap();
         assert!(
             failures.is_empty(),
             "no I/O failures expected: {failures:?}"
@@ -3454,9 +5056,14 @@ mod tests {
         let mut prefs = machine::MachinePrefs::default();
         prefs.toggle_per_dir_blocklist(&dir_name, SkillName::new("excluded-here").unwrap(), true);
 
-        let (removed, excluded, failures) =
-            cleanup_disabled_from_target(target.path(), library.path(), &dir_name, &prefs, false)
-                .unwrap();
+        let (removed, excluded, failures) = cleanup_disabled_from_target(
+            &test_dir_config(target.path()),
+            library.path(),
+            &dir_name,
+            &prefs,
+            false,
+        )
+        .unwrap();
         assert!(
             failures.is_empty(),
             "no I/O failures expected: {failures:?}"
@@ -3498,9 +5105,14 @@ mod tests {
             true,
         );
 
-        let (removed, excluded, failures) =
-            cleanup_disabled_from_target(target.path(), library.path(), &dir_name, &prefs, false)
-                .unwrap();
+        let (removed, excluded, failures) = cleanup_disabled_from_target(
+            &test_dir_config(target.path()),
+            library.path(),
+            &dir_name,
+            &prefs,
+            false,
+        )
+        .unwrap();
         assert!(
             failures.is_empty(),
             "no I/O failures expected: {failures:?}"
@@ -3549,8 +5161,13 @@ mod tests {
         std::fs::set_permissions(target.path(), perms).unwrap();
 
         let dir_name = test_dir_name();
-        let result =
-            cleanup_disabled_from_target(target.path(), library.path(), &dir_name, &prefs, false);
+        let result = cleanup_disabled_from_target(
+            &test_dir_config(target.path()),
+            library.path(),
+            &dir_name,
+            &prefs,
+            false,
+        );
 
         // Restore perms before any assertion can panic so TempDir cleanup works.
         let mut restored = std::fs::metadata(target.path()).unwrap().permissions();
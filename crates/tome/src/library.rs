@@ -12,13 +12,13 @@
 //! strategy transitions when a skill's `managed` flag flips between syncs.
 
 use anyhow::{Context, Result};
-use std::path::Path;
-use tracing::{info, warn};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
 
 use crate::change_cause::ChangeCause;
-use crate::discover::DiscoveredSkill;
+use crate::discover::{DiscoveredSkill, SkillName};
 use crate::manifest::{self, Manifest, SkillEntry};
-use crate::paths::TomePaths;
+use crate::paths::{TomePaths, back_up_collision};
 
 /// What already exists at the library destination path.
 enum DestinationState {
@@ -41,13 +41,25 @@ fn classify_destination(dest: &Path) -> DestinationState {
 }
 
 /// Result of a consolidation operation.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, serde::Serialize)]
 pub struct ConsolidateResult {
     pub created: usize,
     pub unchanged: usize,
     pub updated: usize,
     /// Skills skipped because a non-managed entry already exists at the library path.
     pub skipped: usize,
+    /// Names of the skills counted in `created`, in consolidation order.
+    pub created_names: Vec<SkillName>,
+    /// Names of the skills counted in `updated`, in consolidation order.
+    pub updated_names: Vec<SkillName>,
+    /// Names of the skills counted in `skipped`, in consolidation order.
+    pub skipped_names: Vec<SkillName>,
+    /// Library paths whose existing entry caused each skip, in the same
+    /// order as `skipped_names`.
+    pub skipped_paths: Vec<PathBuf>,
+    /// Backup paths (`<name>.tome-bak`) of colliding entries moved aside by
+    /// `--force --adopt` before the skill was copied into place.
+    pub backed_up: Vec<PathBuf>,
 }
 
 /// Record a skill in the manifest after consolidation.
@@ -74,6 +86,11 @@ fn record_in_manifest(
 /// provenance for idempotent updates. When `force` is true, all skills are
 /// re-synced regardless of state.
 ///
+/// When `force` and `adopt` are both true, an entry at the library path that
+/// the manifest does not know about is renamed to `<name>.tome-bak` and the
+/// skill is copied in its place, instead of being skipped. An existing
+/// backup is never overwritten; that collision is skipped as before.
+///
 /// `paths.tome_home` is the top-level `~/.tome/` directory where metadata files
 /// (manifest, lockfile, config) are stored. `paths.library_dir` is the subdirectory
 /// (typically `~/.tome/skills/`) where skill contents actually live.
@@ -87,7 +104,9 @@ pub fn consolidate(
     paths: &TomePaths,
     dry_run: bool,
     force: bool,
+    adopt: bool,
 ) -> Result<(ConsolidateResult, Manifest)> {
+    let adopt = force && adopt;
     let library_dir = paths.library_dir();
     let config_dir = paths.config_dir();
 
@@ -107,8 +126,35 @@ pub fn consolidate(
     for skill in skills {
         let dest = library_dir.join(skill.name.as_str());
 
+        // A library that is (or links to) a discovery directory would have
+        // each skill copied onto itself. Config validation rejects that
+        // setup; this catches what it can't see, such as a symlink created
+        // after the config was loaded. A symlinked entry is a v0.1 library
+        // slot, which consolidate migrates rather than skips.
+        if !dest.is_symlink() && resolves_to_same_dir(&skill.path, &dest) {
+            warn!(
+                "skill '{}' at {} is its own library entry, skipping — is library_dir also a source directory?",
+                skill.name,
+                skill.path.display()
+            );
+            result.skipped += 1;
+            result.skipped_names.push(skill.name.clone());
+            log_action("skipped", skill, &dest);
+            result.skipped_paths.push(dest);
+            continue;
+        }
+
+        let before = (result.created, result.updated, result.skipped);
         if skill.origin.is_managed() {
-            consolidate_managed(skill, &dest, &mut manifest, &mut result, dry_run, force)?;
+            consolidate_managed(
+                skill,
+                &dest,
+                &mut manifest,
+                &mut result,
+                dry_run,
+                force,
+                adopt,
+            )?;
         } else {
             consolidate_local(
                 skill,
@@ -118,13 +164,108 @@ pub fn consolidate(
                 &mut result,
                 dry_run,
                 force,
+                adopt,
             )?;
         }
+        let action = if result.created > before.0 {
+            "created"
+        } else if result.updated > before.1 {
+            "updated"
+        } else if result.skipped > before.2 {
+            "skipped"
+        } else {
+            "unchanged"
+        };
+        log_action(action, skill, &dest);
     }
 
     Ok((result, manifest))
 }
 
+/// Per-skill detail for `--verbose` (debug level): what consolidate did
+/// with `skill` and where it copied from and to.
+fn log_action(action: &str, skill: &DiscoveredSkill, dest: &Path) {
+    debug!(
+        "library: {action} {} ({} -> {})",
+        skill.name,
+        skill.path.display(),
+        dest.display()
+    );
+}
+
+/// Whether both paths exist and canonicalize to the same directory.
+fn resolves_to_same_dir(a: &Path, b: &Path) -> bool {
+    match (std::fs::canonicalize(a), std::fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Copy a skill into an empty library slot and record it as newly added.
+fn copy_new_skill(
+    skill: &DiscoveredSkill,
+    dest: &Path,
+    content_hash: crate::validation::ContentHash,
+    manifest: &mut Manifest,
+    result: &mut ConsolidateResult,
+    dry_run: bool,
+) -> Result<()> {
+    if !dry_run {
+        copy_dir_recursive(&skill.path, dest)?;
+    }
+    record_in_manifest(manifest, skill, content_hash);
+    result.created += 1;
+    result.created_names.push(skill.name.clone());
+    info!(
+        skill = %skill.name,
+        directory = %skill.source_name,
+        cause = %ChangeCause::NewlyAdded,
+        "re-emitted",
+    );
+    Ok(())
+}
+
+/// An entry the manifest does not know about occupies `dest`.
+///
+/// Without `adopt` — or when the `.tome-bak` slot is already taken — warn
+/// and count the skill as skipped. With it, move the entry aside and return
+/// `true` so the caller copies the skill into the now-empty slot.
+fn adopt_collision(
+    skill: &DiscoveredSkill,
+    dest: &Path,
+    adopt: bool,
+    result: &mut ConsolidateResult,
+    dry_run: bool,
+) -> Result<bool> {
+    if !adopt {
+        warn!(
+            "{} exists but is not in the manifest, skipping",
+            dest.display()
+        );
+        result.skipped += 1;
+        result.skipped_names.push(skill.name.clone());
+        result.skipped_paths.push(dest.to_path_buf());
+        return Ok(false);
+    }
+    match back_up_collision(dest, dry_run)? {
+        Some(backup) => {
+            info!("backed up {} to {}", dest.display(), backup.display());
+            result.backed_up.push(backup);
+            Ok(true)
+        }
+        None => {
+            warn!(
+                "{} exists but is not in the manifest and its backup slot is taken, skipping",
+                dest.display()
+            );
+            result.skipped += 1;
+            result.skipped_names.push(skill.name.clone());
+            result.skipped_paths.push(dest.to_path_buf());
+            Ok(false)
+        }
+    }
+}
+
 /// Consolidate a managed skill: copy the source directory into the library.
 ///
 /// Per LIB-01 (v0.10), managed skills are stored as real directory copies in
@@ -150,6 +291,7 @@ fn consolidate_managed(
     result: &mut ConsolidateResult,
     dry_run: bool,
     force: bool,
+    adopt: bool,
 ) -> Result<()> {
     let content_hash = manifest::hash_directory(&skill.path)?;
 
@@ -166,6 +308,8 @@ fn consolidate_managed(
                 dest.display()
             );
             result.skipped += 1;
+            result.skipped_names.push(skill.name.clone());
+            result.skipped_paths.push(dest.to_path_buf());
         }
         DestinationState::Directory => {
             if let Some(entry) = manifest.get(skill.name.as_str()) {
@@ -176,6 +320,7 @@ fn consolidate_managed(
                     if !entry.managed {
                         record_in_manifest(manifest, skill, content_hash.clone());
                         result.updated += 1;
+                        result.updated_names.push(skill.name.clone());
                         // OBS-04 emission: managed-flag flip with unchanged content.
                         // Locally approximated as HashChanged (no separate
                         // "flag flipped" variant in CONTEXT.md D-SPAN-3 vocabulary;
@@ -200,41 +345,27 @@ fn consolidate_managed(
                 }
                 record_in_manifest(manifest, skill, content_hash.clone());
                 result.updated += 1;
+                result.updated_names.push(skill.name.clone());
                 info!(
                     skill = %skill.name,
                     directory = %skill.source_name,
                     cause = %ChangeCause::HashChanged,
                     "re-emitted",
                 );
-            } else {
-                // Real dir exists but not in manifest — user-created collision, skip.
-                warn!(
-                    "{} exists but is not in the manifest, skipping",
-                    dest.display()
-                );
-                result.skipped += 1;
+            } else if adopt_collision(skill, dest, adopt, result, dry_run)? {
+                // Real dir exists but not in manifest — user-created
+                // collision, moved aside under --force --adopt.
+                copy_new_skill(skill, dest, content_hash, manifest, result, dry_run)?;
             }
         }
         DestinationState::Empty => {
             // New managed skill — copy from source.
-            if !dry_run {
-                copy_dir_recursive(&skill.path, dest)?;
-            }
-            record_in_manifest(manifest, skill, content_hash.clone());
-            result.created += 1;
-            info!(
-                skill = %skill.name,
-                directory = %skill.source_name,
-                cause = %ChangeCause::NewlyAdded,
-                "re-emitted",
-            );
+            copy_new_skill(skill, dest, content_hash, manifest, result, dry_run)?;
         }
         DestinationState::Other => {
-            warn!(
-                "{} exists but is not in the manifest, skipping",
-                dest.display()
-            );
-            result.skipped += 1;
+            if adopt_collision(skill, dest, adopt, result, dry_run)? {
+                copy_new_skill(skill, dest, content_hash, manifest, result, dry_run)?;
+            }
         }
     }
 
@@ -242,6 +373,7 @@ fn consolidate_managed(
 }
 
 /// Consolidate a local skill: copy the directory into the library.
+#[allow(clippy::too_many_arguments)]
 fn consolidate_local(
     skill: &DiscoveredSkill,
     dest: &Path,
@@ -250,6 +382,7 @@ fn consolidate_local(
     result: &mut ConsolidateResult,
     dry_run: bool,
     force: bool,
+    adopt: bool,
 ) -> Result<()> {
     let content_hash = manifest::hash_directory(&skill.path)?;
 
@@ -268,6 +401,7 @@ fn consolidate_local(
                 }
                 record_in_manifest(manifest, skill, content_hash.clone());
                 result.updated += 1;
+                result.updated_names.push(skill.name.clone());
                 info!(
                     skill = %skill.name,
                     directory = %skill.source_name,
@@ -299,6 +433,7 @@ fn consolidate_local(
             }
             record_in_manifest(manifest, skill, content_hash.clone());
             result.updated += 1;
+            result.updated_names.push(skill.name.clone());
             info!(
                 skill = %skill.name,
                 directory = %skill.source_name,
@@ -316,6 +451,7 @@ fn consolidate_local(
                     if entry.managed {
                         record_in_manifest(manifest, skill, content_hash.clone());
                         result.updated += 1;
+                        result.updated_names.push(skill.name.clone());
                         // OBS-04 emission: managed-flag flip with unchanged content
                         // (symmetric to consolidate_managed line ~176). Approximated
                         // as HashChanged per Plan 18-02 vocabulary; documented in
@@ -342,6 +478,7 @@ fn consolidate_local(
                 }
                 record_in_manifest(manifest, skill, content_hash.clone());
                 result.updated += 1;
+                result.updated_names.push(skill.name.clone());
                 info!(
                     skill = %skill.name,
                     directory = %skill.source_name,
@@ -349,25 +486,14 @@ fn consolidate_local(
                     "re-emitted",
                 );
             } else if dest.exists() {
-                // Something exists that's NOT in the manifest — skip with warning
-                warn!(
-                    "{} exists but is not in the manifest, skipping",
-                    dest.display()
-                );
-                result.skipped += 1;
+                // Something exists that's NOT in the manifest — skip with
+                // warning, or move it aside under --force --adopt.
+                if adopt_collision(skill, dest, adopt, result, dry_run)? {
+                    copy_new_skill(skill, dest, content_hash, manifest, result, dry_run)?;
+                }
             } else {
                 // New skill — copy
-                if !dry_run {
-                    copy_dir_recursive(&skill.path, dest)?;
-                }
-                record_in_manifest(manifest, skill, content_hash.clone());
-                result.created += 1;
-                info!(
-                    skill = %skill.name,
-                    directory = %skill.source_name,
-                    cause = %ChangeCause::NewlyAdded,
-                    "re-emitted",
-                );
+                copy_new_skill(skill, dest, content_hash, manifest, result, dry_run)?;
             }
         }
     }
@@ -379,6 +505,14 @@ fn consolidate_local(
 fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
     std::fs::create_dir_all(dst).with_context(|| format!("failed to create {}", dst.display()))?;
 
+    // A Cursor rule file is copied in as a skill directory holding the
+    // generated SKILL.md.
+    if let Some(content) = crate::skill::read_cursor_rule_as_skill_md(src)? {
+        let target = dst.join(crate::skill::SKILL_FILE_NAME);
+        return std::fs::write(&target, content)
+            .with_context(|| format!("failed to write {}", target.display()));
+    }
+
     for entry in walkdir::WalkDir::new(src).follow_links(false).into_iter() {
         let entry = entry.with_context(|| format!("failed to walk directory {}", src.display()))?;
         let rel = entry.path().strip_prefix(src).with_context(|| {
@@ -462,6 +596,72 @@ pub fn generate_gitignore(library_dir: &Path, manifest: &Manifest) -> Result<()>
     Ok(())
 }
 
+/// File name of the generated library index (`generate_index = true`).
+pub const INDEX_FILENAME: &str = "INDEX.md";
+
+/// Generate or update `INDEX.md` in the library directory: a markdown table
+/// of every manifest entry with its source directory and frontmatter
+/// description, sorted by name.
+/// Only writes the file if the content would change, to avoid unnecessary git noise.
+pub fn generate_index(
+    library_dir: &Path,
+    manifest: &Manifest,
+    skill_file_names: &[String],
+) -> Result<()> {
+    let mut content = String::from(
+        "<!-- Auto-generated by tome — do not edit -->\n\
+         # Skill library\n\n\
+         | Skill | Source | Description |\n\
+         | --- | --- | --- |\n",
+    );
+    for (name, entry) in manifest.iter() {
+        let source = entry.source_name().map_or("(unowned)", |s| s.as_str());
+        let description = skill_description(&library_dir.join(name.as_str()), skill_file_names)
+            .unwrap_or_default();
+        content.push_str(&format!(
+            "| {} | {} | {} |\n",
+            index_cell(name.as_str()),
+            index_cell(source),
+            index_cell(&description)
+        ));
+    }
+
+    let index_path = library_dir.join(INDEX_FILENAME);
+
+    // Only write if content would change
+    if index_path.exists() {
+        let existing = std::fs::read_to_string(&index_path)
+            .with_context(|| format!("failed to read {}", index_path.display()))?;
+        if existing == content {
+            return Ok(());
+        }
+    }
+
+    std::fs::write(&index_path, &content)
+        .with_context(|| format!("failed to write {}", index_path.display()))?;
+
+    Ok(())
+}
+
+/// The frontmatter description of the library skill at `skill_dir`, read
+/// from the first of `skill_file_names` present.
+fn skill_description(skill_dir: &Path, skill_file_names: &[String]) -> Option<String> {
+    let content = skill_file_names
+        .iter()
+        .find_map(|file| std::fs::read_to_string(skill_dir.join(file)).ok())?;
+    let (frontmatter, _) = crate::skill::parse(&content).ok()?;
+    frontmatter.description
+}
+
+/// A value on one line, safe inside a markdown table cell.
+fn index_cell(value: &str) -> String {
+    value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('|', "\\|")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -495,6 +695,7 @@ mod tests {
             origin,
             frontmatter: None,
             synced_at: None,
+            skill_file: "SKILL.md".to_string(),
         }
     }
 
@@ -509,10 +710,16 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.created, 1);
         assert_eq!(result.unchanged, 0);
+        assert_eq!(
+            result.created_names,
+            vec![SkillName::new("my-skill").unwrap()]
+        );
+        assert!(result.updated_names.is_empty());
 
         let dest = library.path().join("my-skill");
         assert!(dest.is_dir());
@@ -520,6 +727,42 @@ mod tests {
         assert!(dest.join("SKILL.md").is_file());
     }
 
+    #[test]
+    fn consolidate_converts_cursor_rule_into_skill_dir() {
+        let source = TempDir::new().unwrap();
+        let library = TempDir::new().unwrap();
+        let rule = source.path().join("style.mdc");
+        std::fs::write(
+            &rule,
+            "---\ndescription: House style\nglobs: \"*.rs\"\n---\nUse tabs.\n",
+        )
+        .unwrap();
+        let skill = DiscoveredSkill {
+            path: rule.clone(),
+            ..make_skill(source.path(), "style")
+        };
+        let paths =
+            TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap();
+
+        let (result, manifest) =
+            consolidate(std::slice::from_ref(&skill), &paths, false, false, false).unwrap();
+        assert_eq!(result.created, 1);
+        let dest = library.path().join("style");
+        let skill_md = std::fs::read_to_string(dest.join("SKILL.md")).unwrap();
+        assert!(skill_md.contains("name: style"), "{skill_md}");
+        assert!(skill_md.ends_with("Use tabs.\n"), "{skill_md}");
+        assert_eq!(
+            manifest::hash_directory(&rule).unwrap(),
+            manifest::hash_directory(&dest).unwrap()
+        );
+
+        // The copy hashes like its source, so a resync leaves it alone.
+        manifest::save(&manifest, library.path()).unwrap();
+        let (result, _) = consolidate(&[skill], &paths, false, false, false).unwrap();
+        assert_eq!(result.unchanged, 1);
+        assert_eq!(result.updated, 0);
+    }
+
     #[test]
     fn consolidate_idempotent() {
         let source = TempDir::new().unwrap();
@@ -531,6 +774,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         manifest::save(&manifest, library.path()).unwrap();
@@ -539,6 +783,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.created, 0);
@@ -556,6 +801,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         manifest::save(&manifest, library.path()).unwrap();
@@ -564,6 +810,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             true,
+            false,
         )
         .unwrap();
         assert_eq!(result.updated, 1, "force should recopy unchanged skill");
@@ -581,6 +828,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         manifest::save(&manifest, library.path()).unwrap();
@@ -593,9 +841,15 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.updated, 1);
+        assert_eq!(
+            result.updated_names,
+            vec![SkillName::new("my-skill").unwrap()]
+        );
+        assert!(result.created_names.is_empty());
 
         // Library copy should have the new content
         let content = std::fs::read_to_string(library.path().join("my-skill/SKILL.md")).unwrap();
@@ -613,6 +867,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             true,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.created, 1);
@@ -633,6 +888,7 @@ mod tests {
             &TomePaths::new(nonexistent_lib.to_path_buf(), nonexistent_lib.to_path_buf()).unwrap(),
             true,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.created, 1);
@@ -656,17 +912,101 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.created, 0);
         assert_eq!(result.unchanged, 0);
         assert_eq!(result.skipped, 1);
+        assert_eq!(
+            result.skipped_names,
+            vec![SkillName::new("my-skill").unwrap()]
+        );
+        assert_eq!(result.skipped_paths, vec![collision.clone()]);
 
         // User-created content should be untouched
         let content = std::fs::read_to_string(library.path().join("my-skill/README.md")).unwrap();
         assert_eq!(content, "user-created");
     }
 
+    #[test]
+    fn consolidate_skips_skill_that_is_its_own_library_entry() {
+        // library_dir doubles as the source directory.
+        let library = TempDir::new().unwrap();
+        let skill = make_skill(library.path(), "my-skill");
+
+        let (result, manifest) = consolidate(
+            &[skill],
+            &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
+            false,
+            true,
+            false,
+        )
+        .unwrap();
+        assert_eq!(result.created, 0);
+        assert_eq!(result.skipped, 1);
+        assert!(manifest.is_empty());
+        assert!(library.path().join("my-skill/SKILL.md").is_file());
+    }
+
+    #[test]
+    fn consolidate_adopt_backs_up_unmanaged_collision() {
+        let source = TempDir::new().unwrap();
+        let library = TempDir::new().unwrap();
+        let paths =
+            TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap();
+
+        let skill = make_skill(source.path(), "my-skill");
+        let collision = library.path().join("my-skill");
+        std::fs::create_dir_all(&collision).unwrap();
+        std::fs::write(collision.join("README.md"), "user-created").unwrap();
+
+        // `adopt` without `force` is inert.
+        let (result, _) =
+            consolidate(std::slice::from_ref(&skill), &paths, false, false, true).unwrap();
+        assert_eq!(result.skipped, 1);
+        assert!(result.backed_up.is_empty());
+
+        let (result, manifest) = consolidate(&[skill], &paths, false, true, true).unwrap();
+        let backup = library.path().join("my-skill.tome-bak");
+        assert_eq!(result.created, 1);
+        assert_eq!(result.skipped, 0);
+        assert_eq!(result.backed_up, vec![backup.clone()]);
+        assert_eq!(
+            std::fs::read_to_string(backup.join("README.md")).unwrap(),
+            "user-created"
+        );
+        assert!(collision.join("SKILL.md").is_file());
+        assert!(manifest.get("my-skill").is_some());
+    }
+
+    #[test]
+    fn consolidate_adopt_never_overwrites_existing_backup() {
+        let source = TempDir::new().unwrap();
+        let library = TempDir::new().unwrap();
+
+        let skill = make_skill(source.path(), "my-skill");
+        std::fs::create_dir_all(library.path().join("my-skill")).unwrap();
+        let backup = library.path().join("my-skill.tome-bak");
+        std::fs::create_dir_all(&backup).unwrap();
+        std::fs::write(backup.join("README.md"), "older backup").unwrap();
+
+        let (result, _) = consolidate(
+            &[skill],
+            &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
+            false,
+            true,
+            true,
+        )
+        .unwrap();
+        assert_eq!(result.skipped, 1);
+        assert!(result.backed_up.is_empty());
+        assert_eq!(
+            std::fs::read_to_string(backup.join("README.md")).unwrap(),
+            "older backup"
+        );
+    }
+
     #[test]
     fn consolidate_migrates_v01_symlink() {
         use std::os::unix::fs as unix_fs;
@@ -686,6 +1026,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.updated, 1, "symlink should be migrated");
@@ -712,6 +1053,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         manifest::save(&manifest, library.path()).unwrap();
@@ -727,6 +1069,7 @@ mod tests {
             origin: crate::discover::SkillOrigin::Local,
             frontmatter: None,
             synced_at: None,
+            skill_file: "SKILL.md".to_string(),
         };
 
         let (result, _manifest) = consolidate(
@@ -734,6 +1077,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.updated, 1);
@@ -753,6 +1097,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
 
@@ -782,6 +1127,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.updated, 1);
@@ -810,6 +1156,7 @@ mod tests {
             origin: crate::discover::SkillOrigin::Local,
             frontmatter: None,
             synced_at: None,
+            skill_file: "SKILL.md".to_string(),
         };
 
         let (result, _) = consolidate(
@@ -817,6 +1164,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.created, 1);
@@ -844,6 +1192,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             true,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.created, 1);
@@ -866,6 +1215,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         manifest::save(&manifest, library.path()).unwrap();
@@ -877,6 +1227,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             true,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.updated, 1);
@@ -908,6 +1259,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         let entry = manifest
@@ -932,6 +1284,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.created, 1);
@@ -962,6 +1315,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         manifest::save(&manifest, library.path()).unwrap();
@@ -970,6 +1324,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.unchanged, 1);
@@ -989,6 +1344,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         manifest::save(&manifest, library.path()).unwrap();
@@ -1001,6 +1357,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.updated, 1);
@@ -1028,6 +1385,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         manifest::save(&manifest, library.path()).unwrap();
@@ -1042,6 +1400,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.updated, 1);
@@ -1065,6 +1424,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         manifest::save(&manifest, library.path()).unwrap();
@@ -1080,6 +1440,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert!(dest.is_dir());
@@ -1101,6 +1462,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         let entry = manifest.get("plugin-skill").unwrap();
@@ -1123,6 +1485,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         generate_gitignore(library.path(), &manifest).unwrap();
@@ -1147,6 +1510,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         generate_gitignore(library.path(), &manifest).unwrap();
@@ -1158,6 +1522,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn index_lists_skills_with_source_and_description() {
+        let library = TempDir::new().unwrap();
+        let source = TempDir::new().unwrap();
+
+        let alpha = make_skill(source.path(), "alpha");
+        std::fs::write(
+            alpha.path.join("SKILL.md"),
+            "---\nname: alpha\ndescription: First | one\n---\n",
+        )
+        .unwrap();
+        let beta = make_skill(source.path(), "beta");
+        let (_, manifest) = consolidate(
+            &[beta, alpha],
+            &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
+            false,
+            false,
+            false,
+        )
+        .unwrap();
+
+        let names = vec!["SKILL.md".to_string()];
+        generate_index(library.path(), &manifest, &names).unwrap();
+        let content = std::fs::read_to_string(library.path().join(INDEX_FILENAME)).unwrap();
+        assert!(
+            content.contains("| alpha | test | First \\| one |\n| beta | test |  |\n"),
+            "{content}"
+        );
+
+        let modified = std::fs::metadata(library.path().join(INDEX_FILENAME))
+            .unwrap()
+            .modified()
+            .unwrap();
+        generate_index(library.path(), &manifest, &names).unwrap();
+        let again = std::fs::metadata(library.path().join(INDEX_FILENAME))
+            .unwrap()
+            .modified()
+            .unwrap();
+        assert_eq!(modified, again, "unchanged index should not be rewritten");
+    }
+
     #[test]
     fn gitignore_idempotent() {
         let library = TempDir::new().unwrap();
@@ -1169,6 +1574,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
 
@@ -1195,6 +1601,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             true,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.created, 1);
@@ -1219,6 +1626,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         manifest::save(&manifest, library.path()).unwrap();
@@ -1227,6 +1635,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             true,
+            false,
         )
         .unwrap();
         assert_eq!(result.updated, 1, "force should re-copy managed skill");
@@ -1251,6 +1660,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         manifest::save(&manifest, library.path()).unwrap();
@@ -1286,6 +1696,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(
@@ -1322,6 +1733,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.skipped, 1);
@@ -1344,6 +1756,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         manifest::save(&manifest1, library.path()).unwrap();
@@ -1357,6 +1770,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.updated, 1);
@@ -1382,11 +1796,12 @@ mod tests {
             &TomePaths::new(tome_home.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
         assert_eq!(result.created, 1);
 
-        // consolidate() no longer saves the manifest — that's the caller's job
+        // consolidate(, false) no longer saves the manifest — that's the caller's job
         assert!(
             !tome_home.path().join(".tome-manifest.json").exists(),
             "consolidate should NOT write manifest to disk"
@@ -1468,11 +1883,21 @@ mod tests {
             origin: crate::discover::SkillOrigin::Managed { provenance: None },
             frontmatter: None,
             synced_at: None,
+            skill_file: "SKILL.md".to_string(),
         };
 
         // Call consolidate_managed directly
         let mut result = ConsolidateResult::default();
-        consolidate_managed(&skill, &dest, &mut manifest, &mut result, false, false).unwrap();
+        consolidate_managed(
+            &skill,
+            &dest,
+            &mut manifest,
+            &mut result,
+            false,
+            false,
+            false,
+        )
+        .unwrap();
 
         // Per LIB-01: the destination remains a real directory (the local copy
         // is replaced by a copy of the managed source, not a symlink).
@@ -1523,6 +1948,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
 
@@ -1548,6 +1974,7 @@ mod tests {
             &TomePaths::new(library.path().to_path_buf(), library.path().to_path_buf()).unwrap(),
             false,
             false,
+            false,
         )
         .unwrap();
 
@@ -0,0 +1,161 @@
+//! Ledger of the links tome created in a distribution directory
+//! (`link_marker = true` in `tome.toml`).
+//!
+//! Without it, "tome's link" means "a symlink pointing into the library",
+//! which also matches a link the user made by hand. With `link_marker` on,
+//! sync records the name of every link it creates in a `.tome-links` file
+//! next to them, and cleanup, eject, prune, doctor, and `status --target`
+//! only treat listed names as tome's. A directory without the file falls back to the
+//! path-based check, so existing setups keep working; the first recording
+//! sync adopts the links that check already attributes to tome.
+
+use anyhow::{Context, Result};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::discover::SkillName;
+use crate::paths::resolve_symlink_target;
+
+/// File name of the ledger inside a distribution directory.
+pub(crate) const LINK_MARKER_FILENAME: &str = ".tome-links";
+
+const HEADER: &str = "# Links created by tome — do not edit\n";
+
+/// The link names recorded in `target_dir`, or `None` when it has no
+/// ledger (every link into the library then counts as tome's).
+pub(crate) fn load(target_dir: &Path) -> Result<Option<BTreeSet<String>>> {
+    let path = target_dir.join(LINK_MARKER_FILENAME);
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(e).with_context(|| format!("failed to read {}", path.display()));
+        }
+    };
+    Ok(Some(
+        content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_string)
+            .collect(),
+    ))
+}
+
+/// Whether the link called `name` is tome's according to `ledger` (as
+/// returned by [`load`]). Callers still check that it points into the
+/// library.
+pub(crate) fn is_marked(ledger: Option<&BTreeSet<String>>, name: &str) -> bool {
+    ledger.is_none_or(|names| names.contains(name))
+}
+
+/// Record `created` — links sync just made in `target_dir` — in its
+/// ledger. Names that are no longer symlinks are dropped. A directory
+/// without a ledger gets one seeded with every existing link into
+/// `library_dir`. Only writes the file if the content would change, and
+/// then via temp+rename like the manifest and lockfile, since cleanup,
+/// eject, prune, and doctor all trust it.
+pub(crate) fn record(target_dir: &Path, library_dir: &Path, created: &[SkillName]) -> Result<()> {
+    if !target_dir.is_dir() {
+        return Ok(());
+    }
+    let mut names = match load(target_dir)? {
+        Some(names) => names,
+        None => links_into_library(target_dir, library_dir)?,
+    };
+    names.extend(created.iter().map(|name| name.as_str().to_string()));
+    names.retain(|name| target_dir.join(name).is_symlink());
+
+    let mut content = String::from(HEADER);
+    for name in &names {
+        content.push_str(name);
+        content.push('\n');
+    }
+
+    let path = target_dir.join(LINK_MARKER_FILENAME);
+    if std::fs::read_to_string(&path).is_ok_and(|existing| existing == content) {
+        return Ok(());
+    }
+    let tmp_path = target_dir.join(".tome-links.tmp");
+    std::fs::write(&tmp_path, content)
+        .with_context(|| format!("failed to write temporary ledger {}", tmp_path.display()))?;
+    if let Err(e) = std::fs::rename(&tmp_path, &path) {
+        // Best-effort cleanup; the rename error is the one to surface.
+        let _ = std::fs::remove_file(&tmp_path);
+        return Err(e).with_context(|| {
+            format!(
+                "failed to rename ledger {} -> {}",
+                tmp_path.display(),
+                path.display()
+            )
+        });
+    }
+    Ok(())
+}
+
+/// Names of the symlinks in `target_dir` that point into `library_dir`.
+fn links_into_library(target_dir: &Path, library_dir: &Path) -> Result<BTreeSet<String>> {
+    let canonical_library =
+        std::fs::canonicalize(library_dir).unwrap_or_else(|_| library_dir.to_path_buf());
+    let mut names = BTreeSet::new();
+    for entry in std::fs::read_dir(target_dir)
+        .with_context(|| format!("failed to read target dir {}", target_dir.display()))?
+    {
+        let entry =
+            entry.with_context(|| format!("failed to read entry in {}", target_dir.display()))?;
+        let path = entry.path();
+        let Ok(raw_target) = std::fs::read_link(&path) else {
+            continue;
+        };
+        let target = resolve_symlink_target(&path, &raw_target);
+        if target.starts_with(library_dir) || target.starts_with(&canonical_library) {
+            names.insert(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs as unix_fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn record_seeds_from_existing_links_and_adds_created() {
+        let library = TempDir::new().unwrap();
+        let target = TempDir::new().unwrap();
+        let external = TempDir::new().unwrap();
+        for name in ["old", "new"] {
+            std::fs::create_dir(library.path().join(name)).unwrap();
+        }
+        unix_fs::symlink(library.path().join("old"), target.path().join("old")).unwrap();
+        unix_fs::symlink(library.path().join("new"), target.path().join("new")).unwrap();
+        unix_fs::symlink(external.path(), target.path().join("mine")).unwrap();
+
+        assert!(load(target.path()).unwrap().is_none());
+        record(
+            target.path(),
+            library.path(),
+            &[SkillName::new("new").unwrap()],
+        )
+        .unwrap();
+        let ledger = load(target.path()).unwrap().unwrap();
+        assert_eq!(
+            ledger.iter().map(String::as_str).collect::<Vec<_>>(),
+            ["new", "old"]
+        );
+        assert!(
+            !target.path().join(".tome-links.tmp").exists(),
+            "the temp file is renamed into place"
+        );
+
+        // Removed links drop out on the next recording.
+        std::fs::remove_file(target.path().join("old")).unwrap();
+        record(target.path(), library.path(), &[]).unwrap();
+        let ledger = load(target.path()).unwrap().unwrap();
+        assert!(is_marked(Some(&ledger), "new"));
+        assert!(!is_marked(Some(&ledger), "old"));
+        assert!(is_marked(None, "anything"));
+    }
+}
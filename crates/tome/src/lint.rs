@@ -251,8 +251,9 @@ pub fn lint_library(library_dir: &Path) -> LintReport {
                 None => continue,
             };
 
-            // Skip non-skill entries (manifest, gitignore, etc.)
-            if dir_name.starts_with('.') {
+            // Skip non-skill entries (manifest, gitignore, etc.) and
+            // `sync --adopt` backups
+            if dir_name.starts_with('.') || dir_name.ends_with(crate::paths::BACKUP_SUFFIX) {
                 continue;
             }
 
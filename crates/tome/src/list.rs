@@ -10,6 +10,7 @@
 //! split that is the CORE-01 template.
 
 use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime};
 
 use anyhow::Result;
 
@@ -48,10 +49,96 @@ pub fn collect(config: &Config) -> Result<ListReport> {
     Ok(ListReport { skills, warnings })
 }
 
+/// How `--tag` filters combine when given more than once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum TagMode {
+    /// Keep skills carrying every listed tag.
+    #[default]
+    All,
+    /// Keep skills carrying at least one listed tag.
+    Any,
+}
+
+/// Whether `skill` passes the `--tag` filter. Tags come from the skill's
+/// frontmatter (`metadata.tags` or `tags`) and compare case-insensitively;
+/// an empty `tags` list matches everything.
+pub(crate) fn matches_tags(skill: &DiscoveredSkill, tags: &[String], mode: TagMode) -> bool {
+    if tags.is_empty() {
+        return true;
+    }
+    let have = skill
+        .frontmatter
+        .as_ref()
+        .map(crate::info::tags)
+        .unwrap_or_default();
+    let has = |want: &String| have.iter().any(|t| t.eq_ignore_ascii_case(want));
+    match mode {
+        TagMode::All => tags.iter().all(has),
+        TagMode::Any => tags.iter().any(has),
+    }
+}
+
+/// Parse a `--since` window: a whole number followed by `s`, `m`, `h`, `d`,
+/// or `w` (e.g. `30m`, `1h`, `2d`).
+pub(crate) fn parse_since(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| format!("'{value}' has no unit (use s, m, h, d, or w, e.g. 2d)"))?;
+    let (count, unit) = value.split_at(split);
+    let count: u64 = count
+        .parse()
+        .map_err(|_| format!("'{value}' does not start with a number (e.g. 2d)"))?;
+    let seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(format!("unknown unit '{unit}' (use s, m, h, d, or w)")),
+    };
+    count
+        .checked_mul(seconds)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("'{value}' is too large"))
+}
+
+/// Whether `skill`'s skill file was modified within `window` of `now`.
+/// `None` when its modification time can't be read. A file dated in the
+/// future counts as recent.
+pub(crate) fn modified_within(
+    skill: &DiscoveredSkill,
+    window: Duration,
+    now: SystemTime,
+) -> Option<bool> {
+    let modified = discover::skill_file_modified(skill)?;
+    Some(
+        now.duration_since(modified)
+            .map_or(true, |age| age <= window),
+    )
+}
+
+/// Maximum description length, in characters, for `tome list --long`.
+pub(crate) const DESCRIPTION_WIDTH: usize = 60;
+
+/// A description on one line for the `tome list --long` table: whitespace
+/// runs (including newlines from YAML block scalars) collapse to one space,
+/// and anything past `max` characters is cut with a trailing `…`.
+pub(crate) fn truncate_description(description: &str, max: usize) -> String {
+    let flat = description.split_whitespace().collect::<Vec<_>>().join(" ");
+    if flat.chars().count() <= max {
+        return flat;
+    }
+    let cut: String = flat.chars().take(max.saturating_sub(1)).collect();
+    format!("{}…", cut.trim_end())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType};
+    use crate::config::{
+        DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, NameTransform,
+    };
     use crate::discover::{DiscoveredSkill, SkillName, SkillOrigin};
     use std::path::PathBuf;
     use tempfile::TempDir;
@@ -67,7 +154,14 @@ mod tests {
                 role: Some(DirectoryRole::Source),
                 git_ref: None,
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
         Config {
@@ -86,6 +180,33 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn parse_since_accepts_unit_suffixes() {
+        assert_eq!(parse_since("90s"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_since("1h"), Ok(Duration::from_secs(3600)));
+        assert_eq!(parse_since("2d"), Ok(Duration::from_secs(2 * 86400)));
+        assert_eq!(parse_since("1w"), Ok(Duration::from_secs(7 * 86400)));
+        assert!(parse_since("2").is_err());
+        assert!(parse_since("d").is_err());
+        assert!(parse_since("2y").is_err());
+    }
+
+    #[test]
+    fn modified_within_compares_skill_file_mtime() {
+        let tmp = TempDir::new().unwrap();
+        create_skill(tmp.path(), "alpha");
+        let config = config_with_source(tmp.path().to_path_buf());
+        let mut skill = collect(&config).unwrap().skills.remove(0);
+        let hour = Duration::from_secs(3600);
+
+        let now = SystemTime::now();
+        assert_eq!(modified_within(&skill, hour, now), Some(true));
+        assert_eq!(modified_within(&skill, hour, now + 2 * hour), Some(false));
+
+        skill.skill_file = "MISSING.md".to_string();
+        assert_eq!(modified_within(&skill, hour, now), None);
+    }
+
     /// D-16: a discover-only run (no manifest join) returns skills with
     /// `synced_at: None`. Pins that `collect()` does NOT spontaneously
     /// stamp a value — `list` is read-only and never writes the manifest.
@@ -129,6 +250,7 @@ mod tests {
             origin: SkillOrigin::Local,
             frontmatter: None,
             synced_at: Some("2026-06-05T10:00:00Z".to_string()),
+            skill_file: "SKILL.md".to_string(),
         };
         let unstamped = DiscoveredSkill {
             name: SkillName::new("unstamped").unwrap(),
@@ -137,6 +259,7 @@ mod tests {
             origin: SkillOrigin::Local,
             frontmatter: None,
             synced_at: None,
+            skill_file: "SKILL.md".to_string(),
         };
         let report = ListReport {
             skills: vec![stamped, unstamped],
@@ -153,4 +276,36 @@ mod tests {
             "None synced_at must serialize as JSON null; got: {json}",
         );
     }
+
+    #[test]
+    fn truncate_description_flattens_and_cuts_with_ellipsis() {
+        assert_eq!(truncate_description("short", 10), "short");
+        assert_eq!(
+            truncate_description("multi\n  line   text", 40),
+            "multi line text"
+        );
+        assert_eq!(truncate_description("abcdef ghijkl", 8), "abcdef…");
+        assert_eq!(truncate_description("ééééééééé", 5).chars().count(), 5);
+    }
+
+    #[test]
+    fn matches_tags_honors_mode_and_ignores_case() {
+        let (frontmatter, _) =
+            crate::skill::parse("---\nname: s\nmetadata:\n  tags: [Rust, cli]\n---\n").unwrap();
+        let skill = DiscoveredSkill {
+            name: SkillName::new("s").unwrap(),
+            path: PathBuf::from("/src/s"),
+            source_name: DirectoryName::new("test").unwrap(),
+            origin: SkillOrigin::Local,
+            frontmatter: Some(frontmatter),
+            synced_at: None,
+            skill_file: "SKILL.md".to_string(),
+        };
+        let tags = |t: &[&str]| t.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert!(matches_tags(&skill, &[], TagMode::All));
+        assert!(matches_tags(&skill, &tags(&["rust", "CLI"]), TagMode::All));
+        assert!(!matches_tags(&skill, &tags(&["rust", "web"]), TagMode::All));
+        assert!(matches_tags(&skill, &tags(&["rust", "web"]), TagMode::Any));
+        assert!(!matches_tags(&skill, &tags(&["web"]), TagMode::Any));
+    }
 }
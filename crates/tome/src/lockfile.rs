@@ -367,6 +367,7 @@ mod tests {
             origin,
             frontmatter: None,
             synced_at: None,
+            skill_file: "SKILL.md".to_string(),
         }
     }
 
@@ -693,6 +694,7 @@ mod tests {
                     DirectoryType::Git => "git",
                     DirectoryType::Directory => "directory",
                     DirectoryType::ClaudePlugins => "claude-plugins",
+                    DirectoryType::CursorRules => "cursor-rules",
                 },
                 match dc.role() {
                     DirectoryRole::Source => "source",
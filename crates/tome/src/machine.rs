@@ -233,13 +233,10 @@ impl MachinePrefs {
     }
 }
 
-/// Default path for the machine preferences file: `~/.config/tome/machine.toml`.
+/// Default path for the machine preferences file: `~/.config/tome/machine.toml`,
+/// under `$XDG_CONFIG_HOME` instead of `~/.config` when that is set.
 pub fn default_machine_path() -> Result<PathBuf> {
-    Ok(dirs::home_dir()
-        .context("could not determine home directory")?
-        .join(".config")
-        .join("tome")
-        .join("machine.toml"))
+    Ok(crate::config::xdg_config_dir()?.join("machine.toml"))
 }
 
 /// Load machine preferences from a TOML file.
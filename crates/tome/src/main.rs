@@ -41,6 +41,18 @@ fn main() -> ExitCode {
                 eprintln!("error: {migration_failed}");
                 return ExitCode::FAILURE;
             }
+            if let Some(doctor_issues) = e.downcast_ref::<tome::DoctorIssuesFound>() {
+                eprintln!("error: {doctor_issues}");
+                return ExitCode::FAILURE;
+            }
+            if let Some(check_failed) = e.downcast_ref::<tome::StatusCheckFailed>() {
+                eprintln!("error: {check_failed}");
+                return ExitCode::FAILURE;
+            }
+            if let Some(config_invalid) = e.downcast_ref::<tome::ConfigInvalid>() {
+                eprintln!("error: {config_invalid}");
+                return ExitCode::FAILURE;
+            }
             eprintln!("error: {e:#}");
             ExitCode::FAILURE
         }
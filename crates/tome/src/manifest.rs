@@ -417,6 +417,22 @@ pub fn save(manifest: &Manifest, tome_home: &Path) -> Result<()> {
 /// Walks all files in sorted order by relative path, hashing each file's
 /// relative path and content into a single digest.
 pub fn hash_directory(dir: &Path) -> Result<ContentHash> {
+    // A Cursor rule hashes as the skill directory its library copy becomes.
+    if let Some(content) = crate::skill::read_cursor_rule_as_skill_md(dir)? {
+        let mut hasher = Sha256::new();
+        hasher.update(crate::skill::SKILL_FILE_NAME.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(content.as_bytes());
+        return Ok(ContentHash::new(
+            hasher
+                .finalize()
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>(),
+        )
+        .expect("SHA-256 always produces 64 valid hex characters"));
+    }
+
     let mut entries: Vec<(String, PathBuf)> = Vec::new();
 
     for entry in WalkDir::new(dir).follow_links(false).into_iter() {
@@ -1221,7 +1221,14 @@ mod tests {
             role: None,
             git_ref,
             subdir: None,
+            max_depth: None,
+            follow_links: false,
+            name_transform: crate::config::NameTransform::None,
+            prefix: None,
+            include: None,
+            exclude: None,
             override_applied: false,
+            scan_origin: Default::default(),
         }
     }
 
@@ -25,6 +25,69 @@ pub fn expand_tilde(path: &Path) -> Result<PathBuf> {
     }
 }
 
+/// Expand `$VAR` and `${VAR}` references using the process environment.
+///
+/// Applied to config paths before [`expand_tilde`], so
+/// `$XDG_DATA_HOME/tome/skills` works the way it would in a shell. A
+/// variable that is not set is an error naming the variable and the path —
+/// silently expanding to an empty string would point tome at the wrong
+/// directory. A `$` not followed by a name (or `{`) is kept literally.
+/// Non-UTF-8 paths are returned unchanged.
+pub fn expand_env_vars(path: &Path) -> Result<PathBuf> {
+    expand_env_vars_with(path, |name| std::env::var(name).ok())
+}
+
+/// [`expand_env_vars`] with an injectable variable lookup (for tests).
+fn expand_env_vars_with(path: &Path, lookup: impl Fn(&str) -> Option<String>) -> Result<PathBuf> {
+    let Some(raw) = path.to_str() else {
+        return Ok(path.to_path_buf());
+    };
+    if !raw.contains('$') {
+        return Ok(path.to_path_buf());
+    }
+
+    let is_name_char = |c: char| c == '_' || c.is_ascii_alphanumeric();
+    let mut out = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(idx) = rest.find('$') {
+        out.push_str(&rest[..idx]);
+        let after = &rest[idx + 1..];
+        let (name, consumed) = if let Some(braced) = after.strip_prefix('{') {
+            let end = braced
+                .find('}')
+                .with_context(|| format!("unterminated `${{` in path {}", path.display()))?;
+            (&braced[..end], end + 2)
+        } else {
+            let end = after.find(|c| !is_name_char(c)).unwrap_or(after.len());
+            (&after[..end], end)
+        };
+        let starts_like_name = name
+            .chars()
+            .next()
+            .is_some_and(|c| c == '_' || c.is_ascii_alphabetic());
+        if !starts_like_name || !name.chars().all(is_name_char) {
+            anyhow::ensure!(
+                !after.starts_with('{'),
+                "invalid variable name `${{{name}}}` in path {}",
+                path.display()
+            );
+            out.push('$');
+            rest = after;
+            continue;
+        }
+        let value = lookup(name).with_context(|| {
+            format!(
+                "environment variable `${name}` is not set (used in path {})",
+                path.display()
+            )
+        })?;
+        out.push_str(&value);
+        rest = &after[consumed..];
+    }
+    out.push_str(rest);
+    Ok(PathBuf::from(out))
+}
+
 /// Inverse of [`expand_tilde`]: rewrites a path under `$HOME` to `~/...` shape.
 ///
 /// Paths outside `$HOME` are returned unchanged. Idempotent on already-tilde
@@ -156,12 +219,69 @@ impl TomePaths {
 /// Resolve a symlink's raw target to an absolute path.
 ///
 /// `read_link()` returns the raw stored target, which may be relative.
-/// This function resolves relative targets against the symlink's parent directory.
+/// This function resolves relative targets against the symlink's parent
+/// directory and folds the resulting `..` components lexically, so a
+/// `relative_links` target like `../../.tome/skills/foo` still
+/// `starts_with` the library dir even when the link is stale.
 pub fn resolve_symlink_target(link_path: &Path, raw_target: &Path) -> PathBuf {
     if raw_target.is_absolute() {
         raw_target.to_path_buf()
     } else {
-        link_path.parent().unwrap_or(link_path).join(raw_target)
+        let joined = link_path.parent().unwrap_or(link_path).join(raw_target);
+        let mut resolved = PathBuf::new();
+        for component in joined.components() {
+            match component {
+                std::path::Component::CurDir => {}
+                std::path::Component::ParentDir => {
+                    resolved.pop();
+                }
+                other => resolved.push(other),
+            }
+        }
+        resolved
+    }
+}
+
+/// What to store in a symlink at `link_path` that points to `target`:
+/// `target` itself, or with `relative` the path from the link's parent
+/// directory to `target` (`relative_links = true`). Falls back to the
+/// absolute target when no relative path exists.
+pub(crate) fn symlink_target_for(link_path: &Path, target: &Path, relative: bool) -> PathBuf {
+    if relative
+        && let Some(parent) = link_path.parent()
+        && let Some(diff) = pathdiff::diff_paths(target, parent)
+    {
+        return diff;
+    }
+    target.to_path_buf()
+}
+
+/// Create a symlink at `link` pointing to the directory `target`.
+///
+/// Unix symlinks are untyped; Windows needs a directory symlink, which
+/// requires Developer Mode or an elevated shell. Lacking that privilege is
+/// turned into an error that says so instead of the bare OS code.
+pub(crate) fn create_dir_symlink(target: &Path, link: &Path) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(target, link)
+    }
+    #[cfg(windows)]
+    {
+        /// `ERROR_PRIVILEGE_NOT_HELD`
+        const PRIVILEGE_NOT_HELD: i32 = 1314;
+        std::os::windows::fs::symlink_dir(target, link).map_err(|e| {
+            if e.raw_os_error() == Some(PRIVILEGE_NOT_HELD) {
+                std::io::Error::new(
+                    std::io::ErrorKind::PermissionDenied,
+                    "creating symlinks on Windows requires Developer Mode or an \
+                     administrator shell; enable Developer Mode, or copy skills into \
+                     this directory instead of linking them",
+                )
+            } else {
+                e
+            }
+        })
     }
 }
 
@@ -207,6 +327,66 @@ pub fn symlink_points_to(link_path: &Path, expected_target: &Path) -> bool {
     resolved == expected
 }
 
+/// Suffix appended to entries `tome sync --force --adopt` moves aside.
+pub(crate) const BACKUP_SUFFIX: &str = ".tome-bak";
+
+/// Sibling path (`<name>.tome-bak`) a colliding entry is backed up to.
+pub(crate) fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(BACKUP_SUFFIX);
+    path.with_file_name(name)
+}
+
+/// Move the real file or directory at `path` aside to [`backup_path`] so a
+/// tome-managed entry can take its place.
+///
+/// Returns the backup path, or `None` when something already exists there —
+/// an earlier backup is never overwritten. In dry-run mode nothing is
+/// renamed; the would-be backup path is still returned.
+pub(crate) fn back_up_collision(path: &Path, dry_run: bool) -> Result<Option<PathBuf>> {
+    let backup = backup_path(path);
+    if backup.symlink_metadata().is_ok() {
+        return Ok(None);
+    }
+    if !dry_run {
+        std::fs::rename(path, &backup).with_context(|| {
+            format!(
+                "failed to back up {} to {}",
+                path.display(),
+                backup.display()
+            )
+        })?;
+    }
+    Ok(Some(backup))
+}
+
+/// Whether the filesystem holding `dir` treats names case-insensitively
+/// (the default on macOS and Windows), so `My-Skill` and `my-skill` would
+/// be the same entry.
+///
+/// Probes by creating a lowercase temp file in `dir` — or its nearest
+/// existing ancestor, so a library that doesn't exist yet is covered — and
+/// checking whether the uppercase spelling resolves to it. The file is
+/// removed again. Any failure (read-only directory, …) reports `false`.
+pub(crate) fn is_case_insensitive(dir: &Path) -> bool {
+    let Some(existing) = dir.ancestors().find(|p| p.is_dir()) else {
+        return false;
+    };
+    let probe = existing.join(format!(".tome-case-probe-{}", std::process::id()));
+    if std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+        .is_err()
+    {
+        return false;
+    }
+    let upper = existing.join(format!(".TOME-CASE-PROBE-{}", std::process::id()));
+    let insensitive = upper.symlink_metadata().is_ok();
+    let _ = std::fs::remove_file(&probe);
+    insensitive
+}
+
 /// Collapse the user's home directory prefix to `~/` for display.
 pub(crate) fn collapse_home(path: &Path) -> String {
     collapse_home_path(path).display().to_string()
@@ -230,6 +410,41 @@ mod tests {
     use std::os::unix::fs as unix_fs;
     use tempfile::TempDir;
 
+    #[test]
+    fn expand_env_vars_expands_bare_and_braced_forms() {
+        let home = std::env::var("HOME").unwrap();
+        assert_eq!(
+            expand_env_vars(Path::new("$HOME/x")).unwrap(),
+            PathBuf::from(format!("{home}/x"))
+        );
+        assert_eq!(
+            expand_env_vars(Path::new("${HOME}/x")).unwrap(),
+            PathBuf::from(format!("{home}/x"))
+        );
+    }
+
+    #[test]
+    fn expand_env_vars_errors_on_undefined_variable() {
+        let err = expand_env_vars_with(Path::new("$TOME_NOPE/skills"), |_| None).unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("`$TOME_NOPE` is not set"), "{msg}");
+        assert!(msg.contains("$TOME_NOPE/skills"), "{msg}");
+    }
+
+    #[test]
+    fn expand_env_vars_keeps_literal_dollars_and_plain_paths() {
+        let lookup = |name: &str| (name == "A").then(|| "a".to_string());
+        assert_eq!(
+            expand_env_vars_with(Path::new("/x/$/y$A-$1"), lookup).unwrap(),
+            PathBuf::from("/x/$/ya-$1")
+        );
+        assert_eq!(
+            expand_env_vars_with(Path::new("~/skills"), lookup).unwrap(),
+            PathBuf::from("~/skills")
+        );
+        assert!(expand_env_vars_with(Path::new("${A"), lookup).is_err());
+    }
+
     // === HARD-22 / D-TILDE-1: unexpand_tilde tests ===
     //
     // unexpand_tilde is the inverse of expand_tilde: paths under $HOME are
@@ -336,7 +551,29 @@ mod tests {
             Path::new("/lib/skills/my-skill"),
             Path::new("../../sources/my-skill"),
         );
-        assert_eq!(result, PathBuf::from("/lib/skills/../../sources/my-skill"));
+        assert_eq!(result, PathBuf::from("/sources/my-skill"));
+    }
+
+    #[test]
+    fn resolve_relative_target_folds_dot_and_dotdot_segments() {
+        let result = resolve_symlink_target(
+            Path::new("/home/u/.claude/skills/foo"),
+            Path::new("./../../x/../../.tome/./skills/foo"),
+        );
+        assert_eq!(result, PathBuf::from("/home/.tome/skills/foo"));
+        // `..` past the root stays at the root, as the kernel does.
+        let result = resolve_symlink_target(Path::new("/a/link"), Path::new("../../../b"));
+        assert_eq!(result, PathBuf::from("/b"));
+    }
+
+    #[test]
+    fn symlink_target_for_relative_and_absolute() {
+        let link = Path::new("/home/u/dotfiles/claude/skills/foo");
+        let target = Path::new("/home/u/dotfiles/tome/skills/foo");
+        assert_eq!(symlink_target_for(link, target, false), target);
+        let relative = symlink_target_for(link, target, true);
+        assert_eq!(relative, PathBuf::from("../../tome/skills/foo"));
+        assert_eq!(resolve_symlink_target(link, &relative), target);
     }
 
     #[test]
@@ -482,4 +719,59 @@ mod tests {
         );
         assert!(result.is_ok());
     }
+    #[test]
+    fn back_up_collision_renames_and_never_overwrites() {
+        let tmp = TempDir::new().unwrap();
+        let entry = tmp.path().join("my-skill");
+        std::fs::create_dir(&entry).unwrap();
+        std::fs::write(entry.join("SKILL.md"), "mine").unwrap();
+
+        let backup = back_up_collision(&entry, false).unwrap().unwrap();
+        assert_eq!(backup, tmp.path().join("my-skill.tome-bak"));
+        assert!(!entry.exists());
+        assert_eq!(
+            std::fs::read_to_string(backup.join("SKILL.md")).unwrap(),
+            "mine"
+        );
+
+        // A second collision with the backup slot taken is left alone.
+        std::fs::create_dir(&entry).unwrap();
+        assert!(back_up_collision(&entry, false).unwrap().is_none());
+        assert!(entry.is_dir());
+        assert_eq!(
+            std::fs::read_to_string(backup.join("SKILL.md")).unwrap(),
+            "mine"
+        );
+    }
+
+    #[test]
+    fn back_up_collision_dry_run_does_not_rename() {
+        let tmp = TempDir::new().unwrap();
+        let entry = tmp.path().join("notes.md");
+        std::fs::write(&entry, "x").unwrap();
+
+        let backup = back_up_collision(&entry, true).unwrap().unwrap();
+        assert_eq!(backup, tmp.path().join("notes.md.tome-bak"));
+        assert!(entry.exists());
+        assert!(!backup.exists());
+    }
+
+    #[test]
+    fn is_case_insensitive_probe_leaves_nothing_behind() {
+        let tmp = TempDir::new().unwrap();
+        let library = tmp.path().join("not-yet/library");
+        // Probing a missing library probes its nearest existing ancestor.
+        // The result depends on the host filesystem; only cleanup is
+        // portable to assert.
+        let _ = is_case_insensitive(&library);
+        assert_eq!(std::fs::read_dir(tmp.path()).unwrap().count(), 0);
+        assert!(!is_case_insensitive(Path::new("")));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn is_case_insensitive_false_on_linux_tmp() {
+        let tmp = TempDir::new().unwrap();
+        assert!(!is_case_insensitive(tmp.path()));
+    }
 }
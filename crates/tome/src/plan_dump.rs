@@ -0,0 +1,144 @@
+//! `tome sync --dump-plan-on-error` — persist the sync pipeline's
+//! plan-so-far when a run fails midway.
+//!
+//! `sync()` records each stage's decisions into a [`SyncPlanDump`] as it
+//! goes (reconcile classification, discovered skills, consolidate counts,
+//! per-directory distribute results). On success the dump is discarded; on
+//! failure — and only when the user opted in with `--dump-plan-on-error` —
+//! it is written as JSON to `<library_dir>/.tome-cache/sync-plan.json` and
+//! the error message points at the file.
+//!
+//! This is a debugging aid, not a resume format: nothing reads the file
+//! back. The reconcile section reuses the [`crate::reconcile`] structures
+//! directly so the dump shows exactly what the pipeline decided.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::discover::DiscoveredSkill;
+use crate::distribute::DistributeResult;
+use crate::library::ConsolidateResult;
+use crate::progress::SyncStage;
+use crate::reconcile::{Classified, EditDecision, Edited, ReconcileReport};
+
+/// Directory (under `library_dir`) that holds tome's debugging artifacts.
+pub(crate) const CACHE_DIRNAME: &str = ".tome-cache";
+
+/// File name of the dump inside [`CACHE_DIRNAME`]. A later failure
+/// overwrites an earlier dump — only the most recent failed run is kept.
+pub(crate) const DUMP_FILENAME: &str = "sync-plan.json";
+
+/// Plan-so-far snapshot of one `sync()` run.
+///
+/// Sections for stages that never ran stay `None` / empty, so the populated
+/// fields double as the list of stages that completed before the failure.
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct SyncPlanDump {
+    /// RFC-3339 timestamp of when the dump was written.
+    pub(crate) written_at: String,
+    /// Stage that was running when the error surfaced (the last stage
+    /// observed to start). `None` if the pipeline failed before the first
+    /// stage boundary.
+    pub(crate) failed_stage: Option<SyncStage>,
+    /// The error chain, outermost first.
+    pub(crate) error: Vec<String>,
+    /// Reconcile classification. `None` when reconcile did not run (no
+    /// `claude-plugins` directory configured) or failed before reporting.
+    pub(crate) reconcile: Option<ReconcilePlan>,
+    /// Skills discovery decided to consolidate, in discovery order.
+    pub(crate) discovered: Vec<DiscoveredSkill>,
+    /// Consolidate counts, once the stage finished.
+    pub(crate) consolidate: Option<ConsolidateResult>,
+    /// One entry per distribution directory that finished distributing.
+    pub(crate) distributions: Vec<DistributeResult>,
+    /// Stale distribution symlinks pruned, once the cleanup stage finished.
+    pub(crate) removed_from_targets: Option<usize>,
+}
+
+/// The reconcile section of [`SyncPlanDump`].
+#[derive(Debug, Serialize)]
+pub(crate) struct ReconcilePlan {
+    pub(crate) matches: usize,
+    pub(crate) drift: Vec<Classified>,
+    pub(crate) vanished: Vec<Classified>,
+    pub(crate) missing: Vec<Classified>,
+    pub(crate) edited: Vec<Edited>,
+    pub(crate) edit_decisions: Vec<EditDecision>,
+    pub(crate) apply_skipped: bool,
+    /// `<plugin_id>: <error>` per failed install/update.
+    pub(crate) install_failures: Vec<String>,
+}
+
+impl From<&ReconcileReport> for ReconcilePlan {
+    fn from(report: &ReconcileReport) -> Self {
+        Self {
+            matches: report.matches,
+            drift: report.drift.clone(),
+            vanished: report.vanished.clone(),
+            missing: report.missing.clone(),
+            edited: report.edited.clone(),
+            edit_decisions: report.edit_decisions.clone(),
+            apply_skipped: report.apply_skipped,
+            install_failures: report
+                .install_failures
+                .iter()
+                .map(|f| format!("{}: {:#}", f.plugin_id, f.source))
+                .collect(),
+        }
+    }
+}
+
+impl SyncPlanDump {
+    /// Stamp the failure details onto the plan.
+    pub(crate) fn record_failure(&mut self, stage: Option<SyncStage>, err: &anyhow::Error) {
+        self.written_at = crate::manifest::now_iso8601();
+        self.failed_stage = stage;
+        self.error = err.chain().map(|cause| cause.to_string()).collect();
+    }
+}
+
+/// Write `dump` to `<library_dir>/.tome-cache/sync-plan.json`, creating the
+/// cache directory as needed. Returns the path written.
+pub(crate) fn write(library_dir: &Path, dump: &SyncPlanDump) -> Result<PathBuf> {
+    let cache_dir = library_dir.join(CACHE_DIRNAME);
+    std::fs::create_dir_all(&cache_dir)
+        .with_context(|| format!("failed to create {}", cache_dir.display()))?;
+    let path = cache_dir.join(DUMP_FILENAME);
+    let json = serde_json::to_string_pretty(dump).context("failed to serialize sync plan")?;
+    std::fs::write(&path, json).with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn write_creates_cache_dir_and_records_failure() {
+        let tmp = TempDir::new().unwrap();
+        let mut dump = SyncPlanDump {
+            consolidate: Some(ConsolidateResult {
+                created: 2,
+                ..ConsolidateResult::default()
+            }),
+            ..SyncPlanDump::default()
+        };
+        let err = anyhow::anyhow!("disk full").context("failed to create target dir /x");
+        dump.record_failure(Some(SyncStage::Distribute), &err);
+
+        let path = write(tmp.path(), &dump).unwrap();
+        assert_eq!(path, tmp.path().join(CACHE_DIRNAME).join(DUMP_FILENAME));
+
+        let value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(value["failed_stage"], "Distribute");
+        assert_eq!(value["error"][0], "failed to create target dir /x");
+        assert_eq!(value["error"][1], "disk full");
+        assert_eq!(value["consolidate"]["created"], 2);
+        assert!(value["reconcile"].is_null());
+        assert!(value["removed_from_targets"].is_null());
+    }
+}
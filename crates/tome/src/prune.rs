@@ -0,0 +1,125 @@
+//! `tome prune` — remove tome's symlinks from directories disabled on this
+//! machine.
+//!
+//! Sync skips a directory listed in `disabled_directories`, so the links it
+//! created before the directory was disabled stay behind. Prune removes
+//! every symlink into the library from those directories; external
+//! symlinks and real files are left alone. Re-enable the directory and run
+//! `tome sync` to recreate the links.
+
+use anyhow::Result;
+use console::style;
+
+use crate::config::Config;
+use crate::eject::{self, EjectPlan};
+use crate::machine::MachinePrefs;
+use crate::paths::TomePaths;
+
+/// Library symlinks in every distribution directory disabled in
+/// `machine_prefs`.
+pub(crate) fn plan(
+    config: &Config,
+    paths: &TomePaths,
+    machine_prefs: &MachinePrefs,
+) -> Result<EjectPlan> {
+    eject::plan_for(
+        config
+            .distribution_dirs()
+            .filter(|(name, _)| machine_prefs.is_directory_disabled(name.as_str())),
+        paths,
+    )
+}
+
+/// Remove the planned symlinks and print a count per directory.
+pub(crate) fn run(plan: &EjectPlan, dry_run: bool) -> Result<usize> {
+    if plan.total_symlinks == 0 {
+        println!("Nothing to prune — no library symlinks in disabled directories.");
+        return Ok(0);
+    }
+
+    let removed = eject::execute(plan, dry_run)?;
+    let (verb, summary) = if dry_run {
+        ("to remove", "Would prune")
+    } else {
+        ("removed", "Pruned")
+    };
+    for entry in &plan.targets {
+        println!(
+            "  {}: {} symlink(s) {verb}",
+            style(entry.name.as_str()).cyan(),
+            entry.symlinks.len()
+        );
+    }
+    println!(
+        "\n{summary} {removed} symlink(s) across {} disabled directory(ies)",
+        plan.targets.len()
+    );
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, NameTransform,
+    };
+    use std::os::unix::fs as unix_fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn target(path: &Path) -> DirectoryConfig {
+        DirectoryConfig {
+            path: path.to_path_buf(),
+            directory_type: DirectoryType::Directory,
+            role: Some(DirectoryRole::Target),
+            git_ref: None,
+            subdir: None,
+            max_depth: None,
+            follow_links: false,
+            name_transform: NameTransform::None,
+            prefix: None,
+            include: None,
+            exclude: None,
+            override_applied: false,
+            scan_origin: Default::default(),
+        }
+    }
+
+    #[test]
+    fn plan_covers_only_disabled_directories_and_library_links() {
+        let tmp = TempDir::new().unwrap();
+        let library = tmp.path().join("library");
+        let skill = library.join("my-skill");
+        std::fs::create_dir_all(&skill).unwrap();
+        let external = tmp.path().join("external");
+        std::fs::create_dir_all(&external).unwrap();
+
+        let on = tmp.path().join("on");
+        let off = tmp.path().join("off");
+        for dir in [&on, &off] {
+            std::fs::create_dir_all(dir).unwrap();
+            unix_fs::symlink(&skill, dir.join("my-skill")).unwrap();
+        }
+        unix_fs::symlink(&external, off.join("external")).unwrap();
+        std::fs::create_dir(off.join("real-dir")).unwrap();
+
+        let mut config = Config {
+            library_dir: library.clone(),
+            ..Config::default()
+        };
+        config
+            .directories
+            .insert(DirectoryName::new("on").unwrap(), target(&on));
+        config
+            .directories
+            .insert(DirectoryName::new("off").unwrap(), target(&off));
+        let mut prefs = MachinePrefs::default();
+        prefs.disable_directory(DirectoryName::new("off").unwrap());
+        let paths = TomePaths::new(tmp.path().to_path_buf(), library).unwrap();
+
+        let plan = plan(&config, &paths, &prefs).unwrap();
+        assert_eq!(plan.total_symlinks, 1);
+        assert_eq!(plan.targets[0].name.as_str(), "off");
+        assert_eq!(plan.targets[0].symlinks, vec![off.join("my-skill")]);
+    }
+}
@@ -143,6 +143,17 @@ pub fn plan(
         );
     }
 
+    // A glob path names several directories, none of which is the one
+    // place to copy the skill into.
+    if to_dir_config.is_glob() {
+        anyhow::bail!(
+            "directory '{}' has a glob path ({}) and cannot receive \
+             reassigned skills. Reassign into a directory with a single path.",
+            to_dir,
+            to_dir_config.path.display(),
+        );
+    }
+
     // Determine action: does the skill already exist in the target directory?
     let target_dir_for_skill = crate::config::expand_tilde(&to_dir_config.path)?.join(skill_name);
     let target_skill_md = target_dir_for_skill.join("SKILL.md");
@@ -426,7 +437,7 @@ mod tests {
     }
 
     fn make_config_with_dir(tmp: &TempDir, name: &str) -> Config {
-        use crate::config::{DirectoryConfig, DirectoryType};
+        use crate::config::{DirectoryConfig, DirectoryType, NameTransform};
         let dir_path = tmp.path().join(name);
         std::fs::create_dir_all(&dir_path).unwrap();
         let mut config = Config::default();
@@ -438,7 +449,14 @@ mod tests {
                 role: None,
                 git_ref: None,
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
         config
@@ -611,7 +629,7 @@ mod tests {
 
     #[test]
     fn plan_rejects_target_only_role() {
-        use crate::config::{DirectoryConfig, DirectoryRole, DirectoryType};
+        use crate::config::{DirectoryConfig, DirectoryRole, DirectoryType, NameTransform};
 
         let tmp = TempDir::new().unwrap();
         let paths = test_paths(&tmp);
@@ -626,7 +644,14 @@ mod tests {
                 role: Some(DirectoryRole::Target), // target-only
                 git_ref: None,
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
         let mut manifest = Manifest::default();
@@ -33,7 +33,7 @@ use crate::paths::TomePaths;
 
 /// Classification of a single managed lockfile entry against the live
 /// marketplace + library state. Drives the drift-apply loop (RECON-01).
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
 pub enum ReconcileClass {
     /// Lockfile content_hash equals freshly-computed library content_hash
     /// AND adapter reports the plugin is installed (D-01).
@@ -59,7 +59,7 @@ pub enum ReconcileClass {
 /// Returned per `Edited` skill so the caller (`lib.rs::sync`) can apply the
 /// manifest mutation — `reconcile_lockfile` only PROPOSES the choice; the
 /// owner of the manifest applies it.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
 pub enum EditDecision {
     /// D-13 in-place flip: `managed: true → false`, `source_name: Some →
     /// None`. Library content unchanged. Lockfile entry preserved with
@@ -77,7 +77,7 @@ pub enum EditDecision {
 
 /// A managed lockfile entry's classification + the metadata needed to drive
 /// downstream rendering / apply.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct Classified {
     pub name: SkillName,
     pub registry_id: String,
@@ -88,7 +88,7 @@ pub struct Classified {
 /// Edit-in-library detection record (RECON-05). Separate from
 /// `ReconcileClass` because the gate is different (manifest-side, not
 /// lockfile-side) and the prompt is independent of `auto_install_plugins`.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct Edited {
     pub name: SkillName,
     pub old_source: DirectoryName,
@@ -14,7 +14,7 @@ use std::path::{Path, PathBuf};
 use crate::config::{Config, DirectoryName, expand_tilde};
 use crate::discover::SkillName;
 use crate::manifest;
-use crate::paths::{TomePaths, resolve_symlink_target};
+use crate::paths::{TomePaths, create_dir_symlink, resolve_symlink_target, symlink_target_for};
 
 /// A plan describing what the relocate command will do.
 #[derive(Debug)]
@@ -568,8 +568,12 @@ fn recreate_target_symlinks(plan: &RelocatePlan) -> Result<()> {
                 })?;
 
                 // Create new symlink pointing to new library
-                let new_target = plan.new_library_dir.join(&skill_name);
-                std::os::unix::fs::symlink(&new_target, &path).with_context(|| {
+                let new_target = symlink_target_for(
+                    &path,
+                    &plan.new_library_dir.join(&skill_name),
+                    config.relative_links,
+                );
+                create_dir_symlink(&new_target, &path).with_context(|| {
                     format!(
                         "failed to create target symlink {} -> {}",
                         path.display(),
@@ -586,7 +590,7 @@ fn recreate_target_symlinks(plan: &RelocatePlan) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{Config, DirectoryConfig, DirectoryRole, DirectoryType};
+    use crate::config::{Config, DirectoryConfig, DirectoryRole, DirectoryType, NameTransform};
     use crate::manifest::{self, SkillEntry};
     use std::collections::BTreeMap;
     use std::os::unix::fs as unix_fs;
@@ -785,7 +789,14 @@ mod tests {
                 git_ref: None,
 
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
 
@@ -809,7 +809,9 @@ pub(crate) fn skill_execute(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType};
+    use crate::config::{
+        Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, NameTransform,
+    };
     use crate::discover::SkillName;
     use crate::manifest::{Manifest, SkillEntry};
     use crate::validation::ContentHash;
@@ -849,7 +851,14 @@ mod tests {
                 role: Some(DirectoryRole::Source),
                 git_ref: None,
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
         directories.insert(
@@ -860,7 +869,14 @@ mod tests {
                 role: Some(DirectoryRole::Target),
                 git_ref: None,
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
 
@@ -12,12 +12,12 @@
 //!    size").
 
 use anyhow::{Context, bail};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Parsed SKILL.md frontmatter fields.
-#[derive(Debug, Clone, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "kebab-case")]
 #[allow(dead_code)]
 pub struct SkillFrontmatter {
@@ -37,6 +37,65 @@ pub struct SkillFrontmatter {
     pub extra: BTreeMap<String, serde_yaml::Value>,
 }
 
+impl SkillFrontmatter {
+    /// Whether the top-level `key` (as written in YAML, e.g.
+    /// `allowed-tools`) is set to a non-empty value.
+    pub(crate) fn has_field(&self, key: &str) -> bool {
+        let Ok(serde_yaml::Value::Mapping(fields)) = serde_yaml::to_value(self) else {
+            return false;
+        };
+        match fields.get(key) {
+            None | Some(serde_yaml::Value::Null) => false,
+            Some(serde_yaml::Value::String(s)) => !s.trim().is_empty(),
+            Some(_) => true,
+        }
+    }
+
+    /// A list read from `metadata.<key>` or a top-level `<key>`, given
+    /// either as a YAML list or a comma-separated string.
+    pub(crate) fn list_field(&self, key: &str) -> Vec<String> {
+        let value = self
+            .metadata
+            .as_ref()
+            .and_then(|m| m.get(key))
+            .or_else(|| self.extra.get(key));
+        match value {
+            Some(serde_yaml::Value::Sequence(items)) => items
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect(),
+            Some(serde_yaml::Value::String(s)) => s
+                .split(',')
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(str::to_string)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Names of the skills this one depends on, from `requires`.
+    pub fn requires(&self) -> Vec<String> {
+        self.list_field("requires")
+    }
+
+    /// Whether the author opted the skill out of sync with `enabled: false`,
+    /// top-level or under `metadata` (where `"false"` also counts, since
+    /// spec metadata values are strings).
+    pub fn is_disabled(&self) -> bool {
+        let value = self
+            .metadata
+            .as_ref()
+            .and_then(|m| m.get("enabled"))
+            .or_else(|| self.extra.get("enabled"));
+        match value {
+            Some(serde_yaml::Value::Bool(enabled)) => !enabled,
+            Some(serde_yaml::Value::String(s)) => s.eq_ignore_ascii_case("false"),
+            _ => false,
+        }
+    }
+}
+
 /// Extract frontmatter YAML block from SKILL.md content.
 /// Returns (yaml_content, body) or None if no valid frontmatter delimiters.
 pub fn extract_frontmatter(content: &str) -> Option<(&str, &str)> {
@@ -62,6 +121,85 @@ pub fn extract_frontmatter(content: &str) -> Option<(&str, &str)> {
     Some((yaml.trim(), body))
 }
 
+/// File that marks a directory as a skill unless `skill_file_names` in
+/// `tome.toml` says otherwise.
+pub const SKILL_FILE_NAME: &str = "SKILL.md";
+
+/// The first of `names` present as a regular file in `skill_dir`.
+pub(crate) fn find_skill_file<'a>(skill_dir: &Path, names: &'a [String]) -> Option<&'a str> {
+    names
+        .iter()
+        .map(String::as_str)
+        .find(|name| skill_dir.join(name).is_file())
+}
+
+/// Extension of the Cursor rule files a `cursor-rules` directory holds.
+pub(crate) const CURSOR_RULE_EXTENSION: &str = "mdc";
+
+/// Generate the `SKILL.md` for a Cursor rule file named `name`.
+///
+/// The rule's `description` is carried over (falling back to a generic one),
+/// its `globs` and `alwaysApply` move under `metadata` as the strings
+/// `cursor-globs` and `cursor-always-apply`, and the body is kept verbatim.
+/// A rule without frontmatter becomes the body as a whole.
+pub(crate) fn cursor_rule_to_skill_md(name: &str, rule: &str) -> String {
+    let (yaml, body) = extract_frontmatter(rule).unwrap_or(("", rule));
+    let rule_fm: serde_yaml::Mapping = serde_yaml::from_str(yaml).unwrap_or_default();
+    // Metadata values are plain strings; a `globs` list is comma-joined the
+    // way Cursor itself writes it.
+    let field = |key: &str| -> Option<String> {
+        let value = match rule_fm.get(key)? {
+            serde_yaml::Value::String(s) => s.trim().to_string(),
+            serde_yaml::Value::Bool(b) => b.to_string(),
+            serde_yaml::Value::Number(n) => n.to_string(),
+            serde_yaml::Value::Sequence(items) => items
+                .iter()
+                .filter_map(|item| item.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+            _ => return None,
+        };
+        (!value.is_empty()).then_some(value)
+    };
+
+    let mut fm = serde_yaml::Mapping::new();
+    fm.insert("name".into(), name.into());
+    fm.insert(
+        "description".into(),
+        field("description")
+            .unwrap_or_else(|| format!("Cursor rule {name}"))
+            .into(),
+    );
+    let mut metadata = serde_yaml::Mapping::new();
+    if let Some(globs) = field("globs") {
+        metadata.insert("cursor-globs".into(), globs.into());
+    }
+    if let Some(always) = field("alwaysApply") {
+        metadata.insert("cursor-always-apply".into(), always.into());
+    }
+    if !metadata.is_empty() {
+        fm.insert("metadata".into(), metadata.into());
+    }
+    let yaml = serde_yaml::to_string(&fm).unwrap_or_default();
+    format!("---\n{yaml}---\n{body}")
+}
+
+/// If `path` is a Cursor rule file, read it and return the `SKILL.md` it
+/// converts to (see [`cursor_rule_to_skill_md`]), named after the file stem.
+/// Returns `None` for anything else, including directories.
+pub(crate) fn read_cursor_rule_as_skill_md(path: &Path) -> anyhow::Result<Option<String>> {
+    if !path.is_file() || path.extension().and_then(|e| e.to_str()) != Some(CURSOR_RULE_EXTENSION) {
+        return Ok(None);
+    }
+    let name = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or_default();
+    let rule = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    Ok(Some(cursor_rule_to_skill_md(name, &rule)))
+}
+
 /// Parse SKILL.md content into frontmatter + body.
 ///
 /// # Errors
@@ -259,11 +397,14 @@ pub fn collect_detail(
         .with_context(|| format!("failed to resolve source path for '{name}'"))?;
 
     // The library-canonical copy is always at `<library_dir>/<name>/SKILL.md`
-    // (v0.10 library-canonical model). Read it directly here — we don't go
+    // (v0.10 library-canonical model), or another of `skill_file_names`. Read it directly here — we don't go
     // through `source_path` because Owned managed skills carry a
     // source_path that points at the upstream package-manager-owned dir,
     // and SKILL.md there may not exist if the manager has gated visibility.
-    let skill_md = paths.library_dir().join(name.as_str()).join("SKILL.md");
+    let skill_dir = paths.library_dir().join(name.as_str());
+    let skill_file =
+        find_skill_file(&skill_dir, config.skill_file_names()).unwrap_or(SKILL_FILE_NAME);
+    let skill_md = skill_dir.join(skill_file);
     let raw = std::fs::read_to_string(&skill_md)
         .with_context(|| format!("failed to read {}", skill_md.display()))?;
     let (frontmatter, mut body) = crate::skill::parse(&raw)
@@ -315,6 +456,57 @@ mod tests {
         assert_eq!(body, "# Body");
     }
 
+    #[test]
+    fn requires_reads_metadata_list_or_top_level_string() {
+        let content = "---\nname: a\nmetadata:\n  requires: [b, c]\n---\n";
+        let (fm, _) = parse(content).unwrap();
+        assert_eq!(fm.requires(), vec!["b", "c"]);
+
+        let content = "---\nname: a\nrequires: \"b, c\"\n---\n";
+        let (fm, _) = parse(content).unwrap();
+        assert_eq!(fm.requires(), vec!["b", "c"]);
+
+        let (fm, _) = parse("---\nname: a\n---\n").unwrap();
+        assert!(fm.requires().is_empty());
+    }
+
+    #[test]
+    fn is_disabled_reads_enabled_false() {
+        let disabled = |content: &str| parse(content).unwrap().0.is_disabled();
+        assert!(disabled("---\nname: a\nenabled: false\n---\n"));
+        assert!(disabled(
+            "---\nname: a\nmetadata:\n  enabled: \"false\"\n---\n"
+        ));
+        assert!(!disabled("---\nname: a\nenabled: true\n---\n"));
+        assert!(!disabled("---\nname: a\n---\n"));
+    }
+
+    #[test]
+    fn cursor_rule_to_skill_md_maps_rule_fields() {
+        let rule = "---\ndescription: Prefer early returns\nglobs:\n  - \"src/**/*.rs\"\n  - \"tests/*.rs\"\nalwaysApply: false\n---\n# Early returns\n";
+        let content = cursor_rule_to_skill_md("early-returns", rule);
+        let (fm, body) = parse(&content).unwrap();
+        assert_eq!(fm.name.as_deref(), Some("early-returns"));
+        assert_eq!(fm.description.as_deref(), Some("Prefer early returns"));
+        let metadata = fm.metadata.unwrap();
+        assert_eq!(
+            metadata["cursor-globs"].as_str(),
+            Some("src/**/*.rs,tests/*.rs")
+        );
+        assert_eq!(metadata["cursor-always-apply"].as_str(), Some("false"));
+        assert_eq!(body, "# Early returns\n");
+    }
+
+    #[test]
+    fn cursor_rule_to_skill_md_without_frontmatter() {
+        let content = cursor_rule_to_skill_md("plain", "Always write tests.\n");
+        let (fm, body) = parse(&content).unwrap();
+        assert_eq!(fm.name.as_deref(), Some("plain"));
+        assert_eq!(fm.description.as_deref(), Some("Cursor rule plain"));
+        assert!(fm.metadata.is_none());
+        assert_eq!(body, "Always write tests.\n");
+    }
+
     #[test]
     fn parse_with_extra_fields() {
         let content = "---\nname: test\nversion: 1.0\ncategory: tools\n---\nbody";
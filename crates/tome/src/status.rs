@@ -5,6 +5,7 @@ use console::style;
 use std::path::{Path, PathBuf};
 use tabled::settings::{Modify, Style, object::Rows};
 
+use crate::cleanup::{self, TargetEntryKind};
 use crate::config::Config;
 use crate::lockfile;
 use crate::machine;
@@ -215,10 +216,33 @@ pub struct StatusReport {
     pub health: CountOrError,
 }
 
+/// Link health of one distribution directory (`tome status --target`).
+#[derive(serde::Serialize)]
+#[cfg_attr(feature = "bindings", derive(specta::Type))]
+pub struct TargetStatus {
+    pub name: String,
+    pub path: String,
+    /// Symlinks into the library whose skill is present.
+    pub linked: usize,
+    /// Symlinks into the library whose skill is gone; `tome sync` or
+    /// `tome doctor` removes them.
+    pub stale: Vec<String>,
+    /// Symlinks pointing somewhere outside the library.
+    pub external: Vec<String>,
+    /// Real files and directories tome does not manage.
+    pub unmanaged: Vec<String>,
+}
+
 // -- Data gathering (pure computation, no I/O) --
 
 /// Gather status data without producing any output.
 pub fn gather(config: &Config, paths: &TomePaths) -> Result<StatusReport> {
+    gather_at_depth(config, paths, crate::doctor::DEFAULT_SCAN_DEPTH)
+}
+
+/// [`gather`], counting target-only links down to `depth` levels below
+/// each directory (`tome status --depth`).
+pub fn gather_at_depth(config: &Config, paths: &TomePaths, depth: usize) -> Result<StatusReport> {
     let configured = paths.library_dir().is_dir() || !config.directories.is_empty();
 
     let library_count = if paths.library_dir().is_dir() {
@@ -232,12 +256,23 @@ pub fn gather(config: &Config, paths: &TomePaths) -> Result<StatusReport> {
         .iter()
         .map(|(name, dir_config)| {
             let role = dir_config.role();
-            let skill_count = if role.is_discovery() {
+            let skill_count = if dir_config.is_glob() {
+                // Glob sources: sum over every matching directory
+                dir_config
+                    .glob_matches()
+                    .and_then(|matches| {
+                        matches
+                            .iter()
+                            .map(|m| count_skill_dirs(m))
+                            .sum::<Result<usize>>()
+                    })
+                    .map_err(|e| format!("{e:#}"))
+            } else if role.is_discovery() {
                 // For discovery directories, count SKILL.md subdirs
                 count_skill_dirs(&dir_config.path).map_err(|e| e.to_string())
             } else {
                 // For target-only directories, count existing symlinks
-                count_symlinks(&dir_config.path).map_err(|e| e.to_string())
+                count_symlinks(&dir_config.path, depth).map_err(|e| e.to_string())
             };
             let warnings = Vec::new();
             DirectoryStatus {
@@ -312,6 +347,46 @@ pub fn gather(config: &Config, paths: &TomePaths) -> Result<StatusReport> {
     })
 }
 
+/// Classify every entry in the distribution directory `name`. Errors if no
+/// such directory is configured or it does not receive skills.
+pub fn gather_target(config: &Config, paths: &TomePaths, name: &str) -> Result<TargetStatus> {
+    let Some((dir_name, dir_config)) = config
+        .distribution_dirs()
+        .find(|(dir_name, _)| dir_name.as_str() == name)
+    else {
+        let known: Vec<&str> = config
+            .distribution_dirs()
+            .map(|(n, _)| n.as_str())
+            .collect();
+        anyhow::bail!(
+            "no target directory named '{name}' (configured: {})",
+            if known.is_empty() {
+                "none".to_string()
+            } else {
+                known.join(", ")
+            }
+        );
+    };
+
+    let mut status = TargetStatus {
+        name: dir_name.as_str().to_string(),
+        path: dir_config.path.display().to_string(),
+        linked: 0,
+        stale: Vec::new(),
+        external: Vec::new(),
+        unmanaged: Vec::new(),
+    };
+    for (entry, kind) in cleanup::classify_target_entries(&dir_config.path, paths.library_dir())? {
+        match kind {
+            TargetEntryKind::ManagedValid => status.linked += 1,
+            TargetEntryKind::ManagedStale => status.stale.push(entry),
+            TargetEntryKind::External => status.external.push(entry),
+            TargetEntryKind::RealFile => status.unmanaged.push(entry),
+        }
+    }
+    Ok(status)
+}
+
 // -- Rendering --
 
 /// Format the PATH column for the directories table. When `override_applied`
@@ -364,8 +439,8 @@ fn format_unowned_section(unowned: &[crate::summary::SkillSummary]) -> Option<St
 }
 
 /// Display the current status of the tome system.
-pub fn show(config: &Config, paths: &TomePaths, json: bool) -> Result<()> {
-    let report = gather(config, paths)?;
+pub fn show(config: &Config, paths: &TomePaths, json: bool, depth: usize) -> Result<()> {
+    let report = gather_at_depth(config, paths, depth)?;
     if json {
         println!("{}", serde_json::to_string_pretty(&report)?);
     } else {
@@ -374,6 +449,114 @@ pub fn show(config: &Config, paths: &TomePaths, json: bool) -> Result<()> {
     Ok(())
 }
 
+/// Health problems found by `tome status --check`, as a one-line summary.
+///
+/// Bubbled through `anyhow::Result` from [`check`] so `main.rs` can
+/// downcast and exit 1 instead of the library calling `process::exit(1)`.
+#[derive(Debug)]
+pub struct StatusCheckFailed {
+    pub broken_symlinks: usize,
+    pub missing_sources: usize,
+}
+
+impl std::fmt::Display for StatusCheckFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut problems = Vec::new();
+        if self.broken_symlinks > 0 {
+            problems.push(format!("{} broken symlink(s)", self.broken_symlinks));
+        }
+        if self.missing_sources > 0 {
+            problems.push(format!(
+                "{} missing source director{}",
+                self.missing_sources,
+                if self.missing_sources == 1 {
+                    "y"
+                } else {
+                    "ies"
+                }
+            ));
+        }
+        write!(
+            f,
+            "status: unhealthy — {} (run `tome doctor` for details)",
+            problems.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for StatusCheckFailed {}
+
+/// Exit-code health check for `tome status --check`. Prints nothing and
+/// returns `Ok` when the library and every distribution directory are free
+/// of broken symlinks and every discovery directory exists; otherwise
+/// returns [`StatusCheckFailed`]. Distribution directories are scanned
+/// `depth` levels deep.
+pub fn check(config: &Config, paths: &TomePaths, depth: usize) -> Result<()> {
+    let mut broken_symlinks = count_broken_symlinks(paths.library_dir(), 1)?;
+    for (_, dir_config) in config.distribution_dirs() {
+        broken_symlinks += count_broken_symlinks(&dir_config.path, depth)?;
+    }
+    let missing_sources = crate::doctor::check_config(config)?
+        .iter()
+        .filter(|issue| issue.kind == Some(crate::doctor::DiagnosticIssueKind::MissingSource))
+        .count();
+
+    if broken_symlinks > 0 || missing_sources > 0 {
+        anyhow::bail!(StatusCheckFailed {
+            broken_symlinks,
+            missing_sources,
+        });
+    }
+    Ok(())
+}
+
+/// Display link health for one distribution directory.
+pub fn show_target(config: &Config, paths: &TomePaths, name: &str, json: bool) -> Result<()> {
+    let status = gather_target(config, paths, name)?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&status)?);
+    } else {
+        print!("{}", render_target(&status));
+    }
+    Ok(())
+}
+
+/// Text form of a [`TargetStatus`]. Pure formatter, so it can be
+/// unit-tested without capturing stdout.
+fn render_target(status: &TargetStatus) -> String {
+    let mut out = format!(
+        "{} {} ({})\n",
+        style("Target:").bold(),
+        status.name,
+        crate::paths::collapse_home(Path::new(&status.path))
+    );
+    out.push_str(&format!(
+        "  {} {} linked\n",
+        style("✓").green(),
+        style(status.linked).cyan()
+    ));
+    let sections = [
+        (
+            &status.stale,
+            "stale link(s) — run `tome sync` or `tome doctor` to remove",
+        ),
+        (&status.external, "link(s) outside the library"),
+        (&status.unmanaged, "unmanaged file(s) or directories"),
+    ];
+    for (names, label) in sections {
+        if names.is_empty() {
+            continue;
+        }
+        out.push_str(&format!(
+            "  {} {} {label}: {}\n",
+            style("⚠").yellow(),
+            style(names.len()).yellow(),
+            names.join(", ")
+        ));
+    }
+    out
+}
+
 fn render_status(report: &StatusReport) {
     if !report.configured {
         println!("Not configured yet. Run `tome init` to get started.");
@@ -549,22 +732,34 @@ fn count_skill_dirs(dir: &Path) -> Result<usize> {
     Ok(count)
 }
 
-/// Count symlinks in a directory (for target-only directories).
-fn count_symlinks(dir: &Path) -> Result<usize> {
+/// Symlinks in a directory, down to `depth` levels (`1` = its immediate
+/// children). Links are never followed, so the walk only descends through
+/// real directories.
+fn symlinks_within(dir: &Path, depth: usize) -> Result<Vec<PathBuf>> {
     if !dir.is_dir() {
-        return Ok(0);
+        return Ok(Vec::new());
     }
-    let mut count = 0;
-    for entry in std::fs::read_dir(dir)
-        .with_context(|| format!("failed to read directory {}", dir.display()))?
-    {
+    let mut links = Vec::new();
+    for entry in walkdir::WalkDir::new(dir).min_depth(1).max_depth(depth) {
         let entry = entry.with_context(|| format!("failed to read entry in {}", dir.display()))?;
-        let path = entry.path();
-        if path.is_symlink() {
-            count += 1;
+        if entry.path_is_symlink() {
+            links.push(entry.into_path());
         }
     }
-    Ok(count)
+    Ok(links)
+}
+
+/// Count symlinks in a directory (for target-only directories).
+fn count_symlinks(dir: &Path, depth: usize) -> Result<usize> {
+    Ok(symlinks_within(dir, depth)?.len())
+}
+
+/// Count symlinks in a directory whose target no longer exists.
+fn count_broken_symlinks(dir: &Path, depth: usize) -> Result<usize> {
+    Ok(symlinks_within(dir, depth)?
+        .iter()
+        .filter(|path| !path.exists())
+        .count())
 }
 
 /// Count health issues: manifest/disk mismatches.
@@ -601,7 +796,9 @@ fn count_health_issues(dir: &Path, tome_home: &Path) -> Result<usize> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType};
+    use crate::config::{
+        Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, NameTransform,
+    };
     use std::collections::BTreeMap;
     use std::path::PathBuf;
 
@@ -636,7 +833,14 @@ mod tests {
                     git_ref: None,
 
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Config::default()
@@ -690,7 +894,14 @@ mod tests {
                     git_ref: None,
 
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Config::default()
@@ -707,6 +918,54 @@ mod tests {
         assert!(report.directories[0].role_description.contains("Target"));
     }
 
+    #[test]
+    fn check_reports_broken_links_and_missing_sources() {
+        let lib_dir = tempfile::TempDir::new().unwrap();
+        let target_dir = tempfile::TempDir::new().unwrap();
+        let dir = |path: PathBuf, role| DirectoryConfig {
+            path,
+            directory_type: DirectoryType::Directory,
+            role: Some(role),
+            git_ref: None,
+
+            subdir: None,
+            max_depth: None,
+            follow_links: false,
+            name_transform: NameTransform::None,
+            prefix: None,
+            include: None,
+            exclude: None,
+            override_applied: false,
+            scan_origin: Default::default(),
+        };
+        let mut config = Config {
+            library_dir: lib_dir.path().to_path_buf(),
+            directories: BTreeMap::from([(
+                DirectoryName::new("claude").unwrap(),
+                dir(target_dir.path().to_path_buf(), DirectoryRole::Target),
+            )]),
+            ..Config::default()
+        };
+        let paths = TomePaths::new(config.library_dir.clone(), config.library_dir.clone()).unwrap();
+        check(&config, &paths, 1).unwrap();
+
+        std::os::unix::fs::symlink(lib_dir.path().join("gone"), target_dir.path().join("gone"))
+            .unwrap();
+        config.directories.insert(
+            DirectoryName::new("local").unwrap(),
+            dir(PathBuf::from("/nonexistent/skills"), DirectoryRole::Source),
+        );
+        let err = check(&config, &paths, 1).unwrap_err();
+        let failed = err.downcast_ref::<StatusCheckFailed>().unwrap();
+        assert_eq!(failed.broken_symlinks, 1);
+        assert_eq!(failed.missing_sources, 1);
+        assert_eq!(
+            failed.to_string(),
+            "status: unhealthy — 1 broken symlink(s), 1 missing source directory \
+             (run `tome doctor` for details)"
+        );
+    }
+
     #[test]
     fn gather_directory_status_includes_role_description() {
         let lib_dir = tempfile::TempDir::new().unwrap();
@@ -722,7 +981,14 @@ mod tests {
                     git_ref: None,
 
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Config::default()
@@ -835,7 +1101,7 @@ mod tests {
 
     #[test]
     fn count_symlinks_nonexistent_returns_zero() {
-        assert_eq!(count_symlinks(Path::new("/nonexistent/dir")).unwrap(), 0);
+        assert_eq!(count_symlinks(Path::new("/nonexistent/dir"), 1).unwrap(), 0);
     }
 
     #[test]
@@ -849,7 +1115,16 @@ mod tests {
         std::fs::create_dir_all(dir.path().join("real-dir")).unwrap();
         std::fs::write(dir.path().join("file.txt"), "").unwrap();
 
-        assert_eq!(count_symlinks(dir.path()).unwrap(), 1);
+        assert_eq!(count_symlinks(dir.path(), 1).unwrap(), 1);
+    }
+
+    #[test]
+    fn count_broken_symlinks_descends_to_depth() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("group")).unwrap();
+        std::os::unix::fs::symlink("/nonexistent/skill", dir.path().join("group/skill")).unwrap();
+        assert_eq!(count_broken_symlinks(dir.path(), 1).unwrap(), 0);
+        assert_eq!(count_broken_symlinks(dir.path(), 2).unwrap(), 1);
     }
 
     // -- count_health_issues --
@@ -986,7 +1261,14 @@ mod tests {
                     role: Some(DirectoryRole::Source),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Config::default()
@@ -1017,7 +1299,14 @@ mod tests {
                     role: Some(DirectoryRole::Source),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: true,
+                    scan_origin: Default::default(),
                 },
             )]),
             ..Config::default()
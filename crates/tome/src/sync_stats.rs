@@ -0,0 +1,118 @@
+//! `tome sync --stats` — per-phase wall-clock timing.
+//!
+//! `sync_pipeline` records one [`PhaseTiming`] per phase (one per
+//! distribution directory for distribute) and prints the table at the end
+//! of the run when `--stats` is set. Skill counts are what each phase
+//! looked at, not what it changed.
+
+use std::time::{Duration, Instant};
+
+use console::style;
+use tabled::settings::{Modify, Style, object::Rows};
+
+/// Wall-clock time and skill count for one sync phase.
+#[derive(Debug, Clone)]
+pub(crate) struct PhaseTiming {
+    pub(crate) label: String,
+    pub(crate) elapsed: Duration,
+    /// Skills processed; `None` for phases that don't work per skill.
+    pub(crate) skills: Option<usize>,
+}
+
+/// Phase timings collected during one sync.
+#[derive(Debug)]
+pub(crate) struct SyncStats {
+    started: Instant,
+    phases: Vec<PhaseTiming>,
+}
+
+impl SyncStats {
+    pub(crate) fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            phases: Vec::new(),
+        }
+    }
+
+    /// Record a phase that began at `since`.
+    pub(crate) fn record(
+        &mut self,
+        label: impl Into<String>,
+        since: Instant,
+        skills: Option<usize>,
+    ) {
+        self.record_elapsed(label, since.elapsed(), skills);
+    }
+
+    /// Like [`record`](Self::record), for a phase timed elsewhere (e.g. on
+    /// a worker thread).
+    pub(crate) fn record_elapsed(
+        &mut self,
+        label: impl Into<String>,
+        elapsed: Duration,
+        skills: Option<usize>,
+    ) {
+        self.phases.push(PhaseTiming {
+            label: label.into(),
+            elapsed,
+            skills,
+        });
+    }
+
+    /// The timing table, ending with a total row covering the whole sync.
+    pub(crate) fn render(&self) -> String {
+        let mut rows = vec![[
+            "PHASE".to_string(),
+            "TIME".to_string(),
+            "SKILLS".to_string(),
+        ]];
+        for phase in &self.phases {
+            rows.push([
+                phase.label.clone(),
+                format_elapsed(phase.elapsed),
+                phase
+                    .skills
+                    .map_or_else(|| "-".to_string(), |n| n.to_string()),
+            ]);
+        }
+        rows.push([
+            "total".to_string(),
+            format_elapsed(self.started.elapsed()),
+            String::new(),
+        ]);
+        let table = tabled::Table::from_iter(rows)
+            .with(Style::blank())
+            .with(
+                Modify::new(Rows::first()).with(tabled::settings::Format::content(|s| {
+                    style(s).bold().to_string()
+                })),
+            )
+            .to_string();
+        format!("{}\n{table}", style("Sync timing").bold())
+    }
+}
+
+fn format_elapsed(elapsed: Duration) -> String {
+    format!("{:.1} ms", elapsed.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_lists_phases_in_order_with_total() {
+        let mut stats = SyncStats::new();
+        let now = Instant::now();
+        stats.record("discover", now, Some(12));
+        stats.record("distribute: claude", now, Some(10));
+        stats.record("save", now, None);
+        let rendered = console::strip_ansi_codes(&stats.render()).to_string();
+        let lines: Vec<&str> = rendered.lines().map(str::trim).collect();
+        assert_eq!(lines[0], "Sync timing");
+        assert!(lines[2].starts_with("discover") && lines[2].ends_with("12"));
+        assert!(lines[3].starts_with("distribute: claude") && lines[3].ends_with("10"));
+        assert!(lines[4].starts_with("save") && lines[4].ends_with('-'));
+        assert!(lines[5].starts_with("total") && lines[5].ends_with("ms"));
+    }
+}
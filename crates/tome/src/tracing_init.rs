@@ -11,6 +11,9 @@
 //! - Filter: TOME_LOG env wins; falls back to LogLevel-derived directive
 //!   (D-ENV-1)
 //! - Default level: info (D-ENV-2)
+//! - Warning counter: every `warn!` event is counted regardless of the
+//!   filter, so `--warnings-as-errors` still trips under `--quiet` and the
+//!   sync summary can report how many fired ([`warning_count`])
 //!
 //! Idempotency: `try_init` returns `Err` if a global subscriber is already
 //! installed. We propagate that as `anyhow::Error`; the caller in `main.rs`
@@ -18,11 +21,16 @@
 //! the process aborting.
 
 use std::io::IsTerminal;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use anyhow::Result;
+use tracing::{Event, Level, Subscriber};
 use tracing_subscriber::{
-    EnvFilter,
+    EnvFilter, Layer,
+    filter::LevelFilter,
     fmt::{self, format::FmtSpan},
+    layer::{Context, SubscriberExt},
+    util::SubscriberInitExt,
 };
 
 use crate::cli::LogLevel;
@@ -34,15 +42,38 @@ pub fn install(level: LogLevel) -> Result<()> {
     let filter =
         EnvFilter::try_from_env("TOME_LOG").unwrap_or_else(|_| EnvFilter::new(level.directive()));
 
-    fmt::fmt()
+    let output = fmt::layer()
         .with_writer(std::io::stderr)
         .with_ansi(std::io::stderr().is_terminal())
         .with_target(false)
         .with_span_events(FmtSpan::CLOSE)
         .compact()
-        .with_env_filter(filter)
+        .with_filter(filter);
+
+    tracing_subscriber::registry()
+        .with(output)
+        .with(WarningCounter.with_filter(LevelFilter::WARN))
         .try_init()
         .map_err(|e| anyhow::anyhow!("tracing subscriber init failed: {e}"))?;
 
     Ok(())
 }
+
+static WARNINGS: AtomicUsize = AtomicUsize::new(0);
+
+/// Number of `warn!` events emitted so far in this process, whether or not
+/// they were printed. Always 0 when [`install`] was never called.
+pub fn warning_count() -> usize {
+    WARNINGS.load(Ordering::Relaxed)
+}
+
+/// Counts warn-level events into [`WARNINGS`].
+struct WarningCounter;
+
+impl<S: Subscriber> Layer<S> for WarningCounter {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if *event.metadata().level() == Level::WARN {
+            WARNINGS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
@@ -0,0 +1,283 @@
+//! `tome uninstall` — remove every link tome created, and with `--purge` the
+//! config file.
+//!
+//! Distribution directories are cleared the way `tome eject` clears them;
+//! the library additionally loses the symlinks tome left there in a
+//! pre-v0.10 layout. Library copies, source directories, external symlinks
+//! (in targets or the library), and real files in targets are never
+//! touched, so a later `tome init` / `tome sync` starts from intact sources.
+
+use anyhow::{Context, Result};
+use console::style;
+use std::path::{Path, PathBuf};
+
+use crate::config::{Config, DirectoryType};
+use crate::eject::{self, EjectPlan};
+use crate::manifest;
+use crate::paths::{TomePaths, resolve_symlink_target};
+
+/// Everything `tome uninstall` will remove.
+pub(crate) struct UninstallPlan {
+    /// Library symlinks in every distribution directory.
+    pub targets: EjectPlan,
+    /// Symlinks tome created at the top of the library directory.
+    pub library_symlinks: Vec<PathBuf>,
+    /// The config file, when `--purge` was given and it exists.
+    pub config_file: Option<PathBuf>,
+}
+
+impl UninstallPlan {
+    /// Total number of symlinks to remove.
+    pub fn total_symlinks(&self) -> usize {
+        self.targets.total_symlinks + self.library_symlinks.len()
+    }
+
+    /// Whether there is nothing to do.
+    pub fn is_empty(&self) -> bool {
+        self.total_symlinks() == 0 && self.config_file.is_none()
+    }
+}
+
+/// Build an uninstall plan. `config_file` is the resolved config path when
+/// `--purge` was given; it is only planned for deletion if it exists.
+pub(crate) fn plan(
+    config: &Config,
+    paths: &TomePaths,
+    config_file: Option<&Path>,
+) -> Result<UninstallPlan> {
+    Ok(UninstallPlan {
+        targets: eject::plan(config, paths)?,
+        library_symlinks: library_symlinks(config, paths)?,
+        config_file: config_file.filter(|p| p.is_file()).map(Path::to_path_buf),
+    })
+}
+
+/// Symlinks directly inside the library that tome made. Since v0.10
+/// (LIB-01) every library entry is a real directory, so these are links
+/// from an un-migrated v0.9 library or leftovers from a broken sync. A link
+/// counts as tome's when the manifest lists its name or it points into a
+/// configured source directory (or the git clone cache); anything else was
+/// put there by hand and stays.
+fn library_symlinks(config: &Config, paths: &TomePaths) -> Result<Vec<PathBuf>> {
+    let library_dir = paths.library_dir();
+    if !library_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let manifest = manifest::load(paths.config_dir())?;
+    let mut roots = vec![paths.repos_dir()];
+    for (_, dir) in config.discovery_dirs() {
+        if dir.is_glob() {
+            roots.extend(dir.glob_matches().unwrap_or_default());
+        } else if dir.directory_type != DirectoryType::Git {
+            roots.push(dir.path.clone());
+        }
+    }
+    let canonical_roots: Vec<PathBuf> = roots
+        .iter()
+        .filter_map(|root| std::fs::canonicalize(root).ok())
+        .collect();
+
+    let mut links = Vec::new();
+    for entry in std::fs::read_dir(library_dir)
+        .with_context(|| format!("failed to read {}", library_dir.display()))?
+    {
+        let path = entry?.path();
+        let Ok(raw_target) = std::fs::read_link(&path) else {
+            continue;
+        };
+        let in_manifest = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| manifest.contains_key(name));
+        let target = resolve_symlink_target(&path, &raw_target);
+        let into_source = roots.iter().any(|root| target.starts_with(root))
+            || std::fs::canonicalize(&target)
+                .is_ok_and(|t| canonical_roots.iter().any(|root| t.starts_with(root)));
+        if in_manifest || into_source {
+            links.push(path);
+        }
+    }
+    links.sort();
+    Ok(links)
+}
+
+/// Render the uninstall plan to stdout.
+pub(crate) fn render_plan(plan: &UninstallPlan, library_dir: &Path) {
+    if plan.is_empty() {
+        println!("Nothing to uninstall — no tome symlinks found in the library or any target.");
+        return;
+    }
+
+    println!("Uninstall plan:");
+    for entry in &plan.targets.targets {
+        println!(
+            "  {}: {} symlink(s) to remove",
+            style(entry.name.as_str()).cyan(),
+            entry.symlinks.len()
+        );
+    }
+    if !plan.library_symlinks.is_empty() {
+        println!(
+            "  {}: {} symlink(s) to remove",
+            style("library").cyan(),
+            plan.library_symlinks.len()
+        );
+    }
+    if let Some(config_file) = &plan.config_file {
+        println!(
+            "  {}: delete {}",
+            style("config").cyan(),
+            config_file.display()
+        );
+    }
+    println!(
+        "\nSource directories and the skill copies in {} are left in place.",
+        library_dir.display()
+    );
+}
+
+/// What [`execute`] removed.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct UninstallResult {
+    pub symlinks_removed: usize,
+    pub config_removed: bool,
+}
+
+/// Execute the plan: remove every planned symlink, then the config file.
+pub(crate) fn execute(plan: &UninstallPlan) -> Result<UninstallResult> {
+    let mut symlinks_removed = eject::execute(&plan.targets, false)?;
+    for link in &plan.library_symlinks {
+        std::fs::remove_file(link)
+            .with_context(|| format!("failed to remove {}", link.display()))?;
+        symlinks_removed += 1;
+    }
+    if let Some(config_file) = &plan.config_file {
+        std::fs::remove_file(config_file)
+            .with_context(|| format!("failed to delete {}", config_file.display()))?;
+    }
+    Ok(UninstallResult {
+        symlinks_removed,
+        config_removed: plan.config_file.is_some(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, NameTransform,
+    };
+    use std::collections::BTreeMap;
+    use std::os::unix::fs as unix_fs;
+    use tempfile::TempDir;
+
+    fn dir(path: &Path, role: DirectoryRole) -> DirectoryConfig {
+        DirectoryConfig {
+            path: path.to_path_buf(),
+            directory_type: DirectoryType::Directory,
+            role: Some(role),
+            git_ref: None,
+            subdir: None,
+            max_depth: None,
+            follow_links: false,
+            name_transform: NameTransform::None,
+            prefix: None,
+            include: None,
+            exclude: None,
+            override_applied: false,
+            scan_origin: Default::default(),
+        }
+    }
+
+    #[test]
+    fn uninstall_removes_tome_links_only() {
+        let tmp = TempDir::new().unwrap();
+        let library = tmp.path().join("library");
+        let source = tmp.path().join("source");
+        let target = tmp.path().join("target");
+        let external = tmp.path().join("external");
+        for d in [&library, &source, &target, &external] {
+            std::fs::create_dir_all(d).unwrap();
+        }
+        std::fs::create_dir(library.join("copied")).unwrap();
+        std::fs::create_dir(source.join("legacy")).unwrap();
+        // v0.9-shape library entry linking back to its source.
+        unix_fs::symlink(source.join("legacy"), library.join("legacy")).unwrap();
+        // A link the user made in the library by hand, and one pointing
+        // elsewhere that the manifest still lists as a skill.
+        unix_fs::symlink(&external, library.join("handmade")).unwrap();
+        unix_fs::symlink(&external, library.join("listed")).unwrap();
+        let mut manifest = crate::manifest::Manifest::default();
+        manifest.insert(
+            crate::discover::SkillName::new("listed").unwrap(),
+            crate::manifest::SkillEntry::new(
+                external.clone(),
+                DirectoryName::new("source").unwrap(),
+                crate::validation::ContentHash::new("a".repeat(64)).unwrap(),
+                false,
+            ),
+        );
+        crate::manifest::save(&manifest, tmp.path()).unwrap();
+        unix_fs::symlink(library.join("copied"), target.join("copied")).unwrap();
+        unix_fs::symlink(&external, target.join("foreign")).unwrap();
+        std::fs::create_dir(target.join("real")).unwrap();
+        let config_file = tmp.path().join("tome.toml");
+        std::fs::write(&config_file, "").unwrap();
+
+        let config = Config {
+            library_dir: library.clone(),
+            directories: BTreeMap::from([
+                (
+                    DirectoryName::new("source").unwrap(),
+                    dir(&source, DirectoryRole::Source),
+                ),
+                (
+                    DirectoryName::new("target").unwrap(),
+                    dir(&target, DirectoryRole::Target),
+                ),
+            ]),
+            ..Config::default()
+        };
+        let paths = TomePaths::new(tmp.path().to_path_buf(), library.clone()).unwrap();
+
+        let p = plan(&config, &paths, Some(&config_file)).unwrap();
+        assert_eq!(p.total_symlinks(), 3);
+        let result = execute(&p).unwrap();
+        assert_eq!(
+            result,
+            UninstallResult {
+                symlinks_removed: 3,
+                config_removed: true,
+            }
+        );
+
+        assert!(!target.join("copied").is_symlink());
+        assert!(!library.join("legacy").is_symlink());
+        assert!(!library.join("listed").is_symlink());
+        assert!(!config_file.exists());
+        assert!(target.join("foreign").is_symlink(), "external link kept");
+        assert!(
+            library.join("handmade").is_symlink(),
+            "user's library link kept"
+        );
+        assert!(target.join("real").is_dir(), "real directory kept");
+        assert!(library.join("copied").is_dir(), "library copy kept");
+        assert!(source.join("legacy").is_dir(), "source kept");
+    }
+
+    #[test]
+    fn uninstall_without_purge_keeps_config() {
+        let tmp = TempDir::new().unwrap();
+        let config_file = tmp.path().join("tome.toml");
+        std::fs::write(&config_file, "").unwrap();
+        let config = Config {
+            library_dir: tmp.path().join("library"),
+            ..Config::default()
+        };
+        let paths = TomePaths::new(tmp.path().to_path_buf(), config.library_dir.clone()).unwrap();
+
+        let p = plan(&config, &paths, None).unwrap();
+        assert!(p.is_empty());
+        assert!(config_file.exists());
+    }
+}
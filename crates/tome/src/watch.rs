@@ -0,0 +1,522 @@
+//! `tome watch` — re-run the sync pipeline whenever a source changes.
+//!
+//! Watches every discovery directory that lives on the local filesystem:
+//! `directory` entries recursively, and `claude-plugins` entries through
+//! their `installed_plugins.json` registry (looked up in the directory and
+//! its parent, mirroring discovery). `git` entries are not watched — their
+//! content only changes when `tome sync` pulls.
+//!
+//! Events are debounced for [`DEBOUNCE`], and every batch that is already
+//! queued is drained before syncing, so a burst of writes (an editor save,
+//! a `git checkout`) produces one sync. Roots that do not exist yet, or that
+//! are deleted and recreated, are re-registered on the next poll tick; a
+//! root coming back counts as a change. Events raised while a sync is
+//! running are kept, so an edit made mid-sync schedules one more sync;
+//! only tome's own writes (links into the library and the `.tome-links`
+//! ledger in `synced` directories) are dropped so they don't trigger
+//! another round.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use notify::RecursiveMode;
+use notify_debouncer_full::{DebounceEventResult, Debouncer, FileIdCache, new_debouncer};
+
+use crate::config::{Config, DirectoryType};
+use crate::link_marker::LINK_MARKER_FILENAME;
+use crate::paths::{TomePaths, collapse_home};
+use crate::progress::{CancelToken, NullSink};
+use crate::{SyncOptions, machine, sync};
+
+/// Quiet period after the last event before a sync is started.
+pub(crate) const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// How often missing roots are re-checked when no events arrive.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// File name of the Claude plugin registry read by `claude-plugins` discovery.
+const PLUGIN_REGISTRY: &str = "installed_plugins.json";
+
+/// A filesystem location `tome watch` observes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum WatchRoot {
+    /// A `directory` source: any change below it counts.
+    Tree(PathBuf),
+    /// A directory that may hold `installed_plugins.json`: only changes to
+    /// that file count.
+    PluginRegistry(PathBuf),
+}
+
+impl WatchRoot {
+    fn path(&self) -> &Path {
+        match self {
+            WatchRoot::Tree(path) | WatchRoot::PluginRegistry(path) => path,
+        }
+    }
+
+    fn mode(&self) -> RecursiveMode {
+        match self {
+            WatchRoot::Tree(_) => RecursiveMode::Recursive,
+            WatchRoot::PluginRegistry(_) => RecursiveMode::NonRecursive,
+        }
+    }
+
+    /// Whether an event at `path` should trigger a sync.
+    fn matches(&self, path: &Path) -> bool {
+        match self {
+            WatchRoot::Tree(root) => path.starts_with(root),
+            WatchRoot::PluginRegistry(dir) => {
+                path.parent() == Some(dir.as_path())
+                    && path.file_name().is_some_and(|n| n == PLUGIN_REGISTRY)
+            }
+        }
+    }
+}
+
+/// Options for [`run`], mirroring the subset of `tome sync` flags that make
+/// sense for an unattended loop.
+pub(crate) struct WatchOptions<'a> {
+    pub(crate) dry_run: bool,
+    pub(crate) verbose: bool,
+    pub(crate) quiet: bool,
+    pub(crate) machine_path: &'a Path,
+    pub(crate) machine_prefs: &'a machine::MachinePrefs,
+}
+
+/// Collect the roots to watch for `config`, deduplicated, in config order.
+pub(crate) fn watch_roots(config: &Config) -> Vec<WatchRoot> {
+    let mut roots = Vec::new();
+    for dir in config.directories.values() {
+        if !dir.role().is_discovery() {
+            continue;
+        }
+        match dir.directory_type {
+            // A glob is expanded once, at startup: directories that start
+            // matching later are picked up on the next `tome watch`.
+            DirectoryType::Directory if dir.is_glob() => roots.extend(
+                dir.glob_matches()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(WatchRoot::Tree),
+            ),
+            DirectoryType::Directory | DirectoryType::CursorRules => {
+                roots.push(WatchRoot::Tree(dir.path.clone()))
+            }
+            DirectoryType::ClaudePlugins => {
+                roots.push(WatchRoot::PluginRegistry(dir.path.clone()));
+                if let Some(parent) = dir.path.parent() {
+                    roots.push(WatchRoot::PluginRegistry(parent.to_path_buf()));
+                }
+            }
+            DirectoryType::Git => {}
+        }
+    }
+    let mut seen = BTreeSet::new();
+    roots.retain(|root| seen.insert(root.path().to_path_buf()));
+    roots
+}
+
+/// Return the changed paths in `events` that fall under one of `roots`.
+pub(crate) fn relevant_paths(roots: &[WatchRoot], events: &[PathBuf]) -> Vec<PathBuf> {
+    events
+        .iter()
+        .filter(|path| roots.iter().any(|root| root.matches(path)))
+        .cloned()
+        .collect()
+}
+
+/// Run an initial sync, then re-sync on every relevant change until the
+/// process is interrupted.
+pub(crate) fn run(config: &Config, paths: &TomePaths, opts: WatchOptions<'_>) -> Result<()> {
+    let configured = watch_roots(config);
+    anyhow::ensure!(
+        !configured.is_empty(),
+        "nothing to watch: no local discovery directories are configured"
+    );
+
+    let (tx, rx) = mpsc::channel::<DebounceEventResult>();
+    let mut debouncer = new_debouncer(DEBOUNCE, None, move |result| {
+        let _ = tx.send(result);
+    })
+    .context("failed to start filesystem watcher")?;
+
+    let mut watches = Watches::new(configured);
+    watches.refresh(&mut debouncer);
+
+    if !opts.quiet {
+        println!(
+            "Watching {} location(s) — press Ctrl-C to stop",
+            watches.configured.len()
+        );
+        for root in &watches.configured {
+            println!("  {}", collapse_home(root.path()));
+        }
+    }
+    let mut during_sync = sync_round(config, paths, &opts, &rx);
+
+    loop {
+        // Edits that landed while the last sync ran go first, without
+        // waiting for a new event.
+        let mut changed = if during_sync.is_empty() {
+            match rx.recv_timeout(POLL_INTERVAL) {
+                Ok(result) => batch_paths(result),
+                Err(mpsc::RecvTimeoutError::Timeout) => Vec::new(),
+                Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+        } else {
+            std::mem::take(&mut during_sync)
+        };
+        // Coalesce: anything else already queued belongs to the same burst.
+        while let Ok(result) = rx.try_recv() {
+            changed.extend(batch_paths(result));
+        }
+        let mut changed = watches.relevant(&changed);
+        changed.extend(watches.refresh(&mut debouncer));
+        if changed.is_empty() {
+            continue;
+        }
+
+        if !opts.quiet {
+            changed.sort();
+            changed.dedup();
+            let first = collapse_home(&changed[0]);
+            match changed.len() {
+                1 => println!("\nChange detected: {first}"),
+                n => println!("\nChange detected: {first} (and {} more)", n - 1),
+            }
+        }
+        during_sync = sync_round(config, paths, &opts, &rx);
+    }
+}
+
+/// Registration state for the configured roots.
+struct Watches {
+    configured: Vec<WatchRoot>,
+    /// Canonicalized root per configured entry while its watch is active.
+    /// Events carry the OS's resolved paths, so matching uses these.
+    active: Vec<Option<WatchRoot>>,
+    /// Indices whose root was watched and has since disappeared.
+    lost: BTreeSet<usize>,
+}
+
+impl Watches {
+    fn new(configured: Vec<WatchRoot>) -> Self {
+        let active = vec![None; configured.len()];
+        Self {
+            configured,
+            active,
+            lost: BTreeSet::new(),
+        }
+    }
+
+    /// Changed paths that fall under a configured or active root.
+    fn relevant(&self, events: &[PathBuf]) -> Vec<PathBuf> {
+        let mut roots = self.configured.clone();
+        roots.extend(self.active.iter().flatten().cloned());
+        relevant_paths(&roots, events)
+    }
+
+    /// Register watches for roots that exist but aren't watched, and drop
+    /// roots that have disappeared so they are re-registered when they
+    /// return. Returns the paths of lost roots that came back.
+    fn refresh<W: notify::Watcher, C: FileIdCache>(
+        &mut self,
+        debouncer: &mut Debouncer<W, C>,
+    ) -> Vec<PathBuf> {
+        let mut reappeared = Vec::new();
+        for (idx, root) in self.configured.iter().enumerate() {
+            let exists = root.path().is_dir();
+            if let Some(active) = &self.active[idx] {
+                if !exists {
+                    let _ = debouncer.unwatch(active.path());
+                    self.active[idx] = None;
+                    self.lost.insert(idx);
+                }
+                continue;
+            }
+            if !exists {
+                continue;
+            }
+            let canonical =
+                std::fs::canonicalize(root.path()).unwrap_or_else(|_| root.path().to_path_buf());
+            let resolved = match root {
+                WatchRoot::Tree(_) => WatchRoot::Tree(canonical),
+                WatchRoot::PluginRegistry(_) => WatchRoot::PluginRegistry(canonical),
+            };
+            match debouncer.watch(resolved.path(), resolved.mode()) {
+                Ok(()) => {
+                    self.active[idx] = Some(resolved);
+                    if self.lost.remove(&idx) {
+                        reappeared.push(root.path().to_path_buf());
+                    }
+                }
+                Err(e) => tracing::warn!("failed to watch {}: {e}", root.path().display()),
+            }
+        }
+        reappeared
+    }
+}
+
+/// Run one sync and report failures without ending the watch loop.
+fn run_sync(config: &Config, paths: &TomePaths, opts: &WatchOptions<'_>) {
+    let cancel = CancelToken::new();
+    let result = sync(
+        config,
+        paths,
+        SyncOptions {
+            dry_run: opts.dry_run,
+            force: false,
+            adopt: false,
+            no_triage: true,
+            no_input: true,
+            no_install: false,
+            verbose: opts.verbose,
+            quiet: opts.quiet,
+            machine_path: opts.machine_path,
+            machine_prefs: opts.machine_prefs,
+            start_stage: None,
+            dump_plan_on_error: false,
+            no_cache: false,
+            stats: false,
+            only: &[],
+            exclude_sources: &[],
+            tags: &[],
+            tag_mode: Default::default(),
+            prune_disabled: false,
+            targets_only: false,
+            parallel: 1,
+            json: false,
+            library_git_commit: None,
+        },
+        &NullSink,
+        &cancel,
+    );
+    if let Err(e) = result {
+        eprintln!("error: {e:#}");
+    }
+}
+
+/// Run one sync, then return the paths changed while it ran.
+fn sync_round(
+    config: &Config,
+    paths: &TomePaths,
+    opts: &WatchOptions<'_>,
+    rx: &mpsc::Receiver<DebounceEventResult>,
+) -> Vec<PathBuf> {
+    let started = Instant::now();
+    run_sync(config, paths, opts);
+    drain(rx, started, paths.library_dir())
+}
+
+/// Empty the queue after a sync, including the trailing batch the debouncer
+/// emits once tome's own writes settle.
+///
+/// Events from before `since` (the sync start) were already covered by that
+/// sync and are discarded. Later ones are returned so the caller can sync
+/// again, except tome's own writes: symlinks that resolve into
+/// `library_dir` and the link ledger.
+fn drain(
+    rx: &mpsc::Receiver<DebounceEventResult>,
+    since: Instant,
+    library_dir: &Path,
+) -> Vec<PathBuf> {
+    let canonical_library =
+        std::fs::canonicalize(library_dir).unwrap_or_else(|_| library_dir.to_path_buf());
+    let mut changed = Vec::new();
+    while let Ok(result) = rx.recv_timeout(DEBOUNCE * 2) {
+        let recent = result.map(|events| events.into_iter().filter(|e| e.time >= since).collect());
+        changed.extend(
+            batch_paths(recent)
+                .into_iter()
+                .filter(|path| !is_own_write(path, &canonical_library)),
+        );
+    }
+    changed
+}
+
+/// Whether `path` is something sync itself writes into a watched
+/// directory: the link ledger, or a symlink into the library.
+fn is_own_write(path: &Path, canonical_library: &Path) -> bool {
+    if path
+        .file_name()
+        .is_some_and(|name| name == LINK_MARKER_FILENAME)
+    {
+        return true;
+    }
+    path.is_symlink()
+        && std::fs::canonicalize(path).is_ok_and(|target| target.starts_with(canonical_library))
+}
+
+/// Flatten one debouncer batch into the paths it touched.
+fn batch_paths(result: DebounceEventResult) -> Vec<PathBuf> {
+    match result {
+        Ok(events) => events.into_iter().flat_map(|e| e.event.paths).collect(),
+        Err(errors) => {
+            for e in errors {
+                tracing::warn!("watch error: {e}");
+            }
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{DirectoryConfig, DirectoryName, DirectoryRole, NameTransform};
+    use std::collections::BTreeMap;
+
+    fn dir_config(
+        path: &str,
+        directory_type: DirectoryType,
+        role: DirectoryRole,
+    ) -> DirectoryConfig {
+        DirectoryConfig {
+            path: PathBuf::from(path),
+            directory_type,
+            role: Some(role),
+            git_ref: None,
+            subdir: None,
+            max_depth: None,
+            follow_links: false,
+            name_transform: NameTransform::None,
+            prefix: None,
+            include: None,
+            exclude: None,
+            override_applied: false,
+            scan_origin: Default::default(),
+        }
+    }
+
+    fn config(dirs: Vec<(&str, DirectoryConfig)>) -> Config {
+        Config {
+            directories: dirs
+                .into_iter()
+                .map(|(name, dir)| (DirectoryName::new(name).unwrap(), dir))
+                .collect::<BTreeMap<_, _>>(),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn watch_roots_covers_local_discovery_directories_only() {
+        let config = config(vec![
+            (
+                "local",
+                dir_config(
+                    "/skills/local",
+                    DirectoryType::Directory,
+                    DirectoryRole::Source,
+                ),
+            ),
+            (
+                "plugins",
+                dir_config(
+                    "/home/.claude/plugins/cache",
+                    DirectoryType::ClaudePlugins,
+                    DirectoryRole::Managed,
+                ),
+            ),
+            (
+                "remote",
+                dir_config("/repos/remote", DirectoryType::Git, DirectoryRole::Source),
+            ),
+            (
+                "target",
+                dir_config(
+                    "/tool/skills",
+                    DirectoryType::Directory,
+                    DirectoryRole::Target,
+                ),
+            ),
+        ]);
+        assert_eq!(
+            watch_roots(&config),
+            vec![
+                WatchRoot::Tree(PathBuf::from("/skills/local")),
+                WatchRoot::PluginRegistry(PathBuf::from("/home/.claude/plugins/cache")),
+                WatchRoot::PluginRegistry(PathBuf::from("/home/.claude/plugins")),
+            ]
+        );
+    }
+
+    #[test]
+    fn watch_roots_deduplicates_shared_paths() {
+        let config = config(vec![
+            (
+                "a",
+                dir_config("/skills", DirectoryType::Directory, DirectoryRole::Source),
+            ),
+            (
+                "b",
+                dir_config("/skills", DirectoryType::Directory, DirectoryRole::Synced),
+            ),
+        ]);
+        assert_eq!(
+            watch_roots(&config),
+            vec![WatchRoot::Tree(PathBuf::from("/skills"))]
+        );
+    }
+
+    #[test]
+    fn relevant_paths_filters_by_root_kind() {
+        let roots = vec![
+            WatchRoot::Tree(PathBuf::from("/skills")),
+            WatchRoot::PluginRegistry(PathBuf::from("/plugins")),
+        ];
+        let events = vec![
+            PathBuf::from("/skills/foo/SKILL.md"),
+            PathBuf::from("/plugins/installed_plugins.json"),
+            PathBuf::from("/plugins/known_marketplaces.json"),
+            PathBuf::from("/plugins/cache/installed_plugins.json"),
+            PathBuf::from("/elsewhere/SKILL.md"),
+        ];
+        assert_eq!(
+            relevant_paths(&roots, &events),
+            vec![
+                PathBuf::from("/skills/foo/SKILL.md"),
+                PathBuf::from("/plugins/installed_plugins.json"),
+            ]
+        );
+    }
+
+    #[test]
+    fn drain_keeps_edits_made_during_sync_but_not_own_writes() {
+        use notify::{Event, EventKind};
+        use notify_debouncer_full::DebouncedEvent;
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let library = tmp.path().join("library");
+        let source = tmp.path().join("skills");
+        std::fs::create_dir_all(library.join("linked")).unwrap();
+        std::fs::create_dir_all(&source).unwrap();
+        let link = source.join("linked");
+        std::os::unix::fs::symlink(library.join("linked"), &link).unwrap();
+
+        let event = |path: &Path, time: Instant| {
+            DebouncedEvent::new(Event::new(EventKind::Any).add_path(path.into()), time)
+        };
+        let before = Instant::now();
+        std::thread::sleep(Duration::from_millis(5));
+        let started = Instant::now();
+        let after = started + Duration::from_millis(1);
+
+        let (tx, rx) = mpsc::channel::<DebounceEventResult>();
+        tx.send(Ok(vec![
+            event(&source.join("old/SKILL.md"), before),
+            event(&source.join("edited/SKILL.md"), after),
+            event(&link, after),
+            event(&source.join(LINK_MARKER_FILENAME), after),
+        ]))
+        .unwrap();
+        drop(tx);
+
+        assert_eq!(
+            drain(&rx, started, &library),
+            vec![source.join("edited/SKILL.md")]
+        );
+    }
+}
@@ -14,8 +14,8 @@ use tabled::settings::{Format, Modify, Style, Width, object::Rows, peaker::Prior
 use terminal_size::{Width as TermWidth, terminal_size};
 
 use crate::config::{
-    Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, TomeHomeSource,
-    expand_tilde,
+    Config, DirectoryConfig, DirectoryName, DirectoryRole, DirectoryType, NameTransform,
+    TomeHomeSource, expand_tilde,
 };
 
 // ---------------------------------------------------------------------------
@@ -119,6 +119,13 @@ const KNOWN_DIRECTORIES: &[KnownDirectory] = &[
         directory_type: DirectoryType::Directory,
         default_role: DirectoryRole::Synced,
     },
+    KnownDirectory {
+        name: "cursor-rules",
+        display: "Cursor Rules",
+        default_path: ".cursor/rules",
+        directory_type: DirectoryType::CursorRules,
+        default_role: DirectoryRole::Source,
+    },
 ];
 
 // ---------------------------------------------------------------------------
@@ -334,7 +341,7 @@ pub(crate) fn run(
         let path = crate::paths::collapse_home_path(&expand_tilde(&PathBuf::from(&path_str))?);
 
         // Type picker (Git not available in wizard since it needs URLs)
-        let type_labels = ["directory", "claude-plugins"];
+        let type_labels = ["directory", "claude-plugins", "cursor-rules"];
         let type_idx = Select::new()
             .with_prompt("Directory type")
             .items(type_labels)
@@ -343,7 +350,8 @@ pub(crate) fn run(
 
         let directory_type = match type_idx {
             0 => DirectoryType::Directory,
-            _ => DirectoryType::ClaudePlugins,
+            1 => DirectoryType::ClaudePlugins,
+            _ => DirectoryType::CursorRules,
         };
 
         // Role picker (filtered by type)
@@ -368,7 +376,14 @@ pub(crate) fn run(
                 role: Some(role),
                 git_ref: None,
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
 
@@ -392,22 +407,12 @@ pub(crate) fn run(
 
     if dry_run {
         eprintln!("  (dry run -- not saving)");
-        // Dry-run validates the same way a real save would, but without writing
-        // to disk. Use a clone so we can expand tildes without mutating the
-        // original Config (which is returned to the caller).
-        let mut expanded = config.clone();
-        expanded
-            .expand_tildes()
-            .context("wizard dry-run: tilde expansion failed")?;
-        expanded
-            .validate()
+        // Dry-run emits exactly what a real save would write — validated,
+        // round-trip checked, and with `~/` paths kept — so
+        // `tome init --dry-run > tome.toml` stays portable.
+        let toml_str = config
+            .to_checked_toml()
             .context("wizard dry-run: configuration is invalid")?;
-        let toml_str = toml::to_string_pretty(&expanded)
-            .context("wizard dry-run: failed to serialize config")?;
-        // Defense in depth: reparse to confirm round-trip integrity before
-        // telling the user "this config would save cleanly".
-        let _: Config =
-            toml::from_str(&toml_str).context("wizard dry-run: generated TOML did not reparse")?;
         // The TOML body itself stays on stdout — it's a programmatic artifact
         // a user may pipe (e.g. `tome init --dry-run > tome.toml`). Header +
         // surrounding whitespace are diagnostic chrome and go to stderr.
@@ -485,6 +490,151 @@ pub(crate) fn assemble_config(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Unattended init (`tome init --yes`)
+// ---------------------------------------------------------------------------
+
+/// Build a `Config` from `tome init --yes` flags, without prompts or
+/// auto-discovery.
+///
+/// - `sources`: `NAME=PATH[:TYPE]`; `directory` gets the `source` role,
+///   `claude-plugins` the `managed` role
+/// - `targets`: `NAME=PATH`, a `directory` with the `target` role
+/// - `library`: library location; defaults to `<tome_home>/skills`
+///
+/// Paths are stored `~/`-shaped like the wizard's. Validation is left to
+/// `Config::save_checked` / `to_checked_toml`.
+pub(crate) fn config_from_flags(
+    sources: &[String],
+    targets: &[String],
+    library: Option<&Path>,
+    tome_home: &Path,
+) -> Result<Config> {
+    let mut directories = BTreeMap::new();
+    let specs = sources
+        .iter()
+        .map(|s| ("--source", s, parse_source_flag(s)))
+        .chain(
+            targets
+                .iter()
+                .map(|s| ("--target", s, parse_target_flag(s))),
+        );
+    for (flag, raw, parsed) in specs {
+        let (name, dir) = parsed.with_context(|| format!("invalid {flag} '{raw}'"))?;
+        anyhow::ensure!(
+            !directories.contains_key(&name),
+            "directory '{name}' is given more than once"
+        );
+        directories.insert(name, dir);
+    }
+    let library_dir = match library {
+        Some(path) => flag_path(path)?,
+        None => crate::paths::collapse_home_path(&tome_home.join("skills")),
+    };
+    Ok(assemble_config(
+        directories,
+        library_dir,
+        std::collections::BTreeSet::new(),
+    ))
+}
+
+/// Save (or with `dry_run`, print) a config built by [`config_from_flags`].
+/// An existing `tome.toml` is backed up before being replaced.
+pub(crate) fn save_unattended(config: &Config, tome_home: &Path, dry_run: bool) -> Result<()> {
+    let config_path = crate::config::resolve_config_dir(tome_home).join("tome.toml");
+    if dry_run {
+        let toml_str = config
+            .to_checked_toml()
+            .context("tome init --yes: configuration is invalid")?;
+        println!("{toml_str}");
+        return Ok(());
+    }
+    if config_path.exists() {
+        let backup = backup_brownfield_config(&config_path)?;
+        eprintln!(
+            "  Backed up existing config to: {}",
+            style(backup.display()).cyan()
+        );
+    }
+    config
+        .save_checked(&config_path)
+        .context("tome init --yes: configuration is invalid")?;
+    eprintln!(
+        "{} Config saved to {}",
+        style("done").green(),
+        style(config_path.display()).cyan()
+    );
+    Ok(())
+}
+
+/// `NAME=PATH` or `NAME=PATH:TYPE`. The `:TYPE` suffix is only taken as a
+/// type when it names one, so paths containing `:` still work.
+fn parse_source_flag(spec: &str) -> Result<(DirectoryName, DirectoryConfig)> {
+    let (name, rest) = split_flag(spec)?;
+    let (path, directory_type) = match rest.rsplit_once(':') {
+        Some((path, "directory")) => (path, DirectoryType::Directory),
+        Some((path, "claude-plugins")) => (path, DirectoryType::ClaudePlugins),
+        Some((path, "cursor-rules")) => (path, DirectoryType::CursorRules),
+        _ => (rest, DirectoryType::Directory),
+    };
+    let role = match directory_type {
+        DirectoryType::ClaudePlugins => DirectoryRole::Managed,
+        _ => DirectoryRole::Source,
+    };
+    let dir = flag_directory(flag_path(Path::new(path))?, directory_type, role);
+    Ok((name, dir))
+}
+
+/// `NAME=PATH`.
+fn parse_target_flag(spec: &str) -> Result<(DirectoryName, DirectoryConfig)> {
+    let (name, path) = split_flag(spec)?;
+    let dir = flag_directory(
+        flag_path(Path::new(path))?,
+        DirectoryType::Directory,
+        DirectoryRole::Target,
+    );
+    Ok((name, dir))
+}
+
+fn split_flag(spec: &str) -> Result<(DirectoryName, &str)> {
+    let (name, path) = spec.split_once('=').context("expected NAME=PATH")?;
+    anyhow::ensure!(!path.is_empty(), "expected NAME=PATH");
+    Ok((DirectoryName::new(name)?, path))
+}
+
+/// Expand `~` for the absolute-path check, then store the path `~/`-shaped.
+fn flag_path(path: &Path) -> Result<PathBuf> {
+    let expanded = expand_tilde(path)?;
+    anyhow::ensure!(
+        expanded.is_absolute(),
+        "path '{}' must be absolute or start with ~",
+        path.display()
+    );
+    Ok(crate::paths::collapse_home_path(&expanded))
+}
+
+fn flag_directory(
+    path: PathBuf,
+    directory_type: DirectoryType,
+    role: DirectoryRole,
+) -> DirectoryConfig {
+    DirectoryConfig {
+        path,
+        directory_type,
+        role: Some(role),
+        git_ref: None,
+        subdir: None,
+        max_depth: None,
+        follow_links: false,
+        name_transform: NameTransform::None,
+        prefix: None,
+        include: None,
+        exclude: None,
+        override_applied: false,
+        scan_origin: Default::default(),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Internal helpers
 // ---------------------------------------------------------------------------
@@ -635,7 +785,14 @@ fn configure_directories(
                     role: Some(kd.default_role),
                     git_ref: None,
                     subdir: None,
+                    max_depth: None,
+                    follow_links: false,
+                    name_transform: NameTransform::None,
+                    prefix: None,
+                    include: None,
+                    exclude: None,
                     override_applied: false,
+                    scan_origin: Default::default(),
                 },
             );
         }
@@ -1154,7 +1311,14 @@ mod tests {
                 role: Some(DirectoryRole::Synced),
                 git_ref: None,
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
         dirs.insert(
@@ -1165,7 +1329,14 @@ mod tests {
                 role: Some(DirectoryRole::Source),
                 git_ref: None,
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
 
@@ -1425,7 +1596,14 @@ mod tests {
             role: Some(role),
             git_ref: None,
             subdir: None,
+            max_depth: None,
+            follow_links: false,
+            name_transform: NameTransform::None,
+            prefix: None,
+            include: None,
+            exclude: None,
             override_applied: false,
+            scan_origin: Default::default(),
         }
     }
 
@@ -1859,7 +2037,14 @@ mod tests {
                 role: Some(DirectoryRole::Synced),
                 git_ref: None,
                 subdir: None,
+                max_depth: None,
+                follow_links: false,
+                name_transform: NameTransform::None,
+                prefix: None,
+                include: None,
+                exclude: None,
                 override_applied: false,
+                scan_origin: Default::default(),
             },
         );
 
@@ -1952,4 +2137,48 @@ mod tests {
             backup_path.file_name()
         );
     }
+
+    #[test]
+    fn config_from_flags_assigns_types_and_roles() {
+        let home = dirs::home_dir().unwrap();
+        let config = config_from_flags(
+            &[
+                "local=/srv/skills".to_string(),
+                "plugins=~/.claude/plugins:claude-plugins".to_string(),
+            ],
+            &["claude=~/.claude/skills".to_string()],
+            None,
+            &home.join(".tome"),
+        )
+        .unwrap();
+
+        let dirs = config.directories();
+        let local = &dirs[&DirectoryName::new("local").unwrap()];
+        assert_eq!(local.path, PathBuf::from("/srv/skills"));
+        assert_eq!(local.directory_type, DirectoryType::Directory);
+        assert_eq!(local.role, Some(DirectoryRole::Source));
+        let plugins = &dirs[&DirectoryName::new("plugins").unwrap()];
+        assert_eq!(plugins.path, PathBuf::from("~/.claude/plugins"));
+        assert_eq!(plugins.directory_type, DirectoryType::ClaudePlugins);
+        assert_eq!(plugins.role, Some(DirectoryRole::Managed));
+        let claude = &dirs[&DirectoryName::new("claude").unwrap()];
+        assert_eq!(claude.role, Some(DirectoryRole::Target));
+        assert_eq!(config.library_dir, PathBuf::from("~/.tome/skills"));
+    }
+
+    #[test]
+    fn config_from_flags_rejects_bad_specs() {
+        let tome_home = Path::new("/tmp/tome");
+        let err = |sources: &[&str], targets: &[&str]| {
+            let sources: Vec<String> = sources.iter().map(|s| s.to_string()).collect();
+            let targets: Vec<String> = targets.iter().map(|s| s.to_string()).collect();
+            format!(
+                "{:#}",
+                config_from_flags(&sources, &targets, None, tome_home).unwrap_err()
+            )
+        };
+        assert!(err(&["local"], &[]).contains("expected NAME=PATH"));
+        assert!(err(&["local=relative/dir"], &[]).contains("must be absolute"));
+        assert!(err(&["a=/x"], &["a=/y"]).contains("more than once"));
+    }
 }
@@ -86,6 +86,7 @@ fn skill_row(name: &str, source: &str, path: &str, managed: bool, synced_at: &st
         managed,
         synced_at: synced_at.to_string(),
         source_directory: None,
+        skill_file: "SKILL.md".into(),
     }
 }
 
@@ -260,3 +260,206 @@ fn config_toml_tome_home_override() {
         .assert()
         .success();
 }
+
+#[test]
+fn config_validate_passes_for_valid_config() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-tool")
+        .skill("my-skill", "local")
+        .build();
+
+    env.cmd()
+        .args(["config", "--validate"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("is valid"));
+
+    // Validation must not sync anything into the library.
+    assert!(!env.library_dir.join("my-skill").exists());
+}
+
+#[test]
+fn config_validate_reports_missing_directory_path() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-tool")
+        .build();
+    let target_dir = &env.target_dirs[0].1;
+    std::fs::remove_dir_all(target_dir).unwrap();
+
+    env.cmd()
+        .args(["config", "--validate"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("invalid"))
+        .stdout(predicate::str::contains("directory 'test-tool'"))
+        .stdout(predicate::str::contains(
+            "does not exist (sync would create it)",
+        ))
+        .stderr(predicate::str::contains("config invalid: 1 problem(s)"));
+
+    assert!(!target_dir.exists(), "validate must not create directories");
+}
+
+#[test]
+fn config_validate_reports_unparsable_config() {
+    let tmp = TempDir::new().unwrap();
+    let config_path = tmp.path().join("tome.toml");
+    std::fs::write(&config_path, "library_dri = \"/tmp/lib\"\n").unwrap();
+
+    tome()
+        .args([
+            "--config",
+            config_path.to_str().unwrap(),
+            "config",
+            "--validate",
+        ])
+        .env("NO_COLOR", "1")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("library_dri"))
+        .stderr(predicate::str::contains("config invalid"));
+}
+
+/// An `$EDITOR` script that overwrites the file it is given with `content`.
+fn editor_writing(dir: &std::path::Path, content: &str) -> std::path::PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+    let script = dir.join("editor.sh");
+    std::fs::write(
+        &script,
+        format!("#!/bin/sh\ncat > \"$1\" <<'TOML'\n{content}TOML\n"),
+    )
+    .unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+    script
+}
+
+#[test]
+fn config_edit_creates_missing_config_and_validates() {
+    let tmp = TempDir::new().unwrap();
+    let config_path = tmp.path().join("nested").join("tome.toml");
+
+    tome()
+        .args(["--config", config_path.to_str().unwrap(), "config", "edit"])
+        .arg("--machine")
+        .arg(tmp.path().join("machine.toml"))
+        .env("EDITOR", "true")
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("is valid"));
+
+    assert!(
+        config_path.exists(),
+        "config should be created with defaults"
+    );
+}
+
+#[test]
+fn config_edit_reports_errors_and_keeps_the_edits() {
+    let tmp = TempDir::new().unwrap();
+    let config_path = tmp.path().join("tome.toml");
+    let broken = "library_dri = \"/tmp/lib\"\n";
+    let editor = editor_writing(tmp.path(), broken);
+
+    tome()
+        .args(["--config", config_path.to_str().unwrap(), "config", "edit"])
+        .arg("--machine")
+        .arg(tmp.path().join("machine.toml"))
+        .env("EDITOR", &editor)
+        .env("NO_COLOR", "1")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("library_dri"))
+        .stderr(predicate::str::contains("config invalid"));
+
+    assert_eq!(std::fs::read_to_string(&config_path).unwrap(), broken);
+}
+
+#[test]
+fn config_edit_requires_an_editor() {
+    let tmp = TempDir::new().unwrap();
+    let config_path = tmp.path().join("tome.toml");
+
+    tome()
+        .args(["--config", config_path.to_str().unwrap(), "config", "edit"])
+        .env_remove("EDITOR")
+        .env_remove("VISUAL")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("set $EDITOR or $VISUAL"));
+}
+
+#[test]
+fn migrate_config_rewrites_v05_layout_and_keeps_backup() {
+    let tmp = TempDir::new().unwrap();
+    let config_path = tmp.path().join("tome.toml");
+    let old = format!(
+        r#"library_dir = "{lib}"
+
+[[sources]]
+name = "local"
+path = "{src}"
+type = "directory"
+
+[targets.claude]
+enabled = true
+method = "symlink"
+skills_dir = "{dst}"
+"#,
+        lib = tmp.path().join("library").display(),
+        src = tmp.path().join("skills").display(),
+        dst = tmp.path().join("claude").display(),
+    );
+    std::fs::write(&config_path, &old).unwrap();
+
+    // The old layout doesn't load...
+    tome()
+        .env("TOME_HOME", tmp.path())
+        .args(["status"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("tome migrate-config"));
+
+    // ...a dry run reports the plan without writing...
+    tome()
+        .env("TOME_HOME", tmp.path())
+        .args(["--dry-run", "migrate-config"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "[[sources]] 'local' -> [directories.local]",
+        ))
+        .stdout(predicate::str::contains("Dry run"));
+    assert_eq!(std::fs::read_to_string(&config_path).unwrap(), old);
+
+    // ...and the real run rewrites it, keeping the original as a backup.
+    tome()
+        .env("TOME_HOME", tmp.path())
+        .args(["migrate-config"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "[targets.claude] -> [directories.claude]",
+        ));
+    assert_eq!(
+        std::fs::read_to_string(tmp.path().join("tome.toml.bak")).unwrap(),
+        old
+    );
+    let migrated = std::fs::read_to_string(&config_path).unwrap();
+    assert!(migrated.contains("schema_version = 1"), "{migrated}");
+    assert!(migrated.contains("[directories.claude]"), "{migrated}");
+
+    tome()
+        .env("TOME_HOME", tmp.path())
+        .args(["status"])
+        .assert()
+        .success();
+    tome()
+        .env("TOME_HOME", tmp.path())
+        .args(["migrate-config"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("nothing to migrate"));
+}
@@ -0,0 +1,67 @@
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn diff_lists_new_skills_without_touching_disk() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("my-skill", "local")
+        .build();
+
+    env.cmd()
+        .arg("diff")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Library ("))
+        .stdout(predicate::str::contains("+ my-skill (new)"))
+        .stdout(predicate::str::contains("test-target ("));
+
+    assert!(!env.library_dir().join("my-skill").exists());
+    assert!(!env.target_dir("test-target").join("my-skill").exists());
+}
+
+#[test]
+fn diff_reports_changed_and_removed_skills_after_sync() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("edited", "local")
+        .skill("gone", "local")
+        .skill("steady", "local")
+        .build();
+    env.cmd().arg("sync").assert().success();
+
+    env.modify_skill("edited", "local", "---\nname: edited\n---\n# changed\n");
+    env.remove_skill("gone", "local");
+
+    let output = env.cmd().arg("diff").assert().success();
+    let stdout = String::from_utf8(output.get_output().stdout.clone()).unwrap();
+    assert!(stdout.contains("~ edited (content changed)"), "{stdout}");
+    assert!(stdout.contains("- gone (missing from source)"), "{stdout}");
+    assert!(stdout.contains("- gone (stale)"), "{stdout}");
+    assert!(!stdout.contains("steady"), "{stdout}");
+
+    assert!(env.library_dir().join("gone").is_dir());
+    assert!(env.target_dir("test-target").join("gone").is_symlink());
+}
+
+#[test]
+fn diff_reports_no_changes_when_in_sync() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("my-skill", "local")
+        .build();
+    env.cmd().arg("sync").assert().success();
+
+    env.cmd()
+        .arg("diff")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "No changes — library and directories are up to date.",
+        ));
+}
@@ -36,11 +36,13 @@ fn doctor_detects_broken_symlinks() {
 
     let config = write_config(tmp.path(), "");
 
+    // Dry-run fixes nothing, so a found issue must fail the process.
     tome()
         .args(["--config", config.to_str().unwrap(), "--dry-run", "doctor"])
         .assert()
-        .success()
-        .stdout(predicate::str::contains("1 issue(s)"));
+        .failure()
+        .stdout(predicate::str::contains("1 issue(s)"))
+        .stderr(predicate::str::contains("1 issue(s) unresolved"));
 }
 
 #[test]
@@ -97,10 +99,10 @@ fn doctor_dry_run_reports_issues_without_filesystem_mutation() {
     //
     // Assertions:
     //   1. `tome doctor --dry-run` reports issues to stdout and exits
-    //      successfully.
+    //      non-zero (nothing was fixed).
     //   2. The broken symlink is STILL present after the dry-run pass
     //      (no filesystem mutation).
-    //   3. `tome doctor --no-input` (without --dry-run) exits successfully
+    //   3. `tome doctor --no-input` (without --dry-run) exits non-zero
     //      and ALSO leaves the symlink in place (the global repair prompt
     //      requires a TTY; --no-input suppresses it).
     use std::os::unix::fs as unix_fs;
@@ -117,7 +119,7 @@ fn doctor_dry_run_reports_issues_without_filesystem_mutation() {
     tome()
         .args(["--config", config.to_str().unwrap(), "--dry-run", "doctor"])
         .assert()
-        .success()
+        .failure()
         .stdout(predicate::str::contains("issue(s)"));
 
     assert!(
@@ -132,7 +134,7 @@ fn doctor_dry_run_reports_issues_without_filesystem_mutation() {
     tome()
         .args(["--config", config.to_str().unwrap(), "--no-input", "doctor"])
         .assert()
-        .success()
+        .failure()
         .stdout(predicate::str::contains("issue(s)"));
 
     assert!(
@@ -165,6 +167,181 @@ fn doctor_json_output() {
     assert!(json["library_issues"].is_array());
 }
 
+#[test]
+fn doctor_json_reports_typed_issues_and_exits_nonzero() {
+    use std::os::unix::fs as unix_fs;
+
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("claude")
+        .skill("skill-a", "local")
+        .build();
+    env.cmd().args(["sync", "--no-input"]).assert().success();
+
+    // Dangling library symlink in the distribution directory.
+    let stale = env.target_dir("claude").join("gone");
+    unix_fs::symlink(env.library_dir().join("gone"), &stale).unwrap();
+
+    let output = env.cmd().args(["doctor", "--json"]).output().unwrap();
+    assert!(
+        !output.status.success(),
+        "doctor --json must exit non-zero when issues are found"
+    );
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("doctor --json should produce valid JSON");
+    assert_eq!(json["total"], 1);
+    let issues = json["issues"].as_array().unwrap();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0]["kind"], "stale_target_symlink");
+    assert_eq!(issues[0]["path"], stale.to_string_lossy().as_ref());
+    assert!(
+        issues[0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("stale symlink")
+    );
+}
+
+#[test]
+fn doctor_depth_finds_and_repairs_nested_stale_symlink() {
+    use std::os::unix::fs as unix_fs;
+
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("claude")
+        .skill("skill-a", "local")
+        .build();
+    env.cmd().args(["sync", "--no-input"]).assert().success();
+
+    let group = env.target_dir("claude").join("group");
+    std::fs::create_dir(&group).unwrap();
+    let stale = group.join("gone");
+    unix_fs::symlink(env.library_dir().join("gone"), &stale).unwrap();
+
+    // The default depth only looks at the target's immediate children.
+    env.cmd().args(["doctor", "--json"]).assert().success();
+
+    let output = env
+        .cmd()
+        .args(["doctor", "--json", "--depth", "2"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["issues"][0]["kind"], "stale_target_symlink");
+    assert_eq!(json["issues"][0]["path"], stale.to_string_lossy().as_ref());
+
+    env.cmd()
+        .args(["doctor", "--depth", "2", "--yes"])
+        .assert()
+        .success();
+    assert!(!stale.is_symlink());
+}
+
+#[test]
+fn doctor_fix_repairs_only_the_requested_kind() {
+    use std::os::unix::fs as unix_fs;
+
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("claude")
+        .skill("skill-a", "local")
+        .build();
+    env.cmd().args(["sync", "--no-input"]).assert().success();
+
+    let stale = env.target_dir("claude").join("gone");
+    unix_fs::symlink(env.library_dir().join("gone"), &stale).unwrap();
+    let broken = env.library_dir().join("broken-skill");
+    unix_fs::symlink("/nonexistent/path", &broken).unwrap();
+
+    // The library issue is left alone, so the run still fails.
+    env.cmd()
+        .args(["doctor", "--fix", "stale-target-links"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Removed 1 stale symlink(s) from claude",
+        ))
+        .stderr(predicate::str::contains("1 issue(s) unresolved"));
+    assert!(!stale.is_symlink());
+    assert!(broken.is_symlink());
+
+    env.cmd()
+        .args(["doctor", "--fix", "broken-library-links"])
+        .assert()
+        .success();
+    assert!(!broken.is_symlink());
+}
+
+#[test]
+fn doctor_without_terminal_needs_yes_to_repair() {
+    use std::os::unix::fs as unix_fs;
+
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .skill("skill-a", "local")
+        .build();
+    env.cmd().args(["sync", "--no-input"]).assert().success();
+    let broken = env.library_dir().join("broken-skill");
+    unix_fs::symlink("/nonexistent/path", &broken).unwrap();
+
+    // stdin is closed, not a terminal: report, hint, and leave it alone
+    // rather than waiting on a prompt.
+    env.cmd()
+        .arg("doctor")
+        .write_stdin("")
+        .timeout(std::time::Duration::from_secs(30))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pass `--yes`"));
+    assert!(broken.is_symlink());
+
+    env.cmd()
+        .args(["doctor", "--yes"])
+        .write_stdin("")
+        .timeout(std::time::Duration::from_secs(30))
+        .assert()
+        .success();
+    assert!(!broken.is_symlink());
+}
+
+#[test]
+fn doctor_fix_rejects_unknown_kind() {
+    let tmp = TempDir::new().unwrap();
+    let config = write_config(tmp.path(), "");
+
+    tome()
+        .args([
+            "--config",
+            config.to_str().unwrap(),
+            "doctor",
+            "--fix",
+            "everything",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "[possible values: broken-library-links, stale-target-links, target-copies, target-collisions]",
+        ));
+}
+
+#[test]
+fn doctor_json_exits_zero_when_clean() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("claude")
+        .skill("skill-a", "local")
+        .build();
+    env.cmd().args(["sync", "--no-input"]).assert().success();
+
+    let output = env.cmd().args(["doctor", "--json"]).output().unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["total"], 0);
+    assert_eq!(json["issues"].as_array().unwrap().len(), 0);
+}
+
 #[cfg(unix)]
 #[test]
 fn machine_override_unknown_target_warns_and_continues() {
@@ -417,3 +594,149 @@ fn phase14_doctor_informational_unowned_does_not_affect_exit_code() {
         "library_issues must be empty: {json}"
     );
 }
+
+#[test]
+fn doctor_reports_distribution_dirs_sharing_a_path() {
+    let tmp = TempDir::new().unwrap();
+    let shared = tmp.path().join("agent-skills");
+    let config = write_config(
+        tmp.path(),
+        &format!(
+            "[directories.claude]\npath = \"{0}\"\nrole = \"target\"\n\n\
+             [directories.codex]\npath = \"{0}/\"\nrole = \"target\"\n",
+            shared.display()
+        ),
+    );
+
+    let output = tome()
+        .args(["--config", config.to_str().unwrap(), "doctor", "--json"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("doctor --json should produce valid JSON");
+    let issues = json["issues"].as_array().unwrap();
+    let dup = issues
+        .iter()
+        .find(|i| i["kind"] == "duplicate_distribution_dir")
+        .unwrap_or_else(|| panic!("no duplicate_distribution_dir issue: {issues:?}"));
+    assert!(
+        dup["message"]
+            .as_str()
+            .unwrap()
+            .contains("'claude' and 'codex'"),
+        "{dup}"
+    );
+}
+
+#[test]
+fn doctor_yes_leaves_edited_skill_in_synced_dir_alone() {
+    let tmp = TempDir::new().unwrap();
+    let synced = tmp.path().join("agent-skills");
+    create_skill(&synced, "foo");
+    let config = write_config(
+        tmp.path(),
+        &format!(
+            "[directories.agent]\npath = \"{}\"\nrole = \"synced\"\n",
+            synced.display()
+        ),
+    );
+    let config = config.to_str().unwrap();
+    tome()
+        .args(["--config", config, "sync", "--no-input"])
+        .assert()
+        .success();
+
+    // The user edits their own source skill: it now differs from the
+    // library copy, but it is not a collision.
+    std::fs::write(synced.join("foo/SKILL.md"), "---\nname: foo\n---\nedited").unwrap();
+    tome()
+        .args(["--config", config, "doctor", "--yes"])
+        .write_stdin("")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("blocks the library link").not());
+    assert!(synced.join("foo").is_dir() && !synced.join("foo").is_symlink());
+    assert!(!synced.join("foo.tome-bak").exists());
+
+    tome()
+        .args(["--config", config, "sync", "--no-input"])
+        .assert()
+        .success();
+    let library = tmp.path().join("library");
+    assert!(library.join("foo").is_dir());
+    assert!(!library.join("foo.tome-bak").exists());
+}
+
+#[test]
+fn doctor_yes_keeps_unedited_skill_in_synced_dir_as_source() {
+    let tmp = TempDir::new().unwrap();
+    let synced = tmp.path().join("agent-skills");
+    create_skill(&synced, "foo");
+    let config = write_config(
+        tmp.path(),
+        &format!(
+            "[directories.agent]\npath = \"{}\"\nrole = \"synced\"\n",
+            synced.display()
+        ),
+    );
+    let config = config.to_str().unwrap();
+    tome()
+        .args(["--config", config, "sync", "--no-input"])
+        .assert()
+        .success();
+
+    // A clean synced setup has nothing to repair, so no `--yes` hint.
+    tome()
+        .args(["--config", config, "doctor"])
+        .write_stdin("")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("pass `--yes`").not());
+    tome()
+        .args(["--config", config, "doctor", "--yes"])
+        .write_stdin("")
+        .assert()
+        .success();
+    assert!(synced.join("foo").is_dir() && !synced.join("foo").is_symlink());
+
+    tome()
+        .args(["--config", config, "sync", "--no-input"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No skills found").not());
+}
+
+#[test]
+fn doctor_yes_repairs_target_copies_and_stale_links() {
+    use std::os::unix::fs as unix_fs;
+
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("claude")
+        .skill("skill-a", "local")
+        .build();
+    env.cmd().args(["sync", "--no-input"]).assert().success();
+
+    // A real copy of the library skill where the link should be, plus a
+    // dangling link to a library skill that is gone.
+    let target = env.target_dir("claude");
+    let copy = target.join("skill-a");
+    std::fs::remove_file(&copy).unwrap();
+    std::fs::create_dir(&copy).unwrap();
+    for entry in std::fs::read_dir(env.library_dir().join("skill-a")).unwrap() {
+        let entry = entry.unwrap();
+        std::fs::copy(entry.path(), copy.join(entry.file_name())).unwrap();
+    }
+    let stale = target.join("gone");
+    unix_fs::symlink(env.library_dir().join("gone"), &stale).unwrap();
+
+    env.cmd()
+        .args(["doctor", "--yes"])
+        .write_stdin("")
+        .assert()
+        .success();
+    assert!(copy.is_symlink(), "matching copy is replaced by a link");
+    assert!(stale.symlink_metadata().is_err(), "stale link is removed");
+}
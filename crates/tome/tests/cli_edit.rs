@@ -0,0 +1,66 @@
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn edit_launches_editor_on_source_skill_md() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("my-skill", "local")
+        .build();
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+
+    // `echo` stands in for the editor: it prints the argument it was given.
+    let source_md =
+        std::fs::canonicalize(env.source_dir("local").join("my-skill/SKILL.md")).unwrap();
+    env.cmd()
+        .args(["edit", "my-skill"])
+        .env("EDITOR", "echo --")
+        .env_remove("VISUAL")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "-- {}",
+            source_md.display()
+        )));
+}
+
+#[test]
+fn edit_without_editor_prints_path() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .skill("my-skill", "local")
+        .build();
+
+    env.cmd()
+        .args(["--no-input", "edit", "my-skill"])
+        .env_remove("EDITOR")
+        .env_remove("VISUAL")
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("my-skill/SKILL.md\n"));
+}
+
+#[test]
+fn edit_reports_failing_editor_and_unknown_skill() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .skill("my-skill", "local")
+        .build();
+
+    env.cmd()
+        .args(["edit", "my-skill"])
+        .env("EDITOR", "false")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("editor 'false' exited with"));
+
+    env.cmd()
+        .args(["edit", "my-skil"])
+        .env("EDITOR", "echo")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("did you mean 'my-skill'?"));
+}
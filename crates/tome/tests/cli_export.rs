@@ -0,0 +1,91 @@
+use std::collections::BTreeMap;
+use std::io::Read;
+
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+/// Archive entry path -> contents.
+fn read_archive(path: &std::path::Path) -> BTreeMap<String, String> {
+    let file = std::fs::File::open(path).unwrap();
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(file));
+    let mut entries = BTreeMap::new();
+    for entry in archive.entries().unwrap() {
+        let mut entry = entry.unwrap();
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let name = entry.path().unwrap().display().to_string();
+        let mut contents = String::new();
+        entry.read_to_string(&mut contents).unwrap();
+        entries.insert(name, contents);
+    }
+    entries
+}
+
+#[test]
+fn export_writes_skills_and_manifest() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .skill("alpha", "local")
+        .skill("beta", "local")
+        .build();
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+
+    let output = env.tmp.path().join("skills.tar.gz");
+    env.cmd()
+        .args(["export", output.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Exported 2 skill(s)"));
+
+    let entries = read_archive(&output);
+    assert!(entries["skills/alpha/SKILL.md"].contains("name: alpha"));
+    assert!(entries.contains_key("skills/beta/SKILL.md"));
+    let manifest: serde_json::Value = serde_json::from_str(&entries["tome-export.json"]).unwrap();
+    assert_eq!(manifest["skills"][0]["name"], "alpha");
+    assert_eq!(manifest["skills"][0]["source"], "local");
+    assert_eq!(manifest["skills"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn export_refuses_to_overwrite_without_force() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .skill("alpha", "local")
+        .build();
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+
+    let output = env.tmp.path().join("skills.tar.gz");
+    std::fs::write(&output, "keep me").unwrap();
+    env.cmd()
+        .args(["export", output.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists (pass --force"));
+    assert_eq!(std::fs::read_to_string(&output).unwrap(), "keep me");
+
+    env.cmd()
+        .args(["export", output.to_str().unwrap(), "--force"])
+        .assert()
+        .success();
+    assert!(read_archive(&output).contains_key("skills/alpha/SKILL.md"));
+}
+
+#[test]
+fn export_dry_run_writes_nothing() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .skill("alpha", "local")
+        .build();
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+
+    let output = env.tmp.path().join("skills.tar.gz");
+    env.cmd()
+        .args(["--dry-run", "export", output.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Would export 1 skill(s)"));
+    assert!(!output.exists());
+}
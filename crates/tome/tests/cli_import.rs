@@ -0,0 +1,149 @@
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+/// Write a gzipped tarball of `(path, contents)` regular files. Paths are
+/// written into the header verbatim, so tests can build members the `tar`
+/// builder itself would refuse (e.g. `..` components).
+fn write_archive(path: &std::path::Path, files: &[(&str, &str)]) {
+    let file = std::fs::File::create(path).unwrap();
+    let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+        file,
+        flate2::Compression::default(),
+    ));
+    for (name, contents) in files {
+        let mut header = tar::Header::new_gnu();
+        header.as_gnu_mut().unwrap().name[..name.len()].copy_from_slice(name.as_bytes());
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_entry_type(tar::EntryType::Regular);
+        header.set_cksum();
+        builder.append(&header, contents.as_bytes()).unwrap();
+    }
+    builder.into_inner().unwrap().finish().unwrap();
+}
+
+const MANIFEST: (&str, &str) = ("tome-export.json", r#"{"tome_version":"0","skills":[]}"#);
+
+#[test]
+fn import_round_trips_an_export() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .skill("alpha", "local")
+        .skill("beta", "local")
+        .build();
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    let archive = env.tmp.path().join("skills.tar.gz");
+    env.cmd()
+        .args(["export", archive.to_str().unwrap()])
+        .assert()
+        .success();
+
+    let dest = env.tmp.path().join("imported");
+    env.cmd()
+        .args(["import", archive.to_str().unwrap(), dest.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Imported 2 skill(s)"));
+
+    assert!(
+        std::fs::read_to_string(dest.join("alpha/SKILL.md"))
+            .unwrap()
+            .contains("name: alpha")
+    );
+    assert!(dest.join("beta/SKILL.md").is_file());
+    assert!(!dest.join(".tome-import").exists());
+}
+
+#[test]
+fn import_rejects_escaping_members_and_skips_skills_without_skill_md() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let archive = tmp.path().join("crafted.tar.gz");
+    write_archive(
+        &archive,
+        &[
+            MANIFEST,
+            ("skills/good/SKILL.md", "---\nname: good\n---\n"),
+            ("skills/../../evil.txt", "pwned"),
+            ("skills/empty/notes.md", "no skill here"),
+        ],
+    );
+    let dest = tmp.path().join("dest");
+
+    tome()
+        .args(["import", archive.to_str().unwrap(), dest.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Imported 1 skill(s)"))
+        .stderr(predicate::str::contains("path escapes the destination"))
+        .stderr(predicate::str::contains(
+            "skipping skill 'empty': no SKILL.md",
+        ));
+
+    assert!(dest.join("good/SKILL.md").is_file());
+    assert!(!dest.join("empty").exists());
+    assert!(!tmp.path().join("evil.txt").exists());
+}
+
+#[test]
+fn import_refuses_archives_without_export_manifest() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let archive = tmp.path().join("plain.tar.gz");
+    write_archive(&archive, &[("skills/a/SKILL.md", "---\nname: a\n---\n")]);
+
+    tome()
+        .args([
+            "import",
+            archive.to_str().unwrap(),
+            tmp.path().join("dest").to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not a tome export"));
+}
+
+#[test]
+fn import_register_adds_source_directory() {
+    let env = TestEnvBuilder::new().source("local", "directory").build();
+    let archive = env.tmp.path().join("skills.tar.gz");
+    write_archive(
+        &archive,
+        &[
+            MANIFEST,
+            ("skills/shared/SKILL.md", "---\nname: shared\n---\n"),
+        ],
+    );
+    let dest = env.tmp.path().join("shared-skills");
+    // Registering adds one table and leaves everything else as written.
+    let original = format!(
+        "# hand-written\n{}\n[defaults]\nmax_depth = 3\n",
+        std::fs::read_to_string(&env.config_path).unwrap()
+    );
+    std::fs::write(&env.config_path, &original).unwrap();
+
+    env.cmd()
+        .args([
+            "import",
+            archive.to_str().unwrap(),
+            dest.to_str().unwrap(),
+            "--register",
+            "shared",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "and registered it as directory 'shared'",
+        ));
+
+    let config = std::fs::read_to_string(&env.config_path).unwrap();
+    assert!(config.starts_with(&original), "{config}");
+    assert!(config.contains("[directories.shared]"), "{config}");
+    assert!(config.contains("role = \"source\""), "{config}");
+
+    env.cmd()
+        .args(["list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("shared"));
+}
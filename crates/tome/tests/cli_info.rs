@@ -0,0 +1,115 @@
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn info_shows_source_library_links_and_frontmatter() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill_with_content(
+            "code-review",
+            "local",
+            "---\nname: code-review\ndescription: Review a diff.\nmetadata:\n  tags: [git, review]\n---\n# Review\n",
+        )
+        .build();
+
+    env.cmd()
+        .args(["info", "code-review"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Source:"))
+        .stdout(predicate::str::contains("local"))
+        .stdout(predicate::str::contains("not consolidated"))
+        .stdout(predicate::str::contains(
+            "Targets:     test-target: not linked yet (run `tome sync`)",
+        ))
+        .stdout(predicate::str::contains("Description: Review a diff."))
+        .stdout(predicate::str::contains("Tags:        git, review"));
+
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+
+    env.cmd()
+        .args(["info", "code-review"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("not consolidated").not())
+        .stdout(predicate::str::contains("Targets:     test-target: linked"));
+}
+
+#[test]
+fn info_reports_reach_for_enabled_and_disabled_targets() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("codex")
+        .target("claude")
+        .disable_target("claude")
+        .skill("code-review", "local")
+        .build();
+
+    env.cmd_with_machine()
+        .args(["sync", "--no-triage"])
+        .assert()
+        .success();
+
+    env.cmd_with_machine()
+        .args(["info", "code-review"])
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "  Targets: claude: directory disabled on this machine\n\
+             \x20          codex: linked\n",
+        ));
+}
+
+#[test]
+fn info_unknown_skill_suggests_closest_name() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .skill("code-review", "local")
+        .build();
+
+    env.cmd()
+        .args(["info", "code-reveiw"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "skill 'code-reveiw' not found — did you mean 'code-review'?",
+        ));
+
+    env.cmd()
+        .args(["info", "deploy"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("run `tome list`"));
+}
+
+#[test]
+fn info_reads_agents_md_when_listed_in_skill_file_names() {
+    let tmp = assert_fs::TempDir::new().unwrap();
+    let skills_dir = tmp.path().join("skills");
+    let skill_dir = skills_dir.join("agent-notes");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    std::fs::write(
+        skill_dir.join("AGENTS.md"),
+        "---\nname: agent-notes\ndescription: Notes for agents.\n---\n",
+    )
+    .unwrap();
+    let config = write_config(
+        tmp.path(),
+        &format!(
+            "skill_file_names = [\"SKILL.md\", \"AGENTS.md\"]\n\n\
+             [directories.local]\npath = \"{}\"\ntype = \"directory\"\nrole = \"source\"\n",
+            skills_dir.display()
+        ),
+    );
+
+    tome()
+        .args(["--config", config.to_str().unwrap(), "info", "agent-notes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("File:        AGENTS.md"))
+        .stdout(predicate::str::contains("Description: Notes for agents."));
+}
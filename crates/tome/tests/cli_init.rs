@@ -10,16 +10,18 @@ use common::*;
 /// HARD-15; only the TOML body itself stays on stdout so a user can pipe
 /// `tome init --dry-run > tome.toml`.
 ///
-/// The `--dry-run` branch of the wizard runs `expand_tildes()` before emitting,
-/// so the returned Config has absolute paths — tilde-relative comparisons do
-/// NOT work; test callers must compare against expanded (TempDir-prefixed) paths.
-fn parse_generated_config(stdout: &str) -> Config {
+/// The `--dry-run` body keeps paths under the subprocess's `HOME` in `~/`
+/// shape, exactly as a real save writes them. `home` is that `HOME`: the
+/// returned Config has those tildes expanded against it, so callers compare
+/// against TempDir-prefixed paths.
+fn parse_generated_config(stdout: &str, home: &std::path::Path) -> Config {
     let body = stdout.trim();
     assert!(
         !body.is_empty(),
         "dry-run stdout was empty — wizard never emitted the TOML body",
     );
-    toml::from_str::<Config>(body)
+    let body = body.replace("\"~/", &format!("\"{}/", home.display()));
+    toml::from_str::<Config>(&body)
         .unwrap_or_else(|e| panic!("generated TOML did not parse: {e}\n---\n{body}"))
 }
 
@@ -83,7 +85,7 @@ fn init_dry_run_no_input_empty_home() {
         "tome init --dry-run --no-input failed (empty HOME).\nstdout:\n{stdout}\nstderr:\n{stderr}",
     );
 
-    let config = parse_generated_config(&stdout);
+    let config = parse_generated_config(&stdout, tmp.path());
 
     assert!(
         config.directories().is_empty(),
@@ -136,7 +138,7 @@ fn init_dry_run_no_input_seeded_home() {
         "tome init --dry-run --no-input failed (seeded HOME).\nstdout:\n{stdout}\nstderr:\n{stderr}",
     );
 
-    let config = parse_generated_config(&stdout);
+    let config = parse_generated_config(&stdout, tmp.path());
 
     assert_eq!(
         config.directories().len(),
@@ -196,6 +198,37 @@ fn init_dry_run_no_input_seeded_home() {
     assert_config_roundtrips(&config);
 }
 
+#[test]
+fn init_dry_run_keeps_home_relative_paths_in_tilde_form() {
+    // The dry-run body is meant to be piped into tome.toml, so paths under
+    // HOME must come out `~/`-shaped like a real save, not machine-specific.
+    let tmp = TempDir::new().unwrap();
+    std::fs::create_dir_all(tmp.path().join(".claude/skills")).unwrap();
+
+    let output = tome()
+        .args(["init", "--dry-run", "--no-input"])
+        .env("HOME", tmp.path())
+        .env("TOME_HOME", tmp.path().join(".tome"))
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("library_dir = \"~/.tome/skills\""),
+        "library_dir should keep ~/ shape, got:\n{stdout}",
+    );
+    assert!(
+        stdout.contains("path = \"~/.claude/skills\""),
+        "directory path should keep ~/ shape, got:\n{stdout}",
+    );
+    assert!(
+        !stdout.contains(&tmp.path().display().to_string()),
+        "no absolute HOME path should leak into the preview, got:\n{stdout}",
+    );
+}
+
 #[test]
 fn init_no_input_writes_config_and_reloads() {
     // End-to-end save path: `tome init --no-input` (no --dry-run) runs the wizard
@@ -623,7 +656,7 @@ fn init_derived_library_default_under_custom_tome_home() {
     );
 
     let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
-    let config = parse_generated_config(&stdout);
+    let config = parse_generated_config(&stdout, tmp.path());
     // library_dir after tilde expansion should be under the custom tome_home,
     // NOT under tmp/.tome/skills.
     assert_eq!(
@@ -669,7 +702,7 @@ fn wizard_library_default_follows_custom_tome_home() {
     );
 
     let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
-    let config = parse_generated_config(&stdout);
+    let config = parse_generated_config(&stdout, tmp.path());
     // library_dir after tilde expansion should be under the custom tome_home,
     // NOT under <HOME>/.tome/skills.
     assert_eq!(
@@ -708,7 +741,7 @@ fn wizard_library_default_does_not_fall_back_to_home_tome_skills() {
     );
 
     let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
-    let config = parse_generated_config(&stdout);
+    let config = parse_generated_config(&stdout, tmp.path());
     assert_ne!(
         config.library_dir(),
         home_fallback.as_path(),
@@ -846,3 +879,68 @@ fn init_brownfield_with_legacy_runs_both_cleanups() {
     );
     assert_eq!(std::fs::read_to_string(&xdg_file).unwrap(), legacy_seed);
 }
+
+#[test]
+fn init_yes_builds_config_from_flags_and_syncs() {
+    let tmp = TempDir::new().unwrap();
+    let tome_home = tmp.path().join(".tome");
+    let source = tmp.path().join("skills");
+    let target = tmp.path().join(".claude/skills");
+    create_skill(&source, "my-skill");
+    // A known directory the wizard would auto-discover; --yes must not.
+    std::fs::create_dir_all(tmp.path().join(".claude/plugins")).unwrap();
+
+    let output = tome()
+        .args(["init", "--yes", "--source"])
+        .arg(format!("local={}", source.display()))
+        .arg("--target")
+        .arg("claude=~/.claude/skills")
+        .env("HOME", tmp.path())
+        .env("TOME_HOME", &tome_home)
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "init --yes failed:\n{stderr}");
+
+    let on_disk = std::fs::read_to_string(tome_home.join("tome.toml")).unwrap();
+    assert!(
+        on_disk.contains("library_dir = \"~/.tome/skills\""),
+        "{on_disk}"
+    );
+    assert!(on_disk.contains("path = \"~/skills\""), "{on_disk}");
+    assert!(on_disk.contains("role = \"source\""), "{on_disk}");
+    assert!(on_disk.contains("path = \"~/.claude/skills\""), "{on_disk}");
+    assert!(!on_disk.contains("claude-plugins"), "{on_disk}");
+
+    assert!(
+        target.join("my-skill").is_symlink(),
+        "post-init sync should distribute the skill"
+    );
+}
+
+#[test]
+fn init_yes_rejects_malformed_source_without_writing() {
+    let tmp = TempDir::new().unwrap();
+    let tome_home = tmp.path().join(".tome");
+
+    tome()
+        .args(["init", "--yes", "--source", "local"])
+        .env("HOME", tmp.path())
+        .env("TOME_HOME", &tome_home)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid --source 'local'"));
+    assert!(!tome_home.join("tome.toml").exists());
+}
+
+#[test]
+fn init_source_flag_requires_yes() {
+    let tmp = TempDir::new().unwrap();
+    tome()
+        .args(["init", "--source", "local=/tmp/x"])
+        .env("HOME", tmp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--yes"));
+}
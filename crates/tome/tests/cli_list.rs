@@ -45,6 +45,255 @@ fn list_shows_discovered_skills() {
     });
 }
 
+#[test]
+fn list_exclude_source_hides_that_directory() {
+    let tmp = TempDir::new().unwrap();
+    let local = tmp.path().join("local");
+    let share = tmp.path().join("share");
+    create_skill(&local, "my-skill");
+    create_skill(&share, "shared-skill");
+
+    let config = write_config(
+        tmp.path(),
+        &format!(
+            "[directories.local]\npath = \"{}\"\ntype = \"directory\"\nrole = \"source\"\n\n\
+             [directories.share]\npath = \"{}\"\ntype = \"directory\"\nrole = \"source\"\n",
+            local.display(),
+            share.display()
+        ),
+    );
+
+    tome()
+        .args([
+            "--config",
+            config.to_str().unwrap(),
+            "list",
+            "--exclude-source",
+            "share",
+            "--exclude-source",
+            "nope",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("my-skill"))
+        .stdout(predicate::str::contains("shared-skill").not())
+        .stderr(predicate::str::contains("no source directory named 'nope'"));
+
+    // Same warning channel as `tome sync`: counted by --warnings-as-errors.
+    tome()
+        .args([
+            "--config",
+            config.to_str().unwrap(),
+            "list",
+            "--exclude-source",
+            "nope",
+            "--warnings-as-errors",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--warnings-as-errors"));
+}
+
+#[test]
+fn list_source_shows_only_that_directory() {
+    let tmp = TempDir::new().unwrap();
+    let local = tmp.path().join("local");
+    let share = tmp.path().join("share");
+    create_skill(&local, "my-skill");
+    create_skill(&share, "shared-skill");
+
+    let config = write_config(
+        tmp.path(),
+        &format!(
+            "[directories.local]\npath = \"{}\"\ntype = \"directory\"\nrole = \"source\"\n\n\
+             [directories.share]\npath = \"{}\"\ntype = \"directory\"\nrole = \"source\"\n",
+            local.display(),
+            share.display()
+        ),
+    );
+    let config = config.to_str().unwrap();
+
+    tome()
+        .args(["--config", config, "list", "--source", "share"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("shared-skill"))
+        .stdout(predicate::str::contains("my-skill").not());
+
+    let output = tome()
+        .args(["--config", config, "list", "--json", "--source", "local"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let rows: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let names: Vec<&str> = rows
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["my-skill"]);
+
+    tome()
+        .args(["--config", config, "list", "--source", "shaer"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "no source directory named 'shaer' — did you mean 'share'?",
+        ));
+
+    // Nothing within the edit-distance cutoff: list the sources instead.
+    tome()
+        .args(["--config", config, "list", "--source", "vendored"])
+        .assert()
+        .failure()
+        .stderr(
+            predicate::str::contains("configured sources:")
+                .and(predicate::str::contains("did you mean").not()),
+        );
+}
+
+#[test]
+fn list_tag_filters_by_all_or_any_tag() {
+    let tmp = TempDir::new().unwrap();
+    let skills_dir = tmp.path().join("skills");
+    create_skill(&skills_dir, "untagged");
+    for (name, tags) in [("rusty", "[rust, cli]"), ("webby", "[web]")] {
+        let dir = skills_dir.join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("SKILL.md"),
+            format!("---\nname: {name}\nmetadata:\n  tags: {tags}\n---\n"),
+        )
+        .unwrap();
+    }
+    let config = write_config(
+        tmp.path(),
+        &format!(
+            "[directories.test]\npath = \"{}\"\ntype = \"directory\"\nrole = \"source\"\n",
+            skills_dir.display()
+        ),
+    );
+    let config = config.to_str().unwrap();
+
+    tome()
+        .args(["--config", config, "list", "--tag", "Rust", "--tag", "cli"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("rusty"))
+        .stdout(predicate::str::contains("webby").not())
+        .stdout(predicate::str::contains("untagged").not());
+
+    tome()
+        .args(["--config", config, "list", "--tag", "rust", "--tag", "web"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "No skills match the given tag(s).",
+        ));
+
+    tome()
+        .args([
+            "--config",
+            config,
+            "list",
+            "--tag",
+            "rust",
+            "--tag",
+            "web",
+            "--tag-mode",
+            "any",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("rusty"))
+        .stdout(predicate::str::contains("webby"))
+        .stdout(predicate::str::contains("untagged").not());
+}
+
+#[test]
+fn list_since_shows_only_recently_modified_skills() {
+    let tmp = TempDir::new().unwrap();
+    let skills_dir = tmp.path().join("skills");
+    create_skill(&skills_dir, "fresh-skill");
+    create_skill(&skills_dir, "stale-skill");
+    let three_days_ago =
+        std::time::SystemTime::now() - std::time::Duration::from_secs(3 * 24 * 60 * 60);
+    std::fs::File::options()
+        .write(true)
+        .open(skills_dir.join("stale-skill/SKILL.md"))
+        .unwrap()
+        .set_modified(three_days_ago)
+        .unwrap();
+
+    let config = write_config(
+        tmp.path(),
+        &format!(
+            "[directories.test]\npath = \"{}\"\ntype = \"directory\"\nrole = \"source\"\n",
+            skills_dir.display()
+        ),
+    );
+    let config = config.to_str().unwrap();
+
+    tome()
+        .args(["--config", config, "list", "--since", "1h"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("fresh-skill"))
+        .stdout(predicate::str::contains("stale-skill").not());
+
+    tome()
+        .args(["--config", config, "list", "--since", "1w"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("fresh-skill"))
+        .stdout(predicate::str::contains("stale-skill"));
+
+    tome()
+        .args(["--config", config, "list", "--since", "2"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("has no unit"));
+}
+
+#[test]
+fn list_long_adds_truncated_description_column() {
+    let tmp = TempDir::new().unwrap();
+    let skills_dir = tmp.path().join("skills");
+    create_skill(&skills_dir, "bare-skill");
+    let described = skills_dir.join("described-skill");
+    std::fs::create_dir_all(&described).unwrap();
+    std::fs::write(
+        described.join("SKILL.md"),
+        format!(
+            "---\nname: described-skill\ndescription: Reviews pull requests {}\n---\n",
+            "and more ".repeat(20)
+        ),
+    )
+    .unwrap();
+
+    let config = write_config(
+        tmp.path(),
+        &format!(
+            "[directories.test]\npath = \"{}\"\ntype = \"directory\"\nrole = \"source\"\n",
+            skills_dir.display()
+        ),
+    );
+
+    let output = tome()
+        .args(["--config", config.to_str().unwrap(), "list", "-l"])
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let settings = snapshot_settings(&tmp);
+    settings.bind(|| {
+        insta::assert_snapshot!("list_table_long", stdout);
+    });
+}
+
 #[test]
 fn list_json_outputs_valid_json() {
     let tmp = TempDir::new().unwrap();
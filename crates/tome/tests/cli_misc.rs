@@ -134,6 +134,29 @@ fn completions_print_outputs_to_stdout() {
         .stdout(predicate::str::contains("complete -c tome"));
 }
 
+#[test]
+fn completions_bash_print_includes_subcommands() {
+    let tmp = TempDir::new().unwrap();
+    tome()
+        .env("TOME_HOME", tmp.path())
+        .args(["completions", "bash", "--print"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("sync"))
+        .stdout(predicate::str::contains("doctor"));
+}
+
+#[test]
+fn completions_elvish_print_succeeds() {
+    let tmp = TempDir::new().unwrap();
+    tome()
+        .env("TOME_HOME", tmp.path())
+        .args(["completions", "elvish", "--print"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("tome"));
+}
+
 #[test]
 fn exit_code_2_for_invalid_args() {
     // Clap returns exit code 2 for usage errors (invalid flags)
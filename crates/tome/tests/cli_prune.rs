@@ -0,0 +1,74 @@
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+/// Sync with every directory enabled, so both targets get links, then
+/// return the env whose machine prefs disable `off`.
+fn synced_env() -> TestEnv {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("on")
+        .target("off")
+        .skill("alpha", "local")
+        .skill("beta", "local")
+        .disable_target("off")
+        .build();
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    assert!(env.target_dir("off").join("alpha").is_symlink());
+    env
+}
+
+#[test]
+fn prune_removes_library_links_from_disabled_directories_only() {
+    let env = synced_env();
+    let off = env.target_dir("off");
+    let external = env.tmp.path().join("external");
+    std::fs::create_dir_all(&external).unwrap();
+    std::os::unix::fs::symlink(&external, off.join("external")).unwrap();
+    std::fs::create_dir_all(off.join("real-skill")).unwrap();
+
+    env.cmd_with_machine()
+        .arg("prune")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("off: 2 symlink(s) removed"))
+        .stdout(predicate::str::contains("Pruned 2 symlink(s)"));
+
+    assert!(!off.join("alpha").is_symlink());
+    assert!(!off.join("beta").is_symlink());
+    assert!(off.join("external").is_symlink());
+    assert!(off.join("real-skill").is_dir());
+    assert!(env.target_dir("on").join("alpha").is_symlink());
+    assert!(env.library_dir().join("alpha").is_dir());
+}
+
+#[test]
+fn prune_dry_run_keeps_links() {
+    let env = synced_env();
+
+    env.cmd_with_machine()
+        .args(["--dry-run", "prune"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("off: 2 symlink(s) to remove"))
+        .stdout(predicate::str::contains("Would prune 2 symlink(s)"));
+    assert!(env.target_dir("off").join("alpha").is_symlink());
+}
+
+#[test]
+fn prune_with_nothing_disabled_is_a_no_op() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("on")
+        .skill("alpha", "local")
+        .build();
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+
+    env.cmd()
+        .arg("prune")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Nothing to prune"));
+    assert!(env.target_dir("on").join("alpha").is_symlink());
+}
@@ -559,3 +559,85 @@ fn phase14_status_text_omits_unowned_section_when_empty() {
         "unowned array must be empty (not omitted): {json}"
     );
 }
+
+#[test]
+fn status_target_reports_link_health() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-tool")
+        .skill("skill-a", "local")
+        .build();
+    env.cmd().arg("sync").assert().success();
+    let target = env.target_dir("test-tool");
+    std::os::unix::fs::symlink(env.library_dir.join("gone"), target.join("gone")).unwrap();
+    std::fs::create_dir(target.join("hand-made")).unwrap();
+
+    let output = env
+        .cmd()
+        .args(["status", "--target", "test-tool", "--json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["linked"], 1);
+    assert_eq!(json["stale"], serde_json::json!(["gone"]));
+    assert_eq!(json["external"], serde_json::json!([]));
+    assert_eq!(json["unmanaged"], serde_json::json!(["hand-made"]));
+
+    env.cmd()
+        .args(["status", "--target", "test-tool"])
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(predicates::str::contains("1 linked"))
+        .stdout(predicates::str::contains("1 stale link(s)"))
+        .stdout(predicates::str::contains(
+            "1 unmanaged file(s) or directories: hand-made",
+        ));
+}
+
+#[test]
+fn status_check_exits_nonzero_on_broken_link() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-tool")
+        .skill("skill-a", "local")
+        .build();
+    env.cmd().arg("sync").assert().success();
+
+    env.cmd()
+        .args(["status", "--check"])
+        .assert()
+        .success()
+        .stdout(predicates::str::is_empty());
+
+    std::os::unix::fs::symlink(
+        env.library_dir.join("gone"),
+        env.target_dir("test-tool").join("gone"),
+    )
+    .unwrap();
+    env.cmd()
+        .args(["status", "--check"])
+        .assert()
+        .failure()
+        .stdout(predicates::str::is_empty())
+        .stderr(predicates::str::contains(
+            "status: unhealthy — 1 broken symlink(s)",
+        ));
+}
+
+#[test]
+fn status_target_rejects_unknown_directory() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-tool")
+        .build();
+
+    env.cmd()
+        .args(["status", "--target", "local"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "no target directory named 'local' (configured: test-tool)",
+        ));
+}
@@ -1349,6 +1349,126 @@ fn edge_permission_denied_on_target() {
     );
 }
 
+#[test]
+fn sync_dump_plan_on_error_writes_partial_plan() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("a-tool")
+        .target("b-tool")
+        .skill("my-skill", "local")
+        .build();
+
+    // Distribution runs in directory-name order: a-tool succeeds, then
+    // b-tool fails because its path is a regular file.
+    let broken = env.target_dir("b-tool").to_path_buf();
+    std::fs::remove_dir_all(&broken).unwrap();
+    std::fs::write(&broken, "not a directory").unwrap();
+
+    let output = env
+        .cmd()
+        .args(["sync", "--no-triage", "--dump-plan-on-error"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success(), "sync should fail on b-tool");
+
+    let dump_path = env.library_dir().join(".tome-cache/sync-plan.json");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("plan-so-far written to") && stderr.contains("sync-plan.json"),
+        "error should point at the dump; stderr:\n{stderr}"
+    );
+
+    let dump: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&dump_path).unwrap()).unwrap();
+    assert_eq!(dump["failed_stage"], "Distribute");
+    assert_eq!(dump["discovered"][0]["name"], "my-skill");
+    assert_eq!(dump["consolidate"]["created"], 1);
+    let distributions = dump["distributions"].as_array().unwrap();
+    assert_eq!(distributions.len(), 1, "only a-tool finished distributing");
+    assert_eq!(distributions[0]["directory_name"], "a-tool");
+    assert!(dump["removed_from_targets"].is_null(), "cleanup never ran");
+    assert!(
+        dump["error"][0]
+            .as_str()
+            .unwrap()
+            .contains("failed to create target dir"),
+        "dump should record the error chain: {}",
+        dump["error"]
+    );
+}
+
+#[test]
+fn sync_failure_without_flag_writes_no_dump() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("a-tool")
+        .skill("my-skill", "local")
+        .build();
+
+    let broken = env.target_dir("a-tool").to_path_buf();
+    std::fs::remove_dir_all(&broken).unwrap();
+    std::fs::write(&broken, "not a directory").unwrap();
+
+    env.cmd()
+        .args(["sync", "--no-triage"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("plan-so-far").not());
+    assert!(!env.library_dir().join(".tome-cache").exists());
+}
+
+#[test]
+fn sync_force_adopt_backs_up_real_dir_in_target() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("a-tool")
+        .skill("my-skill", "local")
+        .build();
+
+    let collision = env.target_dir("a-tool").join("my-skill");
+    std::fs::create_dir_all(&collision).unwrap();
+    std::fs::write(collision.join("SKILL.md"), "imported copy").unwrap();
+
+    // Default: skipped, left alone, and named in the summary.
+    env.cmd()
+        .args(["sync", "--no-triage"])
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "skipped: {} (path conflict)",
+            collision.display()
+        )));
+    assert!(!collision.is_symlink());
+
+    env.cmd()
+        .args(["sync", "--no-triage", "--force", "--adopt"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Backed up existing entry"));
+
+    let backup = env.target_dir("a-tool").join("my-skill.tome-bak");
+    assert!(collision.is_symlink());
+    assert_eq!(
+        std::fs::read_to_string(backup.join("SKILL.md")).unwrap(),
+        "imported copy"
+    );
+}
+
+#[test]
+fn sync_adopt_requires_force() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .skill("my-skill", "local")
+        .build();
+
+    env.cmd()
+        .args(["sync", "--adopt"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--force"));
+}
+
 #[test]
 fn edge_corrupted_manifest() {
     let env = TestEnvBuilder::new()
@@ -1605,9 +1725,9 @@ fn lifecycle_doctor_detects_and_reports() {
     let broken_link = env.target_dir("test-tool").join("broken");
     std::os::unix::fs::symlink("/nonexistent/path", &broken_link).unwrap();
 
-    // Doctor should detect issues
+    // Doctor should detect issues (and fail, since dry-run fixes nothing)
     let output = env.cmd().args(["doctor", "--dry-run"]).output().unwrap();
-    assert!(output.status.success());
+    assert!(!output.status.success());
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(
         stdout.contains("issue") || stdout.contains("Issue"),
@@ -2093,3 +2213,1108 @@ fn sync_verbose_emits_step_spans_on_stderr() {
         "stderr must contain 'time.busy' timing field (RESEARCH §elapsed_ms FINDING). stderr was:\n{stderr}"
     );
 }
+
+#[test]
+fn sync_verbose_lists_skill_names_under_counts() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .skill("beta", "local")
+        .build();
+
+    env.cmd()
+        .args(["--verbose", "sync", "--no-triage"])
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("    created: alpha, beta"))
+        .stdout(predicate::str::contains("    linked:"));
+
+    env.cmd()
+        .args(["sync", "--no-triage"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("created:").not());
+}
+
+#[test]
+fn sync_verbose_logs_each_skill_action_with_paths() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .build();
+    let library_alpha = env.library_dir().join("alpha");
+    let linked_alpha = env.target_dir("test-target").join("alpha");
+
+    env.cmd()
+        .args(["--verbose", "sync", "--no-triage"])
+        .env("NO_COLOR", "1")
+        .env_remove("TOME_LOG")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(format!(
+            "library: created alpha ({} -> {})",
+            env.source_dir("local").join("alpha").display(),
+            library_alpha.display()
+        )))
+        .stderr(predicate::str::contains(format!(
+            "test-target: created alpha ({} -> {})",
+            library_alpha.display(),
+            linked_alpha.display()
+        )));
+
+    env.cmd()
+        .args(["--verbose", "sync", "--no-triage"])
+        .env("NO_COLOR", "1")
+        .env_remove("TOME_LOG")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("library: unchanged alpha"))
+        .stderr(predicate::str::contains("test-target: unchanged alpha"));
+}
+
+#[test]
+fn sync_prefix_renames_skills_and_cleans_up_on_change() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("review", "local")
+        .build();
+    let set_prefix = |prefix: &str| {
+        let config = std::fs::read_to_string(&env.config_path).unwrap();
+        let mut lines: Vec<String> = config
+            .lines()
+            .filter(|l| !l.starts_with("prefix = "))
+            .map(str::to_string)
+            .collect();
+        let header = lines
+            .iter()
+            .position(|l| l == "[directories.local]")
+            .unwrap();
+        lines.insert(header + 1, format!("prefix = \"{prefix}\""));
+        std::fs::write(&env.config_path, lines.join("\n")).unwrap();
+    };
+
+    set_prefix("work");
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    assert!(env.library_dir().join("work-review").is_dir());
+    assert!(!env.library_dir().join("review").exists());
+    let target = env.target_dir("test-target");
+    assert!(target.join("work-review").is_symlink());
+    // The source directory keeps its original name.
+    assert!(env.source_dir("local").join("review").is_dir());
+
+    // Renaming the prefix orphans the old names; cleanup removes them.
+    set_prefix("team");
+    env.cmd()
+        .args(["sync", "--no-triage", "--no-input"])
+        .assert()
+        .success();
+    assert!(env.library_dir().join("team-review").is_dir());
+    assert!(target.join("team-review").is_symlink());
+    assert!(!env.library_dir().join("work-review").exists());
+    assert!(!target.join("work-review").is_symlink());
+}
+
+#[test]
+fn sync_relative_links_are_relative_unchanged_on_rerun_and_cleaned_when_stale() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .skill("beta", "local")
+        .build();
+    let config = std::fs::read_to_string(&env.config_path).unwrap();
+    std::fs::write(&env.config_path, format!("relative_links = true\n{config}")).unwrap();
+
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    let link = env.target_dir("test-target").join("alpha");
+    let raw = std::fs::read_link(&link).unwrap();
+    assert!(raw.is_relative(), "expected a relative link, got {raw:?}");
+    assert!(link.join("SKILL.md").is_file());
+
+    env.cmd()
+        .args(["sync", "--no-triage"])
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 linked, 2 unchanged"));
+
+    // A relative link whose library entry is gone is still recognised as
+    // tome's and cleaned up.
+    env.remove_skill("beta", "local");
+    env.cmd()
+        .args(["sync", "--no-triage", "--no-input"])
+        .assert()
+        .success();
+    assert!(!env.target_dir("test-target").join("beta").is_symlink());
+    assert!(link.is_symlink());
+}
+
+#[test]
+fn sync_link_marker_cleans_up_only_recorded_links() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .skill("beta", "local")
+        .build();
+    let config = std::fs::read_to_string(&env.config_path).unwrap();
+    std::fs::write(&env.config_path, format!("link_marker = true\n{config}")).unwrap();
+
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    let target = env.target_dir("test-target");
+    let ledger = std::fs::read_to_string(target.join(".tome-links")).unwrap();
+    assert!(ledger.lines().any(|l| l == "alpha"), "{ledger}");
+    assert!(ledger.lines().any(|l| l == "beta"), "{ledger}");
+
+    // A hand-made link into the library that tome never recorded.
+    std::os::unix::fs::symlink(env.library_dir().join("gone"), target.join("handmade")).unwrap();
+
+    env.remove_skill("beta", "local");
+    env.cmd()
+        .args(["sync", "--no-triage", "--no-input"])
+        .assert()
+        .success();
+    assert!(!target.join("beta").is_symlink(), "recorded link removed");
+    assert!(target.join("handmade").is_symlink(), "unrecorded link kept");
+    assert!(target.join("alpha").is_symlink());
+}
+
+#[test]
+fn sync_skips_skill_disabled_in_frontmatter_but_list_shows_it() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("ready", "local")
+        .skill_with_content(
+            "wip",
+            "local",
+            "---\nname: wip\ndescription: Not done yet\nenabled: false\n---\n# wip\n",
+        )
+        .build();
+
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    assert!(env.library_dir().join("ready").is_dir());
+    assert!(env.target_dir("test-target").join("ready").is_symlink());
+    assert!(!env.library_dir().join("wip").exists());
+    assert!(!env.target_dir("test-target").join("wip").exists());
+
+    env.cmd()
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("wip (disabled)"))
+        .stdout(predicate::str::contains("ready (disabled)").not());
+
+    let output = env.cmd().args(["list", "--json"]).output().unwrap();
+    let rows: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let wip = rows
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|r| r["name"] == "wip")
+        .unwrap();
+    assert_eq!(wip["disabled"], true);
+}
+
+#[test]
+fn sync_generate_index_lists_every_library_skill() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill_with_content(
+            "alpha",
+            "local",
+            "---\nname: alpha\ndescription: The first skill\n---\n# alpha\n",
+        )
+        .skill("beta", "local")
+        .build();
+    let index = env.library_dir().join("INDEX.md");
+
+    // Off by default.
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    assert!(!index.exists());
+
+    let config = std::fs::read_to_string(&env.config_path).unwrap();
+    std::fs::write(&env.config_path, format!("generate_index = true\n{config}")).unwrap();
+
+    env.cmd()
+        .args(["--dry-run", "sync", "--no-triage"])
+        .assert()
+        .success();
+    assert!(!index.exists(), "dry run must not write the index");
+
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    let content = std::fs::read_to_string(&index).unwrap();
+    assert!(
+        content.contains("| alpha | local | The first skill |"),
+        "{content}"
+    );
+    assert!(content.contains("| beta | local |"), "{content}");
+}
+
+#[test]
+fn sync_exclude_removes_target_link_even_while_library_copy_remains() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .skill("beta", "local")
+        .build();
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    let target = env.target_dir("test-target");
+    assert!(target.join("alpha").is_symlink());
+
+    let config = std::fs::read_to_string(&env.config_path).unwrap();
+    std::fs::write(&env.config_path, format!("exclude = [\"alpha\"]\n{config}")).unwrap();
+
+    // A dry run keeps the library copy, so the link is not broken; it is
+    // still reported for removal.
+    env.cmd()
+        .args(["--dry-run", "sync", "--no-triage", "--no-input"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Cleaned 1 stale target link(s)"));
+    assert!(env.library_dir().join("alpha").is_dir());
+    assert!(target.join("alpha").is_symlink());
+
+    env.cmd()
+        .args(["sync", "--no-triage", "--no-input"])
+        .assert()
+        .success();
+    assert!(!target.join("alpha").is_symlink());
+    assert!(target.join("beta").is_symlink());
+}
+
+#[test]
+fn sync_dry_run_shows_link_delta_per_target() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .skill("beta", "local")
+        .build();
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+
+    let config = std::fs::read_to_string(&env.config_path).unwrap();
+    std::fs::write(&env.config_path, format!("exclude = [\"alpha\"]\n{config}")).unwrap();
+    env.add_skill("gamma", "local");
+
+    env.cmd()
+        .args(["--dry-run", "sync", "--no-triage", "--no-input"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "test-target: would link 1, would remove 1, unchanged 1",
+        ));
+
+    // A real run keeps the plain counts.
+    env.cmd()
+        .args(["sync", "--no-triage", "--no-input"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "test-target: 1 linked, 1 unchanged",
+        ))
+        .stdout(predicate::str::contains("would link").not());
+}
+
+#[test]
+fn sync_check_fails_until_synced_and_writes_nothing() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .build();
+    // The builder pre-creates the library; start from a fresh machine.
+    std::fs::remove_dir_all(env.library_dir()).unwrap();
+
+    env.cmd()
+        .args(["sync", "--check"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("+ alpha (new)"))
+        .stderr(predicate::str::contains("2 change(s) pending"));
+    assert!(
+        !env.library_dir().exists(),
+        "--check must not create the library"
+    );
+    assert!(!env.target_dir("test-target").join("alpha").exists());
+
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    env.cmd()
+        .args(["sync", "--check"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("up to date"));
+
+    env.add_skill("beta", "local");
+    env.cmd()
+        .args(["sync", "--check"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("+ beta (new)"));
+    assert!(!env.library_dir().join("beta").exists());
+}
+
+#[test]
+fn sync_targets_only_relinks_from_library_without_discovery() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .build();
+
+    env.cmd()
+        .args(["sync", "--targets-only"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is empty or missing"));
+
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    let link = env.target_dir("test-target").join("alpha");
+    std::fs::remove_file(&link).unwrap();
+    // Not yet in the library, so --targets-only must not pick it up.
+    env.add_skill("beta", "local");
+
+    env.cmd()
+        .args(["sync", "--targets-only"])
+        .assert()
+        .success();
+    assert!(link.is_symlink(), "alpha should be relinked");
+    assert!(!env.library_dir().join("beta").exists());
+    assert!(!env.target_dir("test-target").join("beta").exists());
+}
+
+#[test]
+fn sync_format_json_prints_one_parseable_summary() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .skill("beta", "local")
+        .build();
+
+    for quiet in [false, true] {
+        let mut cmd = env.cmd();
+        if quiet {
+            cmd.arg("--quiet");
+        }
+        let output = cmd
+            .args(["sync", "--no-triage", "--format", "json"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let summary: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("stdout should be one JSON object");
+        let created = if quiet { 0 } else { 2 };
+        assert_eq!(summary["library"]["created"], created);
+        assert_eq!(summary["targets"][0]["directory"], "test-target");
+        assert_eq!(summary["targets"][0]["linked"], created);
+        assert_eq!(summary["cleanup"]["removed_from_targets"], 0);
+    }
+}
+
+#[test]
+fn sync_format_json_prints_zero_summary_when_nothing_to_sync() {
+    let tmp = TempDir::new().unwrap();
+    let empty = tmp.path().join("empty");
+    std::fs::create_dir_all(&empty).unwrap();
+    let no_dirs = write_config(tmp.path(), "");
+    let no_skills = tmp.path().join("no-skills.toml");
+    std::fs::write(
+        &no_skills,
+        format!(
+            "library_dir = \"{}\"\n[directories.local]\npath = \"{}\"\nrole = \"source\"\n",
+            tmp.path().join("library").display(),
+            empty.display()
+        ),
+    )
+    .unwrap();
+
+    for config in [&no_dirs, &no_skills] {
+        let output = tome()
+            .args(["--config", config.to_str().unwrap()])
+            .args(["sync", "--no-triage", "--format", "json"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let summary: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("stdout should be one JSON object");
+        assert_eq!(summary["library"]["created"], 0);
+        assert_eq!(summary["targets"], serde_json::json!([]));
+        assert_eq!(summary["cleanup"]["removed_from_library"], 0);
+    }
+}
+
+#[test]
+fn sync_parallel_matches_serial_run() {
+    let build = || {
+        let mut builder = TestEnvBuilder::new().source("local", "directory");
+        for target in ["t1", "t2", "t3", "t4"] {
+            builder = builder.target(target);
+        }
+        for skill in ["alpha", "beta", "gamma"] {
+            builder = builder.skill(skill, "local");
+        }
+        builder.build()
+    };
+    let summary = |env: &TestEnv, parallel: &str| {
+        let output = env
+            .cmd()
+            .args([
+                "sync",
+                "--no-triage",
+                "--format",
+                "json",
+                "--parallel",
+                parallel,
+            ])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap()
+    };
+
+    let serial = build();
+    let parallel = build();
+    let serial_summary = summary(&serial, "1");
+    assert_eq!(serial_summary, summary(&parallel, "3"));
+    assert_eq!(serial_summary["targets"][3]["directory"], "t4");
+    assert_eq!(serial_summary["targets"][3]["linked"], 3);
+    for target in ["t1", "t2", "t3", "t4"] {
+        for skill in ["alpha", "beta", "gamma"] {
+            assert!(parallel.target_dir(target).join(skill).is_symlink());
+        }
+    }
+
+    // Re-running in parallel leaves everything unchanged, as serially.
+    assert_eq!(summary(&serial, "1"), summary(&parallel, "4"));
+
+    parallel
+        .cmd()
+        .args(["sync", "--parallel", "0"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn sync_parallel_verbose_groups_lines_per_directory() {
+    let mut builder = TestEnvBuilder::new().source("local", "directory");
+    for target in ["t1", "t2", "t3"] {
+        builder = builder.target(target);
+    }
+    for skill in ["alpha", "beta", "gamma", "delta"] {
+        builder = builder.skill(skill, "local");
+    }
+    let env = builder.build();
+
+    let output = env
+        .cmd()
+        .args(["--verbose", "sync", "--no-triage", "--parallel", "3"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    // Each directory's per-skill lines come out as one block, in order.
+    let dirs: Vec<&str> = stderr
+        .lines()
+        .filter_map(|line| {
+            ["t1", "t2", "t3"]
+                .into_iter()
+                .find(|dir| line.contains(&format!("{dir}: created ")))
+        })
+        .collect();
+    let mut expected = Vec::new();
+    for dir in ["t1", "t2", "t3"] {
+        expected.extend([dir; 4]);
+    }
+    assert_eq!(dirs, expected, "{stderr}");
+}
+
+#[test]
+fn sync_library_git_commit_commits_library_changes() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .skill("alpha", "local")
+        .build();
+    git_init(env.library_dir());
+    let last_subject = || {
+        let out = StdCommand::new("git")
+            .args(["log", "-1", "--format=%s"])
+            .current_dir(env.library_dir())
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&out.stdout).trim().to_string()
+    };
+
+    env.cmd()
+        .args(["sync", "--no-triage", "--library-git-commit"])
+        .assert()
+        .success();
+    assert_eq!(last_subject(), "tome sync: 1 created");
+
+    // Nothing changed: no empty commit.
+    env.cmd()
+        .args(["sync", "--no-triage", "--library-git-commit"])
+        .assert()
+        .success();
+    assert_eq!(last_subject(), "tome sync: 1 created");
+
+    env.add_skill("beta", "local");
+    env.cmd()
+        .args(["sync", "--no-triage", "--library-git-commit", "add beta"])
+        .assert()
+        .success();
+    assert_eq!(last_subject(), "add beta");
+}
+
+#[test]
+fn sync_library_git_commit_failure_counts_as_warning() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .skill("alpha", "local")
+        .build();
+    git_init(env.library_dir());
+    let hook = env.library_dir().join(".git/hooks/pre-commit");
+    std::fs::create_dir_all(hook.parent().unwrap()).unwrap();
+    std::fs::write(&hook, "#!/bin/sh\necho 'rejected by hook' >&2\nexit 1\n").unwrap();
+    std::fs::set_permissions(&hook, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    env.cmd()
+        .args([
+            "sync",
+            "--no-triage",
+            "--library-git-commit",
+            "--warnings-as-errors",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("git commit failed"))
+        .stderr(predicate::str::contains("rejected by hook"))
+        .stderr(predicate::str::contains("--warnings-as-errors"));
+    assert!(
+        env.library_dir().join("alpha").is_dir(),
+        "the sync itself completes"
+    );
+}
+
+#[test]
+fn sync_discovery_cache_is_written_and_picks_up_source_changes() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .skill("alpha", "local")
+        .build();
+    let cache = env.tome_home().join(".tome-discovery-cache.json");
+
+    env.cmd()
+        .args(["--dry-run", "sync", "--no-triage"])
+        .assert()
+        .success();
+    assert!(!cache.exists(), "dry run must not write the cache");
+    env.cmd()
+        .args(["sync", "--no-triage", "--no-cache"])
+        .assert()
+        .success();
+    assert!(!cache.exists(), "--no-cache must not write the cache");
+
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    assert!(cache.is_file());
+
+    // Cached runs still see new and edited skills.
+    env.add_skill("beta", "local");
+    env.modify_skill(
+        "alpha",
+        "local",
+        "---\nname: alpha\n---\n# edited alpha body\n",
+    );
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    assert!(env.library_dir().join("beta/SKILL.md").is_file());
+    assert!(
+        std::fs::read_to_string(env.library_dir().join("alpha/SKILL.md"))
+            .unwrap()
+            .contains("edited alpha body")
+    );
+}
+
+#[test]
+fn sync_stats_prints_per_phase_timing_table() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .skill("beta", "local")
+        .build();
+
+    let output = env
+        .cmd()
+        .args(["sync", "--no-triage", "--stats"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Sync timing"), "{stdout}");
+    for phase in [
+        "reconcile",
+        "discover",
+        "consolidate",
+        "cleanup: library",
+        "distribute: test-target",
+        "cleanup: targets",
+        "save",
+        "total",
+    ] {
+        assert!(stdout.contains(phase), "missing {phase}: {stdout}");
+    }
+    let discover = stdout.lines().find(|l| l.contains("discover")).unwrap();
+    assert!(discover.trim_end().ends_with('2'), "{discover}");
+
+    env.cmd()
+        .args(["sync", "--no-triage"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Sync timing").not());
+}
+
+#[test]
+fn sync_glob_source_path_discovers_every_matching_directory() {
+    let tmp = TempDir::new().unwrap();
+    let dev = tmp.path().join("dev");
+    create_skill(&dev.join("app-one/skills"), "one-skill");
+    create_skill(&dev.join("app-two/skills"), "two-skill");
+    std::fs::create_dir_all(dev.join("no-skills-here")).unwrap();
+    let target_dir = tmp.path().join("target");
+    let config = write_config_with_target(
+        tmp.path(),
+        &format!(
+            "[directories.projects]\npath = \"{}/*/skills\"\ntype = \"directory\"\nrole = \"source\"\n",
+            dev.display()
+        ),
+        &target_dir,
+    );
+
+    tome()
+        .args(["--config", config.to_str().unwrap(), "sync", "--no-triage"])
+        .assert()
+        .success();
+    assert!(tmp.path().join("library/one-skill/SKILL.md").is_file());
+    assert!(tmp.path().join("library/two-skill/SKILL.md").is_file());
+    assert!(target_dir.join("one-skill").is_symlink());
+
+    let manifest = std::fs::read_to_string(tmp.path().join(".tome-manifest.json")).unwrap();
+    assert_eq!(
+        manifest.matches("\"source\": \"projects\"").count(),
+        2,
+        "{manifest}"
+    );
+}
+
+#[test]
+fn sync_warns_about_skill_names_differing_only_in_case() {
+    let env = TestEnvBuilder::new()
+        .source("alpha-src", "directory")
+        .source("beta-src", "directory")
+        .skill("Review", "alpha-src")
+        .skill("review", "beta-src")
+        .build();
+
+    env.cmd()
+        .args(["sync", "--no-triage"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "'Review' (directory 'alpha-src') and 'review' (directory 'beta-src') differ only in case",
+        ));
+}
+
+#[test]
+fn sync_on_conflict_keep_all_distributes_every_copy() {
+    let env = TestEnvBuilder::new()
+        .source("alpha", "directory")
+        .source("beta", "directory")
+        .target("test-target")
+        .skill("shared", "alpha")
+        .skill("shared", "beta")
+        .build();
+    let config = std::fs::read_to_string(&env.config_path).unwrap();
+    std::fs::write(
+        &env.config_path,
+        format!("on_conflict = \"keep-all\"\n{config}"),
+    )
+    .unwrap();
+
+    env.cmd()
+        .args(["sync", "--no-triage"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("found in both").not());
+
+    let target = env.target_dir("test-target");
+    assert!(target.join("shared").join("SKILL.md").is_file());
+    assert!(target.join("shared@beta").join("SKILL.md").is_file());
+    assert!(env.library_dir.join("shared@beta").is_dir());
+}
+
+#[test]
+fn sync_conflict_resolution_last_source_keeps_later_copy() {
+    let env = TestEnvBuilder::new()
+        .source("alpha", "directory")
+        .source("beta", "directory")
+        .target("test-target")
+        .skill("shared", "alpha")
+        .skill("shared", "beta")
+        .build();
+    let config = std::fs::read_to_string(&env.config_path).unwrap();
+    std::fs::write(
+        &env.config_path,
+        format!("conflict_resolution = \"last-source\"\n{config}"),
+    )
+    .unwrap();
+
+    env.cmd()
+        .args(["sync", "--no-triage"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "skill 'shared' found in both 'beta' and 'alpha', using 'beta' (last-source)",
+        ));
+
+    env.cmd()
+        .args(["list", "--json"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"source\": \"beta\""));
+}
+
+#[test]
+fn sync_only_limits_consolidate_and_distribute_to_named_skills() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .skill("beta", "local")
+        .build();
+    let target = env.target_dir("test-target");
+    // A stale tome link that a full sync would clean up.
+    std::fs::create_dir_all(target).unwrap();
+    std::os::unix::fs::symlink(env.library_dir.join("gone"), target.join("gone")).unwrap();
+
+    env.cmd()
+        .args(["sync", "--only", "alpha"])
+        .assert()
+        .success();
+
+    assert!(env.library_dir.join("alpha").is_dir());
+    assert!(target.join("alpha").is_symlink());
+    assert!(!env.library_dir.join("beta").exists());
+    assert!(!target.join("beta").exists());
+    assert!(
+        target.join("gone").is_symlink(),
+        "--only must not clean up unrelated links"
+    );
+}
+
+#[test]
+fn sync_tag_limits_sync_to_tagged_skills_without_pruning() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill_with_content(
+            "alpha",
+            "local",
+            "---\nname: alpha\nmetadata:\n  tags: [rust]\n---\n",
+        )
+        .skill("beta", "local")
+        .build();
+    let target = env.target_dir("test-target");
+
+    env.cmd().args(["sync"]).assert().success();
+    std::fs::remove_dir_all(env.source_dir("local").join("beta")).unwrap();
+
+    env.cmd().args(["sync", "--tag", "rust"]).assert().success();
+
+    assert!(target.join("alpha").is_symlink());
+    assert!(
+        env.library_dir.join("beta").is_dir() && target.join("beta").is_symlink(),
+        "--tag must not prune untagged skills"
+    );
+}
+
+#[test]
+fn sync_warnings_as_errors_fails_after_completing() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .build();
+
+    env.cmd()
+        .args(["sync", "--exclude-source", "nope", "--warnings-as-errors"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("1 warning(s)"))
+        .stderr(predicate::str::contains("no source directory named 'nope'"))
+        .stderr(predicate::str::contains(
+            "1 warning(s) emitted (--warnings-as-errors)",
+        ));
+    assert!(
+        env.target_dir("test-target").join("alpha").is_symlink(),
+        "the sync itself must still complete"
+    );
+
+    env.cmd()
+        .args([
+            "--quiet",
+            "sync",
+            "--exclude-source",
+            "nope",
+            "--warnings-as-errors",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no source directory named").not())
+        .stderr(predicate::str::contains("--warnings-as-errors"));
+
+    env.cmd()
+        .args(["sync", "--warnings-as-errors"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn sync_prune_disabled_removes_links_from_disabled_target() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("target-a")
+        .target("target-b")
+        .skill("my-skill", "local")
+        .build();
+    env.cmd().arg("sync").assert().success();
+    let target_b = env.target_dir("target-b");
+    std::os::unix::fs::symlink("/some/external/path", target_b.join("external")).unwrap();
+    std::fs::create_dir(target_b.join("hand-made")).unwrap();
+
+    let machine_path = env.tome_home().join("machine.toml");
+    std::fs::write(&machine_path, "disabled_directories = [\"target-b\"]\n").unwrap();
+    let machine = machine_path.to_str().unwrap();
+
+    env.cmd()
+        .args(["--machine", machine, "sync"])
+        .assert()
+        .success();
+    assert!(
+        target_b.join("my-skill").is_symlink(),
+        "without --prune-disabled the link stays"
+    );
+
+    env.cmd()
+        .args(["--machine", machine, "sync", "--prune-disabled"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Pruned 1 link(s) from disabled directories",
+        ));
+    assert!(!target_b.join("my-skill").exists());
+    assert!(target_b.join("external").is_symlink());
+    assert!(target_b.join("hand-made").is_dir());
+    assert!(env.target_dir("target-a").join("my-skill").is_symlink());
+}
+
+#[test]
+fn sync_library_flag_consolidates_into_override_dir() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .build();
+    let other = env.tmp.path().join("other-library");
+    let config_before = std::fs::read_to_string(&env.config_path).unwrap();
+
+    env.cmd()
+        .args(["--library", other.to_str().unwrap(), "sync"])
+        .assert()
+        .success();
+
+    assert!(other.join("alpha").join("SKILL.md").is_file());
+    assert!(!env.library_dir.join("alpha").exists());
+    assert_eq!(
+        std::fs::read_link(env.target_dir("test-target").join("alpha")).unwrap(),
+        other.join("alpha")
+    );
+    assert_eq!(
+        std::fs::read_to_string(&env.config_path).unwrap(),
+        config_before,
+        "--library must not be written to the config"
+    );
+}
+
+#[test]
+fn sync_only_rejects_undiscovered_skill() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .build();
+
+    env.cmd()
+        .args(["sync", "--only", "alpha", "--only", "nope"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--only: skill(s) not discovered: nope",
+        ));
+    assert!(!env.library_dir.join("alpha").exists());
+}
+
+#[test]
+fn sync_exclude_source_skips_directory_and_keeps_its_links() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .source("share", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .skill("beta", "share")
+        .build();
+    let target = env.target_dir("test-target");
+    env.cmd().arg("sync").assert().success();
+    assert!(target.join("beta").is_symlink());
+
+    // The share drops off the network and gains a skill meanwhile.
+    env.remove_skill("beta", "share");
+    env.add_skill("gamma", "share");
+    env.cmd()
+        .args(["sync", "--exclude-source", "share"])
+        .assert()
+        .success();
+
+    assert!(env.library_dir.join("beta").is_dir());
+    assert!(
+        target.join("beta").is_symlink(),
+        "excluded source's links must be left alone"
+    );
+    assert!(!env.library_dir.join("gamma").exists());
+    let manifest = std::fs::read_to_string(env.manifest_path()).unwrap();
+    assert!(manifest.contains("\"beta\""));
+}
+
+#[test]
+fn sync_check_with_exclude_source_matches_what_sync_would_do() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .source("share", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .skill("beta", "share")
+        .build();
+    env.cmd().args(["sync", "--no-input"]).assert().success();
+
+    // Excluding a source is not a change: its entries stay owned.
+    env.cmd()
+        .args(["sync", "--check", "--exclude-source", "share"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("source removed from config").not());
+
+    // Changes in the excluded source don't count; ones elsewhere still do.
+    env.add_skill("gamma", "share");
+    env.cmd()
+        .args(["sync", "--check", "--exclude-source", "share"])
+        .assert()
+        .success();
+    env.add_skill("delta", "local");
+    env.cmd()
+        .args(["sync", "--check", "--exclude-source", "share"])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("delta").and(predicate::str::contains("gamma").not()));
+}
+
+#[test]
+fn sync_exclude_source_warns_on_unknown_name() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("test-target")
+        .skill("alpha", "local")
+        .build();
+
+    env.cmd()
+        .args(["sync", "--exclude-source", "nope"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "--exclude-source: no source directory named 'nope', ignoring it",
+        ));
+    assert!(env.target_dir("test-target").join("alpha").is_symlink());
+}
+
+#[test]
+fn sync_target_include_links_only_listed_skills() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("claude")
+        .target("codex")
+        .skill("alpha", "local")
+        .skill("beta", "local")
+        .skill("gamma", "local")
+        .build();
+    env.cmd().args(["sync", "--no-triage"]).assert().success();
+    let codex = env.target_dir("codex");
+    assert!(codex.join("beta").is_symlink());
+
+    // `codex` is the last table in the generated config, so the appended
+    // keys land on it.
+    let config = std::fs::read_to_string(&env.config_path).unwrap();
+    std::fs::write(
+        &env.config_path,
+        format!("{config}include = [\"alpha\", \"beta\"]\nexclude = [\"beta\"]\n"),
+    )
+    .unwrap();
+
+    env.cmd()
+        .args(["sync", "--no-triage", "--no-input"])
+        .assert()
+        .success();
+    assert!(codex.join("alpha").is_symlink());
+    assert!(!codex.join("beta").exists(), "excluded after include");
+    assert!(!codex.join("gamma").exists(), "not included");
+    let claude = env.target_dir("claude");
+    for name in ["alpha", "beta", "gamma"] {
+        assert!(
+            claude.join(name).is_symlink(),
+            "{name} in unfiltered target"
+        );
+    }
+}
+
+#[test]
+fn sync_refuses_distribution_dirs_sharing_a_path() {
+    let tmp = TempDir::new().unwrap();
+    let skills_dir = tmp.path().join("skills");
+    create_skill(&skills_dir, "my-skill");
+    let shared = tmp.path().join("agent-skills");
+    let config = write_config(
+        tmp.path(),
+        &format!(
+            "[directories.local]\npath = \"{}\"\nrole = \"source\"\n\n\
+             [directories.claude]\npath = \"{1}\"\nrole = \"target\"\n\n\
+             [directories.codex]\npath = \"{1}\"\nrole = \"target\"\n",
+            skills_dir.display(),
+            shared.display()
+        ),
+    );
+
+    tome()
+        .args(["--config", config.to_str().unwrap(), "sync", "--no-input"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "distribution directories 'claude' and 'codex' are the same path",
+        ));
+    assert!(!shared.exists(), "nothing may be distributed");
+    assert!(!tmp.path().join("library/my-skill").exists());
+}
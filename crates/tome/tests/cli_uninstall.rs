@@ -0,0 +1,72 @@
+use predicates::prelude::*;
+
+mod common;
+use common::*;
+
+#[test]
+fn uninstall_removes_managed_links_and_keeps_sources() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("claude")
+        .target("codex")
+        .skill("alpha", "local")
+        .skill("beta", "local")
+        .build();
+    env.cmd().arg("sync").assert().success();
+
+    let external = env.tmp.path().join("external-skill");
+    std::fs::create_dir_all(&external).unwrap();
+    std::os::unix::fs::symlink(&external, env.target_dir("claude").join("external")).unwrap();
+
+    env.cmd()
+        .args(["uninstall", "--purge", "--yes"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Removed 4 symlink(s)."));
+
+    for target in ["claude", "codex"] {
+        for skill in ["alpha", "beta"] {
+            assert!(
+                !env.target_dir(target).join(skill).exists(),
+                "{target}/{skill} should be removed"
+            );
+        }
+    }
+    assert!(env.target_dir("claude").join("external").is_symlink());
+    for skill in ["alpha", "beta"] {
+        assert!(
+            env.source_dir("local")
+                .join(skill)
+                .join("SKILL.md")
+                .is_file()
+        );
+        assert!(env.library_dir().join(skill).is_dir());
+    }
+    assert!(!env.config_path.exists(), "--purge deletes the config");
+}
+
+#[test]
+fn uninstall_requires_yes_without_a_terminal() {
+    let env = TestEnvBuilder::new()
+        .source("local", "directory")
+        .target("claude")
+        .skill("alpha", "local")
+        .build();
+    env.cmd().arg("sync").assert().success();
+
+    env.cmd()
+        .arg("uninstall")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("use --yes"));
+    assert!(env.target_dir("claude").join("alpha").is_symlink());
+
+    env.cmd()
+        .args(["--dry-run", "uninstall", "--purge"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("claude: 1 symlink(s) to remove"))
+        .stdout(predicate::str::contains("Dry run — no changes made."));
+    assert!(env.target_dir("claude").join("alpha").is_symlink());
+    assert!(env.config_path.exists());
+}
@@ -126,6 +126,7 @@ fn opts<'a>(machine_path: &'a std::path::Path, machine_prefs: &'a MachinePrefs)
     SyncOptions {
         dry_run: false,
         force: false,
+        adopt: false,
         no_triage: true,
         no_input: true,
         no_install: true,
@@ -136,6 +137,18 @@ fn opts<'a>(machine_path: &'a std::path::Path, machine_prefs: &'a MachinePrefs)
         machine_path,
         machine_prefs,
         start_stage: None,
+        dump_plan_on_error: false,
+        no_cache: false,
+        stats: false,
+        only: &[],
+        exclude_sources: &[],
+        tags: &[],
+        tag_mode: Default::default(),
+        prune_disabled: false,
+        targets_only: false,
+        parallel: 1,
+        json: false,
+        library_git_commit: None,
     }
 }
 